@@ -0,0 +1,36 @@
+// Serves the Dear ImGui demo window to a remote_viewer, without a window or a GPU
+//
+// Usage: remote_server [address], the default address is 127.0.0.1:7007.
+
+use imgui_sdl3_sys::imgui_sys;
+use imgui_sdl3_sys::remote::RemoteServer;
+use imgui_sdl3_sys::safe::Context;
+use std::time::Duration;
+
+fn main() {
+    let addr = std::env::args().nth(1).unwrap_or_else(|| "127.0.0.1:7007".to_string());
+
+    let mut ctx = Context::new();
+    unsafe { (*imgui_sys::igGetIO()).IniFilename = std::ptr::null() };
+    let mut server = RemoteServer::bind(&ctx, addr.as_str(), 1280.0, 800.0).unwrap_or_else(|e| {
+        eprintln!("Error: can't listen on {addr}: {e}");
+        std::process::exit(1);
+    });
+    println!("Serving the demo window on {addr}, connect with remote_viewer");
+
+    let mut counter = 0;
+    loop {
+        let ui = server.new_frame(&mut ctx);
+        unsafe { imgui_sys::igShowDemoWindow(std::ptr::null_mut()) };
+        if let Some(_window) = ui.begin("Hello, viewer!") {
+            if ui.button("Button") {
+                counter += 1;
+            }
+            ui.text(format_args!("counter = {counter}"));
+        }
+        server.render(&ui.render());
+
+        // The frames run without vsync
+        std::thread::sleep(Duration::from_millis(16));
+    }
+}
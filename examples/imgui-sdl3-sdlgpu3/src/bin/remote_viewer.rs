@@ -0,0 +1,213 @@
+// Dear ImGui remote viewer for SDL3 + SDL_GPU
+//
+// Draws the UI of a remote_server (or any application serving its UI with imgui_sdl3_sys::remote::RemoteServer)
+// and sends it the inputs of the window. Usage: remote_viewer [address], the default address is 127.0.0.1:7007.
+
+use imgui_sdl3_sys::imgui_sys;
+use imgui_sdl3_sys::remote::{RemoteInput, RemoteViewer};
+use imgui_sdl3_sys::safe::DrawData;
+use imgui_sdl3_sys::sdl3_sys::everything::*;
+use std::ptr;
+use std::time::{Duration, Instant};
+
+fn main() {
+    let addr = std::env::args().nth(1).unwrap_or_else(|| "127.0.0.1:7007".to_string());
+
+    unsafe {
+        // Setup SDL
+        if !SDL_Init(SDL_INIT_VIDEO | SDL_INIT_GAMEPAD) {
+            eprintln!("Error: SDL_Init(): {}",
+                std::ffi::CStr::from_ptr(SDL_GetError()).to_string_lossy());
+            std::process::exit(1);
+        }
+
+        // Create SDL window graphics context
+        let main_scale = SDL_GetDisplayContentScale(SDL_GetPrimaryDisplay());
+        let window_flags = SDL_WINDOW_RESIZABLE | SDL_WINDOW_HIDDEN | SDL_WINDOW_HIGH_PIXEL_DENSITY;
+        let window = SDL_CreateWindow(
+            c"Dear ImGui remote viewer".as_ptr(),
+            (1280.0 * main_scale) as i32,
+            (800.0 * main_scale) as i32,
+            window_flags,
+        );
+        if window.is_null() {
+            eprintln!("Error: SDL_CreateWindow(): {}",
+                std::ffi::CStr::from_ptr(SDL_GetError()).to_string_lossy());
+            std::process::exit(1);
+        }
+        SDL_SetWindowPosition(window, SDL_WINDOWPOS_CENTERED, SDL_WINDOWPOS_CENTERED);
+        SDL_ShowWindow(window);
+
+        // Create GPU Device
+        let gpu_device = SDL_CreateGPUDevice(
+            SDL_GPU_SHADERFORMAT_SPIRV | SDL_GPU_SHADERFORMAT_DXIL |
+            SDL_GPU_SHADERFORMAT_MSL | SDL_GPU_SHADERFORMAT_METALLIB,
+            true,
+            ptr::null(),
+        );
+        if gpu_device.is_null() {
+            eprintln!("Error: SDL_CreateGPUDevice(): {}",
+                std::ffi::CStr::from_ptr(SDL_GetError()).to_string_lossy());
+            std::process::exit(1);
+        }
+
+        // Claim window for GPU Device
+        if !SDL_ClaimWindowForGPUDevice(gpu_device, window) {
+            eprintln!("Error: SDL_ClaimWindowForGPUDevice(): {}",
+                std::ffi::CStr::from_ptr(SDL_GetError()).to_string_lossy());
+            std::process::exit(1);
+        }
+        SDL_SetGPUSwapchainParameters(
+            gpu_device,
+            window,
+            SDL_GPU_SWAPCHAINCOMPOSITION_SDR,
+            SDL_GPU_PRESENTMODE_VSYNC,
+        );
+
+        // Setup Dear ImGui context, the local one only draws the remote UI and the connection status
+        imgui_sys::igCreateContext(ptr::null_mut());
+        let io = imgui_sys::igGetIO();
+        (*io).IniFilename = ptr::null();
+        imgui_sys::igStyleColorsDark(ptr::null_mut());
+        (*imgui_sys::igGetStyle()).FontScaleDpi = main_scale;
+
+        // Setup Platform/Renderer backends
+        imgui_sys::sdl3::cImGui_ImplSDL3_InitForSDLGPU(window as *mut _);
+        let mut init_info = imgui_sys::sdl3::cImGui_ImplSDLGPU3_InitInfo {
+            Device: gpu_device,
+            ColorTargetFormat: SDL_GetGPUSwapchainTextureFormat(gpu_device, window),
+            MSAASamples: SDL_GPU_SAMPLECOUNT_1,
+            SwapchainComposition: SDL_GPU_SWAPCHAINCOMPOSITION_SDR,
+            PresentMode: SDL_GPU_PRESENTMODE_VSYNC,
+        };
+        imgui_sys::sdl3::cImGui_ImplSDLGPU3_Init(&mut init_info);
+
+        let mut viewer: Option<RemoteViewer> = None;
+        let mut last_attempt: Option<Instant> = None;
+        let mut status = format!("Connecting to {addr}...");
+
+        // Main loop
+        let mut done = false;
+        let mut event = SDL_Event::default();
+
+        while !done {
+            // Poll and handle events
+            while SDL_PollEvent(&mut event) {
+                imgui_sys::sdl3::cImGui_ImplSDL3_ProcessEvent(&event as *const _ as *const _);
+                if event.r#type == SDL_EVENT_QUIT {
+                    done = true;
+                }
+                if event.r#type == SDL_EVENT_WINDOW_CLOSE_REQUESTED
+                    && event.window.windowID == SDL_GetWindowID(window) {
+                    done = true;
+                }
+            }
+
+            // Skip rendering when minimized
+            if SDL_GetWindowFlags(window) & SDL_WINDOW_MINIMIZED != 0 {
+                SDL_Delay(10);
+                continue;
+            }
+
+            // Connect to the server, retrying every second
+            if viewer.is_none() && last_attempt.is_none_or(|last| last.elapsed() >= Duration::from_secs(1)) {
+                last_attempt = Some(Instant::now());
+                match RemoteViewer::connect(addr.as_str()) {
+                    Ok(connected) => viewer = Some(connected),
+                    Err(e) => status = format!("Connecting to {addr}... ({e})"),
+                }
+            }
+
+            // Start the Dear ImGui frame
+            imgui_sys::sdl3::cImGui_ImplSDLGPU3_NewFrame();
+            imgui_sys::sdl3::cImGui_ImplSDL3_NewFrame();
+            imgui_sys::igNewFrame();
+
+            // Send the inputs of this frame, and draw the last remote frame behind the local windows
+            if let Some(remote) = &mut viewer {
+                let result = remote.send_input(&RemoteInput::from_io(&*io)).and_then(|()| remote.poll());
+                if let Err(e) = result {
+                    status = format!("Disconnected from {addr} ({e})");
+                    let mut remote = viewer.take().unwrap();
+                    SDL_WaitForGPUIdle(gpu_device);
+                    remote.destroy_textures(|tex| imgui_sys::sdl3::cImGui_ImplSDLGPU3_UpdateTexture(tex));
+                } else {
+                    remote.draw(imgui_sys::igGetBackgroundDrawList());
+                }
+            }
+            if viewer.is_none() {
+                imgui_sys::igBegin(c"Remote viewer".as_ptr(), ptr::null_mut(), 0);
+                text_unformatted(&status);
+                imgui_sys::igEnd();
+            }
+
+            // Rendering
+            imgui_sys::igRender();
+            let mut draw_data = DrawData::from_raw(imgui_sys::igGetDrawData());
+            if let Some(remote) = &mut viewer {
+                // The remote textures are created and updated with the ones of the local context
+                remote.prepare_draw_data(&mut draw_data);
+            }
+            let is_minimized = draw_data.DisplaySize.x <= 0.0 || draw_data.DisplaySize.y <= 0.0;
+
+            let command_buffer = SDL_AcquireGPUCommandBuffer(gpu_device);
+
+            let mut swapchain_texture: *mut SDL_GPUTexture = ptr::null_mut();
+            SDL_WaitAndAcquireGPUSwapchainTexture(
+                command_buffer,
+                window,
+                &mut swapchain_texture,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+
+            if !swapchain_texture.is_null() && !is_minimized {
+                imgui_sys::sdl3::cImGui_ImplSDLGPU3_PrepareDrawData(draw_data.as_mut_ptr(), command_buffer);
+
+                let target_info = SDL_GPUColorTargetInfo {
+                    texture: swapchain_texture,
+                    clear_color: SDL_FColor { r: 0.1, g: 0.1, b: 0.1, a: 1.0 },
+                    load_op: SDL_GPU_LOADOP_CLEAR,
+                    store_op: SDL_GPU_STOREOP_STORE,
+                    mip_level: 0,
+                    layer_or_depth_plane: 0,
+                    cycle: false,
+                    ..Default::default()
+                };
+                let render_pass = SDL_BeginGPURenderPass(command_buffer, &target_info, 1, ptr::null());
+
+                imgui_sys::sdl3::cImGui_ImplSDLGPU3_RenderDrawData(
+                    draw_data.as_mut_ptr(),
+                    command_buffer,
+                    render_pass,
+                    ptr::null_mut(),
+                );
+
+                SDL_EndGPURenderPass(render_pass);
+            }
+
+            // Submit the command buffer
+            SDL_SubmitGPUCommandBuffer(command_buffer);
+        }
+
+        // Cleanup, the remote textures are destroyed before the renderer backend
+        SDL_WaitForGPUIdle(gpu_device);
+        if let Some(mut remote) = viewer.take() {
+            remote.destroy_textures(|tex| imgui_sys::sdl3::cImGui_ImplSDLGPU3_UpdateTexture(tex));
+        }
+        imgui_sys::sdl3::cImGui_ImplSDL3_Shutdown();
+        imgui_sys::sdl3::cImGui_ImplSDLGPU3_Shutdown();
+        imgui_sys::igDestroyContext(ptr::null_mut());
+
+        SDL_ReleaseWindowFromGPUDevice(gpu_device, window);
+        SDL_DestroyGPUDevice(gpu_device);
+        SDL_DestroyWindow(window);
+        SDL_Quit();
+    }
+}
+
+// Draws a Rust string with the begin/end overload, no NUL-terminated copy needed
+unsafe fn text_unformatted(text: &str) {
+    let range = text.as_bytes().as_ptr_range();
+    unsafe { imgui_sys::igTextUnformattedEx(range.start.cast(), range.end.cast()) };
+}
@@ -5,9 +5,9 @@
 
 // Use imgui_sys for all ImGui bindings (core + backends)
 use imgui_sdl3_sys::imgui_sys;
+use imgui_sdl3_sys::imgui_sys::enums::ConfigFlags;
 // Use the re-exported sdl3_sys from imgui_sdl3_sys for version consistency
 use imgui_sdl3_sys::sdl3_sys::everything::*;
-use std::ffi::CString;
 use std::ptr;
 
 fn main() {
@@ -22,9 +22,8 @@ fn main() {
         // Create SDL window graphics context
         let main_scale = SDL_GetDisplayContentScale(SDL_GetPrimaryDisplay());
         let window_flags = SDL_WINDOW_RESIZABLE | SDL_WINDOW_HIDDEN | SDL_WINDOW_HIGH_PIXEL_DENSITY;
-        let title = CString::new("Dear ImGui SDL3+SDL_GPU example").unwrap();
         let window = SDL_CreateWindow(
-            title.as_ptr(),
+            c"Dear ImGui SDL3+SDL_GPU example".as_ptr(),
             (1280.0 * main_scale) as i32,
             (800.0 * main_scale) as i32,
             window_flags,
@@ -66,8 +65,13 @@ fn main() {
         // Setup Dear ImGui context
         imgui_sys::igCreateContext(ptr::null_mut());
         let io = imgui_sys::igGetIO();
-        (*io).ConfigFlags |= imgui_sys::ImGuiConfigFlags__ImGuiConfigFlags_NavEnableKeyboard as i32;
-        (*io).ConfigFlags |= imgui_sys::ImGuiConfigFlags__ImGuiConfigFlags_NavEnableGamepad as i32;
+        (*io).ConfigFlags |= ConfigFlags::NavEnableKeyboard;     // Enable Keyboard Controls
+        (*io).ConfigFlags |= ConfigFlags::NavEnableGamepad;      // Enable Gamepad Controls
+        #[cfg(feature = "docking")]
+        {
+            (*io).ConfigFlags |= ConfigFlags::DockingEnable;     // Enable Docking
+            (*io).ConfigFlags |= ConfigFlags::ViewportsEnable;   // Enable Multi-Viewport / Platform Windows
+        }
 
         // Setup Dear ImGui style
         imgui_sys::igStyleColorsDark(ptr::null_mut());
@@ -76,14 +80,25 @@ fn main() {
         let style = imgui_sys::igGetStyle();
         imgui_sys::ImGuiStyle_ScaleAllSizes(style, main_scale);
         (*style).FontScaleDpi = main_scale;
+        #[cfg(feature = "docking")]
+        {
+            (*io).ConfigDpiScaleFonts = true;
+            (*io).ConfigDpiScaleViewports = true;
+
+            // When viewports are enabled we tweak WindowRounding/WindowBg so platform windows can look identical to regular ones
+            if ConfigFlags((*io).ConfigFlags).contains(ConfigFlags::ViewportsEnable) {
+                (*style).WindowRounding = 0.0;
+                (*style).Colors[imgui_sys::enums::Col::WindowBg as usize].w = 1.0;
+            }
+        }
 
         // Setup Platform/Renderer backends
         imgui_sys::sdl3::cImGui_ImplSDL3_InitForSDLGPU(window as *mut _);
         let mut init_info = imgui_sys::sdl3::cImGui_ImplSDLGPU3_InitInfo {
             Device: gpu_device,
             ColorTargetFormat: SDL_GetGPUSwapchainTextureFormat(gpu_device, window),
-            MSAASamples: SDL_GPU_SAMPLECOUNT_1,
-            SwapchainComposition: SDL_GPU_SWAPCHAINCOMPOSITION_SDR,
+            MSAASamples: SDL_GPU_SAMPLECOUNT_1,                         // Only used in multi-viewports mode
+            SwapchainComposition: SDL_GPU_SWAPCHAINCOMPOSITION_SDR,     // Only used in multi-viewports mode
             PresentMode: SDL_GPU_PRESENTMODE_VSYNC,
         };
         imgui_sys::sdl3::cImGui_ImplSDLGPU3_Init(&mut init_info);
@@ -140,38 +155,29 @@ fn main() {
                 let mut f = f32::from_bits(F_BITS.load(Ordering::Relaxed));
                 let mut counter = COUNTER.load(Ordering::Relaxed);
 
-                let window_title = CString::new("Hello, world!").unwrap();
-                imgui_sys::igBegin(window_title.as_ptr(), ptr::null_mut(), 0);
+                imgui_sys::igBegin(c"Hello, world!".as_ptr(), ptr::null_mut(), 0);
 
-                let text = CString::new("This is some useful text.").unwrap();
-                imgui_sys::igText(text.as_ptr());
+                imgui_sys::igText(c"This is some useful text.".as_ptr());
 
-                let demo_label = CString::new("Demo Window").unwrap();
-                imgui_sys::igCheckbox(demo_label.as_ptr(), &mut show_demo_window);
+                imgui_sys::igCheckbox(c"Demo Window".as_ptr(), &mut show_demo_window);
 
-                let another_label = CString::new("Another Window").unwrap();
-                imgui_sys::igCheckbox(another_label.as_ptr(), &mut show_another_window);
+                imgui_sys::igCheckbox(c"Another Window".as_ptr(), &mut show_another_window);
 
-                let slider_label = CString::new("float").unwrap();
-                imgui_sys::igSliderFloat(slider_label.as_ptr(), &mut f, 0.0, 1.0);
+                imgui_sys::igSliderFloat(c"float".as_ptr(), &mut f, 0.0, 1.0);
 
-                let color_label = CString::new("clear color").unwrap();
-                imgui_sys::igColorEdit3(color_label.as_ptr(), &mut clear_color.x, 0);
+                imgui_sys::igColorEdit3(c"clear color".as_ptr(), &mut clear_color.x, 0);
 
-                let button_label = CString::new("Button").unwrap();
-                if imgui_sys::igButton(button_label.as_ptr()) {
+                if imgui_sys::igButton(c"Button".as_ptr()) {
                     counter += 1;
                 }
                 imgui_sys::igSameLine();
-                let counter_text = CString::new(format!("counter = {}", counter)).unwrap();
-                imgui_sys::igText(counter_text.as_ptr());
+                text_unformatted(&format!("counter = {}", counter));
 
-                let fps_text = CString::new(format!(
+                text_unformatted(&format!(
                     "Application average {:.3} ms/frame ({:.1} FPS)",
                     1000.0 / (*io).Framerate,
                     (*io).Framerate
-                )).unwrap();
-                imgui_sys::igText(fps_text.as_ptr());
+                ));
 
                 imgui_sys::igEnd();
 
@@ -181,14 +187,11 @@ fn main() {
 
             // 3. Show another simple window
             if show_another_window {
-                let another_title = CString::new("Another Window").unwrap();
-                imgui_sys::igBegin(another_title.as_ptr(), &mut show_another_window, 0);
+                imgui_sys::igBegin(c"Another Window".as_ptr(), &mut show_another_window, 0);
 
-                let hello_text = CString::new("Hello from another window!").unwrap();
-                imgui_sys::igText(hello_text.as_ptr());
+                imgui_sys::igText(c"Hello from another window!".as_ptr());
 
-                let close_label = CString::new("Close Me").unwrap();
-                if imgui_sys::igButton(close_label.as_ptr()) {
+                if imgui_sys::igButton(c"Close Me".as_ptr()) {
                     show_another_window = false;
                 }
 
@@ -244,6 +247,10 @@ fn main() {
                 SDL_EndGPURenderPass(render_pass);
             }
 
+            // Update and Render additional Platform Windows (secondary viewports each get their own SDL window)
+            #[cfg(feature = "docking")]
+            imgui_sys::sdl3::cImGui_ImplSDL3_UpdateAndRenderPlatformWindows();
+
             // Submit the command buffer
             SDL_SubmitGPUCommandBuffer(command_buffer);
         }
@@ -260,3 +267,9 @@ fn main() {
         SDL_Quit();
     }
 }
+
+// Draws a Rust string with the begin/end overload, no NUL-terminated copy needed
+unsafe fn text_unformatted(text: &str) {
+    let range = text.as_bytes().as_ptr_range();
+    unsafe { imgui_sys::igTextUnformattedEx(range.start.cast(), range.end.cast()) };
+}
@@ -0,0 +1,483 @@
+// Generates the safe Ui functions of imgui-sdl3-sys (src/safe/generated.rs) from cimgui.json
+//
+// Run after bumping dcimgui, the output is checked in:
+//     cargo run -p imgui-safe-gen [-- <cimgui.json> <output.rs>]
+//
+// Only the free functions whose arguments all have a safe mapping are generated:
+// - `const char*` is `impl Label`, or `Option<&str>` when it defaults to NULL
+// - a `const char*` begin/end pair (`str_id_begin`/`str_id_end`, `text`/`text_end`) is one `impl Label`, the end
+//   pointer being the end of the same string
+// - `bool*`, `int*`, `float*`... out-pointers are `&mut`, or `Option<&mut>` when they default to NULL
+// - `float[3]`... arrays are `&mut [f32; 3]`
+// - flags and enums are the typed ones of imgui_sys::enums
+// - arguments with a default value are `Option`s, `None` being the default, except in the default argument
+//   helpers (`igButton` for `igButtonEx`...) which leave them out
+// printf formats, callbacks, void/struct pointers and texture refs have no safe mapping. The Begin/End, Push/Pop
+// and text functions are written by hand in the safe module, with tokens and without format strings.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+/// Functions written by hand in the safe module, or which don't belong to a frame, by C++ name prefix
+const HANDWRITTEN: &[&str] = &[
+    "Begin", "End", "Push", "Pop", "Text", "TreeNode", "TreePush", "TreePop", "LabelText", "BulletText", "SetTooltip",
+    "SetItemTooltip", "LogText", "CalcTextSize", "CreateContext", "DestroyContext", "GetCurrentContext",
+    "SetCurrentContext", "NewFrame", "EndFrame", "Render", "GetDrawData", "MemAlloc", "MemFree",
+    "SetAllocatorFunctions", "GetAllocatorFunctions", "DebugCheckVersionAndDataLayout", "DebugLog", "Combo",
+];
+
+/// Typedefs passed as is
+const RAW_TYPEDEFS: &[&str] = &["ImGuiID", "ImU32", "ImGuiKeyChord"];
+
+const KEYWORDS: &[&str] = &["as", "fn", "in", "mod", "move", "ref", "type", "use", "where", "impl", "loop", "match", "self"];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut args = std::env::args_os().skip(1);
+    let json_path = args.next().map_or_else(|| PathBuf::from(imgui_src::SOURCE_DIR).join("cimgui.json"), PathBuf::from);
+    let output_path = args.next().map_or_else(
+        || manifest_dir.join("../imgui-sdl3-sys/src/safe/generated.rs"),
+        PathBuf::from,
+    );
+
+    let json: Value = serde_json::from_str(&std::fs::read_to_string(&json_path)?)?;
+    let output = generate(&json)?;
+    std::fs::write(&output_path, output)?;
+    eprintln!("Generated {}", output_path.display());
+    Ok(())
+}
+
+/// Typed flags and enums of imgui_sys::enums, by typedef name (`ImGuiWindowFlags`, `ImGuiKey`...)
+struct TypedEnum {
+    path: String,
+    is_flags: bool,
+    // First name of each value, to translate the default values
+    names: Vec<(i64, String)>,
+}
+
+/// Same naming as the build script: `ImGuiWindowFlags_` is `WindowFlags`, `ImGuiWindowFlags_NoTitleBar` is `NoTitleBar`
+fn typed_enums(json: &Value) -> HashMap<String, TypedEnum> {
+    let mut enums = HashMap::new();
+    for json_enum in json["enums"].as_array().into_iter().flatten() {
+        let name = json_enum["name"].as_str().unwrap_or_default();
+        let base = name.trim_end_matches('_');
+        let type_name = base.strip_prefix("ImGui").or_else(|| base.strip_prefix("Im")).unwrap_or(base);
+        let is_flags = json_enum["is_flags_enum"].as_bool() == Some(true);
+        let mut names: Vec<(i64, String)> = Vec::new();
+        for element in json_enum["elements"].as_array().into_iter().flatten() {
+            let (Some(element_name), Some(value)) = (element["name"].as_str(), element["value"].as_i64()) else {
+                continue;
+            };
+            let mut element_name = element_name
+                .strip_prefix(base)
+                .and_then(|n| n.strip_prefix('_'))
+                .or_else(|| element_name.strip_prefix("ImGui"))
+                .or_else(|| element_name.strip_prefix("Im"))
+                .unwrap_or(element_name)
+                .to_string();
+            if element_name.starts_with(|c: char| c.is_ascii_digit()) {
+                element_name.insert(0, '_');
+            }
+            // Markers aren't variants of the enums
+            let marker = element["is_count"].as_bool() == Some(true)
+                || element_name.ends_with('_')
+                || matches!(element_name.rsplit('_').next(), Some("BEGIN" | "END" | "COUNT"));
+            if !marker && !names.iter().any(|(v, _)| *v == value) {
+                names.push((value, element_name));
+            }
+        }
+        let typed = TypedEnum { path: format!("enums::{type_name}"), is_flags, names };
+        enums.insert(base.to_string(), typed);
+    }
+    enums
+}
+
+/// How an argument is passed
+enum Kind {
+    /// `impl Label`
+    Label,
+    /// `impl Label` passed as begin/end pointers
+    Range,
+    /// End pointer of the previous argument, merged into a `Range`
+    RangeEnd,
+    /// `Option<&str>`, NULL if `None`
+    OptionalLabel,
+    /// By value, with the expression converting it to the C type
+    Value(&'static str),
+    /// `&mut T`
+    Pointer,
+    /// `Option<&mut T>`, NULL if `None`
+    OptionalPointer,
+    /// `&mut [T; N]`
+    Array,
+}
+
+struct Param {
+    name: String,
+    ty: String,
+    kind: Kind,
+    /// Rust expression of the C default value, if the argument has one
+    default: Option<String>,
+}
+
+fn builtin(declaration: &str) -> Option<&'static str> {
+    Some(match declaration {
+        "bool" => "bool",
+        "int" => "i32",
+        "unsigned int" => "u32",
+        "float" => "f32",
+        "double" => "f64",
+        _ => return None,
+    })
+}
+
+/// Rust expression of a C default value, `None` if it can't be translated
+fn default_value(ty: &str, default: &str, typed: Option<&TypedEnum>) -> Option<String> {
+    let float = |value: &str, float_type: &str| -> Option<String> {
+        let value = value.trim();
+        let (sign, value) = match value.strip_prefix('-') {
+            Some(value) => ("-", value),
+            None => ("", value.strip_prefix('+').unwrap_or(value)),
+        };
+        let value = match value {
+            "FLT_MAX" => format!("{float_type}::MAX"),
+            "FLT_MIN" => format!("{float_type}::MIN_POSITIVE"),
+            _ => {
+                let value = value.strip_suffix('f').unwrap_or(value);
+                value.parse::<f64>().ok()?;
+                if value.contains('.') { value.to_string() } else { format!("{value}.0") }
+            }
+        };
+        Some(format!("{sign}{value}"))
+    };
+    let vector = |default: &str, fields: &[&str]| -> Option<String> {
+        let args = default.strip_suffix(')')?;
+        let values: Vec<&str> = args.split(',').collect();
+        if values.len() != fields.len() {
+            return None;
+        }
+        let fields: Option<Vec<String>> =
+            fields.iter().zip(values).map(|(field, value)| Some(format!("{field}: {}", float(value, "f32")?))).collect();
+        Some(fields?.join(", "))
+    };
+
+    if let Some(typed) = typed {
+        let value: i64 = default.parse().ok()?;
+        let name = &typed.names.iter().find(|(v, _)| *v == value)?.1;
+        return Some(format!("{}::{name}", typed.path));
+    }
+    match ty {
+        "bool" => matches!(default, "true" | "false").then(|| default.to_string()),
+        "f32" | "f64" => float(default, ty),
+        "imgui_sys::ImVec2" => Some(format!("imgui_sys::ImVec2 {{ {} }}", vector(default.strip_prefix("ImVec2(")?, &["x", "y"])?)),
+        "imgui_sys::ImVec4" => {
+            Some(format!("imgui_sys::ImVec4 {{ {} }}", vector(default.strip_prefix("ImVec4(")?, &["x", "y", "z", "w"])?))
+        }
+        _ => default.parse::<i64>().ok().map(|value| value.to_string()),
+    }
+}
+
+/// Safe mapping of an argument, `None` if there is none
+fn param(argument: &Value, enums: &HashMap<String, TypedEnum>) -> Option<Param> {
+    let name = argument["name"].as_str()?;
+    let declaration = argument["type"]["declaration"].as_str()?;
+    let default = argument["default_value"].as_str();
+    let name = if KEYWORDS.contains(&name) { format!("r#{name}") } else { name.to_string() };
+    // printf formats can't be checked
+    if name == "format" || argument["is_varargs"].as_bool() == Some(true) {
+        return None;
+    }
+    let param = |ty: String, kind: Kind| Some(Param { name: name.clone(), ty, kind, default: None });
+
+    // Dear ImGui reads the begin string up to the end pointer, they can't be separate strings
+    if declaration == "const char*" && name.ends_with("_end") {
+        return param(String::new(), Kind::RangeEnd);
+    }
+
+    if default == Some("NULL") {
+        return match declaration {
+            "const char*" => param("Option<&str>".into(), Kind::OptionalLabel),
+            _ => param(format!("Option<&mut {}>", builtin(declaration.strip_suffix('*')?)?), Kind::OptionalPointer),
+        };
+    }
+
+    let (ty, kind, typed) = if declaration == "const char*" {
+        ("impl Label".to_string(), Kind::Label, None)
+    } else if let Some(ty) = builtin(declaration) {
+        (ty.to_string(), Kind::Value(""), None)
+    } else if let Some(inner) = declaration.strip_suffix('*') {
+        (format!("&mut {}", builtin(inner)?), Kind::Pointer, None)
+    } else if let Some((inner, len)) = declaration.strip_suffix(']').and_then(|d| d.split_once('[')) {
+        len.parse::<usize>().ok()?;
+        (format!("&mut [{}; {len}]", builtin(inner)?), Kind::Array, None)
+    } else if matches!(declaration, "ImVec2" | "ImVec4") {
+        (format!("imgui_sys::{declaration}"), Kind::Value(""), None)
+    } else if let Some(typed) = enums.get(declaration) {
+        let conversion = if typed.is_flags { ".bits()" } else { ".raw()" };
+        (typed.path.clone(), Kind::Value(conversion), Some(typed))
+    } else if RAW_TYPEDEFS.contains(&declaration) {
+        (format!("imgui_sys::{declaration}"), Kind::Value(""), None)
+    } else {
+        return None;
+    };
+    let default = match default {
+        Some(default) => Some(default_value(&ty, default, typed)?),
+        None => None,
+    };
+    Some(Param { name, ty, kind, default })
+}
+
+/// Merges the end pointers into the begin arguments, `None` if an end pointer doesn't follow its begin argument
+fn pair_ranges(params: Vec<Param>) -> Option<Vec<Param>> {
+    let mut paired: Vec<Param> = Vec::with_capacity(params.len());
+    for p in params {
+        if !matches!(p.kind, Kind::RangeEnd) {
+            paired.push(p);
+            continue;
+        }
+        let base = p.name.strip_suffix("_end")?;
+        let begin = paired.last_mut().filter(|begin| matches!(begin.kind, Kind::Label) && begin.default.is_none())?;
+        if begin.name != base && begin.name.strip_suffix("_begin") != Some(base) {
+            return None;
+        }
+        begin.name = base.to_string();
+        begin.kind = Kind::Range;
+    }
+    Some(paired)
+}
+
+/// Return type and the conversion of the C return value
+fn return_type(declaration: &str, enums: &HashMap<String, TypedEnum>) -> Option<(String, Option<String>)> {
+    Some(match declaration {
+        "void" => (String::new(), None),
+        "ImVec2" | "ImVec4" => (format!("imgui_sys::{declaration}"), None),
+        _ => match (builtin(declaration), enums.get(declaration)) {
+            (Some(ty), _) => (ty.to_string(), None),
+            (None, Some(typed)) if typed.is_flags => (typed.path.clone(), Some(typed.path.clone())),
+            (None, Some(_)) => (format!("imgui_sys::{declaration}"), None),
+            _ if RAW_TYPEDEFS.contains(&declaration) => (format!("imgui_sys::{declaration}"), None),
+            _ => return None,
+        },
+    })
+}
+
+/// `GetCursorPos` is `get_cursor_pos`, `GetIDStr` is `get_id_str`, `ColorEdit3` is `color_edit3`
+fn snake_case(name: &str) -> String {
+    // "to" between acronyms: `ColorConvertRGBtoHSV` is `color_convert_rgb_to_hsv`
+    let name = name.replace("RGBto", "RgbTo").replace("HSVto", "HsvTo");
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if previous.is_ascii_lowercase() || previous.is_ascii_digit() || (previous.is_ascii_uppercase() && next_is_lower) {
+                snake.push('_');
+            }
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+fn comment(function: &Value) -> Option<&str> {
+    let attached = function["comments"]["attached"].as_str()?;
+    Some(attached.trim_start_matches('/').trim())
+}
+
+fn generate(json: &Value) -> Result<String, Box<dyn Error>> {
+    let enums = typed_enums(json);
+    let functions = json["functions"].as_array().ok_or("cimgui.json has no functions")?;
+
+    // The default argument helpers are commented with the defaults they imply, the full version has the description
+    let mut comments = HashMap::new();
+    for function in functions.iter().filter(|f| f["is_default_argument_helper"].as_bool() != Some(true)) {
+        if let (Some(name), Some(comment)) = (function["original_fully_qualified_name"].as_str(), comment(function)) {
+            comments.entry(name).or_insert(comment);
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str(
+        "// Generated by imgui-safe-gen from cimgui.json, do not edit
+// Regenerate with `cargo run -p imgui-safe-gen` after updating dcimgui
+//
+// The Dear ImGui functions whose arguments all have a safe mapping, see imgui-safe-gen for the mapping.
+// Arguments taking an `Option` use the Dear ImGui default value when `None`.
+
+#![allow(clippy::too_many_arguments)]
+
+use core::ptr;
+
+use super::label::WriteText;
+use super::{Label, Ui};
+use crate::imgui_sys;
+use crate::imgui_sys::enums;
+
+impl Ui<'_> {
+",
+    );
+
+    let mut count = 0;
+    for function in functions {
+        let flag = |key: &str| function[key].as_bool() == Some(true);
+        let name = function["name"].as_str().unwrap_or_default();
+        let cpp_name = function["original_fully_qualified_name"].as_str().unwrap_or_default();
+        let Some(short_name) = cpp_name.strip_prefix("ImGui::") else { continue };
+        let Some(c_name) = name.strip_prefix("ig") else { continue };
+        if flag("is_internal")
+            || flag("is_manual_helper")
+            || flag("is_imstr_helper")
+            || flag("is_unformatted_helper")
+            || !function["original_class"].is_null()
+            || function.get("conditionals").is_some()
+            || HANDWRITTEN.iter().any(|prefix| short_name.starts_with(prefix))
+        {
+            continue;
+        }
+
+        let Some(params) = function["arguments"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|argument| param(argument, &enums))
+            .collect::<Option<Vec<Param>>>()
+            .and_then(pair_ranges)
+        else {
+            continue;
+        };
+        let Some((return_type, return_conversion)) =
+            function["return_type"]["declaration"].as_str().and_then(|ty| return_type(ty, &enums))
+        else {
+            continue;
+        };
+
+        // Default argument helpers leave out the arguments with a default value
+        let helper = flag("is_default_argument_helper");
+        let mut signature = Vec::new();
+        let mut body = String::new();
+        let mut call_args = Vec::new();
+        // The pointers are resolved once all the texts are written, the scratch buffer may move while growing
+        let mut ranges = String::new();
+        let has_labels = params.iter().any(|p| matches!(p.kind, Kind::Label | Kind::Range | Kind::OptionalLabel));
+        // Both panic if the context of the frame isn't the current one
+        if has_labels {
+            body.push_str("        let scratch = self.scratch();\n");
+        } else {
+            body.push_str("        self.check_current();\n");
+        }
+        for p in &params {
+            let name = &p.name;
+            match (&p.default, helper) {
+                (Some(default), true) => {
+                    let _ = writeln!(body, "        let {name} = {default};");
+                }
+                (Some(default), false) => {
+                    signature.push(format!("{name}: Option<{}>", p.ty));
+                    let _ = writeln!(body, "        let {name} = {name}.unwrap_or({default});");
+                }
+                (None, _) => {
+                    if helper && matches!(p.kind, Kind::OptionalLabel | Kind::OptionalPointer) {
+                        let _ = writeln!(body, "        let {name}: {} = None;", p.ty);
+                    } else {
+                        signature.push(format!("{name}: {}", p.ty));
+                    }
+                }
+            }
+            call_args.push(match p.kind {
+                Kind::Label => {
+                    let _ = writeln!(body, "        let {name} = {name}.write_c_str(scratch);");
+                    format!("scratch.c_str({name})")
+                }
+                Kind::Range => {
+                    let _ = writeln!(body, "        let {name} = {name}.write_range(scratch);");
+                    let _ = writeln!(ranges, "        let ({name}_begin, {name}_end) = scratch.range({name});");
+                    format!("{name}_begin, {name}_end")
+                }
+                Kind::RangeEnd => unreachable!("merged by pair_ranges"),
+                Kind::OptionalLabel => {
+                    let _ = writeln!(body, "        let {name} = {name}.map(|{name}| {name}.write_c_str(scratch));");
+                    format!("{name}.map_or(ptr::null(), |{name}| scratch.c_str({name}))")
+                }
+                Kind::Value(conversion) => format!("{name}{conversion}"),
+                Kind::Pointer => name.clone(),
+                Kind::OptionalPointer => format!("{name}.map_or(ptr::null_mut(), ptr::from_mut)"),
+                Kind::Array => format!("{name}.as_mut_ptr()"),
+            });
+        }
+
+        body.push_str(&ranges);
+        let call = format!("unsafe {{ imgui_sys::{name}({}) }}", call_args.join(", "));
+        let call = match return_conversion {
+            Some(conversion) => format!("{conversion}({call})"),
+            None => call,
+        };
+        if count > 0 {
+            out.push('\n');
+        }
+        match comments.get(cpp_name) {
+            Some(comment) => {
+                let _ = writeln!(out, "    /// `{name}`: {comment}");
+            }
+            None => {
+                let _ = writeln!(out, "    /// `{name}`");
+            }
+        }
+        let signature = std::iter::once("&self".to_string()).chain(signature).collect::<Vec<_>>().join(", ");
+        let returns = if return_type.is_empty() { String::new() } else { format!(" -> {return_type}") };
+        let _ = writeln!(out, "    pub fn {}({signature}){returns} {{", snake_case(c_name));
+        out.push_str(&body);
+        let _ = writeln!(out, "        {call}");
+        out.push_str("    }\n");
+        count += 1;
+    }
+    out.push_str("}\n");
+    eprintln!("{count} functions");
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("GetCursorPos"), "get_cursor_pos");
+        assert_eq!(snake_case("GetIDStr"), "get_id_str");
+        assert_eq!(snake_case("ColorEdit3"), "color_edit3");
+        assert_eq!(snake_case("DragFloat2Ex"), "drag_float2_ex");
+        assert_eq!(snake_case("IsMouseHoveringRect"), "is_mouse_hovering_rect");
+        assert_eq!(snake_case("ColorConvertRGBtoHSV"), "color_convert_rgb_to_hsv");
+    }
+
+    #[test]
+    fn test_default_value() {
+        assert_eq!(default_value("f32", "1.0f", None).as_deref(), Some("1.0"));
+        assert_eq!(default_value("f32", "-FLT_MIN", None).as_deref(), Some("-f32::MIN_POSITIVE"));
+        assert_eq!(default_value("f32", "+360.0f", None).as_deref(), Some("360.0"));
+        assert_eq!(default_value("i32", "-1", None).as_deref(), Some("-1"));
+        assert_eq!(
+            default_value("imgui_sys::ImVec2", "ImVec2(-FLT_MIN, 0)", None).as_deref(),
+            Some("imgui_sys::ImVec2 { x: -f32::MIN_POSITIVE, y: 0.0 }")
+        );
+        assert_eq!(default_value("i32", "sizeof(float)", None), None);
+    }
+
+    #[test]
+    fn test_pair_ranges() {
+        let param = |name: &str, kind| Param { name: name.to_string(), ty: String::new(), kind, default: None };
+        let paired = pair_ranges(vec![param("str_id_begin", Kind::Label), param("str_id_end", Kind::RangeEnd)]).unwrap();
+        assert_eq!(paired.len(), 1);
+        assert_eq!(paired[0].name, "str_id");
+        assert!(matches!(paired[0].kind, Kind::Range));
+
+        let paired = pair_ranges(vec![param("text", Kind::Label), param("text_end", Kind::RangeEnd)]).unwrap();
+        assert_eq!(paired[0].name, "text");
+        assert!(pair_ranges(vec![param("label", Kind::Label), param("text_end", Kind::RangeEnd)]).is_none());
+        assert!(pair_ranges(vec![param("text_end", Kind::RangeEnd)]).is_none());
+    }
+}
@@ -1,11 +1,68 @@
-#[cfg(feature = "build-from-source")]
+#[cfg(all(any(feature = "build-from-source", feature = "bindgen"), not(feature = "docking")))]
 const SOURCE_DIR: &str = imgui_src::SOURCE_DIR;
+#[cfg(feature = "docking")]
+const SOURCE_DIR: &str = imgui_src::DOCKING_SOURCE_DIR;
+
+#[cfg(all(feature = "docking", feature = "internal"))]
+compile_error!("the `internal` feature isn't supported with the `docking` feature yet");
+
+// A shared imgui library can't call the assertion hook defined in the Rust executable
+#[cfg(all(feature = "assert-hook", not(feature = "link-static")))]
+compile_error!("the `assert-hook` feature needs the `link-static` feature");
 
 const LINK_FRAMEWORK: bool = cfg!(feature = "link-framework");
 
+/// imconfig.h options enabled by cargo features.
+/// They're defined for the CMake build and for every bindgen builder, so the layouts match.
+#[cfg(any(feature = "build-from-source", feature = "bindgen"))]
+const IMCONFIG_DEFINES: &[(&str, bool)] = &[
+    ("IMGUI_DISABLE_DEMO_WINDOWS", cfg!(feature = "disable-demo-windows")),
+    ("IMGUI_DISABLE_DEBUG_TOOLS", cfg!(feature = "disable-debug-tools")),
+    ("IMGUI_DISABLE_OBSOLETE_FUNCTIONS", cfg!(feature = "disable-obsolete-functions")),
+    ("IMGUI_USE_WCHAR32", cfg!(feature = "use-wchar32")),
+    ("IMGUI_USE_BGRA_PACKED_COLOR", cfg!(feature = "use-bgra-packed-color")),
+    ("IMGUI_DISABLE_DEFAULT_FONT", cfg!(feature = "disable-default-font")),
+    // Not an upstream option, added to imconfig.h to route IM_ASSERT to the assert_hook module
+    ("IMGUI_ASSERT_HOOK", cfg!(feature = "assert-hook")),
+];
+
+#[cfg(any(feature = "build-from-source", feature = "bindgen"))]
+fn imconfig_defines() -> impl Iterator<Item = &'static str> {
+    IMCONFIG_DEFINES
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(define, _)| *define)
+}
+
 include!("build-common.rs");
 
+/// Custom imconfig header from the `IMGUI_USER_CONFIG` env var.
+/// Like the imconfig.h options, it's defined for the CMake build and for every bindgen builder.
+#[cfg(any(feature = "build-from-source", feature = "bindgen"))]
+fn user_config() -> Result<Option<std::path::PathBuf>, Box<dyn Error>> {
+    println!("cargo::rerun-if-env-changed=IMGUI_USER_CONFIG");
+    let Some(path) = env::var_os("IMGUI_USER_CONFIG") else {
+        return Ok(None);
+    };
+    let path = std::path::absolute(&path)?;
+    if !path.is_file() {
+        return Err(format!("IMGUI_USER_CONFIG header not found: {}", path.display()).into());
+    }
+    println!("cargo::rerun-if-changed={}", path.display());
+    Ok(Some(path))
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
+    #[cfg(any(feature = "build-from-source", feature = "bindgen"))]
+    let user_config = user_config()?;
+
+    #[cfg(all(feature = "build-from-source", not(feature = "bindgen")))]
+    if user_config.is_some() {
+        println!(
+            "cargo::warning=IMGUI_USER_CONFIG is used with the pre-generated bindings, enable the `bindgen` feature if it changes any layout"
+        );
+    }
+
     build(|config| {
         let _ = config;
         #[cfg(feature = "build-from-source")]
@@ -28,6 +85,12 @@ fn main() -> Result<(), Box<dyn Error>> {
 
             config.define("SDLTTF_SAMPLES", "OFF");
 
+            // Always set, so disabling an option overrides the value in the CMake cache
+            for (define, enabled) in IMCONFIG_DEFINES {
+                config.define(define, if *enabled { "ON" } else { "OFF" });
+            }
+            config.define("IMGUI_USER_CONFIG", user_config.as_deref().unwrap_or("".as_ref()));
+
             // cmake_vars! { config =>
             //     SDLTTF_VENDORED,
             //     SDLTTF_HARFBUZZ,
@@ -44,27 +107,70 @@ fn main() -> Result<(), Box<dyn Error>> {
     #[cfg(all(not(target_vendor = "apple"), not(target_env = "msvc")))]
     println!("cargo::rustc-link-lib=stdc++");
 
-    #[cfg(feature = "build-from-source")]
-    generate_bindings()?;
+    // The `bindgen` feature generates the bindings into OUT_DIR,
+    // otherwise the pre-generated ones in src/bindings/<target> are used
+    #[cfg(feature = "bindgen")]
+    generate_bindings(user_config.as_deref())?;
+
+    #[cfg(not(feature = "bindgen"))]
+    {
+        let bindings_dir = bindings_dir()?;
+        if !bindings_dir.join("imgui.rs").exists() {
+            return Err(format!(
+                "no pre-generated bindings in {}, enable the `bindgen` feature to generate them for this target",
+                bindings_dir.display()
+            )
+            .into());
+        }
+        println!(
+            "cargo::rustc-env=IMGUI_SDL3_SYS_BINDINGS_DIR={}",
+            bindings_dir.display()
+        );
+    }
 
     Ok(())
 }
 
-#[cfg(feature = "build-from-source")]
-fn generate_bindings() -> Result<(), Box<dyn Error>> {
+/// Directory of the pre-generated bindings for the current target.
+/// Linux x86_64/aarch64 bindings are checked in, other targets need the `bindgen` feature.
+#[cfg(not(feature = "bindgen"))]
+fn bindings_dir() -> Result<std::path::PathBuf, Box<dyn Error>> {
+    let manifest_dir = std::path::PathBuf::from(env::var("CARGO_MANIFEST_DIR")?);
+    let target_os = env::var("CARGO_CFG_TARGET_OS")?;
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH")?;
+    let name = match (target_os.as_str(), target_arch.as_str()) {
+        // musl and gnu share the same layouts
+        ("linux", "x86_64" | "aarch64") => format!("{target_arch}-unknown-linux-gnu"),
+        _ => env::var("TARGET")?,
+    };
+    Ok(manifest_dir.join("src").join("bindings").join(name))
+}
+
+#[cfg(feature = "bindgen")]
+const VARIADIC_DOC: &str = "    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c\"%s\"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+";
+
+#[cfg(feature = "bindgen")]
+fn generate_bindings(user_config: Option<&std::path::Path>) -> Result<(), Box<dyn Error>> {
     use std::path::PathBuf;
 
     eprintln!("generate_bindings: START");
-    let _out_dir = env::var("OUT_DIR")?;
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
     let header_dir = PathBuf::from(SOURCE_DIR);
     let header_path = header_dir.join("cimgui_all.h");
     let backends_dir = header_dir.join("backends");
     let sdl3_backend_path = backends_dir.join("imgui_impl_sdl3.h");
     let sdlgpu3_backend_path = backends_dir.join("imgui_impl_sdlgpu3.h");
+    let sdlrenderer3_backend_path = backends_dir.join("imgui_impl_sdlrenderer3.h");
 
     println!("cargo::rerun-if-changed={}", header_path.display());
     println!("cargo::rerun-if-changed={}", sdl3_backend_path.display());
     println!("cargo::rerun-if-changed={}", sdlgpu3_backend_path.display());
+    println!("cargo::rerun-if-changed={}", sdlrenderer3_backend_path.display());
 
     eprintln!("Header path: {}", header_path.display());
     eprintln!("Header exists: {}", header_path.exists());
@@ -73,9 +179,15 @@ fn generate_bindings() -> Result<(), Box<dyn Error>> {
     eprintln!("SDL3 GPU backend path: {}", sdlgpu3_backend_path.display());
     eprintln!("SDL3 GPU backend exists: {}", sdlgpu3_backend_path.exists());
 
+    let mut imconfig_args: Vec<String> = imconfig_defines().map(|define| format!("-D{define}")).collect();
+    if let Some(path) = user_config {
+        imconfig_args.push(format!("-DIMGUI_USER_CONFIG=\"{}\"", path.display()));
+    }
+
     eprintln!("generate_bindings: Creating bindgen builder");
     let bindings = bindgen::Builder::default()
         .header(header_path.to_str().unwrap())
+        .clang_args(&imconfig_args)
         .clang_arg(format!("-I{}", header_dir.display()))
         .clang_arg(format!("-I{}", backends_dir.display()))
         .clang_arg("-xc++")
@@ -87,19 +199,14 @@ fn generate_bindings() -> Result<(), Box<dyn Error>> {
         .blocklist_file(".*imgui_internal\\.h")
         .blocklist_file(".*cimgui_internal\\.h")
         .blocklist_file(".*imstb.*\\.h")
+        // Declared by imconfig.h with IMGUI_ASSERT_HOOK, defined in Rust by the assert_hook module
+        .blocklist_function("cImGui_AssertFailed")
         .layout_tests(false)
         .use_core()
         .opaque_type("ImDrawListSharedData_t")
         .opaque_type("ImFontAtlasBuilder_t")
         .opaque_type("ImFontLoader_t")
         .opaque_type("ImGuiContext_t")
-        .raw_line("#![allow(non_upper_case_globals)]")
-        .raw_line("#![allow(non_camel_case_types)]")
-        .raw_line("#![allow(non_snake_case)]")
-        .raw_line("#![allow(dead_code)]")
-        .raw_line("#![allow(clippy::all)]")
-        .raw_line("#![allow(unsafe_op_in_unsafe_fn)]")
-        .raw_line("#![allow(unnecessary_transmutes)]")
         .generate()?;
 
     eprintln!("generate_bindings: Generating SDL3 backend bindings from C wrapper");
@@ -107,22 +214,16 @@ fn generate_bindings() -> Result<(), Box<dyn Error>> {
     let sdl3_wrapper_header = backends_dir.join("cimgui_impl_sdl3.h");
     let sdl3_bindings = bindgen::Builder::default()
         .header(sdl3_wrapper_header.to_str().unwrap())
+        .clang_args(&imconfig_args)
         .clang_arg(format!("-I{}", backends_dir.display()))
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         .allowlist_function("cImGui_ImplSDL3_.*")
         .allowlist_type("cImGui_ImplSDL3_.*")
         .allowlist_var("cImGui_ImplSDL3_.*")
-        .opaque_type("SDL_Window")
-        .opaque_type("SDL_Renderer")
-        .opaque_type("SDL_Gamepad")
-        .opaque_type("SDL_Event")
+        // SDL and ImGui types are the sdl3-sys and core ones, re-exported by the parent module
+        .blocklist_type("SDL_.*")
+        .blocklist_type("ImGuiViewport")
         .use_core()
-        .raw_line("#![allow(non_upper_case_globals)]")
-        .raw_line("#![allow(non_camel_case_types)]")
-        .raw_line("#![allow(non_snake_case)]")
-        .raw_line("#![allow(dead_code)]")
-        .raw_line("#![allow(clippy::all)]")
-        .raw_line("#![allow(unnecessary_transmutes)]")
         .raw_line("use super::*;")
         .generate()?;
 
@@ -192,31 +293,18 @@ fn generate_bindings() -> Result<(), Box<dyn Error>> {
     let sdlgpu3_wrapper_header = backends_dir.join("cimgui_impl_sdlgpu3.h");
     let mut sdlgpu3_builder = bindgen::Builder::default()
         .header(sdlgpu3_wrapper_header.to_str().unwrap())
+        .clang_args(&imconfig_args)
         .clang_arg(format!("-I{}", backends_dir.display()))
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         .allowlist_function("cImGui_ImplSDLGPU3_.*")
         .allowlist_type("cImGui_ImplSDLGPU3_.*")
         .allowlist_var("cImGui_ImplSDLGPU3_.*")
-        .opaque_type("SDL_GPUDevice")
-        .opaque_type("SDL_GPUCommandBuffer")
-        .opaque_type("SDL_GPURenderPass")
-        .opaque_type("SDL_GPUGraphicsPipeline")
-        .opaque_type("SDL_GPUSampler")
-        .opaque_type("SDL_GPUTextureFormat")
-        .opaque_type("SDL_GPUSampleCount")
-        .opaque_type("SDL_GPUSwapchainComposition")
-        .opaque_type("SDL_GPUPresentMode")
-        .opaque_type("ImDrawData")
-        .opaque_type("ImTextureData")
+        // SDL and ImGui types are the sdl3-sys and core ones, re-exported by the parent module
+        .blocklist_type("SDL_.*")
+        .blocklist_type("ImDrawData")
+        .blocklist_type("ImTextureData")
         .use_core()
         .derive_debug(false)  // Don't derive Debug since SDL GPU types don't implement it
-        .raw_line("#![allow(non_upper_case_globals)]")
-        .raw_line("#![allow(non_camel_case_types)]")
-        .raw_line("#![allow(non_snake_case)]")
-        .raw_line("#![allow(dead_code)]")
-        .raw_line("#![allow(clippy::all)]")
-        .raw_line("#![allow(unsafe_op_in_unsafe_fn)]")
-        .raw_line("#![allow(unnecessary_transmutes)]")
         .raw_line("use super::*;");
 
     // Add SDL3 include directory if available
@@ -230,30 +318,83 @@ fn generate_bindings() -> Result<(), Box<dyn Error>> {
 
     let sdlgpu3_bindings = sdlgpu3_builder.generate()?;
 
-    let manifest_dir = env::var("CARGO_MANIFEST_DIR")?;
-    let bindings_dir = PathBuf::from(manifest_dir).join("src/bindings");
-    std::fs::create_dir_all(&bindings_dir)?;
-
-    // Write main imgui bindings
-    let output_path = bindings_dir.join("imgui.rs");
-    bindings.write_to_file(&output_path)?;
+    // C wrapper is built by CMake in imgui-src, SDL_Renderer is only forward declared so no SDL3 headers needed
+    let sdlrenderer3_wrapper_header = backends_dir.join("cimgui_impl_sdlrenderer3.h");
+    let sdlrenderer3_bindings = bindgen::Builder::default()
+        .header(sdlrenderer3_wrapper_header.to_str().unwrap())
+        .clang_args(&imconfig_args)
+        .clang_arg(format!("-I{}", backends_dir.display()))
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .allowlist_function("cImGui_ImplSDLRenderer3_.*")
+        .allowlist_type("cImGui_ImplSDLRenderer3_.*")
+        .allowlist_var("cImGui_ImplSDLRenderer3_.*")
+        // SDL and ImGui types are the sdl3-sys and core ones, re-exported by the parent module
+        .blocklist_type("SDL_.*")
+        .blocklist_type("ImDrawData")
+        .blocklist_type("ImTextureData")
+        .use_core()
+        .raw_line("use super::*;")
+        .generate()?;
 
     // Write SDL3 backend bindings
-    let sdl3_output_path = bindings_dir.join("sdl3_backend.rs");
+    let sdl3_output_path = out_dir.join("sdl3_backend.rs");
     sdl3_bindings.write_to_file(&sdl3_output_path)?;
 
     // Write SDL3 GPU backend bindings
-    let sdlgpu3_output_path = bindings_dir.join("sdlgpu3_backend.rs");
+    let sdlgpu3_output_path = out_dir.join("sdlgpu3_backend.rs");
     sdlgpu3_bindings.write_to_file(&sdlgpu3_output_path)?;
 
-    // Post-process: Add missing opaque type definitions
-    let content = std::fs::read_to_string(&output_path)?;
-    let opaque_types = vec![
-        "ImDrawListSharedData_t",
-        "ImFontAtlasBuilder_t",
-        "ImFontLoader_t",
-        "ImGuiContext_t",
-    ];
+    // Write SDL3 Renderer backend bindings
+    let sdlrenderer3_output_path = out_dir.join("sdlrenderer3_backend.rs");
+    sdlrenderer3_bindings.write_to_file(&sdlrenderer3_output_path)?;
+
+    #[cfg(feature = "internal")]
+    {
+        // Only the items declared in cimgui_internal.h are generated, the public types come from the parent module
+        let internal_header_path = header_dir.join("cimgui_internal.h");
+        println!("cargo::rerun-if-changed={}", internal_header_path.display());
+        let internal_bindings = bindgen::Builder::default()
+            .header(header_path.to_str().unwrap())
+            .clang_args(&imconfig_args)
+            .clang_arg(format!("-I{}", header_dir.display()))
+            .clang_arg("-xc++")
+            .clang_arg("-DCIMGUI_DEFINE_ENUMS_AND_STRUCTS")
+            .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+            .allowlist_file(".*cimgui_internal\\.h")
+            .blocklist_file(".*cimgui\\.h")
+            .blocklist_file(".*imgui\\.h")
+            .blocklist_file(".*imconfig\\.h")
+            .blocklist_file(".*imstb.*\\.h")
+            // Typedefs repeated from cimgui.h, so a glob import of both modules isn't ambiguous
+            .blocklist_type("ImDrawListSharedData")
+            .blocklist_type("ImDrawTextFlags")
+            .blocklist_type("ImFontAtlasBuilder")
+            .blocklist_type("ImGuiContext")
+            .layout_tests(false)
+            .use_core()
+            .raw_line("use super::*;")
+            .generate()?;
+
+        // Write internal API bindings
+        let internal_output_path = out_dir.join("internal.rs");
+        let mut internal_result = internal_bindings.to_string();
+        internal_result.push_str(&im_vector_impls(&internal_result));
+        std::fs::write(&internal_output_path, internal_result)?;
+    }
+
+    // Post-process: Add missing opaque type definitions to the main imgui bindings.
+    // With the `internal` feature, they are defined by the internal bindings instead.
+    let content = bindings.to_string();
+    let opaque_types = if cfg!(feature = "internal") {
+        vec![]
+    } else {
+        vec![
+            "ImDrawListSharedData_t",
+            "ImFontAtlasBuilder_t",
+            "ImFontLoader_t",
+            "ImGuiContext_t",
+        ]
+    };
 
     let mut opaque_defs = String::new();
     for opaque_type in opaque_types {
@@ -265,7 +406,7 @@ fn generate_bindings() -> Result<(), Box<dyn Error>> {
         }
     }
 
-    // Insert opaque definitions after #![allow...] lines
+    // Insert opaque definitions after the header comment and any #![allow...] lines
     let lines: Vec<&str> = content.lines().collect();
     let mut result = String::new();
     let mut allow_section_done = false;
@@ -293,7 +434,222 @@ fn generate_bindings() -> Result<(), Box<dyn Error>> {
         result.push('\n');
     }
 
-    std::fs::write(&output_path, result)?;
+    // Post-process: Add a warning to the doc of the C variadic functions, whose format string must not be user text
+    let lines: Vec<&str> = result.lines().collect();
+    let mut documented = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("pub fn ") {
+            let end = lines[i..].iter().position(|l| l.trim_end().ends_with(';')).map_or(lines.len(), |n| i + n + 1);
+            if lines[i..end].iter().any(|l| l.contains("...")) {
+                documented.push_str(VARIADIC_DOC);
+            }
+        }
+        documented.push_str(line);
+        documented.push('\n');
+    }
+    let mut result = documented;
+
+    // Post-process: bindgen skips macros without a value, so the IMGUI_HAS_* feature
+    // macros (IMGUI_HAS_TABLE, IMGUI_HAS_DOCK...) are added as bool constants.
+    let header_content = std::fs::read_to_string(header_dir.join("cimgui.h"))?;
+    for line in header_content.lines() {
+        if let Some(rest) = line.strip_prefix("#define IMGUI_HAS_") {
+            let name = rest.split_whitespace().next().unwrap_or_default();
+            result.push_str(&format!("pub const IMGUI_HAS_{}: bool = true;\n", name));
+        }
+    }
+
+    // Post-process: ImVector accessors for each ImVector_* struct, see src/imgui_sys/vector.rs
+    let vectors = im_vector_impls(&result);
+    result.push_str(&vectors);
+
+    // Typed flags and enums, from the enum constants bindgen generated
+    let enums = generate_enums(&result)?;
+    std::fs::write(out_dir.join("enums.rs"), enums)?;
+
+    // Write main imgui bindings
+    std::fs::write(out_dir.join("imgui.rs"), result)?;
 
     Ok(())
 }
+
+/// `im_vector!` invocation with the element type of each ImVector_* struct of the bindings, from their `Data` field.
+#[cfg(feature = "bindgen")]
+fn im_vector_impls(bindings: &str) -> String {
+    let mut entries = String::new();
+    let mut vector = None;
+    for line in bindings.lines() {
+        if let Some(name) = line.strip_prefix("pub struct ImVector_").and_then(|rest| rest.strip_suffix(" {")) {
+            vector = Some(name);
+        } else if line == "}" {
+            vector = None;
+        } else if let Some(name) = vector {
+            if let Some(item) = line.trim().strip_prefix("pub Data: *mut ").and_then(|rest| rest.strip_suffix(',')) {
+                entries.push_str(&format!("    ImVector_{} => {};\n", name, item));
+            }
+        }
+    }
+    if entries.is_empty() {
+        return String::new();
+    }
+    format!("im_vector! {{\n{}}}\n", entries)
+}
+
+/// Typed flags and enums for the imgui_sys::enums module, see src/imgui_sys/enums.rs.
+/// The values are the enum constants of the generated bindings, so they follow the imconfig options and the
+/// docking branch. The flags/internal/count metadata and the docs come from cimgui.json. The docking branch
+/// doesn't ship one, its enums are a superset of master's so the master one is used, and the enums missing from it
+/// fall back to the naming conventions.
+#[cfg(feature = "bindgen")]
+fn generate_enums(bindings: &str) -> Result<String, Box<dyn Error>> {
+    use std::collections::HashMap;
+    use std::fmt::Write;
+
+    let json_path = std::path::Path::new(imgui_src::SOURCE_DIR).join("cimgui.json");
+    println!("cargo::rerun-if-changed={}", json_path.display());
+    let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&json_path)?)?;
+
+    fn comment(value: &serde_json::Value) -> Option<&str> {
+        value.as_str().map(|line| line.trim_start_matches('/').trim())
+    }
+
+    // Metadata by enum name and by element name
+    let mut json_enums = HashMap::new();
+    let mut json_elements = HashMap::new();
+    for json_enum in json["enums"].as_array().ok_or("cimgui.json has no enums")? {
+        let name = json_enum["name"].as_str().unwrap_or_default();
+        json_enums.insert(name, json_enum);
+        for element in json_enum["elements"].as_array().into_iter().flatten() {
+            json_elements.insert(element["name"].as_str().unwrap_or_default(), (name, element));
+        }
+    }
+
+    // bindgen emits the constants of each enum before its type alias: `pub const ImGuiCol__ImGuiCol_Text: ImGuiCol_ = 0;`
+    // Anonymous enums (`ImGuiKey`, `ImGuiDir`...) get a `_bindgen_ty_N` type and unprefixed constants.
+    let mut groups: Vec<(&str, Vec<(&str, &str)>)> = Vec::new();
+    for line in bindings.lines() {
+        let Some(constant) = line.strip_prefix("pub const ") else { continue };
+        let Some((name, rest)) = constant.split_once(": ") else { continue };
+        let Some((ty, value)) = rest.split_once(" = ") else { continue };
+        let anonymous = ty.starts_with("_bindgen_ty_");
+        let Some(element) = (if anonymous { Some(name) } else { name.strip_prefix(ty).and_then(|n| n.strip_prefix('_')) }) else {
+            continue;
+        };
+        let value = value.trim_end_matches(';');
+        match groups.last_mut() {
+            Some((group_ty, elements)) if *group_ty == ty => elements.push((element, value)),
+            _ => groups.push((ty, vec![(element, value)])),
+        }
+    }
+
+    let mut out = String::from("// Generated by build.rs from the bindings and cimgui.json, do not edit\n");
+    for (ty, elements) in &groups {
+        let enum_name = if ty.starts_with("_bindgen_ty_") {
+            let first = elements[0].0;
+            json_elements
+                .get(first)
+                .map(|(name, _)| *name)
+                .unwrap_or_else(|| first.split('_').next().unwrap_or(first))
+        } else {
+            ty
+        };
+        let json_enum = json_enums.get(enum_name);
+        // `ImGuiWindowFlags_` values are stored as `ImGuiWindowFlags`, `ImGuiKey` values as `ImGuiKey`
+        let base = enum_name.trim_end_matches('_');
+        let raw = if bindings.contains(&format!("pub type {base} = ")) {
+            format!("super::{base}")
+        } else {
+            "::core::ffi::c_int".to_string()
+        };
+        let type_name = base.strip_prefix("ImGui").or_else(|| base.strip_prefix("Im")).unwrap_or(base);
+        let is_flags = json_enum.map_or(base.ends_with("Flags"), |e| e["is_flags_enum"].as_bool() == Some(true));
+
+        // Enum docs, then the elements with their metadata
+        let mut docs = String::new();
+        for line in json_enum.and_then(|e| e["comments"]["preceding"].as_array()).into_iter().flatten() {
+            let _ = writeln!(docs, "    /// {}", comment(line).unwrap_or_default());
+        }
+        if !docs.is_empty() {
+            docs.push_str("    ///\n");
+        }
+        let _ = writeln!(docs, "    /// Typed `{enum_name}`, the raw value is [`{base}`]({raw}).");
+
+        struct Element<'a> {
+            name: String,
+            value: &'a str,
+            doc: Option<&'a str>,
+            internal: bool,
+            marker: bool,
+        }
+        let elements: Vec<Element> = elements
+            .iter()
+            .map(|(element, value)| {
+                let json_element = json_elements.get(element).map(|(_, e)| *e);
+                let flag = |key: &str| json_element.is_some_and(|e| e[key].as_bool() == Some(true));
+                let mut name = element
+                    .strip_prefix(base)
+                    .and_then(|n| n.strip_prefix('_'))
+                    .or_else(|| element.strip_prefix("ImGui"))
+                    .or_else(|| element.strip_prefix("Im"))
+                    .unwrap_or(element)
+                    .to_string();
+                if name.starts_with(|c: char| c.is_ascii_digit()) {
+                    name.insert(0, '_');
+                }
+                // COUNT, NamedKey_BEGIN, Mod_Mask_...: ranges and masks, not values
+                let marker = flag("is_count")
+                    || name.ends_with('_')
+                    || matches!(name.rsplit('_').next(), Some("BEGIN" | "END" | "COUNT"));
+                Element {
+                    name,
+                    value,
+                    doc: json_element.and_then(|e| comment(&e["comments"]["attached"])),
+                    internal: flag("is_internal"),
+                    marker,
+                }
+            })
+            .collect();
+
+        let attributes = |out: &mut String, element: &Element, indent: &str| {
+            if let Some(doc) = element.doc {
+                let _ = writeln!(out, "{indent}/// {doc}");
+            }
+            if element.internal {
+                let _ = writeln!(out, "{indent}#[doc(hidden)]");
+            }
+        };
+
+        out.push('\n');
+        if is_flags {
+            let _ = writeln!(out, "flags! {{\n{docs}    pub struct {type_name}: {raw} {{");
+            for element in &elements {
+                attributes(&mut out, element, "        ");
+                let _ = writeln!(out, "        const {} = {};", element.name, element.value);
+            }
+            out.push_str("    }\n}\n");
+        } else {
+            // Markers are raw constants, and the aliases (renamed values...) constants of their first variant
+            let mut variants: HashMap<&str, &str> = HashMap::new();
+            let mut constants = String::new();
+            let _ = writeln!(out, "enumeration! {{\n{docs}    pub enum {type_name}: {raw} {{");
+            for element in &elements {
+                if element.marker {
+                    attributes(&mut constants, element, "    ");
+                    let _ = writeln!(constants, "    pub const {}: {raw} = {};", element.name, element.value);
+                } else if let Some(variant) = variants.get(element.value) {
+                    attributes(&mut constants, element, "    ");
+                    let _ = writeln!(constants, "    pub const {}: Self = Self::{variant};", element.name);
+                } else {
+                    variants.insert(element.value, &element.name);
+                    attributes(&mut out, element, "        ");
+                    let _ = writeln!(out, "        {} = {},", element.name, element.value);
+                }
+            }
+            out.push_str("    }\n}\n");
+            if !constants.is_empty() {
+                let _ = writeln!(out, "\nimpl {type_name} {{\n{constants}}}");
+            }
+        }
+    }
+    Ok(out)
+}
@@ -0,0 +1,81 @@
+// Dear ImGui assertions routed to a Rust handler
+//
+// With the `assert-hook` feature, imgui is built with IMGUI_ASSERT_HOOK: imconfig.h then defines IM_ASSERT to call
+// cImGui_AssertFailed() when the expression is false, also in release builds (NDEBUG). It's defined here, and calls
+// the handler set with set_assert_handler(), panic_handler() by default.
+//
+// Dear ImGui is C++ called through `extern "C"` bindings, a panic can't unwind through it. cImGui_AssertFailed() is
+// `extern "C"` too: a panicking handler runs the panic hook (message, backtrace, crash reporters), then the process
+// aborts at the FFI boundary.
+
+extern crate std;
+
+use core::ffi::{CStr, c_char, c_int};
+use core::fmt;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use std::backtrace::Backtrace;
+
+/// A failed Dear ImGui assertion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssertFailure<'a> {
+    /// The asserted expression, with its message if any, e.g.
+    /// `(g.FrameCount == 0 || g.FrameCountEnded == g.FrameCount) && "Forgot to call Render() or EndFrame() at the end of the previous frame?"`
+    pub expression: &'a str,
+    /// Source file of Dear ImGui, as given by `__FILE__`
+    pub file: &'a str,
+    pub line: u32,
+}
+
+impl fmt::Display for AssertFailure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Dear ImGui assertion failed: {}, {}:{}", self.expression, self.file, self.line)
+    }
+}
+
+/// Function called when a Dear ImGui assertion fails.
+pub type AssertHandler = fn(&AssertFailure<'_>);
+
+// The AssertHandler, null for panic_handler
+static HANDLER: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Sets the function called when a Dear ImGui assertion fails, from any thread, and returns the previous one.
+///
+/// When the handler returns, Dear ImGui goes on like with the assertions compiled out: a handler that only logs
+/// keeps the application running after recoverable errors, but the next steps may crash on invalid state.
+pub fn set_assert_handler(handler: AssertHandler) -> AssertHandler {
+    let previous = HANDLER.swap(handler as *mut (), Ordering::AcqRel);
+    handler_from_ptr(previous)
+}
+
+/// The function called when a Dear ImGui assertion fails.
+pub fn assert_handler() -> AssertHandler {
+    handler_from_ptr(HANDLER.load(Ordering::Acquire))
+}
+
+/// The default handler: panics with the assertion and a backtrace of the C++ and Rust frames that led to it.
+///
+/// The panic can't unwind through Dear ImGui, the process aborts after the panic hook.
+pub fn panic_handler(failure: &AssertFailure<'_>) {
+    // Whatever RUST_BACKTRACE is, the point is to know where the assertion came from
+    let backtrace = Backtrace::force_capture();
+    panic!("{failure}\n{backtrace}");
+}
+
+fn handler_from_ptr(handler: *mut ()) -> AssertHandler {
+    if handler.is_null() {
+        panic_handler
+    } else {
+        // Only AssertHandler values are stored
+        unsafe { core::mem::transmute::<*mut (), AssertHandler>(handler) }
+    }
+}
+
+// Called by IM_ASSERT, declared in imconfig.h
+#[unsafe(no_mangle)]
+unsafe extern "C" fn cImGui_AssertFailed(expr: *const c_char, file: *const c_char, line: c_int) {
+    let expression = unsafe { CStr::from_ptr(expr) }.to_string_lossy();
+    let file = unsafe { CStr::from_ptr(file) }.to_string_lossy();
+    let failure = AssertFailure { expression: &expression, file: &file, line: line as u32 };
+    assert_handler()(&failure);
+}
@@ -0,0 +1,1641 @@
+// Generated by build.rs from the bindings and cimgui.json, do not edit
+
+flags! {
+    /// Flags for ImGui::Begin()
+    /// (Those are per-window flags. There are shared flags in ImGuiIO: io.ConfigWindowsResizeFromEdges and io.ConfigWindowsMoveFromTitleBarOnly)
+    ///
+    /// Typed `ImGuiWindowFlags_`, the raw value is [`ImGuiWindowFlags`](super::ImGuiWindowFlags).
+    pub struct WindowFlags: super::ImGuiWindowFlags {
+        const None = 0;
+        /// Disable title-bar
+        const NoTitleBar = 1;
+        /// Disable user resizing with the lower-right grip
+        const NoResize = 2;
+        /// Disable user moving the window
+        const NoMove = 4;
+        /// Disable scrollbars (window can still scroll with mouse or programmatically)
+        const NoScrollbar = 8;
+        /// Disable user vertically scrolling with mouse wheel. On child window, mouse wheel will be forwarded to the parent unless NoScrollbar is also set.
+        const NoScrollWithMouse = 16;
+        /// Disable user collapsing window by double-clicking on it. Also referred to as Window Menu Button (e.g. within a docking node).
+        const NoCollapse = 32;
+        /// Resize every window to its content every frame
+        const AlwaysAutoResize = 64;
+        /// Disable drawing background color (WindowBg, etc.) and outside border. Similar as using SetNextWindowBgAlpha(0.0f).
+        const NoBackground = 128;
+        /// Never load/save settings in .ini file
+        const NoSavedSettings = 256;
+        /// Disable catching mouse, hovering test with pass through.
+        const NoMouseInputs = 512;
+        /// Has a menu-bar
+        const MenuBar = 1024;
+        /// Allow horizontal scrollbar to appear (off by default). You may use SetNextWindowContentSize(ImVec2(width,0.0f)); prior to calling Begin() to specify width. Read code in imgui_demo in the "Horizontal Scrolling" section.
+        const HorizontalScrollbar = 2048;
+        /// Disable taking focus when transitioning from hidden to visible state
+        const NoFocusOnAppearing = 4096;
+        /// Disable bringing window to front when taking focus (e.g. clicking on it or programmatically giving it focus)
+        const NoBringToFrontOnFocus = 8192;
+        /// Always show vertical scrollbar (even if ContentSize.y < Size.y)
+        const AlwaysVerticalScrollbar = 16384;
+        /// Always show horizontal scrollbar (even if ContentSize.x < Size.x)
+        const AlwaysHorizontalScrollbar = 32768;
+        /// No keyboard/gamepad navigation within the window
+        const NoNavInputs = 65536;
+        /// No focusing toward this window with keyboard/gamepad navigation (e.g. skipped by Ctrl+Tab)
+        const NoNavFocus = 131072;
+        /// Display a dot next to the title. When used in a tab/docking context, tab is selected when clicking the X + closure is not assumed (will wait for user to stop submitting the tab). Otherwise closure is assumed when pressing the X, so if you keep submitting the tab may reappear at end of tab bar.
+        const UnsavedDocument = 262144;
+        const NoNav = 196608;
+        const NoDecoration = 43;
+        const NoInputs = 197120;
+        /// Don't use! For internal use by BeginChild()
+        #[doc(hidden)]
+        const ChildWindow = 16777216;
+        /// Don't use! For internal use by BeginTooltip()
+        #[doc(hidden)]
+        const Tooltip = 33554432;
+        /// Don't use! For internal use by BeginPopup()
+        #[doc(hidden)]
+        const Popup = 67108864;
+        /// Don't use! For internal use by BeginPopupModal()
+        #[doc(hidden)]
+        const Modal = 134217728;
+        /// Don't use! For internal use by BeginMenu()
+        #[doc(hidden)]
+        const ChildMenu = 268435456;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::BeginChild()
+    /// (Legacy: bit 0 must always correspond to ImGuiChildFlags_Borders to be backward compatible with old API using 'bool border = false'.)
+    /// About using AutoResizeX/AutoResizeY flags:
+    /// - May be combined with SetNextWindowSizeConstraints() to set a min/max size for each axis (see "Demo->Child->Auto-resize with Constraints").
+    /// - Size measurement for a given axis is only performed when the child window is within visible boundaries, or is just appearing.
+    /// - This allows BeginChild() to return false when not within boundaries (e.g. when scrolling), which is more optimal. BUT it won't update its auto-size while clipped.
+    /// While not perfect, it is a better default behavior as the always-on performance gain is more valuable than the occasional "resizing after becoming visible again" glitch.
+    /// - You may also use ImGuiChildFlags_AlwaysAutoResize to force an update even when child window is not in view.
+    /// HOWEVER PLEASE UNDERSTAND THAT DOING SO WILL PREVENT BeginChild() FROM EVER RETURNING FALSE, disabling benefits of coarse clipping.
+    ///
+    /// Typed `ImGuiChildFlags_`, the raw value is [`ImGuiChildFlags`](super::ImGuiChildFlags).
+    pub struct ChildFlags: super::ImGuiChildFlags {
+        const None = 0;
+        /// Show an outer border and enable WindowPadding. (IMPORTANT: this is always == 1 == true for legacy reason)
+        const Borders = 1;
+        /// Pad with style.WindowPadding even if no border are drawn (no padding by default for non-bordered child windows because it makes more sense)
+        const AlwaysUseWindowPadding = 2;
+        /// Allow resize from right border (layout direction). Enable .ini saving (unless ImGuiWindowFlags_NoSavedSettings passed to window flags)
+        const ResizeX = 4;
+        /// Allow resize from bottom border (layout direction). "
+        const ResizeY = 8;
+        /// Enable auto-resizing width. Read "IMPORTANT: Size measurement" details above.
+        const AutoResizeX = 16;
+        /// Enable auto-resizing height. Read "IMPORTANT: Size measurement" details above.
+        const AutoResizeY = 32;
+        /// Combined with AutoResizeX/AutoResizeY. Always measure size even when child is hidden, always return true, always disable clipping optimization! NOT RECOMMENDED.
+        const AlwaysAutoResize = 64;
+        /// Style the child window like a framed item: use FrameBg, FrameRounding, FrameBorderSize, FramePadding instead of ChildBg, ChildRounding, ChildBorderSize, WindowPadding.
+        const FrameStyle = 128;
+        /// [BETA] Share focus scope, allow keyboard/gamepad navigation to cross over parent border to this child or between sibling child windows.
+        const NavFlattened = 256;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::PushItemFlag()
+    /// (Those are shared by all items)
+    ///
+    /// Typed `ImGuiItemFlags_`, the raw value is [`ImGuiItemFlags`](super::ImGuiItemFlags).
+    pub struct ItemFlags: super::ImGuiItemFlags {
+        /// (Default)
+        const None = 0;
+        /// false    // Disable keyboard tabbing. This is a "lighter" version of ImGuiItemFlags_NoNav.
+        const NoTabStop = 1;
+        /// false    // Disable any form of focusing (keyboard/gamepad directional navigation and SetKeyboardFocusHere() calls).
+        const NoNav = 2;
+        /// false    // Disable item being a candidate for default focus (e.g. used by title bar items).
+        const NoNavDefaultFocus = 4;
+        /// false    // Any button-like behavior will have repeat mode enabled (based on io.KeyRepeatDelay and io.KeyRepeatRate values). Note that you can also call IsItemActive() after any button to tell if it is being held.
+        const ButtonRepeat = 8;
+        /// true     // MenuItem()/Selectable() automatically close their parent popup window.
+        const AutoClosePopups = 16;
+        /// false    // Allow submitting an item with the same identifier as an item already submitted this frame without triggering a warning tooltip if io.ConfigDebugHighlightIdConflicts is set.
+        const AllowDuplicateId = 32;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::InputText()
+    /// (Those are per-item flags. There are shared flags in ImGuiIO: io.ConfigInputTextCursorBlink and io.ConfigInputTextEnterKeepActive)
+    ///
+    /// Typed `ImGuiInputTextFlags_`, the raw value is [`ImGuiInputTextFlags`](super::ImGuiInputTextFlags).
+    pub struct InputTextFlags: super::ImGuiInputTextFlags {
+        const None = 0;
+        /// Allow 0123456789.+-*/
+        const CharsDecimal = 1;
+        /// Allow 0123456789ABCDEFabcdef
+        const CharsHexadecimal = 2;
+        /// Allow 0123456789.+-*/eE (Scientific notation input)
+        const CharsScientific = 4;
+        /// Turn a..z into A..Z
+        const CharsUppercase = 8;
+        /// Filter out spaces, tabs
+        const CharsNoBlank = 16;
+        /// Pressing TAB input a '\t' character into the text field
+        const AllowTabInput = 32;
+        /// Return 'true' when Enter is pressed (as opposed to every time the value was modified). Consider using IsItemDeactivatedAfterEdit() instead!
+        const EnterReturnsTrue = 64;
+        /// Escape key clears content if not empty, and deactivate otherwise (contrast to default behavior of Escape to revert)
+        const EscapeClearsAll = 128;
+        /// In multi-line mode, validate with Enter, add new line with Ctrl+Enter (default is opposite: validate with Ctrl+Enter, add line with Enter).
+        const CtrlEnterForNewLine = 256;
+        /// Read-only mode
+        const ReadOnly = 512;
+        /// Password mode, display all characters as '*', disable copy
+        const Password = 1024;
+        /// Overwrite mode
+        const AlwaysOverwrite = 2048;
+        /// Select entire text when first taking mouse focus
+        const AutoSelectAll = 4096;
+        /// InputFloat(), InputInt(), InputScalar() etc. only: parse empty string as zero value.
+        const ParseEmptyRefVal = 8192;
+        /// InputFloat(), InputInt(), InputScalar() etc. only: when value is zero, do not display it. Generally used with ImGuiInputTextFlags_ParseEmptyRefVal.
+        const DisplayEmptyRefVal = 16384;
+        /// Disable following the cursor horizontally
+        const NoHorizontalScroll = 32768;
+        /// Disable undo/redo. Note that input text owns the text data while active, if you want to provide your own undo/redo stack you need e.g. to call ClearActiveID().
+        const NoUndoRedo = 65536;
+        /// When text doesn't fit, elide left side to ensure right side stays visible. Useful for path/filenames. Single-line only!
+        const ElideLeft = 131072;
+        /// Callback on pressing Up/Down arrows (for history handling)
+        const CallbackHistory = 524288;
+        /// Callback on each iteration. User code may query cursor position, modify text buffer.
+        const CallbackAlways = 1048576;
+        /// Callback on buffer capacity changes request (beyond 'buf_size' parameter value), allowing the string to grow. Notify when the string wants to be resized (for string types which hold a cache of their Size). You will be provided a new BufSize in the callback and NEED to honor it. (see misc/cpp/imgui_stdlib.h for an example of using this)
+        const CallbackResize = 4194304;
+        /// Callback on any edit. Note that InputText() already returns true on edit + you can always use IsItemEdited(). The callback is useful to manipulate the underlying buffer while focus is active.
+        const CallbackEdit = 8388608;
+        /// InputTextMultiline(): word-wrap lines that are too long.
+        const WordWrap = 16777216;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::TreeNodeEx(), ImGui::CollapsingHeader*()
+    ///
+    /// Typed `ImGuiTreeNodeFlags_`, the raw value is [`ImGuiTreeNodeFlags`](super::ImGuiTreeNodeFlags).
+    pub struct TreeNodeFlags: super::ImGuiTreeNodeFlags {
+        const None = 0;
+        /// Draw as selected
+        const Selected = 1;
+        /// Draw frame with background (e.g. for CollapsingHeader)
+        const Framed = 2;
+        /// Hit testing to allow subsequent widgets to overlap this one
+        const AllowOverlap = 4;
+        /// Don't do a TreePush() when open (e.g. for CollapsingHeader) = no extra indent nor pushing on ID stack
+        const NoTreePushOnOpen = 8;
+        /// Don't automatically and temporarily open node when Logging is active (by default logging will automatically open tree nodes)
+        const NoAutoOpenOnLog = 16;
+        /// Default node to be open
+        const DefaultOpen = 32;
+        /// Open on double-click instead of simple click (default for multi-select unless any _OpenOnXXX behavior is set explicitly). Both behaviors may be combined.
+        const OpenOnDoubleClick = 64;
+        /// Open when clicking on the arrow part (default for multi-select unless any _OpenOnXXX behavior is set explicitly). Both behaviors may be combined.
+        const OpenOnArrow = 128;
+        /// No collapsing, no arrow (use as a convenience for leaf nodes).
+        const Leaf = 256;
+        /// Display a bullet instead of arrow. IMPORTANT: node can still be marked open/close if you don't set the _Leaf flag!
+        const Bullet = 512;
+        /// Use FramePadding (even for an unframed text node) to vertically align text baseline to regular widget height. Equivalent to calling AlignTextToFramePadding() before the node.
+        const FramePadding = 1024;
+        /// Extend hit box to the right-most edge, even if not framed. This is not the default in order to allow adding other items on the same line without using AllowOverlap mode.
+        const SpanAvailWidth = 2048;
+        /// Extend hit box to the left-most and right-most edges (cover the indent area).
+        const SpanFullWidth = 4096;
+        /// Narrow hit box + narrow hovering highlight, will only cover the label text.
+        const SpanLabelWidth = 8192;
+        /// Frame will span all columns of its container table (label will still fit in current column)
+        const SpanAllColumns = 16384;
+        /// Label will span all columns of its container table
+        const LabelSpanAllColumns = 32768;
+        /// Nav: left arrow moves back to parent. This is processed in TreePop() when there's an unfulfilled Left nav request remaining.
+        const NavLeftJumpsToParent = 131072;
+        const CollapsingHeader = 26;
+        /// No lines drawn
+        const DrawLinesNone = 262144;
+        /// Horizontal lines to child nodes. Vertical line drawn down to TreePop() position: cover full contents. Faster (for large trees).
+        const DrawLinesFull = 524288;
+        /// Horizontal lines to child nodes. Vertical line drawn down to bottom-most child node. Slower (for large trees).
+        const DrawLinesToNodes = 1048576;
+        /// Renamed in 1.92.0
+        const NavLeftJumpsBackHere = 131072;
+        /// Renamed in 1.90.7
+        const SpanTextWidth = 8192;
+    }
+}
+
+flags! {
+    /// Flags for OpenPopup*(), BeginPopupContext*(), IsPopupOpen() functions.
+    /// - To be backward compatible with older API which took an 'int mouse_button = 1' argument instead of 'ImGuiPopupFlags flags',
+    /// we need to treat small flags values as a mouse button index, so we encode the mouse button in the first few bits of the flags.
+    /// It is therefore guaranteed to be legal to pass a mouse button index in ImGuiPopupFlags.
+    /// - For the same reason, we exceptionally default the ImGuiPopupFlags argument of BeginPopupContextXXX functions to 1 instead of 0.
+    /// IMPORTANT: because the default parameter is 1 (==ImGuiPopupFlags_MouseButtonRight), if you rely on the default parameter
+    /// and want to use another flag, you need to pass in the ImGuiPopupFlags_MouseButtonRight flag explicitly.
+    /// - Multiple buttons currently cannot be combined/or-ed in those functions (we could allow it later).
+    ///
+    /// Typed `ImGuiPopupFlags_`, the raw value is [`ImGuiPopupFlags`](super::ImGuiPopupFlags).
+    pub struct PopupFlags: super::ImGuiPopupFlags {
+        const None = 0;
+        /// For BeginPopupContext*(): open on Left Mouse release. Guaranteed to always be == 0 (same as ImGuiMouseButton_Left)
+        const MouseButtonLeft = 0;
+        /// For BeginPopupContext*(): open on Right Mouse release. Guaranteed to always be == 1 (same as ImGuiMouseButton_Right)
+        const MouseButtonRight = 1;
+        /// For BeginPopupContext*(): open on Middle Mouse release. Guaranteed to always be == 2 (same as ImGuiMouseButton_Middle)
+        const MouseButtonMiddle = 2;
+        #[doc(hidden)]
+        const MouseButtonMask_ = 31;
+        #[doc(hidden)]
+        const MouseButtonDefault_ = 1;
+        /// For OpenPopup*(), BeginPopupContext*(): don't reopen same popup if already open (won't reposition, won't reinitialize navigation)
+        const NoReopen = 32;
+        /// For OpenPopup*(), BeginPopupContext*(): don't open if there's already a popup at the same level of the popup stack
+        const NoOpenOverExistingPopup = 128;
+        /// For BeginPopupContextWindow(): don't return true when hovering items, only when hovering empty space
+        const NoOpenOverItems = 256;
+        /// For IsPopupOpen(): ignore the ImGuiID parameter and test for any popup.
+        const AnyPopupId = 1024;
+        /// For IsPopupOpen(): search/test at any level of the popup stack (default test in the current level)
+        const AnyPopupLevel = 2048;
+        const AnyPopup = 3072;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::Selectable()
+    ///
+    /// Typed `ImGuiSelectableFlags_`, the raw value is [`ImGuiSelectableFlags`](super::ImGuiSelectableFlags).
+    pub struct SelectableFlags: super::ImGuiSelectableFlags {
+        const None = 0;
+        /// Clicking this doesn't close parent popup window (overrides ImGuiItemFlags_AutoClosePopups)
+        const NoAutoClosePopups = 1;
+        /// Frame will span all columns of its container table (text will still fit in current column)
+        const SpanAllColumns = 2;
+        /// Generate press events on double clicks too
+        const AllowDoubleClick = 4;
+        /// Cannot be selected, display grayed out text
+        const Disabled = 8;
+        /// (WIP) Hit testing to allow subsequent widgets to overlap this one
+        const AllowOverlap = 16;
+        /// Make the item be displayed as if it is hovered
+        const Highlight = 32;
+        /// Auto-select when moved into, unless Ctrl is held. Automatic when in a BeginMultiSelect() block.
+        const SelectOnNav = 64;
+        /// Renamed in 1.91.0
+        const DontClosePopups = 1;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::BeginCombo()
+    ///
+    /// Typed `ImGuiComboFlags_`, the raw value is [`ImGuiComboFlags`](super::ImGuiComboFlags).
+    pub struct ComboFlags: super::ImGuiComboFlags {
+        const None = 0;
+        /// Align the popup toward the left by default
+        const PopupAlignLeft = 1;
+        /// Max ~4 items visible. Tip: If you want your combo popup to be a specific size you can use SetNextWindowSizeConstraints() prior to calling BeginCombo()
+        const HeightSmall = 2;
+        /// Max ~8 items visible (default)
+        const HeightRegular = 4;
+        /// Max ~20 items visible
+        const HeightLarge = 8;
+        /// As many fitting items as possible
+        const HeightLargest = 16;
+        /// Display on the preview box without the square arrow button
+        const NoArrowButton = 32;
+        /// Display only a square arrow button
+        const NoPreview = 64;
+        /// Width dynamically calculated from preview contents
+        const WidthFitPreview = 128;
+        #[doc(hidden)]
+        const HeightMask_ = 30;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::BeginTabBar()
+    ///
+    /// Typed `ImGuiTabBarFlags_`, the raw value is [`ImGuiTabBarFlags`](super::ImGuiTabBarFlags).
+    pub struct TabBarFlags: super::ImGuiTabBarFlags {
+        const None = 0;
+        /// Allow manually dragging tabs to re-order them + New tabs are appended at the end of list
+        const Reorderable = 1;
+        /// Automatically select new tabs when they appear
+        const AutoSelectNewTabs = 2;
+        /// Disable buttons to open the tab list popup
+        const TabListPopupButton = 4;
+        /// Disable behavior of closing tabs (that are submitted with p_open != NULL) with middle mouse button. You may handle this behavior manually on user's side with if (IsItemHovered() && IsMouseClicked(2)) *p_open = false.
+        const NoCloseWithMiddleMouseButton = 8;
+        /// Disable scrolling buttons (apply when fitting policy is ImGuiTabBarFlags_FittingPolicyScroll)
+        const NoTabListScrollingButtons = 16;
+        /// Disable tooltips when hovering a tab
+        const NoTooltip = 32;
+        /// Draw selected overline markers over selected tab
+        const DrawSelectedOverline = 64;
+        /// Shrink down tabs when they don't fit, until width is style.TabMinWidthShrink, then enable scrolling buttons.
+        const FittingPolicyMixed = 128;
+        /// Shrink down tabs when they don't fit
+        const FittingPolicyShrink = 256;
+        /// Enable scrolling buttons when tabs don't fit
+        const FittingPolicyScroll = 512;
+        #[doc(hidden)]
+        const FittingPolicyMask_ = 896;
+        #[doc(hidden)]
+        const FittingPolicyDefault_ = 128;
+        /// Renamed in 1.92.2
+        const FittingPolicyResizeDown = 256;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::BeginTabItem()
+    ///
+    /// Typed `ImGuiTabItemFlags_`, the raw value is [`ImGuiTabItemFlags`](super::ImGuiTabItemFlags).
+    pub struct TabItemFlags: super::ImGuiTabItemFlags {
+        const None = 0;
+        /// Display a dot next to the title + set ImGuiTabItemFlags_NoAssumedClosure.
+        const UnsavedDocument = 1;
+        /// Trigger flag to programmatically make the tab selected when calling BeginTabItem()
+        const SetSelected = 2;
+        /// Disable behavior of closing tabs (that are submitted with p_open != NULL) with middle mouse button. You may handle this behavior manually on user's side with if (IsItemHovered() && IsMouseClicked(2)) *p_open = false.
+        const NoCloseWithMiddleMouseButton = 4;
+        /// Don't call PushID()/PopID() on BeginTabItem()/EndTabItem()
+        const NoPushId = 8;
+        /// Disable tooltip for the given tab
+        const NoTooltip = 16;
+        /// Disable reordering this tab or having another tab cross over this tab
+        const NoReorder = 32;
+        /// Enforce the tab position to the left of the tab bar (after the tab list popup button)
+        const Leading = 64;
+        /// Enforce the tab position to the right of the tab bar (before the scrolling buttons)
+        const Trailing = 128;
+        /// Tab is selected when trying to close + closure is not immediately assumed (will wait for user to stop submitting the tab). Otherwise closure is assumed when pressing the X, so if you keep submitting the tab may reappear at end of tab bar.
+        const NoAssumedClosure = 256;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::IsWindowFocused()
+    ///
+    /// Typed `ImGuiFocusedFlags_`, the raw value is [`ImGuiFocusedFlags`](super::ImGuiFocusedFlags).
+    pub struct FocusedFlags: super::ImGuiFocusedFlags {
+        const None = 0;
+        /// Return true if any children of the window is focused
+        const ChildWindows = 1;
+        /// Test from root window (top most parent of the current hierarchy)
+        const RootWindow = 2;
+        /// Return true if any window is focused. Important: If you are trying to tell how to dispatch your low-level inputs, do NOT use this. Use 'io.WantCaptureMouse' instead! Please read the FAQ!
+        const AnyWindow = 4;
+        /// Do not consider popup hierarchy (do not treat popup emitter as parent of popup) (when used with _ChildWindows or _RootWindow)
+        const NoPopupHierarchy = 8;
+        const RootAndChildWindows = 3;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::IsItemHovered(), ImGui::IsWindowHovered()
+    /// Note: if you are trying to check whether your mouse should be dispatched to Dear ImGui or to your app, you should use 'io.WantCaptureMouse' instead! Please read the FAQ!
+    /// Note: windows with the ImGuiWindowFlags_NoInputs flag are ignored by IsWindowHovered() calls.
+    ///
+    /// Typed `ImGuiHoveredFlags_`, the raw value is [`ImGuiHoveredFlags`](super::ImGuiHoveredFlags).
+    pub struct HoveredFlags: super::ImGuiHoveredFlags {
+        /// Return true if directly over the item/window, not obstructed by another window, not obstructed by an active popup or modal blocking inputs under them.
+        const None = 0;
+        /// IsWindowHovered() only: Return true if any children of the window is hovered
+        const ChildWindows = 1;
+        /// IsWindowHovered() only: Test from root window (top most parent of the current hierarchy)
+        const RootWindow = 2;
+        /// IsWindowHovered() only: Return true if any window is hovered
+        const AnyWindow = 4;
+        /// IsWindowHovered() only: Do not consider popup hierarchy (do not treat popup emitter as parent of popup) (when used with _ChildWindows or _RootWindow)
+        const NoPopupHierarchy = 8;
+        /// Return true even if a popup window is normally blocking access to this item/window
+        const AllowWhenBlockedByPopup = 32;
+        /// IsItemHovered() only: Return true even if the item uses AllowOverlap mode and is overlapped by another hoverable item.
+        const AllowWhenOverlappedByItem = 256;
+        /// IsItemHovered() only: Return true even if the item is disabled
+        const AllowWhenDisabled = 1024;
+        /// IsItemHovered() only: Disable using keyboard/gamepad navigation state when active, always query mouse
+        const NoNavOverride = 2048;
+        const AllowWhenOverlapped = 768;
+        const RectOnly = 928;
+        const RootAndChildWindows = 3;
+        /// Shortcut for standard flags when using IsItemHovered() + SetTooltip() sequence.
+        const ForTooltip = 4096;
+        /// Require mouse to be stationary for style.HoverStationaryDelay (~0.15 sec) _at least one time_. After this, can move on same item/window. Using the stationary test tends to reduces the need for a long delay.
+        const Stationary = 8192;
+        /// IsItemHovered() only: Return true immediately (default). As this is the default you generally ignore this.
+        const DelayNone = 16384;
+        /// IsItemHovered() only: Return true after style.HoverDelayShort elapsed (~0.15 sec) (shared between items) + requires mouse to be stationary for style.HoverStationaryDelay (once per item).
+        const DelayShort = 32768;
+        /// IsItemHovered() only: Return true after style.HoverDelayNormal elapsed (~0.40 sec) (shared between items) + requires mouse to be stationary for style.HoverStationaryDelay (once per item).
+        const DelayNormal = 65536;
+        /// IsItemHovered() only: Disable shared delay system where moving from one item to the next keeps the previous timer for a short time (standard for tooltips with long delays)
+        const NoSharedDelay = 131072;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::BeginDragDropSource(), ImGui::AcceptDragDropPayload()
+    ///
+    /// Typed `ImGuiDragDropFlags_`, the raw value is [`ImGuiDragDropFlags`](super::ImGuiDragDropFlags).
+    pub struct DragDropFlags: super::ImGuiDragDropFlags {
+        const None = 0;
+        /// Disable preview tooltip. By default, a successful call to BeginDragDropSource opens a tooltip so you can display a preview or description of the source contents. This flag disables this behavior.
+        const SourceNoPreviewTooltip = 1;
+        /// By default, when dragging we clear data so that IsItemHovered() will return false, to avoid subsequent user code submitting tooltips. This flag disables this behavior so you can still call IsItemHovered() on the source item.
+        const SourceNoDisableHover = 2;
+        /// Disable the behavior that allows to open tree nodes and collapsing header by holding over them while dragging a source item.
+        const SourceNoHoldToOpenOthers = 4;
+        /// Allow items such as Text(), Image() that have no unique identifier to be used as drag source, by manufacturing a temporary identifier based on their window-relative position. This is extremely unusual within the dear imgui ecosystem and so we made it explicit.
+        const SourceAllowNullID = 8;
+        /// External source (from outside of dear imgui), won't attempt to read current item/window info. Will always return true. Only one Extern source can be active simultaneously.
+        const SourceExtern = 16;
+        /// Automatically expire the payload if the source cease to be submitted (otherwise payloads are persisting while being dragged)
+        const PayloadAutoExpire = 32;
+        /// Hint to specify that the payload may not be copied outside current dear imgui context.
+        const PayloadNoCrossContext = 64;
+        /// Hint to specify that the payload may not be copied outside current process.
+        const PayloadNoCrossProcess = 128;
+        /// AcceptDragDropPayload() will returns true even before the mouse button is released. You can then call IsDelivery() to test if the payload needs to be delivered.
+        const AcceptBeforeDelivery = 1024;
+        /// Request hiding the BeginDragDropSource tooltip from the BeginDragDropTarget site.
+        const AcceptNoPreviewTooltip = 4096;
+        /// Accepting item will render as if hovered. Useful for e.g. a Button() used as a drop target.
+        const AcceptDrawAsHovered = 8192;
+        /// For peeking ahead and inspecting the payload before delivery.
+        const AcceptPeekOnly = 3072;
+        /// Renamed in 1.90.9
+        const SourceAutoExpirePayload = 32;
+    }
+}
+
+enumeration! {
+    /// A primary data type
+    ///
+    /// Typed `ImGuiDataType_`, the raw value is [`ImGuiDataType`](super::ImGuiDataType).
+    pub enum DataType: super::ImGuiDataType {
+        /// signed char / char (with sensible compilers)
+        S8 = 0,
+        /// unsigned char
+        U8 = 1,
+        /// short
+        S16 = 2,
+        /// unsigned short
+        U16 = 3,
+        /// int
+        S32 = 4,
+        /// unsigned int
+        U32 = 5,
+        /// long long / __int64
+        S64 = 6,
+        /// unsigned long long / unsigned __int64
+        U64 = 7,
+        /// float
+        Float = 8,
+        /// double
+        Double = 9,
+        /// bool (provided for user convenience, not supported by scalar widgets)
+        Bool = 10,
+        /// char* (provided for user convenience, not supported by scalar widgets)
+        String = 11,
+    }
+}
+
+impl DataType {
+    pub const COUNT: super::ImGuiDataType = 12;
+}
+
+enumeration! {
+    /// A cardinal direction
+    ///
+    /// Typed `ImGuiDir`, the raw value is [`ImGuiDir`](super::ImGuiDir).
+    pub enum Dir: super::ImGuiDir {
+        None = -1,
+        Left = 0,
+        Right = 1,
+        Up = 2,
+        Down = 3,
+    }
+}
+
+impl Dir {
+    pub const COUNT: super::ImGuiDir = 4;
+}
+
+enumeration! {
+    /// A sorting direction
+    ///
+    /// Typed `ImGuiSortDirection`, the raw value is [`ImGuiSortDirection`](super::ImGuiSortDirection).
+    pub enum SortDirection: super::ImGuiSortDirection {
+        None = 0,
+        /// Ascending = 0->9, A->Z etc.
+        Ascending = 1,
+        /// Descending = 9->0, Z->A etc.
+        Descending = 2,
+    }
+}
+
+enumeration! {
+    /// A key identifier (ImGuiKey_XXX or ImGuiMod_XXX value): can represent Keyboard, Mouse and Gamepad values.
+    /// All our named keys are >= 512. Keys value 0 to 511 are left unused and were legacy native/opaque key values (< 1.87).
+    /// Support for legacy keys was completely removed in 1.91.5.
+    /// Read details about the 1.87+ transition : https://github.com/ocornut/imgui/issues/4921
+    /// Note that "Keys" related to physical keys and are not the same concept as input "Characters", the later are submitted via io.AddInputCharacter().
+    /// The keyboard key enum values are named after the keys on a standard US keyboard, and on other keyboard types the keys reported may not match the keycaps.
+    ///
+    /// Typed `ImGuiKey`, the raw value is [`ImGuiKey`](super::ImGuiKey).
+    pub enum Key: super::ImGuiKey {
+        None = 0,
+        /// == ImGuiKey_NamedKey_BEGIN
+        Tab = 512,
+        LeftArrow = 513,
+        RightArrow = 514,
+        UpArrow = 515,
+        DownArrow = 516,
+        PageUp = 517,
+        PageDown = 518,
+        Home = 519,
+        End = 520,
+        Insert = 521,
+        Delete = 522,
+        Backspace = 523,
+        Space = 524,
+        Enter = 525,
+        Escape = 526,
+        LeftCtrl = 527,
+        LeftShift = 528,
+        LeftAlt = 529,
+        /// Also see ImGuiMod_Ctrl, ImGuiMod_Shift, ImGuiMod_Alt, ImGuiMod_Super below!
+        LeftSuper = 530,
+        RightCtrl = 531,
+        RightShift = 532,
+        RightAlt = 533,
+        RightSuper = 534,
+        Menu = 535,
+        _0 = 536,
+        _1 = 537,
+        _2 = 538,
+        _3 = 539,
+        _4 = 540,
+        _5 = 541,
+        _6 = 542,
+        _7 = 543,
+        _8 = 544,
+        _9 = 545,
+        A = 546,
+        B = 547,
+        C = 548,
+        D = 549,
+        E = 550,
+        F = 551,
+        G = 552,
+        H = 553,
+        I = 554,
+        J = 555,
+        K = 556,
+        L = 557,
+        M = 558,
+        N = 559,
+        O = 560,
+        P = 561,
+        Q = 562,
+        R = 563,
+        S = 564,
+        T = 565,
+        U = 566,
+        V = 567,
+        W = 568,
+        X = 569,
+        Y = 570,
+        Z = 571,
+        F1 = 572,
+        F2 = 573,
+        F3 = 574,
+        F4 = 575,
+        F5 = 576,
+        F6 = 577,
+        F7 = 578,
+        F8 = 579,
+        F9 = 580,
+        F10 = 581,
+        F11 = 582,
+        F12 = 583,
+        F13 = 584,
+        F14 = 585,
+        F15 = 586,
+        F16 = 587,
+        F17 = 588,
+        F18 = 589,
+        F19 = 590,
+        F20 = 591,
+        F21 = 592,
+        F22 = 593,
+        F23 = 594,
+        F24 = 595,
+        /// '
+        Apostrophe = 596,
+        /// ,
+        Comma = 597,
+        /// -
+        Minus = 598,
+        /// .
+        Period = 599,
+        /// /
+        Slash = 600,
+        /// ;
+        Semicolon = 601,
+        /// =
+        Equal = 602,
+        /// [
+        LeftBracket = 603,
+        /// \ (this text inhibit multiline comment caused by backslash)
+        Backslash = 604,
+        /// ]
+        RightBracket = 605,
+        /// `
+        GraveAccent = 606,
+        CapsLock = 607,
+        ScrollLock = 608,
+        NumLock = 609,
+        PrintScreen = 610,
+        Pause = 611,
+        Keypad0 = 612,
+        Keypad1 = 613,
+        Keypad2 = 614,
+        Keypad3 = 615,
+        Keypad4 = 616,
+        Keypad5 = 617,
+        Keypad6 = 618,
+        Keypad7 = 619,
+        Keypad8 = 620,
+        Keypad9 = 621,
+        KeypadDecimal = 622,
+        KeypadDivide = 623,
+        KeypadMultiply = 624,
+        KeypadSubtract = 625,
+        KeypadAdd = 626,
+        KeypadEnter = 627,
+        KeypadEqual = 628,
+        /// Available on some keyboard/mouses. Often referred as "Browser Back"
+        AppBack = 629,
+        AppForward = 630,
+        /// Non-US backslash.
+        Oem102 = 631,
+        /// Menu        | +       | Options  |
+        GamepadStart = 632,
+        /// View        | -       | Share    |
+        GamepadBack = 633,
+        /// X           | Y       | Square   | Tap: Toggle Menu. Hold: Windowing mode (Focus/Move/Resize windows)
+        GamepadFaceLeft = 634,
+        /// B           | A       | Circle   | Cancel / Close / Exit
+        GamepadFaceRight = 635,
+        /// Y           | X       | Triangle | Text Input / On-screen Keyboard
+        GamepadFaceUp = 636,
+        /// A           | B       | Cross    | Activate / Open / Toggle / Tweak
+        GamepadFaceDown = 637,
+        /// D-pad Left  | "       | "        | Move / Tweak / Resize Window (in Windowing mode)
+        GamepadDpadLeft = 638,
+        /// D-pad Right | "       | "        | Move / Tweak / Resize Window (in Windowing mode)
+        GamepadDpadRight = 639,
+        /// D-pad Up    | "       | "        | Move / Tweak / Resize Window (in Windowing mode)
+        GamepadDpadUp = 640,
+        /// D-pad Down  | "       | "        | Move / Tweak / Resize Window (in Windowing mode)
+        GamepadDpadDown = 641,
+        /// L Bumper    | L       | L1       | Tweak Slower / Focus Previous (in Windowing mode)
+        GamepadL1 = 642,
+        /// R Bumper    | R       | R1       | Tweak Faster / Focus Next (in Windowing mode)
+        GamepadR1 = 643,
+        /// L Trigger   | ZL      | L2       | [Analog]
+        GamepadL2 = 644,
+        /// R Trigger   | ZR      | R2       | [Analog]
+        GamepadR2 = 645,
+        /// L Stick     | L3      | L3       |
+        GamepadL3 = 646,
+        /// R Stick     | R3      | R3       |
+        GamepadR3 = 647,
+        /// |         |          | [Analog] Move Window (in Windowing mode)
+        GamepadLStickLeft = 648,
+        /// |         |          | [Analog] Move Window (in Windowing mode)
+        GamepadLStickRight = 649,
+        /// |         |          | [Analog] Move Window (in Windowing mode)
+        GamepadLStickUp = 650,
+        /// |         |          | [Analog] Move Window (in Windowing mode)
+        GamepadLStickDown = 651,
+        /// |         |          | [Analog]
+        GamepadRStickLeft = 652,
+        /// |         |          | [Analog]
+        GamepadRStickRight = 653,
+        /// |         |          | [Analog]
+        GamepadRStickUp = 654,
+        /// |         |          | [Analog]
+        GamepadRStickDown = 655,
+        MouseLeft = 656,
+        MouseRight = 657,
+        MouseMiddle = 658,
+        MouseX1 = 659,
+        MouseX2 = 660,
+        MouseWheelX = 661,
+        MouseWheelY = 662,
+        #[doc(hidden)]
+        ReservedForModCtrl = 663,
+        #[doc(hidden)]
+        ReservedForModShift = 664,
+        #[doc(hidden)]
+        ReservedForModAlt = 665,
+        #[doc(hidden)]
+        ReservedForModSuper = 666,
+        /// Ctrl (non-macOS), Cmd (macOS)
+        Mod_Ctrl = 4096,
+        /// Shift
+        Mod_Shift = 8192,
+        /// Option/Menu
+        Mod_Alt = 16384,
+        /// Windows/Super (non-macOS), Ctrl (macOS)
+        Mod_Super = 32768,
+    }
+}
+
+impl Key {
+    /// First valid key value (other than 0)
+    pub const NamedKey_BEGIN: super::ImGuiKey = 512;
+    #[doc(hidden)]
+    pub const NamedKey_END: super::ImGuiKey = 667;
+    #[doc(hidden)]
+    pub const NamedKey_COUNT: super::ImGuiKey = 155;
+    pub const Mod_None: Self = Self::None;
+    /// 4-bits
+    #[doc(hidden)]
+    pub const Mod_Mask_: super::ImGuiKey = 61440;
+    /// Obsoleted in 1.91.5 because it was misleading (since named keys don't start at 0 anymore)
+    pub const COUNT: super::ImGuiKey = 667;
+    /// Removed in 1.90.7, you can now simply use ImGuiMod_Ctrl
+    pub const Mod_Shortcut: Self = Self::Mod_Ctrl;
+}
+
+flags! {
+    /// Flags for Shortcut(), SetNextItemShortcut(),
+    /// (and for upcoming extended versions of IsKeyPressed(), IsMouseClicked(), Shortcut(), SetKeyOwner(), SetItemKeyOwner() that are still in imgui_internal.h)
+    /// Don't mistake with ImGuiInputTextFlags! (which is for ImGui::InputText() function)
+    ///
+    /// Typed `ImGuiInputFlags_`, the raw value is [`ImGuiInputFlags`](super::ImGuiInputFlags).
+    pub struct InputFlags: super::ImGuiInputFlags {
+        const None = 0;
+        /// Enable repeat. Return true on successive repeats. Default for legacy IsKeyPressed(). NOT Default for legacy IsMouseClicked(). MUST BE == 1.
+        const Repeat = 1;
+        /// Route to active item only.
+        const RouteActive = 1024;
+        /// Route to windows in the focus stack (DEFAULT). Deep-most focused window takes inputs. Active item takes inputs over deep-most focused window.
+        const RouteFocused = 2048;
+        /// Global route (unless a focused window or active item registered the route).
+        const RouteGlobal = 4096;
+        /// Do not register route, poll keys directly.
+        const RouteAlways = 8192;
+        /// Option: global route: higher priority than focused route (unless active item in focused route).
+        const RouteOverFocused = 16384;
+        /// Option: global route: higher priority than active item. Unlikely you need to use that: will interfere with every active items, e.g. Ctrl+A registered by InputText will be overridden by this. May not be fully honored as user/internal code is likely to always assume they can access keys when active.
+        const RouteOverActive = 32768;
+        /// Option: global route: will not be applied if underlying background/void is focused (== no Dear ImGui windows are focused). Useful for overlay applications.
+        const RouteUnlessBgFocused = 65536;
+        /// Option: route evaluated from the point of view of root window rather than current window.
+        const RouteFromRootWindow = 131072;
+        /// Automatically display a tooltip when hovering item [BETA] Unsure of right api (opt-in/opt-out)
+        const Tooltip = 262144;
+    }
+}
+
+flags! {
+    /// Configuration flags stored in io.ConfigFlags. Set by user/application.
+    ///
+    /// Typed `ImGuiConfigFlags_`, the raw value is [`ImGuiConfigFlags`](super::ImGuiConfigFlags).
+    pub struct ConfigFlags: super::ImGuiConfigFlags {
+        const None = 0;
+        /// Master keyboard navigation enable flag. Enable full Tabbing + directional arrows + space/enter to activate.
+        const NavEnableKeyboard = 1;
+        /// Master gamepad navigation enable flag. Backend also needs to set ImGuiBackendFlags_HasGamepad.
+        const NavEnableGamepad = 2;
+        /// Instruct dear imgui to disable mouse inputs and interactions.
+        const NoMouse = 16;
+        /// Instruct backend to not alter mouse cursor shape and visibility. Use if the backend cursor changes are interfering with yours and you don't want to use SetMouseCursor() to change mouse cursor. You may want to honor requests from imgui by reading GetMouseCursor() yourself instead.
+        const NoMouseCursorChange = 32;
+        /// Instruct dear imgui to disable keyboard inputs and interactions. This is done by ignoring keyboard events and clearing existing states.
+        const NoKeyboard = 64;
+        /// Application is SRGB-aware.
+        const IsSRGB = 1048576;
+        /// Application is using a touch screen instead of a mouse.
+        const IsTouchScreen = 2097152;
+        /// [moved/renamed in 1.91.4] -> use bool io.ConfigNavMoveSetMousePos
+        const NavEnableSetMousePos = 4;
+        /// [moved/renamed in 1.91.4] -> use bool io.ConfigNavCaptureKeyboard
+        const NavNoCaptureKeyboard = 8;
+    }
+}
+
+flags! {
+    /// Backend capabilities flags stored in io.BackendFlags. Set by imgui_impl_xxx or custom backend.
+    ///
+    /// Typed `ImGuiBackendFlags_`, the raw value is [`ImGuiBackendFlags`](super::ImGuiBackendFlags).
+    pub struct BackendFlags: super::ImGuiBackendFlags {
+        const None = 0;
+        /// Backend Platform supports gamepad and currently has one connected.
+        const HasGamepad = 1;
+        /// Backend Platform supports honoring GetMouseCursor() value to change the OS cursor shape.
+        const HasMouseCursors = 2;
+        /// Backend Platform supports io.WantSetMousePos requests to reposition the OS mouse position (only used if io.ConfigNavMoveSetMousePos is set).
+        const HasSetMousePos = 4;
+        /// Backend Renderer supports ImDrawCmd::VtxOffset. This enables output of large meshes (64K+ vertices) while still using 16-bit indices.
+        const RendererHasVtxOffset = 8;
+        /// Backend Renderer supports ImTextureData requests to create/update/destroy textures. This enables incremental texture updates and texture reloads. See https://github.com/ocornut/imgui/blob/master/docs/BACKENDS.md for instructions on how to upgrade your custom backend.
+        const RendererHasTextures = 16;
+    }
+}
+
+enumeration! {
+    /// Enumeration for PushStyleColor() / PopStyleColor()
+    ///
+    /// Typed `ImGuiCol_`, the raw value is [`ImGuiCol`](super::ImGuiCol).
+    pub enum Col: super::ImGuiCol {
+        Text = 0,
+        TextDisabled = 1,
+        /// Background of normal windows
+        WindowBg = 2,
+        /// Background of child windows
+        ChildBg = 3,
+        /// Background of popups, menus, tooltips windows
+        PopupBg = 4,
+        Border = 5,
+        BorderShadow = 6,
+        /// Background of checkbox, radio button, plot, slider, text input
+        FrameBg = 7,
+        FrameBgHovered = 8,
+        FrameBgActive = 9,
+        /// Title bar
+        TitleBg = 10,
+        /// Title bar when focused
+        TitleBgActive = 11,
+        /// Title bar when collapsed
+        TitleBgCollapsed = 12,
+        MenuBarBg = 13,
+        ScrollbarBg = 14,
+        ScrollbarGrab = 15,
+        ScrollbarGrabHovered = 16,
+        ScrollbarGrabActive = 17,
+        /// Checkbox tick and RadioButton circle
+        CheckMark = 18,
+        SliderGrab = 19,
+        SliderGrabActive = 20,
+        Button = 21,
+        ButtonHovered = 22,
+        ButtonActive = 23,
+        /// Header* colors are used for CollapsingHeader, TreeNode, Selectable, MenuItem
+        Header = 24,
+        HeaderHovered = 25,
+        HeaderActive = 26,
+        Separator = 27,
+        SeparatorHovered = 28,
+        SeparatorActive = 29,
+        /// Resize grip in lower-right and lower-left corners of windows.
+        ResizeGrip = 30,
+        ResizeGripHovered = 31,
+        ResizeGripActive = 32,
+        /// InputText cursor/caret
+        InputTextCursor = 33,
+        /// Tab background, when hovered
+        TabHovered = 34,
+        /// Tab background, when tab-bar is focused & tab is unselected
+        Tab = 35,
+        /// Tab background, when tab-bar is focused & tab is selected
+        TabSelected = 36,
+        /// Tab horizontal overline, when tab-bar is focused & tab is selected
+        TabSelectedOverline = 37,
+        /// Tab background, when tab-bar is unfocused & tab is unselected
+        TabDimmed = 38,
+        /// Tab background, when tab-bar is unfocused & tab is selected
+        TabDimmedSelected = 39,
+        /// ..horizontal overline, when tab-bar is unfocused & tab is selected
+        TabDimmedSelectedOverline = 40,
+        PlotLines = 41,
+        PlotLinesHovered = 42,
+        PlotHistogram = 43,
+        PlotHistogramHovered = 44,
+        /// Table header background
+        TableHeaderBg = 45,
+        /// Table outer and header borders (prefer using Alpha=1.0 here)
+        TableBorderStrong = 46,
+        /// Table inner borders (prefer using Alpha=1.0 here)
+        TableBorderLight = 47,
+        /// Table row background (even rows)
+        TableRowBg = 48,
+        /// Table row background (odd rows)
+        TableRowBgAlt = 49,
+        /// Hyperlink color
+        TextLink = 50,
+        /// Selected text inside an InputText
+        TextSelectedBg = 51,
+        /// Tree node hierarchy outlines when using ImGuiTreeNodeFlags_DrawLines
+        TreeLines = 52,
+        /// Rectangle border highlighting a drop target
+        DragDropTarget = 53,
+        /// Rectangle background highlighting a drop target
+        DragDropTargetBg = 54,
+        /// Unsaved Document marker (in window title and tabs)
+        UnsavedMarker = 55,
+        /// Color of keyboard/gamepad navigation cursor/rectangle, when visible
+        NavCursor = 56,
+        /// Highlight window when using Ctrl+Tab
+        NavWindowingHighlight = 57,
+        /// Darken/colorize entire screen behind the Ctrl+Tab window list, when active
+        NavWindowingDimBg = 58,
+        /// Darken/colorize entire screen behind a modal window, when one is active
+        ModalWindowDimBg = 59,
+    }
+}
+
+impl Col {
+    pub const COUNT: super::ImGuiCol = 60;
+    /// [renamed in 1.90.9]
+    pub const TabActive: Self = Self::TabSelected;
+    /// [renamed in 1.90.9]
+    pub const TabUnfocused: Self = Self::TabDimmed;
+    /// [renamed in 1.90.9]
+    pub const TabUnfocusedActive: Self = Self::TabDimmedSelected;
+    /// [renamed in 1.91.4]
+    pub const NavHighlight: Self = Self::NavCursor;
+}
+
+enumeration! {
+    /// Enumeration for PushStyleVar() / PopStyleVar() to temporarily modify the ImGuiStyle structure.
+    /// - The enum only refers to fields of ImGuiStyle which makes sense to be pushed/popped inside UI code.
+    /// During initialization or between frames, feel free to just poke into ImGuiStyle directly.
+    /// - Tip: Use your programming IDE navigation facilities on the names in the _second column_ below to find the actual members and their description.
+    /// - In Visual Studio: Ctrl+Comma ("Edit.GoToAll") can follow symbols inside comments, whereas Ctrl+F12 ("Edit.GoToImplementation") cannot.
+    /// - In Visual Studio w/ Visual Assist installed: Alt+G ("VAssistX.GoToImplementation") can also follow symbols inside comments.
+    /// - In VS Code, CLion, etc.: Ctrl+Click can follow symbols inside comments.
+    /// - When changing this enum, you need to update the associated internal table GStyleVarInfo[] accordingly. This is where we link enum values to members offset/type.
+    ///
+    /// Typed `ImGuiStyleVar_`, the raw value is [`ImGuiStyleVar`](super::ImGuiStyleVar).
+    pub enum StyleVar: super::ImGuiStyleVar {
+        /// float     Alpha
+        Alpha = 0,
+        /// float     DisabledAlpha
+        DisabledAlpha = 1,
+        /// ImVec2    WindowPadding
+        WindowPadding = 2,
+        /// float     WindowRounding
+        WindowRounding = 3,
+        /// float     WindowBorderSize
+        WindowBorderSize = 4,
+        /// ImVec2    WindowMinSize
+        WindowMinSize = 5,
+        /// ImVec2    WindowTitleAlign
+        WindowTitleAlign = 6,
+        /// float     ChildRounding
+        ChildRounding = 7,
+        /// float     ChildBorderSize
+        ChildBorderSize = 8,
+        /// float     PopupRounding
+        PopupRounding = 9,
+        /// float     PopupBorderSize
+        PopupBorderSize = 10,
+        /// ImVec2    FramePadding
+        FramePadding = 11,
+        /// float     FrameRounding
+        FrameRounding = 12,
+        /// float     FrameBorderSize
+        FrameBorderSize = 13,
+        /// ImVec2    ItemSpacing
+        ItemSpacing = 14,
+        /// ImVec2    ItemInnerSpacing
+        ItemInnerSpacing = 15,
+        /// float     IndentSpacing
+        IndentSpacing = 16,
+        /// ImVec2    CellPadding
+        CellPadding = 17,
+        /// float     ScrollbarSize
+        ScrollbarSize = 18,
+        /// float     ScrollbarRounding
+        ScrollbarRounding = 19,
+        /// float     ScrollbarPadding
+        ScrollbarPadding = 20,
+        /// float     GrabMinSize
+        GrabMinSize = 21,
+        /// float     GrabRounding
+        GrabRounding = 22,
+        /// float     ImageBorderSize
+        ImageBorderSize = 23,
+        /// float     TabRounding
+        TabRounding = 24,
+        /// float     TabBorderSize
+        TabBorderSize = 25,
+        /// float     TabMinWidthBase
+        TabMinWidthBase = 26,
+        /// float     TabMinWidthShrink
+        TabMinWidthShrink = 27,
+        /// float     TabBarBorderSize
+        TabBarBorderSize = 28,
+        /// float     TabBarOverlineSize
+        TabBarOverlineSize = 29,
+        /// float     TableAngledHeadersAngle
+        TableAngledHeadersAngle = 30,
+        /// ImVec2  TableAngledHeadersTextAlign
+        TableAngledHeadersTextAlign = 31,
+        /// float     TreeLinesSize
+        TreeLinesSize = 32,
+        /// float     TreeLinesRounding
+        TreeLinesRounding = 33,
+        /// ImVec2    ButtonTextAlign
+        ButtonTextAlign = 34,
+        /// ImVec2    SelectableTextAlign
+        SelectableTextAlign = 35,
+        /// float     SeparatorTextBorderSize
+        SeparatorTextBorderSize = 36,
+        /// ImVec2    SeparatorTextAlign
+        SeparatorTextAlign = 37,
+        /// ImVec2    SeparatorTextPadding
+        SeparatorTextPadding = 38,
+    }
+}
+
+impl StyleVar {
+    pub const COUNT: super::ImGuiStyleVar = 39;
+}
+
+flags! {
+    /// Flags for InvisibleButton() [extended in imgui_internal.h]
+    ///
+    /// Typed `ImGuiButtonFlags_`, the raw value is [`ImGuiButtonFlags`](super::ImGuiButtonFlags).
+    pub struct ButtonFlags: super::ImGuiButtonFlags {
+        const None = 0;
+        /// React on left mouse button (default)
+        const MouseButtonLeft = 1;
+        /// React on right mouse button
+        const MouseButtonRight = 2;
+        /// React on center mouse button
+        const MouseButtonMiddle = 4;
+        /// [Internal]
+        #[doc(hidden)]
+        const MouseButtonMask_ = 7;
+        /// InvisibleButton(): do not disable navigation/tabbing. Otherwise disabled by default.
+        const EnableNav = 8;
+    }
+}
+
+flags! {
+    /// Flags for ColorEdit3() / ColorEdit4() / ColorPicker3() / ColorPicker4() / ColorButton()
+    ///
+    /// Typed `ImGuiColorEditFlags_`, the raw value is [`ImGuiColorEditFlags`](super::ImGuiColorEditFlags).
+    pub struct ColorEditFlags: super::ImGuiColorEditFlags {
+        const None = 0;
+        /// // ColorEdit, ColorPicker, ColorButton: ignore Alpha component (will only read 3 components from the input pointer).
+        const NoAlpha = 2;
+        /// // ColorEdit: disable picker when clicking on color square.
+        const NoPicker = 4;
+        /// // ColorEdit: disable toggling options menu when right-clicking on inputs/small preview.
+        const NoOptions = 8;
+        /// // ColorEdit, ColorPicker: disable color square preview next to the inputs. (e.g. to show only the inputs)
+        const NoSmallPreview = 16;
+        /// // ColorEdit, ColorPicker: disable inputs sliders/text widgets (e.g. to show only the small preview color square).
+        const NoInputs = 32;
+        /// // ColorEdit, ColorPicker, ColorButton: disable tooltip when hovering the preview.
+        const NoTooltip = 64;
+        /// // ColorEdit, ColorPicker: disable display of inline text label (the label is still forwarded to the tooltip and picker).
+        const NoLabel = 128;
+        /// // ColorPicker: disable bigger color preview on right side of the picker, use small color square preview instead.
+        const NoSidePreview = 256;
+        /// // ColorEdit: disable drag and drop target. ColorButton: disable drag and drop source.
+        const NoDragDrop = 512;
+        /// // ColorButton: disable border (which is enforced by default)
+        const NoBorder = 1024;
+        /// // ColorEdit, ColorPicker, ColorButton: disable alpha in the preview,. Contrary to _NoAlpha it may still be edited when calling ColorEdit4()/ColorPicker4(). For ColorButton() this does the same as _NoAlpha.
+        const AlphaOpaque = 2048;
+        /// // ColorEdit, ColorPicker, ColorButton: disable rendering a checkerboard background behind transparent color.
+        const AlphaNoBg = 4096;
+        /// // ColorEdit, ColorPicker, ColorButton: display half opaque / half transparent preview.
+        const AlphaPreviewHalf = 8192;
+        /// // ColorEdit, ColorPicker: show vertical alpha bar/gradient in picker.
+        const AlphaBar = 65536;
+        /// // (WIP) ColorEdit: Currently only disable 0.0f..1.0f limits in RGBA edition (note: you probably want to use ImGuiColorEditFlags_Float flag as well).
+        const HDR = 524288;
+        /// [Display]    // ColorEdit: override _display_ type among RGB/HSV/Hex. ColorPicker: select any combination using one or more of RGB/HSV/Hex.
+        const DisplayRGB = 1048576;
+        /// [Display]    // "
+        const DisplayHSV = 2097152;
+        /// [Display]    // "
+        const DisplayHex = 4194304;
+        /// [DataType]   // ColorEdit, ColorPicker, ColorButton: _display_ values formatted as 0..255.
+        const Uint8 = 8388608;
+        /// [DataType]   // ColorEdit, ColorPicker, ColorButton: _display_ values formatted as 0.0f..1.0f floats instead of 0..255 integers. No round-trip of value via integers.
+        const Float = 16777216;
+        /// [Picker]     // ColorPicker: bar for Hue, rectangle for Sat/Value.
+        const PickerHueBar = 33554432;
+        /// [Picker]     // ColorPicker: wheel for Hue, triangle for Sat/Value.
+        const PickerHueWheel = 67108864;
+        /// [Input]      // ColorEdit, ColorPicker: input and output data in RGB format.
+        const InputRGB = 134217728;
+        /// [Input]      // ColorEdit, ColorPicker: input and output data in HSV format.
+        const InputHSV = 268435456;
+        #[doc(hidden)]
+        const AlphaMask_ = 14338;
+        #[doc(hidden)]
+        const DisplayMask_ = 7340032;
+        #[doc(hidden)]
+        const DataTypeMask_ = 25165824;
+        #[doc(hidden)]
+        const PickerMask_ = 100663296;
+        #[doc(hidden)]
+        const InputMask_ = 402653184;
+        /// Removed in 1.91.8. This is the default now. Will display a checkerboard unless ImGuiColorEditFlags_AlphaNoBg is set.
+        const AlphaPreview = 0;
+    }
+}
+
+flags! {
+    /// Flags for DragFloat(), DragInt(), SliderFloat(), SliderInt() etc.
+    /// We use the same sets of flags for DragXXX() and SliderXXX() functions as the features are the same and it makes it easier to swap them.
+    /// (Those are per-item flags. There is shared behavior flag too: ImGuiIO: io.ConfigDragClickToInputText)
+    ///
+    /// Typed `ImGuiSliderFlags_`, the raw value is [`ImGuiSliderFlags`](super::ImGuiSliderFlags).
+    pub struct SliderFlags: super::ImGuiSliderFlags {
+        const None = 0;
+        /// Make the widget logarithmic (linear otherwise). Consider using ImGuiSliderFlags_NoRoundToFormat with this if using a format-string with small amount of digits.
+        const Logarithmic = 32;
+        /// Disable rounding underlying value to match precision of the display format string (e.g. %.3f values are rounded to those 3 digits).
+        const NoRoundToFormat = 64;
+        /// Disable Ctrl+Click or Enter key allowing to input text directly into the widget.
+        const NoInput = 128;
+        /// Enable wrapping around from max to min and from min to max. Only supported by DragXXX() functions for now.
+        const WrapAround = 256;
+        /// Clamp value to min/max bounds when input manually with Ctrl+Click. By default Ctrl+Click allows going out of bounds.
+        const ClampOnInput = 512;
+        /// Clamp even if min==max==0.0f. Otherwise due to legacy reason DragXXX functions don't clamp with those values. When your clamping limits are dynamic you almost always want to use it.
+        const ClampZeroRange = 1024;
+        /// Disable keyboard modifiers altering tweak speed. Useful if you want to alter tweak speed yourself based on your own logic.
+        const NoSpeedTweaks = 2048;
+        const AlwaysClamp = 1536;
+        /// [Internal] We treat using those bits as being potentially a 'float power' argument from the previous API that has got miscast to this enum, and will trigger an assert if needed.
+        #[doc(hidden)]
+        const InvalidMask_ = 1879048207;
+    }
+}
+
+enumeration! {
+    /// Identify a mouse button.
+    /// Those values are guaranteed to be stable and we frequently use 0/1 directly. Named enums provided for convenience.
+    ///
+    /// Typed `ImGuiMouseButton_`, the raw value is [`ImGuiMouseButton`](super::ImGuiMouseButton).
+    pub enum MouseButton: super::ImGuiMouseButton {
+        Left = 0,
+        Right = 1,
+        Middle = 2,
+    }
+}
+
+impl MouseButton {
+    pub const COUNT: super::ImGuiMouseButton = 5;
+}
+
+enumeration! {
+    /// Enumeration for GetMouseCursor()
+    /// User code may request backend to display given cursor by calling SetMouseCursor(), which is why we have some cursors that are marked unused here
+    ///
+    /// Typed `ImGuiMouseCursor_`, the raw value is [`ImGuiMouseCursor`](super::ImGuiMouseCursor).
+    pub enum MouseCursor: super::ImGuiMouseCursor {
+        None = -1,
+        Arrow = 0,
+        /// When hovering over InputText, etc.
+        TextInput = 1,
+        /// (Unused by Dear ImGui functions)
+        ResizeAll = 2,
+        /// When hovering over a horizontal border
+        ResizeNS = 3,
+        /// When hovering over a vertical border or a column
+        ResizeEW = 4,
+        /// When hovering over the bottom-left corner of a window
+        ResizeNESW = 5,
+        /// When hovering over the bottom-right corner of a window
+        ResizeNWSE = 6,
+        /// (Unused by Dear ImGui functions. Use for e.g. hyperlinks)
+        Hand = 7,
+        /// When waiting for something to process/load.
+        Wait = 8,
+        /// When waiting for something to process/load, but application is still interactive.
+        Progress = 9,
+        /// When hovering something with disallowed interaction. Usually a crossed circle.
+        NotAllowed = 10,
+    }
+}
+
+impl MouseCursor {
+    pub const COUNT: super::ImGuiMouseCursor = 11;
+}
+
+enumeration! {
+    /// Enumeration for AddMouseSourceEvent() actual source of Mouse Input data.
+    /// Historically we use "Mouse" terminology everywhere to indicate pointer data, e.g. MousePos, IsMousePressed(), io.AddMousePosEvent()
+    /// But that "Mouse" data can come from different source which occasionally may be useful for application to know about.
+    /// You can submit a change of pointer type using io.AddMouseSourceEvent().
+    ///
+    /// Typed `ImGuiMouseSource`, the raw value is [`ImGuiMouseSource`](super::ImGuiMouseSource).
+    pub enum MouseSource: super::ImGuiMouseSource {
+        /// Input is coming from an actual mouse.
+        Mouse = 0,
+        /// Input is coming from a touch screen (no hovering prior to initial press, less precise initial press aiming, dual-axis wheeling possible).
+        TouchScreen = 1,
+        /// Input is coming from a pressure/magnetic pen (often used in conjunction with high-sampling rates).
+        Pen = 2,
+    }
+}
+
+impl MouseSource {
+    pub const COUNT: super::ImGuiMouseSource = 3;
+}
+
+enumeration! {
+    /// Enumeration for ImGui::SetNextWindow***(), SetWindow***(), SetNextItem***() functions
+    /// Represent a condition.
+    /// Important: Treat as a regular enum! Do NOT combine multiple values using binary operators! All the functions above treat 0 as a shortcut to ImGuiCond_Always.
+    ///
+    /// Typed `ImGuiCond_`, the raw value is [`ImGuiCond`](super::ImGuiCond).
+    pub enum Cond: super::ImGuiCond {
+        /// No condition (always set the variable), same as _Always
+        None = 0,
+        /// No condition (always set the variable), same as _None
+        Always = 1,
+        /// Set the variable once per runtime session (only the first call will succeed)
+        Once = 2,
+        /// Set the variable if the object/window has no persistently saved data (no entry in .ini file)
+        FirstUseEver = 4,
+        /// Set the variable if the object/window is appearing after being hidden/inactive (or the first time)
+        Appearing = 8,
+    }
+}
+
+flags! {
+    /// Flags for ImGui::BeginTable()
+    /// - Important! Sizing policies have complex and subtle side effects, much more so than you would expect.
+    /// Read comments/demos carefully + experiment with live demos to get acquainted with them.
+    /// - The DEFAULT sizing policies are:
+    /// - Default to ImGuiTableFlags_SizingFixedFit    if ScrollX is on, or if host window has ImGuiWindowFlags_AlwaysAutoResize.
+    /// - Default to ImGuiTableFlags_SizingStretchSame if ScrollX is off.
+    /// - When ScrollX is off:
+    /// - Table defaults to ImGuiTableFlags_SizingStretchSame -> all Columns defaults to ImGuiTableColumnFlags_WidthStretch with same weight.
+    /// - Columns sizing policy allowed: Stretch (default), Fixed/Auto.
+    /// - Fixed Columns (if any) will generally obtain their requested width (unless the table cannot fit them all).
+    /// - Stretch Columns will share the remaining width according to their respective weight.
+    /// - Mixed Fixed/Stretch columns is possible but has various side-effects on resizing behaviors.
+    /// The typical use of mixing sizing policies is: any number of LEADING Fixed columns, followed by one or two TRAILING Stretch columns.
+    /// (this is because the visible order of columns have subtle but necessary effects on how they react to manual resizing).
+    /// - When ScrollX is on:
+    /// - Table defaults to ImGuiTableFlags_SizingFixedFit -> all Columns defaults to ImGuiTableColumnFlags_WidthFixed
+    /// - Columns sizing policy allowed: Fixed/Auto mostly.
+    /// - Fixed Columns can be enlarged as needed. Table will show a horizontal scrollbar if needed.
+    /// - When using auto-resizing (non-resizable) fixed columns, querying the content width to use item right-alignment e.g. SetNextItemWidth(-FLT_MIN) doesn't make sense, would create a feedback loop.
+    /// - Using Stretch columns OFTEN DOES NOT MAKE SENSE if ScrollX is on, UNLESS you have specified a value for 'inner_width' in BeginTable().
+    /// If you specify a value for 'inner_width' then effectively the scrolling space is known and Stretch or mixed Fixed/Stretch columns become meaningful again.
+    /// - Read on documentation at the top of imgui_tables.cpp for details.
+    ///
+    /// Typed `ImGuiTableFlags_`, the raw value is [`ImGuiTableFlags`](super::ImGuiTableFlags).
+    pub struct TableFlags: super::ImGuiTableFlags {
+        const None = 0;
+        /// Enable resizing columns.
+        const Resizable = 1;
+        /// Enable reordering columns in header row (need calling TableSetupColumn() + TableHeadersRow() to display headers)
+        const Reorderable = 2;
+        /// Enable hiding/disabling columns in context menu.
+        const Hideable = 4;
+        /// Enable sorting. Call TableGetSortSpecs() to obtain sort specs. Also see ImGuiTableFlags_SortMulti and ImGuiTableFlags_SortTristate.
+        const Sortable = 8;
+        /// Disable persisting columns order, width and sort settings in the .ini file.
+        const NoSavedSettings = 16;
+        /// Right-click on columns body/contents will display table context menu. By default it is available in TableHeadersRow().
+        const ContextMenuInBody = 32;
+        /// Set each RowBg color with ImGuiCol_TableRowBg or ImGuiCol_TableRowBgAlt (equivalent of calling TableSetBgColor with ImGuiTableBgFlags_RowBg0 on each row manually)
+        const RowBg = 64;
+        /// Draw horizontal borders between rows.
+        const BordersInnerH = 128;
+        /// Draw horizontal borders at the top and bottom.
+        const BordersOuterH = 256;
+        /// Draw vertical borders between columns.
+        const BordersInnerV = 512;
+        /// Draw vertical borders on the left and right sides.
+        const BordersOuterV = 1024;
+        /// Draw horizontal borders.
+        const BordersH = 384;
+        /// Draw vertical borders.
+        const BordersV = 1536;
+        /// Draw inner borders.
+        const BordersInner = 640;
+        /// Draw outer borders.
+        const BordersOuter = 1280;
+        /// Draw all borders.
+        const Borders = 1920;
+        /// [ALPHA] Disable vertical borders in columns Body (borders will always appear in Headers). -> May move to style
+        const NoBordersInBody = 2048;
+        /// [ALPHA] Disable vertical borders in columns Body until hovered for resize (borders will always appear in Headers). -> May move to style
+        const NoBordersInBodyUntilResize = 4096;
+        /// Columns default to _WidthFixed or _WidthAuto (if resizable or not resizable), matching contents width.
+        const SizingFixedFit = 8192;
+        /// Columns default to _WidthFixed or _WidthAuto (if resizable or not resizable), matching the maximum contents width of all columns. Implicitly enable ImGuiTableFlags_NoKeepColumnsVisible.
+        const SizingFixedSame = 16384;
+        /// Columns default to _WidthStretch with default weights proportional to each columns contents widths.
+        const SizingStretchProp = 24576;
+        /// Columns default to _WidthStretch with default weights all equal, unless overridden by TableSetupColumn().
+        const SizingStretchSame = 32768;
+        /// Make outer width auto-fit to columns, overriding outer_size.x value. Only available when ScrollX/ScrollY are disabled and Stretch columns are not used.
+        const NoHostExtendX = 65536;
+        /// Make outer height stop exactly at outer_size.y (prevent auto-extending table past the limit). Only available when ScrollX/ScrollY are disabled. Data below the limit will be clipped and not visible.
+        const NoHostExtendY = 131072;
+        /// Disable keeping column always minimally visible when ScrollX is off and table gets too small. Not recommended if columns are resizable.
+        const NoKeepColumnsVisible = 262144;
+        /// Disable distributing remainder width to stretched columns (width allocation on a 100-wide table with 3 columns: Without this flag: 33,33,34. With this flag: 33,33,33). With larger number of columns, resizing will appear to be less smooth.
+        const PreciseWidths = 524288;
+        /// Disable clipping rectangle for every individual columns (reduce draw command count, items will be able to overflow into other columns). Generally incompatible with TableSetupScrollFreeze().
+        const NoClip = 1048576;
+        /// Default if BordersOuterV is on. Enable outermost padding. Generally desirable if you have headers.
+        const PadOuterX = 2097152;
+        /// Default if BordersOuterV is off. Disable outermost padding.
+        const NoPadOuterX = 4194304;
+        /// Disable inner padding between columns (double inner padding if BordersOuterV is on, single inner padding if BordersOuterV is off).
+        const NoPadInnerX = 8388608;
+        /// Enable horizontal scrolling. Require 'outer_size' parameter of BeginTable() to specify the container size. Changes default sizing policy. Because this creates a child window, ScrollY is currently generally recommended when using ScrollX.
+        const ScrollX = 16777216;
+        /// Enable vertical scrolling. Require 'outer_size' parameter of BeginTable() to specify the container size.
+        const ScrollY = 33554432;
+        /// Hold shift when clicking headers to sort on multiple column. TableGetSortSpecs() may return specs where (SpecsCount > 1).
+        const SortMulti = 67108864;
+        /// Allow no sorting, disable default sorting. TableGetSortSpecs() may return specs where (SpecsCount == 0).
+        const SortTristate = 134217728;
+        /// Highlight column headers when hovered (may evolve into a fuller highlight)
+        const HighlightHoveredColumn = 268435456;
+        #[doc(hidden)]
+        const SizingMask_ = 57344;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::TableSetupColumn()
+    ///
+    /// Typed `ImGuiTableColumnFlags_`, the raw value is [`ImGuiTableColumnFlags`](super::ImGuiTableColumnFlags).
+    pub struct TableColumnFlags: super::ImGuiTableColumnFlags {
+        const None = 0;
+        /// Overriding/master disable flag: hide column, won't show in context menu (unlike calling TableSetColumnEnabled() which manipulates the user accessible state)
+        const Disabled = 1;
+        /// Default as a hidden/disabled column.
+        const DefaultHide = 2;
+        /// Default as a sorting column.
+        const DefaultSort = 4;
+        /// Column will stretch. Preferable with horizontal scrolling disabled (default if table sizing policy is _SizingStretchSame or _SizingStretchProp).
+        const WidthStretch = 8;
+        /// Column will not stretch. Preferable with horizontal scrolling enabled (default if table sizing policy is _SizingFixedFit and table is resizable).
+        const WidthFixed = 16;
+        /// Disable manual resizing.
+        const NoResize = 32;
+        /// Disable manual reordering this column, this will also prevent other columns from crossing over this column.
+        const NoReorder = 64;
+        /// Disable ability to hide/disable this column.
+        const NoHide = 128;
+        /// Disable clipping for this column (all NoClip columns will render in a same draw command).
+        const NoClip = 256;
+        /// Disable ability to sort on this field (even if ImGuiTableFlags_Sortable is set on the table).
+        const NoSort = 512;
+        /// TableHeadersRow() will submit an empty label for this column. Convenient for some small columns. Name will still appear in context menu or in angled headers. You may append into this cell by calling TableSetColumnIndex() right after the TableHeadersRow() call.
+        const NoHeaderLabel = 4096;
+        /// Disable header text width contribution to automatic column width.
+        const NoHeaderWidth = 8192;
+        /// Use current Indent value when entering cell (default for column 0).
+        const IndentEnable = 65536;
+        /// Status: is enabled == not hidden by user/api (referred to as "Hide" in _DefaultHide and _NoHide) flags.
+        const IsEnabled = 16777216;
+        /// Status: is visible == is enabled AND not clipped by scrolling.
+        const IsVisible = 33554432;
+        /// Status: is currently part of the sort specs
+        const IsSorted = 67108864;
+        #[doc(hidden)]
+        const WidthMask_ = 24;
+        #[doc(hidden)]
+        const IndentMask_ = 196608;
+    }
+}
+
+flags! {
+    /// Flags for ImGui::TableNextRow()
+    ///
+    /// Typed `ImGuiTableRowFlags_`, the raw value is [`ImGuiTableRowFlags`](super::ImGuiTableRowFlags).
+    pub struct TableRowFlags: super::ImGuiTableRowFlags {
+        const None = 0;
+        /// Identify header row (set default background color + width of its contents accounted differently for auto column width)
+        const Headers = 1;
+    }
+}
+
+enumeration! {
+    /// Enum for ImGui::TableSetBgColor()
+    /// Background colors are rendering in 3 layers:
+    /// - Layer 0: draw with RowBg0 color if set, otherwise draw with ColumnBg0 if set.
+    /// - Layer 1: draw with RowBg1 color if set, otherwise draw with ColumnBg1 if set.
+    /// - Layer 2: draw with CellBg color if set.
+    /// The purpose of the two row/columns layers is to let you decide if a background color change should override or blend with the existing color.
+    /// When using ImGuiTableFlags_RowBg on the table, each row has the RowBg0 color automatically set for odd/even rows.
+    /// If you set the color of RowBg0 target, your color will override the existing RowBg0 color.
+    /// If you set the color of RowBg1 or ColumnBg1 target, your color will blend over the RowBg0 color.
+    ///
+    /// Typed `ImGuiTableBgTarget_`, the raw value is [`ImGuiTableBgTarget`](super::ImGuiTableBgTarget).
+    pub enum TableBgTarget: super::ImGuiTableBgTarget {
+        None = 0,
+        /// Set row background color 0 (generally used for background, automatically set when ImGuiTableFlags_RowBg is used)
+        RowBg0 = 1,
+        /// Set row background color 1 (generally used for selection marking)
+        RowBg1 = 2,
+        /// Set cell background color (top-most color)
+        CellBg = 3,
+    }
+}
+
+flags! {
+    /// Flags for ImGuiListClipper (currently not fully exposed in function calls: a future refactor will likely add this to ImGuiListClipper::Begin function equivalent)
+    ///
+    /// Typed `ImGuiListClipperFlags_`, the raw value is [`ImGuiListClipperFlags`](super::ImGuiListClipperFlags).
+    pub struct ListClipperFlags: super::ImGuiListClipperFlags {
+        const None = 0;
+    }
+}
+
+flags! {
+    /// Flags for BeginMultiSelect()
+    ///
+    /// Typed `ImGuiMultiSelectFlags_`, the raw value is [`ImGuiMultiSelectFlags`](super::ImGuiMultiSelectFlags).
+    pub struct MultiSelectFlags: super::ImGuiMultiSelectFlags {
+        const None = 0;
+        /// Disable selecting more than one item. This is available to allow single-selection code to share same code/logic if desired. It essentially disables the main purpose of BeginMultiSelect() tho!
+        const SingleSelect = 1;
+        /// Disable Ctrl+A shortcut to select all.
+        const NoSelectAll = 2;
+        /// Disable Shift+selection mouse/keyboard support (useful for unordered 2D selection). With BoxSelect is also ensure contiguous SetRange requests are not combined into one. This allows not handling interpolation in SetRange requests.
+        const NoRangeSelect = 4;
+        /// Disable selecting items when navigating (useful for e.g. supporting range-select in a list of checkboxes).
+        const NoAutoSelect = 8;
+        /// Disable clearing selection when navigating or selecting another one (generally used with ImGuiMultiSelectFlags_NoAutoSelect. useful for e.g. supporting range-select in a list of checkboxes).
+        const NoAutoClear = 16;
+        /// Enable box-selection with same width and same x pos items (e.g. full row Selectable()). Box-selection works better with little bit of spacing between items hit-box in order to be able to aim at empty space.
+        const BoxSelect1d = 64;
+        /// Enable box-selection with varying width or varying x pos items support (e.g. different width labels, or 2D layout/grid). This is slower: alters clipping logic so that e.g. horizontal movements will update selection of normally clipped items.
+        const BoxSelect2d = 128;
+        /// Clear selection when pressing Escape while scope is focused.
+        const ClearOnEscape = 512;
+        /// Scope for _BoxSelect and _ClearOnClickVoid is whole window (Default). Use if BeginMultiSelect() covers a whole window or used a single time in same window.
+        const ScopeWindow = 2048;
+        /// Scope for _BoxSelect and _ClearOnClickVoid is rectangle encompassing BeginMultiSelect()/EndMultiSelect(). Use if BeginMultiSelect() is called multiple times in same window.
+        const ScopeRect = 4096;
+        /// Apply selection on mouse down when clicking on unselected item. (Default)
+        const SelectOnClick = 8192;
+        /// [Temporary] Enable navigation wrapping on X axis. Provided as a convenience because we don't have a design for the general Nav API for this yet. When the more general feature be public we may obsolete this flag in favor of new one.
+        const NavWrapX = 65536;
+    }
+}
+
+enumeration! {
+    /// Selection request type
+    ///
+    /// Typed `ImGuiSelectionRequestType`, the raw value is [`ImGuiSelectionRequestType`](super::ImGuiSelectionRequestType).
+    pub enum SelectionRequestType: super::ImGuiSelectionRequestType {
+        None = 0,
+        /// Request app to clear selection (if Selected==false) or select all items (if Selected==true). We cannot set RangeFirstItem/RangeLastItem as its contents is entirely up to user (not necessarily an index)
+        SetAll = 1,
+    }
+}
+
+flags! {
+    /// Flags for ImDrawList functions
+    /// (Legacy: bit 0 must always correspond to ImDrawFlags_Closed to be backward compatible with old API using a bool. Bits 1..3 must be unused)
+    ///
+    /// Typed `ImDrawFlags_`, the raw value is [`ImDrawFlags`](super::ImDrawFlags).
+    pub struct DrawFlags: super::ImDrawFlags {
+        const None = 0;
+        /// PathStroke(), AddPolyline(): specify that shape should be closed (Important: this is always == 1 for legacy reason)
+        const Closed = 1;
+        /// AddRect(), AddRectFilled(), PathRect(): enable rounding top-left corner only (when rounding > 0.0f, we default to all corners). Was 0x01.
+        const RoundCornersTopLeft = 16;
+        /// AddRect(), AddRectFilled(), PathRect(): enable rounding top-right corner only (when rounding > 0.0f, we default to all corners). Was 0x02.
+        const RoundCornersTopRight = 32;
+        /// AddRect(), AddRectFilled(), PathRect(): enable rounding bottom-left corner only (when rounding > 0.0f, we default to all corners). Was 0x04.
+        const RoundCornersBottomLeft = 64;
+        /// AddRect(), AddRectFilled(), PathRect(): enable rounding bottom-right corner only (when rounding > 0.0f, we default to all corners). Wax 0x08.
+        const RoundCornersBottomRight = 128;
+        /// AddRect(), AddRectFilled(), PathRect(): disable rounding on all corners (when rounding > 0.0f). This is NOT zero, NOT an implicit flag!
+        const RoundCornersNone = 256;
+        const RoundCornersTop = 48;
+        const RoundCornersBottom = 192;
+        const RoundCornersLeft = 80;
+        const RoundCornersRight = 160;
+        const RoundCornersAll = 240;
+        /// Default to ALL corners if none of the _RoundCornersXX flags are specified.
+        #[doc(hidden)]
+        const RoundCornersDefault_ = 240;
+        #[doc(hidden)]
+        const RoundCornersMask_ = 496;
+    }
+}
+
+flags! {
+    /// Flags for ImDrawList instance. Those are set automatically by ImGui:: functions from ImGuiIO settings, and generally not manipulated directly.
+    /// It is however possible to temporarily alter flags between calls to ImDrawList:: functions.
+    ///
+    /// Typed `ImDrawListFlags_`, the raw value is [`ImDrawListFlags`](super::ImDrawListFlags).
+    pub struct DrawListFlags: super::ImDrawListFlags {
+        const None = 0;
+        /// Enable anti-aliased lines/borders (*2 the number of triangles for 1.0f wide line or lines thin enough to be drawn using textures, otherwise *3 the number of triangles)
+        const AntiAliasedLines = 1;
+        /// Enable anti-aliased lines/borders using textures when possible. Require backend to render with bilinear filtering (NOT point/nearest filtering).
+        const AntiAliasedLinesUseTex = 2;
+        /// Enable anti-aliased edge around filled shapes (rounded rectangles, circles).
+        const AntiAliasedFill = 4;
+        /// Can emit 'VtxOffset > 0' to allow large meshes. Set when 'ImGuiBackendFlags_RendererHasVtxOffset' is enabled.
+        const AllowVtxOffset = 8;
+    }
+}
+
+enumeration! {
+    /// Most standard backends only support RGBA32 but we provide a single channel option for low-resource/embedded systems.
+    ///
+    /// Typed `ImTextureFormat`, the raw value is [`ImTextureFormat`](super::ImTextureFormat).
+    pub enum TextureFormat: super::ImTextureFormat {
+        /// 4 components per pixel, each is unsigned 8-bit. Total size = TexWidth * TexHeight * 4
+        RGBA32 = 0,
+        /// 1 component per pixel, each is unsigned 8-bit. Total size = TexWidth * TexHeight
+        Alpha8 = 1,
+    }
+}
+
+enumeration! {
+    /// Status of a texture to communicate with Renderer Backend.
+    ///
+    /// Typed `ImTextureStatus`, the raw value is [`ImTextureStatus`](super::ImTextureStatus).
+    pub enum TextureStatus: super::ImTextureStatus {
+        OK = 0,
+        /// Backend destroyed the texture.
+        Destroyed = 1,
+        /// Requesting backend to create the texture. Set status OK when done.
+        WantCreate = 2,
+        /// Requesting backend to update specific blocks of pixels (write to texture portions which have never been used before). Set status OK when done.
+        WantUpdates = 3,
+        /// Requesting backend to destroy the texture. Set status to Destroyed when done.
+        WantDestroy = 4,
+    }
+}
+
+flags! {
+    /// Flags for ImFontAtlas build
+    ///
+    /// Typed `ImFontAtlasFlags_`, the raw value is [`ImFontAtlasFlags`](super::ImFontAtlasFlags).
+    pub struct FontAtlasFlags: super::ImFontAtlasFlags {
+        const None = 0;
+        /// Don't round the height to next power of two
+        const NoPowerOfTwoHeight = 1;
+        /// Don't build software mouse cursors into the atlas (save a little texture memory)
+        const NoMouseCursors = 2;
+        /// Don't build thick line textures into the atlas (save a little texture memory, allow support for point/nearest filtering). The AntiAliasedLinesUseTex features uses them, otherwise they will be rendered using polygons (more expensive for CPU/GPU).
+        const NoBakedLines = 4;
+    }
+}
+
+flags! {
+    /// Font flags
+    /// (in future versions as we redesign font loading API, this will become more important and better documented. for now please consider this as internal/advanced use)
+    ///
+    /// Typed `ImFontFlags_`, the raw value is [`ImFontFlags`](super::ImFontFlags).
+    pub struct FontFlags: super::ImFontFlags {
+        const None = 0;
+        /// Disable throwing an error/assert when calling AddFontXXX() with missing file/data. Calling code is expected to check AddFontXXX() return value.
+        const NoLoadError = 2;
+        /// [Internal] Disable loading new glyphs.
+        const NoLoadGlyphs = 4;
+        /// [Internal] Disable loading new baked sizes, disable garbage collecting current ones. e.g. if you want to lock a font to a single size. Important: if you use this to preload given sizes, consider the possibility of multiple font density used on Retina display.
+        const LockBakedSizes = 8;
+    }
+}
+
+flags! {
+    /// Flags stored in ImGuiViewport::Flags, giving indications to the platform backends.
+    ///
+    /// Typed `ImGuiViewportFlags_`, the raw value is [`ImGuiViewportFlags`](super::ImGuiViewportFlags).
+    pub struct ViewportFlags: super::ImGuiViewportFlags {
+        const None = 0;
+        /// Represent a Platform Window
+        const IsPlatformWindow = 1;
+        /// Represent a Platform Monitor (unused yet)
+        const IsPlatformMonitor = 2;
+        /// Platform Window: Is created/managed by the application (rather than a dear imgui backend)
+        const OwnedByApp = 4;
+    }
+}
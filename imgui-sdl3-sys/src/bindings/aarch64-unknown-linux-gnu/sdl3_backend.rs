@@ -0,0 +1,71 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_InitForOpenGL(
+        window: *mut SDL_Window,
+        sdl_gl_context: *mut ::core::ffi::c_void,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_InitForVulkan(window: *mut SDL_Window) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_InitForD3D(window: *mut SDL_Window) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_InitForMetal(window: *mut SDL_Window) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_InitForSDLRenderer(
+        window: *mut SDL_Window,
+        renderer: *mut SDL_Renderer,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_InitForSDLGPU(window: *mut SDL_Window) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_InitForOther(window: *mut SDL_Window) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_Shutdown();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_NewFrame();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_ProcessEvent(event: *const SDL_Event) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_ProcessEventForMainWindow(event: *const SDL_Event) -> bool;
+}
+pub const cImGui_ImplSDL3_GamepadMode_cImGui_ImplSDL3_GamepadMode_AutoFirst:
+    cImGui_ImplSDL3_GamepadMode = 0;
+pub const cImGui_ImplSDL3_GamepadMode_cImGui_ImplSDL3_GamepadMode_AutoAll:
+    cImGui_ImplSDL3_GamepadMode = 1;
+pub const cImGui_ImplSDL3_GamepadMode_cImGui_ImplSDL3_GamepadMode_Manual:
+    cImGui_ImplSDL3_GamepadMode = 2;
+pub type cImGui_ImplSDL3_GamepadMode = ::core::ffi::c_uint;
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_SetGamepadMode(
+        mode: cImGui_ImplSDL3_GamepadMode,
+        manual_gamepads_array: *mut *mut SDL_Gamepad,
+        manual_gamepads_count: ::core::ffi::c_int,
+    );
+}
+pub type cImGui_ImplSDL3_ClockFn =
+    ::core::option::Option<unsafe extern "C" fn(user_data: *mut ::core::ffi::c_void) -> f64>;
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_SetDeltaTime(delta_time: f32);
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_GetDeltaTime() -> f32;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDL3_SetClock(
+        clock: cImGui_ImplSDL3_ClockFn,
+        user_data: *mut ::core::ffi::c_void,
+    );
+}
@@ -0,0 +1,55 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct cImGui_ImplSDLGPU3_InitInfo {
+    pub Device: *mut SDL_GPUDevice,
+    pub ColorTargetFormat: SDL_GPUTextureFormat,
+    pub MSAASamples: SDL_GPUSampleCount,
+    pub SwapchainComposition: SDL_GPUSwapchainComposition,
+    pub PresentMode: SDL_GPUPresentMode,
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLGPU3_Init(info: *mut cImGui_ImplSDLGPU3_InitInfo) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLGPU3_Shutdown();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLGPU3_NewFrame();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLGPU3_PrepareDrawData(
+        draw_data: *mut ImDrawData,
+        command_buffer: *mut SDL_GPUCommandBuffer,
+    );
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLGPU3_RenderDrawData(
+        draw_data: *mut ImDrawData,
+        command_buffer: *mut SDL_GPUCommandBuffer,
+        render_pass: *mut SDL_GPURenderPass,
+        pipeline: *mut SDL_GPUGraphicsPipeline,
+    );
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLGPU3_CreateDeviceObjects();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLGPU3_DestroyDeviceObjects();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLGPU3_UpdateTexture(tex: *mut ImTextureData);
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct cImGui_ImplSDLGPU3_RenderState {
+    pub Device: *mut SDL_GPUDevice,
+    pub SamplerDefault: *mut SDL_GPUSampler,
+    pub SamplerCurrent: *mut SDL_GPUSampler,
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLGPU3_GetRenderState() -> *mut cImGui_ImplSDLGPU3_RenderState;
+}
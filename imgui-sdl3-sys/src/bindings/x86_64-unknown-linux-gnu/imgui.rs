@@ -0,0 +1,6085 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImDrawListSharedData_t {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImFontAtlasBuilder_t {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImFontLoader_t {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiContext_t {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage> {
+    storage: Storage,
+}
+impl<Storage> __BindgenBitfieldUnit<Storage> {
+    #[inline]
+    pub const fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+impl<Storage> __BindgenBitfieldUnit<Storage>
+where
+    Storage: AsRef<[u8]> + AsMut<[u8]>,
+{
+    #[inline]
+    fn extract_bit(byte: u8, index: usize) -> bool {
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        Self::extract_bit(byte, index)
+    }
+    #[inline]
+    pub unsafe fn raw_get_bit(this: *const Self, index: usize) -> bool {
+        debug_assert!(index / 8 < core::mem::size_of::<Storage>());
+        let byte_index = index / 8;
+        let byte = unsafe {
+            *(core::ptr::addr_of!((*this).storage) as *const u8).offset(byte_index as isize)
+        };
+        Self::extract_bit(byte, index)
+    }
+    #[inline]
+    fn change_bit(byte: u8, index: usize, val: bool) -> u8 {
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        if val {
+            byte | mask
+        } else {
+            byte & !mask
+        }
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        *byte = Self::change_bit(*byte, index, val);
+    }
+    #[inline]
+    pub unsafe fn raw_set_bit(this: *mut Self, index: usize, val: bool) {
+        debug_assert!(index / 8 < core::mem::size_of::<Storage>());
+        let byte_index = index / 8;
+        let byte = unsafe {
+            (core::ptr::addr_of_mut!((*this).storage) as *mut u8).offset(byte_index as isize)
+        };
+        unsafe { *byte = Self::change_bit(*byte, index, val) };
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub unsafe fn raw_get(this: *const Self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < core::mem::size_of::<Storage>());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= core::mem::size_of::<Storage>());
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if unsafe { Self::raw_get_bit(this, i + bit_offset) } {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+    #[inline]
+    pub unsafe fn raw_set(this: *mut Self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < core::mem::size_of::<Storage>());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= core::mem::size_of::<Storage>());
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            unsafe { Self::raw_set_bit(this, index + bit_offset, val_bit_is_set) };
+        }
+    }
+}
+pub const IMGUI_VERSION: &[u8; 7] = b"1.92.5\0";
+pub const IMGUI_VERSION_NUM: u32 = 19250;
+pub const IMGUI_PAYLOAD_TYPE_COLOR_3F: &[u8; 7] = b"_COL3F\0";
+pub const IMGUI_PAYLOAD_TYPE_COLOR_4F: &[u8; 7] = b"_COL4F\0";
+pub const IM_UNICODE_CODEPOINT_INVALID: u32 = 65533;
+pub const IM_UNICODE_CODEPOINT_MAX: u32 = 65535;
+pub const IM_COL32_R_SHIFT: u32 = 0;
+pub const IM_COL32_G_SHIFT: u32 = 8;
+pub const IM_COL32_B_SHIFT: u32 = 16;
+pub const IM_COL32_A_SHIFT: u32 = 24;
+pub const IM_COL32_A_MASK: u32 = 4278190080;
+pub const IM_DRAWLIST_TEX_LINES_WIDTH_MAX: u32 = 32;
+pub const ImFontAtlasRectId_Invalid: i32 = -1;
+pub type ImVec2 = ImVec2_t;
+pub type ImVec4 = ImVec4_t;
+pub type ImTextureRef = ImTextureRef_t;
+pub type ImVector_ImGuiTextRange = ImVector_ImGuiTextRange_t;
+pub type ImVector_char = ImVector_char_t;
+pub type ImVector_ImGuiStoragePair = ImVector_ImGuiStoragePair_t;
+pub type ImVector_ImGuiSelectionRequest = ImVector_ImGuiSelectionRequest_t;
+pub type ImVector_ImDrawChannel = ImVector_ImDrawChannel_t;
+pub type ImVector_ImDrawCmd = ImVector_ImDrawCmd_t;
+pub type ImVector_ImDrawIdx = ImVector_ImDrawIdx_t;
+pub type ImVector_ImDrawVert = ImVector_ImDrawVert_t;
+pub type ImVector_ImVec2 = ImVector_ImVec2_t;
+pub type ImVector_ImVec4 = ImVector_ImVec4_t;
+pub type ImVector_ImTextureRef = ImVector_ImTextureRef_t;
+pub type ImVector_ImU8 = ImVector_ImU8_t;
+pub type ImVector_ImDrawListPtr = ImVector_ImDrawListPtr_t;
+pub type ImVector_ImTextureRect = ImVector_ImTextureRect_t;
+pub type ImVector_ImU32 = ImVector_ImU32_t;
+pub type ImVector_ImWchar = ImVector_ImWchar_t;
+pub type ImVector_ImFontPtr = ImVector_ImFontPtr_t;
+pub type ImVector_ImFontConfig = ImVector_ImFontConfig_t;
+pub type ImVector_ImDrawListSharedDataPtr = ImVector_ImDrawListSharedDataPtr_t;
+pub type ImVector_float = ImVector_float_t;
+pub type ImVector_ImU16 = ImVector_ImU16_t;
+pub type ImVector_ImFontGlyph = ImVector_ImFontGlyph_t;
+pub type ImVector_ImFontConfigPtr = ImVector_ImFontConfigPtr_t;
+pub type ImVector_ImTextureDataPtr = ImVector_ImTextureDataPtr_t;
+pub type ImGuiTextFilter_ImGuiTextRange = ImGuiTextFilter_ImGuiTextRange_t;
+pub type ImDrawCmdHeader = ImDrawCmdHeader_t;
+pub type ImDrawChannel = ImDrawChannel_t;
+pub type ImDrawCmd = ImDrawCmd_t;
+pub type ImDrawData = ImDrawData_t;
+pub type ImDrawList = ImDrawList_t;
+pub type ImDrawListSharedData = ImDrawListSharedData_t;
+pub type ImDrawListSplitter = ImDrawListSplitter_t;
+pub type ImDrawVert = ImDrawVert_t;
+pub type ImFont = ImFont_t;
+pub type ImFontAtlas = ImFontAtlas_t;
+pub type ImFontAtlasBuilder = ImFontAtlasBuilder_t;
+pub type ImFontAtlasRect = ImFontAtlasRect_t;
+pub type ImFontBaked = ImFontBaked_t;
+pub type ImFontConfig = ImFontConfig_t;
+pub type ImFontGlyph = ImFontGlyph_t;
+pub type ImFontGlyphRangesBuilder = ImFontGlyphRangesBuilder_t;
+pub type ImFontLoader = ImFontLoader_t;
+pub type ImTextureData = ImTextureData_t;
+pub type ImTextureRect = ImTextureRect_t;
+pub type ImColor = ImColor_t;
+pub type ImGuiContext = ImGuiContext_t;
+pub type ImGuiIO = ImGuiIO_t;
+pub type ImGuiInputTextCallbackData = ImGuiInputTextCallbackData_t;
+pub type ImGuiKeyData = ImGuiKeyData_t;
+pub type ImGuiListClipper = ImGuiListClipper_t;
+pub type ImGuiMultiSelectIO = ImGuiMultiSelectIO_t;
+pub type ImGuiPayload = ImGuiPayload_t;
+pub type ImGuiPlatformIO = ImGuiPlatformIO_t;
+pub type ImGuiPlatformImeData = ImGuiPlatformImeData_t;
+pub type ImGuiSelectionBasicStorage = ImGuiSelectionBasicStorage_t;
+pub type ImGuiSelectionExternalStorage = ImGuiSelectionExternalStorage_t;
+pub type ImGuiSelectionRequest = ImGuiSelectionRequest_t;
+pub type ImGuiSizeCallbackData = ImGuiSizeCallbackData_t;
+pub type ImGuiStorage = ImGuiStorage_t;
+pub type ImGuiStoragePair = ImGuiStoragePair_t;
+pub type ImGuiStyle = ImGuiStyle_t;
+pub type ImGuiTableSortSpecs = ImGuiTableSortSpecs_t;
+pub type ImGuiTableColumnSortSpecs = ImGuiTableColumnSortSpecs_t;
+pub type ImGuiTextBuffer = ImGuiTextBuffer_t;
+pub type ImGuiTextFilter = ImGuiTextFilter_t;
+pub type ImGuiViewport = ImGuiViewport_t;
+pub type ImDrawIdx = ::core::ffi::c_ushort;
+pub type ImGuiID = ::core::ffi::c_uint;
+pub type ImS8 = ::core::ffi::c_schar;
+pub type ImU8 = ::core::ffi::c_uchar;
+pub type ImS16 = ::core::ffi::c_short;
+pub type ImU16 = ::core::ffi::c_ushort;
+pub type ImS32 = ::core::ffi::c_int;
+pub type ImU32 = ::core::ffi::c_uint;
+pub type ImS64 = ::core::ffi::c_longlong;
+pub type ImU64 = ::core::ffi::c_ulonglong;
+pub type ImGuiDir = ::core::ffi::c_int;
+pub type ImGuiKey = ::core::ffi::c_int;
+pub type ImGuiMouseSource = ::core::ffi::c_int;
+pub type ImGuiSortDirection = ImU8;
+pub type ImGuiCol = ::core::ffi::c_int;
+pub type ImGuiCond = ::core::ffi::c_int;
+pub type ImGuiDataType = ::core::ffi::c_int;
+pub type ImGuiMouseButton = ::core::ffi::c_int;
+pub type ImGuiMouseCursor = ::core::ffi::c_int;
+pub type ImGuiStyleVar = ::core::ffi::c_int;
+pub type ImGuiTableBgTarget = ::core::ffi::c_int;
+pub type ImDrawFlags = ::core::ffi::c_int;
+pub type ImDrawListFlags = ::core::ffi::c_int;
+pub type ImDrawTextFlags = ::core::ffi::c_int;
+pub type ImFontFlags = ::core::ffi::c_int;
+pub type ImFontAtlasFlags = ::core::ffi::c_int;
+pub type ImGuiBackendFlags = ::core::ffi::c_int;
+pub type ImGuiButtonFlags = ::core::ffi::c_int;
+pub type ImGuiChildFlags = ::core::ffi::c_int;
+pub type ImGuiColorEditFlags = ::core::ffi::c_int;
+pub type ImGuiConfigFlags = ::core::ffi::c_int;
+pub type ImGuiComboFlags = ::core::ffi::c_int;
+pub type ImGuiDragDropFlags = ::core::ffi::c_int;
+pub type ImGuiFocusedFlags = ::core::ffi::c_int;
+pub type ImGuiHoveredFlags = ::core::ffi::c_int;
+pub type ImGuiInputFlags = ::core::ffi::c_int;
+pub type ImGuiInputTextFlags = ::core::ffi::c_int;
+pub type ImGuiItemFlags = ::core::ffi::c_int;
+pub type ImGuiKeyChord = ::core::ffi::c_int;
+pub type ImGuiListClipperFlags = ::core::ffi::c_int;
+pub type ImGuiPopupFlags = ::core::ffi::c_int;
+pub type ImGuiMultiSelectFlags = ::core::ffi::c_int;
+pub type ImGuiSelectableFlags = ::core::ffi::c_int;
+pub type ImGuiSliderFlags = ::core::ffi::c_int;
+pub type ImGuiTabBarFlags = ::core::ffi::c_int;
+pub type ImGuiTabItemFlags = ::core::ffi::c_int;
+pub type ImGuiTableFlags = ::core::ffi::c_int;
+pub type ImGuiTableColumnFlags = ::core::ffi::c_int;
+pub type ImGuiTableRowFlags = ::core::ffi::c_int;
+pub type ImGuiTreeNodeFlags = ::core::ffi::c_int;
+pub type ImGuiViewportFlags = ::core::ffi::c_int;
+pub type ImGuiWindowFlags = ::core::ffi::c_int;
+pub type ImWchar32 = ::core::ffi::c_uint;
+pub type ImWchar16 = ::core::ffi::c_ushort;
+pub type ImWchar = ImWchar16;
+pub type ImGuiSelectionUserData = ImS64;
+pub type ImGuiInputTextCallback = ::core::option::Option<
+    unsafe extern "C" fn(data: *mut ImGuiInputTextCallbackData) -> ::core::ffi::c_int,
+>;
+pub type ImGuiSizeCallback =
+    ::core::option::Option<unsafe extern "C" fn(data: *mut ImGuiSizeCallbackData)>;
+pub type ImGuiMemAllocFunc = ::core::option::Option<
+    unsafe extern "C" fn(
+        sz: usize,
+        user_data: *mut ::core::ffi::c_void,
+    ) -> *mut ::core::ffi::c_void,
+>;
+pub type ImGuiMemFreeFunc = ::core::option::Option<
+    unsafe extern "C" fn(ptr: *mut ::core::ffi::c_void, user_data: *mut ::core::ffi::c_void),
+>;
+pub type ImTextureID = ImU64;
+pub type ImDrawCallback = ::core::option::Option<
+    unsafe extern "C" fn(parent_list: *const ImDrawList, cmd: *const ImDrawCmd),
+>;
+pub type ImFontAtlasRectId = ::core::ffi::c_int;
+pub type ImFontAtlasCustomRect = ImFontAtlasRect;
+pub type va_list = __builtin_va_list;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_None: ImGuiWindowFlags_ = 0;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoTitleBar: ImGuiWindowFlags_ = 1;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoResize: ImGuiWindowFlags_ = 2;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoMove: ImGuiWindowFlags_ = 4;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoScrollbar: ImGuiWindowFlags_ = 8;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoScrollWithMouse: ImGuiWindowFlags_ = 16;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoCollapse: ImGuiWindowFlags_ = 32;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_AlwaysAutoResize: ImGuiWindowFlags_ = 64;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoBackground: ImGuiWindowFlags_ = 128;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoSavedSettings: ImGuiWindowFlags_ = 256;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoMouseInputs: ImGuiWindowFlags_ = 512;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_MenuBar: ImGuiWindowFlags_ = 1024;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_HorizontalScrollbar: ImGuiWindowFlags_ = 2048;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoFocusOnAppearing: ImGuiWindowFlags_ = 4096;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoBringToFrontOnFocus: ImGuiWindowFlags_ = 8192;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_AlwaysVerticalScrollbar: ImGuiWindowFlags_ = 16384;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_AlwaysHorizontalScrollbar: ImGuiWindowFlags_ = 32768;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoNavInputs: ImGuiWindowFlags_ = 65536;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoNavFocus: ImGuiWindowFlags_ = 131072;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_UnsavedDocument: ImGuiWindowFlags_ = 262144;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoNav: ImGuiWindowFlags_ = 196608;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoDecoration: ImGuiWindowFlags_ = 43;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_NoInputs: ImGuiWindowFlags_ = 197120;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_ChildWindow: ImGuiWindowFlags_ = 16777216;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_Tooltip: ImGuiWindowFlags_ = 33554432;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_Popup: ImGuiWindowFlags_ = 67108864;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_Modal: ImGuiWindowFlags_ = 134217728;
+pub const ImGuiWindowFlags__ImGuiWindowFlags_ChildMenu: ImGuiWindowFlags_ = 268435456;
+pub type ImGuiWindowFlags_ = ::core::ffi::c_uint;
+pub const ImGuiChildFlags__ImGuiChildFlags_None: ImGuiChildFlags_ = 0;
+pub const ImGuiChildFlags__ImGuiChildFlags_Borders: ImGuiChildFlags_ = 1;
+pub const ImGuiChildFlags__ImGuiChildFlags_AlwaysUseWindowPadding: ImGuiChildFlags_ = 2;
+pub const ImGuiChildFlags__ImGuiChildFlags_ResizeX: ImGuiChildFlags_ = 4;
+pub const ImGuiChildFlags__ImGuiChildFlags_ResizeY: ImGuiChildFlags_ = 8;
+pub const ImGuiChildFlags__ImGuiChildFlags_AutoResizeX: ImGuiChildFlags_ = 16;
+pub const ImGuiChildFlags__ImGuiChildFlags_AutoResizeY: ImGuiChildFlags_ = 32;
+pub const ImGuiChildFlags__ImGuiChildFlags_AlwaysAutoResize: ImGuiChildFlags_ = 64;
+pub const ImGuiChildFlags__ImGuiChildFlags_FrameStyle: ImGuiChildFlags_ = 128;
+pub const ImGuiChildFlags__ImGuiChildFlags_NavFlattened: ImGuiChildFlags_ = 256;
+pub type ImGuiChildFlags_ = ::core::ffi::c_uint;
+pub const ImGuiItemFlags__ImGuiItemFlags_None: ImGuiItemFlags_ = 0;
+pub const ImGuiItemFlags__ImGuiItemFlags_NoTabStop: ImGuiItemFlags_ = 1;
+pub const ImGuiItemFlags__ImGuiItemFlags_NoNav: ImGuiItemFlags_ = 2;
+pub const ImGuiItemFlags__ImGuiItemFlags_NoNavDefaultFocus: ImGuiItemFlags_ = 4;
+pub const ImGuiItemFlags__ImGuiItemFlags_ButtonRepeat: ImGuiItemFlags_ = 8;
+pub const ImGuiItemFlags__ImGuiItemFlags_AutoClosePopups: ImGuiItemFlags_ = 16;
+pub const ImGuiItemFlags__ImGuiItemFlags_AllowDuplicateId: ImGuiItemFlags_ = 32;
+pub type ImGuiItemFlags_ = ::core::ffi::c_uint;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_None: ImGuiInputTextFlags_ = 0;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CharsDecimal: ImGuiInputTextFlags_ = 1;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CharsHexadecimal: ImGuiInputTextFlags_ = 2;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CharsScientific: ImGuiInputTextFlags_ = 4;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CharsUppercase: ImGuiInputTextFlags_ = 8;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CharsNoBlank: ImGuiInputTextFlags_ = 16;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_AllowTabInput: ImGuiInputTextFlags_ = 32;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_EnterReturnsTrue: ImGuiInputTextFlags_ = 64;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_EscapeClearsAll: ImGuiInputTextFlags_ = 128;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CtrlEnterForNewLine: ImGuiInputTextFlags_ = 256;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_ReadOnly: ImGuiInputTextFlags_ = 512;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_Password: ImGuiInputTextFlags_ = 1024;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_AlwaysOverwrite: ImGuiInputTextFlags_ = 2048;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_AutoSelectAll: ImGuiInputTextFlags_ = 4096;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_ParseEmptyRefVal: ImGuiInputTextFlags_ = 8192;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_DisplayEmptyRefVal: ImGuiInputTextFlags_ = 16384;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_NoHorizontalScroll: ImGuiInputTextFlags_ = 32768;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_NoUndoRedo: ImGuiInputTextFlags_ = 65536;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_ElideLeft: ImGuiInputTextFlags_ = 131072;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CallbackCompletion: ImGuiInputTextFlags_ =
+    262144;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CallbackHistory: ImGuiInputTextFlags_ = 524288;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CallbackAlways: ImGuiInputTextFlags_ = 1048576;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CallbackCharFilter: ImGuiInputTextFlags_ =
+    2097152;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CallbackResize: ImGuiInputTextFlags_ = 4194304;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_CallbackEdit: ImGuiInputTextFlags_ = 8388608;
+pub const ImGuiInputTextFlags__ImGuiInputTextFlags_WordWrap: ImGuiInputTextFlags_ = 16777216;
+pub type ImGuiInputTextFlags_ = ::core::ffi::c_uint;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_None: ImGuiTreeNodeFlags_ = 0;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_Selected: ImGuiTreeNodeFlags_ = 1;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_Framed: ImGuiTreeNodeFlags_ = 2;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_AllowOverlap: ImGuiTreeNodeFlags_ = 4;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_NoTreePushOnOpen: ImGuiTreeNodeFlags_ = 8;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_NoAutoOpenOnLog: ImGuiTreeNodeFlags_ = 16;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_DefaultOpen: ImGuiTreeNodeFlags_ = 32;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_OpenOnDoubleClick: ImGuiTreeNodeFlags_ = 64;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_OpenOnArrow: ImGuiTreeNodeFlags_ = 128;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_Leaf: ImGuiTreeNodeFlags_ = 256;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_Bullet: ImGuiTreeNodeFlags_ = 512;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_FramePadding: ImGuiTreeNodeFlags_ = 1024;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_SpanAvailWidth: ImGuiTreeNodeFlags_ = 2048;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_SpanFullWidth: ImGuiTreeNodeFlags_ = 4096;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_SpanLabelWidth: ImGuiTreeNodeFlags_ = 8192;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_SpanAllColumns: ImGuiTreeNodeFlags_ = 16384;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_LabelSpanAllColumns: ImGuiTreeNodeFlags_ = 32768;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_NavLeftJumpsToParent: ImGuiTreeNodeFlags_ = 131072;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_CollapsingHeader: ImGuiTreeNodeFlags_ = 26;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_DrawLinesNone: ImGuiTreeNodeFlags_ = 262144;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_DrawLinesFull: ImGuiTreeNodeFlags_ = 524288;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_DrawLinesToNodes: ImGuiTreeNodeFlags_ = 1048576;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_NavLeftJumpsBackHere: ImGuiTreeNodeFlags_ = 131072;
+pub const ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_SpanTextWidth: ImGuiTreeNodeFlags_ = 8192;
+pub type ImGuiTreeNodeFlags_ = ::core::ffi::c_uint;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_None: ImGuiPopupFlags_ = 0;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_MouseButtonLeft: ImGuiPopupFlags_ = 0;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_MouseButtonRight: ImGuiPopupFlags_ = 1;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_MouseButtonMiddle: ImGuiPopupFlags_ = 2;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_MouseButtonMask_: ImGuiPopupFlags_ = 31;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_MouseButtonDefault_: ImGuiPopupFlags_ = 1;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_NoReopen: ImGuiPopupFlags_ = 32;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_NoOpenOverExistingPopup: ImGuiPopupFlags_ = 128;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_NoOpenOverItems: ImGuiPopupFlags_ = 256;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_AnyPopupId: ImGuiPopupFlags_ = 1024;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_AnyPopupLevel: ImGuiPopupFlags_ = 2048;
+pub const ImGuiPopupFlags__ImGuiPopupFlags_AnyPopup: ImGuiPopupFlags_ = 3072;
+pub type ImGuiPopupFlags_ = ::core::ffi::c_uint;
+pub const ImGuiSelectableFlags__ImGuiSelectableFlags_None: ImGuiSelectableFlags_ = 0;
+pub const ImGuiSelectableFlags__ImGuiSelectableFlags_NoAutoClosePopups: ImGuiSelectableFlags_ = 1;
+pub const ImGuiSelectableFlags__ImGuiSelectableFlags_SpanAllColumns: ImGuiSelectableFlags_ = 2;
+pub const ImGuiSelectableFlags__ImGuiSelectableFlags_AllowDoubleClick: ImGuiSelectableFlags_ = 4;
+pub const ImGuiSelectableFlags__ImGuiSelectableFlags_Disabled: ImGuiSelectableFlags_ = 8;
+pub const ImGuiSelectableFlags__ImGuiSelectableFlags_AllowOverlap: ImGuiSelectableFlags_ = 16;
+pub const ImGuiSelectableFlags__ImGuiSelectableFlags_Highlight: ImGuiSelectableFlags_ = 32;
+pub const ImGuiSelectableFlags__ImGuiSelectableFlags_SelectOnNav: ImGuiSelectableFlags_ = 64;
+pub const ImGuiSelectableFlags__ImGuiSelectableFlags_DontClosePopups: ImGuiSelectableFlags_ = 1;
+pub type ImGuiSelectableFlags_ = ::core::ffi::c_uint;
+pub const ImGuiComboFlags__ImGuiComboFlags_None: ImGuiComboFlags_ = 0;
+pub const ImGuiComboFlags__ImGuiComboFlags_PopupAlignLeft: ImGuiComboFlags_ = 1;
+pub const ImGuiComboFlags__ImGuiComboFlags_HeightSmall: ImGuiComboFlags_ = 2;
+pub const ImGuiComboFlags__ImGuiComboFlags_HeightRegular: ImGuiComboFlags_ = 4;
+pub const ImGuiComboFlags__ImGuiComboFlags_HeightLarge: ImGuiComboFlags_ = 8;
+pub const ImGuiComboFlags__ImGuiComboFlags_HeightLargest: ImGuiComboFlags_ = 16;
+pub const ImGuiComboFlags__ImGuiComboFlags_NoArrowButton: ImGuiComboFlags_ = 32;
+pub const ImGuiComboFlags__ImGuiComboFlags_NoPreview: ImGuiComboFlags_ = 64;
+pub const ImGuiComboFlags__ImGuiComboFlags_WidthFitPreview: ImGuiComboFlags_ = 128;
+pub const ImGuiComboFlags__ImGuiComboFlags_HeightMask_: ImGuiComboFlags_ = 30;
+pub type ImGuiComboFlags_ = ::core::ffi::c_uint;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_None: ImGuiTabBarFlags_ = 0;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_Reorderable: ImGuiTabBarFlags_ = 1;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_AutoSelectNewTabs: ImGuiTabBarFlags_ = 2;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_TabListPopupButton: ImGuiTabBarFlags_ = 4;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_NoCloseWithMiddleMouseButton: ImGuiTabBarFlags_ = 8;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_NoTabListScrollingButtons: ImGuiTabBarFlags_ = 16;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_NoTooltip: ImGuiTabBarFlags_ = 32;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_DrawSelectedOverline: ImGuiTabBarFlags_ = 64;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_FittingPolicyMixed: ImGuiTabBarFlags_ = 128;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_FittingPolicyShrink: ImGuiTabBarFlags_ = 256;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_FittingPolicyScroll: ImGuiTabBarFlags_ = 512;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_FittingPolicyMask_: ImGuiTabBarFlags_ = 896;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_FittingPolicyDefault_: ImGuiTabBarFlags_ = 128;
+pub const ImGuiTabBarFlags__ImGuiTabBarFlags_FittingPolicyResizeDown: ImGuiTabBarFlags_ = 256;
+pub type ImGuiTabBarFlags_ = ::core::ffi::c_uint;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_None: ImGuiTabItemFlags_ = 0;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_UnsavedDocument: ImGuiTabItemFlags_ = 1;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_SetSelected: ImGuiTabItemFlags_ = 2;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_NoCloseWithMiddleMouseButton: ImGuiTabItemFlags_ = 4;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_NoPushId: ImGuiTabItemFlags_ = 8;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_NoTooltip: ImGuiTabItemFlags_ = 16;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_NoReorder: ImGuiTabItemFlags_ = 32;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_Leading: ImGuiTabItemFlags_ = 64;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_Trailing: ImGuiTabItemFlags_ = 128;
+pub const ImGuiTabItemFlags__ImGuiTabItemFlags_NoAssumedClosure: ImGuiTabItemFlags_ = 256;
+pub type ImGuiTabItemFlags_ = ::core::ffi::c_uint;
+pub const ImGuiFocusedFlags__ImGuiFocusedFlags_None: ImGuiFocusedFlags_ = 0;
+pub const ImGuiFocusedFlags__ImGuiFocusedFlags_ChildWindows: ImGuiFocusedFlags_ = 1;
+pub const ImGuiFocusedFlags__ImGuiFocusedFlags_RootWindow: ImGuiFocusedFlags_ = 2;
+pub const ImGuiFocusedFlags__ImGuiFocusedFlags_AnyWindow: ImGuiFocusedFlags_ = 4;
+pub const ImGuiFocusedFlags__ImGuiFocusedFlags_NoPopupHierarchy: ImGuiFocusedFlags_ = 8;
+pub const ImGuiFocusedFlags__ImGuiFocusedFlags_RootAndChildWindows: ImGuiFocusedFlags_ = 3;
+pub type ImGuiFocusedFlags_ = ::core::ffi::c_uint;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_None: ImGuiHoveredFlags_ = 0;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_ChildWindows: ImGuiHoveredFlags_ = 1;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_RootWindow: ImGuiHoveredFlags_ = 2;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_AnyWindow: ImGuiHoveredFlags_ = 4;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_NoPopupHierarchy: ImGuiHoveredFlags_ = 8;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_AllowWhenBlockedByPopup: ImGuiHoveredFlags_ = 32;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_AllowWhenBlockedByActiveItem: ImGuiHoveredFlags_ =
+    128;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_AllowWhenOverlappedByItem: ImGuiHoveredFlags_ = 256;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_AllowWhenOverlappedByWindow: ImGuiHoveredFlags_ =
+    512;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_AllowWhenDisabled: ImGuiHoveredFlags_ = 1024;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_NoNavOverride: ImGuiHoveredFlags_ = 2048;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_AllowWhenOverlapped: ImGuiHoveredFlags_ = 768;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_RectOnly: ImGuiHoveredFlags_ = 928;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_RootAndChildWindows: ImGuiHoveredFlags_ = 3;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_ForTooltip: ImGuiHoveredFlags_ = 4096;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_Stationary: ImGuiHoveredFlags_ = 8192;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_DelayNone: ImGuiHoveredFlags_ = 16384;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_DelayShort: ImGuiHoveredFlags_ = 32768;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_DelayNormal: ImGuiHoveredFlags_ = 65536;
+pub const ImGuiHoveredFlags__ImGuiHoveredFlags_NoSharedDelay: ImGuiHoveredFlags_ = 131072;
+pub type ImGuiHoveredFlags_ = ::core::ffi::c_uint;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_None: ImGuiDragDropFlags_ = 0;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_SourceNoPreviewTooltip: ImGuiDragDropFlags_ = 1;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_SourceNoDisableHover: ImGuiDragDropFlags_ = 2;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_SourceNoHoldToOpenOthers: ImGuiDragDropFlags_ = 4;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_SourceAllowNullID: ImGuiDragDropFlags_ = 8;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_SourceExtern: ImGuiDragDropFlags_ = 16;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_PayloadAutoExpire: ImGuiDragDropFlags_ = 32;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_PayloadNoCrossContext: ImGuiDragDropFlags_ = 64;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_PayloadNoCrossProcess: ImGuiDragDropFlags_ = 128;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_AcceptBeforeDelivery: ImGuiDragDropFlags_ = 1024;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_AcceptNoDrawDefaultRect: ImGuiDragDropFlags_ =
+    2048;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_AcceptNoPreviewTooltip: ImGuiDragDropFlags_ = 4096;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_AcceptDrawAsHovered: ImGuiDragDropFlags_ = 8192;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_AcceptPeekOnly: ImGuiDragDropFlags_ = 3072;
+pub const ImGuiDragDropFlags__ImGuiDragDropFlags_SourceAutoExpirePayload: ImGuiDragDropFlags_ = 32;
+pub type ImGuiDragDropFlags_ = ::core::ffi::c_uint;
+pub const ImGuiDataType__ImGuiDataType_S8: ImGuiDataType_ = 0;
+pub const ImGuiDataType__ImGuiDataType_U8: ImGuiDataType_ = 1;
+pub const ImGuiDataType__ImGuiDataType_S16: ImGuiDataType_ = 2;
+pub const ImGuiDataType__ImGuiDataType_U16: ImGuiDataType_ = 3;
+pub const ImGuiDataType__ImGuiDataType_S32: ImGuiDataType_ = 4;
+pub const ImGuiDataType__ImGuiDataType_U32: ImGuiDataType_ = 5;
+pub const ImGuiDataType__ImGuiDataType_S64: ImGuiDataType_ = 6;
+pub const ImGuiDataType__ImGuiDataType_U64: ImGuiDataType_ = 7;
+pub const ImGuiDataType__ImGuiDataType_Float: ImGuiDataType_ = 8;
+pub const ImGuiDataType__ImGuiDataType_Double: ImGuiDataType_ = 9;
+pub const ImGuiDataType__ImGuiDataType_Bool: ImGuiDataType_ = 10;
+pub const ImGuiDataType__ImGuiDataType_String: ImGuiDataType_ = 11;
+pub const ImGuiDataType__ImGuiDataType_COUNT: ImGuiDataType_ = 12;
+pub type ImGuiDataType_ = ::core::ffi::c_uint;
+pub const ImGuiDir_None: _bindgen_ty_1 = -1;
+pub const ImGuiDir_Left: _bindgen_ty_1 = 0;
+pub const ImGuiDir_Right: _bindgen_ty_1 = 1;
+pub const ImGuiDir_Up: _bindgen_ty_1 = 2;
+pub const ImGuiDir_Down: _bindgen_ty_1 = 3;
+pub const ImGuiDir_COUNT: _bindgen_ty_1 = 4;
+pub type _bindgen_ty_1 = ::core::ffi::c_int;
+pub const ImGuiSortDirection_None: _bindgen_ty_2 = 0;
+pub const ImGuiSortDirection_Ascending: _bindgen_ty_2 = 1;
+pub const ImGuiSortDirection_Descending: _bindgen_ty_2 = 2;
+pub type _bindgen_ty_2 = ::core::ffi::c_uint;
+pub const ImGuiKey_None: _bindgen_ty_3 = 0;
+pub const ImGuiKey_NamedKey_BEGIN: _bindgen_ty_3 = 512;
+pub const ImGuiKey_Tab: _bindgen_ty_3 = 512;
+pub const ImGuiKey_LeftArrow: _bindgen_ty_3 = 513;
+pub const ImGuiKey_RightArrow: _bindgen_ty_3 = 514;
+pub const ImGuiKey_UpArrow: _bindgen_ty_3 = 515;
+pub const ImGuiKey_DownArrow: _bindgen_ty_3 = 516;
+pub const ImGuiKey_PageUp: _bindgen_ty_3 = 517;
+pub const ImGuiKey_PageDown: _bindgen_ty_3 = 518;
+pub const ImGuiKey_Home: _bindgen_ty_3 = 519;
+pub const ImGuiKey_End: _bindgen_ty_3 = 520;
+pub const ImGuiKey_Insert: _bindgen_ty_3 = 521;
+pub const ImGuiKey_Delete: _bindgen_ty_3 = 522;
+pub const ImGuiKey_Backspace: _bindgen_ty_3 = 523;
+pub const ImGuiKey_Space: _bindgen_ty_3 = 524;
+pub const ImGuiKey_Enter: _bindgen_ty_3 = 525;
+pub const ImGuiKey_Escape: _bindgen_ty_3 = 526;
+pub const ImGuiKey_LeftCtrl: _bindgen_ty_3 = 527;
+pub const ImGuiKey_LeftShift: _bindgen_ty_3 = 528;
+pub const ImGuiKey_LeftAlt: _bindgen_ty_3 = 529;
+pub const ImGuiKey_LeftSuper: _bindgen_ty_3 = 530;
+pub const ImGuiKey_RightCtrl: _bindgen_ty_3 = 531;
+pub const ImGuiKey_RightShift: _bindgen_ty_3 = 532;
+pub const ImGuiKey_RightAlt: _bindgen_ty_3 = 533;
+pub const ImGuiKey_RightSuper: _bindgen_ty_3 = 534;
+pub const ImGuiKey_Menu: _bindgen_ty_3 = 535;
+pub const ImGuiKey_0: _bindgen_ty_3 = 536;
+pub const ImGuiKey_1: _bindgen_ty_3 = 537;
+pub const ImGuiKey_2: _bindgen_ty_3 = 538;
+pub const ImGuiKey_3: _bindgen_ty_3 = 539;
+pub const ImGuiKey_4: _bindgen_ty_3 = 540;
+pub const ImGuiKey_5: _bindgen_ty_3 = 541;
+pub const ImGuiKey_6: _bindgen_ty_3 = 542;
+pub const ImGuiKey_7: _bindgen_ty_3 = 543;
+pub const ImGuiKey_8: _bindgen_ty_3 = 544;
+pub const ImGuiKey_9: _bindgen_ty_3 = 545;
+pub const ImGuiKey_A: _bindgen_ty_3 = 546;
+pub const ImGuiKey_B: _bindgen_ty_3 = 547;
+pub const ImGuiKey_C: _bindgen_ty_3 = 548;
+pub const ImGuiKey_D: _bindgen_ty_3 = 549;
+pub const ImGuiKey_E: _bindgen_ty_3 = 550;
+pub const ImGuiKey_F: _bindgen_ty_3 = 551;
+pub const ImGuiKey_G: _bindgen_ty_3 = 552;
+pub const ImGuiKey_H: _bindgen_ty_3 = 553;
+pub const ImGuiKey_I: _bindgen_ty_3 = 554;
+pub const ImGuiKey_J: _bindgen_ty_3 = 555;
+pub const ImGuiKey_K: _bindgen_ty_3 = 556;
+pub const ImGuiKey_L: _bindgen_ty_3 = 557;
+pub const ImGuiKey_M: _bindgen_ty_3 = 558;
+pub const ImGuiKey_N: _bindgen_ty_3 = 559;
+pub const ImGuiKey_O: _bindgen_ty_3 = 560;
+pub const ImGuiKey_P: _bindgen_ty_3 = 561;
+pub const ImGuiKey_Q: _bindgen_ty_3 = 562;
+pub const ImGuiKey_R: _bindgen_ty_3 = 563;
+pub const ImGuiKey_S: _bindgen_ty_3 = 564;
+pub const ImGuiKey_T: _bindgen_ty_3 = 565;
+pub const ImGuiKey_U: _bindgen_ty_3 = 566;
+pub const ImGuiKey_V: _bindgen_ty_3 = 567;
+pub const ImGuiKey_W: _bindgen_ty_3 = 568;
+pub const ImGuiKey_X: _bindgen_ty_3 = 569;
+pub const ImGuiKey_Y: _bindgen_ty_3 = 570;
+pub const ImGuiKey_Z: _bindgen_ty_3 = 571;
+pub const ImGuiKey_F1: _bindgen_ty_3 = 572;
+pub const ImGuiKey_F2: _bindgen_ty_3 = 573;
+pub const ImGuiKey_F3: _bindgen_ty_3 = 574;
+pub const ImGuiKey_F4: _bindgen_ty_3 = 575;
+pub const ImGuiKey_F5: _bindgen_ty_3 = 576;
+pub const ImGuiKey_F6: _bindgen_ty_3 = 577;
+pub const ImGuiKey_F7: _bindgen_ty_3 = 578;
+pub const ImGuiKey_F8: _bindgen_ty_3 = 579;
+pub const ImGuiKey_F9: _bindgen_ty_3 = 580;
+pub const ImGuiKey_F10: _bindgen_ty_3 = 581;
+pub const ImGuiKey_F11: _bindgen_ty_3 = 582;
+pub const ImGuiKey_F12: _bindgen_ty_3 = 583;
+pub const ImGuiKey_F13: _bindgen_ty_3 = 584;
+pub const ImGuiKey_F14: _bindgen_ty_3 = 585;
+pub const ImGuiKey_F15: _bindgen_ty_3 = 586;
+pub const ImGuiKey_F16: _bindgen_ty_3 = 587;
+pub const ImGuiKey_F17: _bindgen_ty_3 = 588;
+pub const ImGuiKey_F18: _bindgen_ty_3 = 589;
+pub const ImGuiKey_F19: _bindgen_ty_3 = 590;
+pub const ImGuiKey_F20: _bindgen_ty_3 = 591;
+pub const ImGuiKey_F21: _bindgen_ty_3 = 592;
+pub const ImGuiKey_F22: _bindgen_ty_3 = 593;
+pub const ImGuiKey_F23: _bindgen_ty_3 = 594;
+pub const ImGuiKey_F24: _bindgen_ty_3 = 595;
+pub const ImGuiKey_Apostrophe: _bindgen_ty_3 = 596;
+pub const ImGuiKey_Comma: _bindgen_ty_3 = 597;
+pub const ImGuiKey_Minus: _bindgen_ty_3 = 598;
+pub const ImGuiKey_Period: _bindgen_ty_3 = 599;
+pub const ImGuiKey_Slash: _bindgen_ty_3 = 600;
+pub const ImGuiKey_Semicolon: _bindgen_ty_3 = 601;
+pub const ImGuiKey_Equal: _bindgen_ty_3 = 602;
+pub const ImGuiKey_LeftBracket: _bindgen_ty_3 = 603;
+pub const ImGuiKey_Backslash: _bindgen_ty_3 = 604;
+pub const ImGuiKey_RightBracket: _bindgen_ty_3 = 605;
+pub const ImGuiKey_GraveAccent: _bindgen_ty_3 = 606;
+pub const ImGuiKey_CapsLock: _bindgen_ty_3 = 607;
+pub const ImGuiKey_ScrollLock: _bindgen_ty_3 = 608;
+pub const ImGuiKey_NumLock: _bindgen_ty_3 = 609;
+pub const ImGuiKey_PrintScreen: _bindgen_ty_3 = 610;
+pub const ImGuiKey_Pause: _bindgen_ty_3 = 611;
+pub const ImGuiKey_Keypad0: _bindgen_ty_3 = 612;
+pub const ImGuiKey_Keypad1: _bindgen_ty_3 = 613;
+pub const ImGuiKey_Keypad2: _bindgen_ty_3 = 614;
+pub const ImGuiKey_Keypad3: _bindgen_ty_3 = 615;
+pub const ImGuiKey_Keypad4: _bindgen_ty_3 = 616;
+pub const ImGuiKey_Keypad5: _bindgen_ty_3 = 617;
+pub const ImGuiKey_Keypad6: _bindgen_ty_3 = 618;
+pub const ImGuiKey_Keypad7: _bindgen_ty_3 = 619;
+pub const ImGuiKey_Keypad8: _bindgen_ty_3 = 620;
+pub const ImGuiKey_Keypad9: _bindgen_ty_3 = 621;
+pub const ImGuiKey_KeypadDecimal: _bindgen_ty_3 = 622;
+pub const ImGuiKey_KeypadDivide: _bindgen_ty_3 = 623;
+pub const ImGuiKey_KeypadMultiply: _bindgen_ty_3 = 624;
+pub const ImGuiKey_KeypadSubtract: _bindgen_ty_3 = 625;
+pub const ImGuiKey_KeypadAdd: _bindgen_ty_3 = 626;
+pub const ImGuiKey_KeypadEnter: _bindgen_ty_3 = 627;
+pub const ImGuiKey_KeypadEqual: _bindgen_ty_3 = 628;
+pub const ImGuiKey_AppBack: _bindgen_ty_3 = 629;
+pub const ImGuiKey_AppForward: _bindgen_ty_3 = 630;
+pub const ImGuiKey_Oem102: _bindgen_ty_3 = 631;
+pub const ImGuiKey_GamepadStart: _bindgen_ty_3 = 632;
+pub const ImGuiKey_GamepadBack: _bindgen_ty_3 = 633;
+pub const ImGuiKey_GamepadFaceLeft: _bindgen_ty_3 = 634;
+pub const ImGuiKey_GamepadFaceRight: _bindgen_ty_3 = 635;
+pub const ImGuiKey_GamepadFaceUp: _bindgen_ty_3 = 636;
+pub const ImGuiKey_GamepadFaceDown: _bindgen_ty_3 = 637;
+pub const ImGuiKey_GamepadDpadLeft: _bindgen_ty_3 = 638;
+pub const ImGuiKey_GamepadDpadRight: _bindgen_ty_3 = 639;
+pub const ImGuiKey_GamepadDpadUp: _bindgen_ty_3 = 640;
+pub const ImGuiKey_GamepadDpadDown: _bindgen_ty_3 = 641;
+pub const ImGuiKey_GamepadL1: _bindgen_ty_3 = 642;
+pub const ImGuiKey_GamepadR1: _bindgen_ty_3 = 643;
+pub const ImGuiKey_GamepadL2: _bindgen_ty_3 = 644;
+pub const ImGuiKey_GamepadR2: _bindgen_ty_3 = 645;
+pub const ImGuiKey_GamepadL3: _bindgen_ty_3 = 646;
+pub const ImGuiKey_GamepadR3: _bindgen_ty_3 = 647;
+pub const ImGuiKey_GamepadLStickLeft: _bindgen_ty_3 = 648;
+pub const ImGuiKey_GamepadLStickRight: _bindgen_ty_3 = 649;
+pub const ImGuiKey_GamepadLStickUp: _bindgen_ty_3 = 650;
+pub const ImGuiKey_GamepadLStickDown: _bindgen_ty_3 = 651;
+pub const ImGuiKey_GamepadRStickLeft: _bindgen_ty_3 = 652;
+pub const ImGuiKey_GamepadRStickRight: _bindgen_ty_3 = 653;
+pub const ImGuiKey_GamepadRStickUp: _bindgen_ty_3 = 654;
+pub const ImGuiKey_GamepadRStickDown: _bindgen_ty_3 = 655;
+pub const ImGuiKey_MouseLeft: _bindgen_ty_3 = 656;
+pub const ImGuiKey_MouseRight: _bindgen_ty_3 = 657;
+pub const ImGuiKey_MouseMiddle: _bindgen_ty_3 = 658;
+pub const ImGuiKey_MouseX1: _bindgen_ty_3 = 659;
+pub const ImGuiKey_MouseX2: _bindgen_ty_3 = 660;
+pub const ImGuiKey_MouseWheelX: _bindgen_ty_3 = 661;
+pub const ImGuiKey_MouseWheelY: _bindgen_ty_3 = 662;
+pub const ImGuiKey_ReservedForModCtrl: _bindgen_ty_3 = 663;
+pub const ImGuiKey_ReservedForModShift: _bindgen_ty_3 = 664;
+pub const ImGuiKey_ReservedForModAlt: _bindgen_ty_3 = 665;
+pub const ImGuiKey_ReservedForModSuper: _bindgen_ty_3 = 666;
+pub const ImGuiKey_NamedKey_END: _bindgen_ty_3 = 667;
+pub const ImGuiKey_NamedKey_COUNT: _bindgen_ty_3 = 155;
+pub const ImGuiMod_None: _bindgen_ty_3 = 0;
+pub const ImGuiMod_Ctrl: _bindgen_ty_3 = 4096;
+pub const ImGuiMod_Shift: _bindgen_ty_3 = 8192;
+pub const ImGuiMod_Alt: _bindgen_ty_3 = 16384;
+pub const ImGuiMod_Super: _bindgen_ty_3 = 32768;
+pub const ImGuiMod_Mask_: _bindgen_ty_3 = 61440;
+pub const ImGuiKey_COUNT: _bindgen_ty_3 = 667;
+pub const ImGuiMod_Shortcut: _bindgen_ty_3 = 4096;
+pub type _bindgen_ty_3 = ::core::ffi::c_uint;
+pub const ImGuiInputFlags__ImGuiInputFlags_None: ImGuiInputFlags_ = 0;
+pub const ImGuiInputFlags__ImGuiInputFlags_Repeat: ImGuiInputFlags_ = 1;
+pub const ImGuiInputFlags__ImGuiInputFlags_RouteActive: ImGuiInputFlags_ = 1024;
+pub const ImGuiInputFlags__ImGuiInputFlags_RouteFocused: ImGuiInputFlags_ = 2048;
+pub const ImGuiInputFlags__ImGuiInputFlags_RouteGlobal: ImGuiInputFlags_ = 4096;
+pub const ImGuiInputFlags__ImGuiInputFlags_RouteAlways: ImGuiInputFlags_ = 8192;
+pub const ImGuiInputFlags__ImGuiInputFlags_RouteOverFocused: ImGuiInputFlags_ = 16384;
+pub const ImGuiInputFlags__ImGuiInputFlags_RouteOverActive: ImGuiInputFlags_ = 32768;
+pub const ImGuiInputFlags__ImGuiInputFlags_RouteUnlessBgFocused: ImGuiInputFlags_ = 65536;
+pub const ImGuiInputFlags__ImGuiInputFlags_RouteFromRootWindow: ImGuiInputFlags_ = 131072;
+pub const ImGuiInputFlags__ImGuiInputFlags_Tooltip: ImGuiInputFlags_ = 262144;
+pub type ImGuiInputFlags_ = ::core::ffi::c_uint;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_None: ImGuiConfigFlags_ = 0;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_NavEnableKeyboard: ImGuiConfigFlags_ = 1;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_NavEnableGamepad: ImGuiConfigFlags_ = 2;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_NoMouse: ImGuiConfigFlags_ = 16;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_NoMouseCursorChange: ImGuiConfigFlags_ = 32;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_NoKeyboard: ImGuiConfigFlags_ = 64;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_IsSRGB: ImGuiConfigFlags_ = 1048576;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_IsTouchScreen: ImGuiConfigFlags_ = 2097152;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_NavEnableSetMousePos: ImGuiConfigFlags_ = 4;
+pub const ImGuiConfigFlags__ImGuiConfigFlags_NavNoCaptureKeyboard: ImGuiConfigFlags_ = 8;
+pub type ImGuiConfigFlags_ = ::core::ffi::c_uint;
+pub const ImGuiBackendFlags__ImGuiBackendFlags_None: ImGuiBackendFlags_ = 0;
+pub const ImGuiBackendFlags__ImGuiBackendFlags_HasGamepad: ImGuiBackendFlags_ = 1;
+pub const ImGuiBackendFlags__ImGuiBackendFlags_HasMouseCursors: ImGuiBackendFlags_ = 2;
+pub const ImGuiBackendFlags__ImGuiBackendFlags_HasSetMousePos: ImGuiBackendFlags_ = 4;
+pub const ImGuiBackendFlags__ImGuiBackendFlags_RendererHasVtxOffset: ImGuiBackendFlags_ = 8;
+pub const ImGuiBackendFlags__ImGuiBackendFlags_RendererHasTextures: ImGuiBackendFlags_ = 16;
+pub type ImGuiBackendFlags_ = ::core::ffi::c_uint;
+pub const ImGuiCol__ImGuiCol_Text: ImGuiCol_ = 0;
+pub const ImGuiCol__ImGuiCol_TextDisabled: ImGuiCol_ = 1;
+pub const ImGuiCol__ImGuiCol_WindowBg: ImGuiCol_ = 2;
+pub const ImGuiCol__ImGuiCol_ChildBg: ImGuiCol_ = 3;
+pub const ImGuiCol__ImGuiCol_PopupBg: ImGuiCol_ = 4;
+pub const ImGuiCol__ImGuiCol_Border: ImGuiCol_ = 5;
+pub const ImGuiCol__ImGuiCol_BorderShadow: ImGuiCol_ = 6;
+pub const ImGuiCol__ImGuiCol_FrameBg: ImGuiCol_ = 7;
+pub const ImGuiCol__ImGuiCol_FrameBgHovered: ImGuiCol_ = 8;
+pub const ImGuiCol__ImGuiCol_FrameBgActive: ImGuiCol_ = 9;
+pub const ImGuiCol__ImGuiCol_TitleBg: ImGuiCol_ = 10;
+pub const ImGuiCol__ImGuiCol_TitleBgActive: ImGuiCol_ = 11;
+pub const ImGuiCol__ImGuiCol_TitleBgCollapsed: ImGuiCol_ = 12;
+pub const ImGuiCol__ImGuiCol_MenuBarBg: ImGuiCol_ = 13;
+pub const ImGuiCol__ImGuiCol_ScrollbarBg: ImGuiCol_ = 14;
+pub const ImGuiCol__ImGuiCol_ScrollbarGrab: ImGuiCol_ = 15;
+pub const ImGuiCol__ImGuiCol_ScrollbarGrabHovered: ImGuiCol_ = 16;
+pub const ImGuiCol__ImGuiCol_ScrollbarGrabActive: ImGuiCol_ = 17;
+pub const ImGuiCol__ImGuiCol_CheckMark: ImGuiCol_ = 18;
+pub const ImGuiCol__ImGuiCol_SliderGrab: ImGuiCol_ = 19;
+pub const ImGuiCol__ImGuiCol_SliderGrabActive: ImGuiCol_ = 20;
+pub const ImGuiCol__ImGuiCol_Button: ImGuiCol_ = 21;
+pub const ImGuiCol__ImGuiCol_ButtonHovered: ImGuiCol_ = 22;
+pub const ImGuiCol__ImGuiCol_ButtonActive: ImGuiCol_ = 23;
+pub const ImGuiCol__ImGuiCol_Header: ImGuiCol_ = 24;
+pub const ImGuiCol__ImGuiCol_HeaderHovered: ImGuiCol_ = 25;
+pub const ImGuiCol__ImGuiCol_HeaderActive: ImGuiCol_ = 26;
+pub const ImGuiCol__ImGuiCol_Separator: ImGuiCol_ = 27;
+pub const ImGuiCol__ImGuiCol_SeparatorHovered: ImGuiCol_ = 28;
+pub const ImGuiCol__ImGuiCol_SeparatorActive: ImGuiCol_ = 29;
+pub const ImGuiCol__ImGuiCol_ResizeGrip: ImGuiCol_ = 30;
+pub const ImGuiCol__ImGuiCol_ResizeGripHovered: ImGuiCol_ = 31;
+pub const ImGuiCol__ImGuiCol_ResizeGripActive: ImGuiCol_ = 32;
+pub const ImGuiCol__ImGuiCol_InputTextCursor: ImGuiCol_ = 33;
+pub const ImGuiCol__ImGuiCol_TabHovered: ImGuiCol_ = 34;
+pub const ImGuiCol__ImGuiCol_Tab: ImGuiCol_ = 35;
+pub const ImGuiCol__ImGuiCol_TabSelected: ImGuiCol_ = 36;
+pub const ImGuiCol__ImGuiCol_TabSelectedOverline: ImGuiCol_ = 37;
+pub const ImGuiCol__ImGuiCol_TabDimmed: ImGuiCol_ = 38;
+pub const ImGuiCol__ImGuiCol_TabDimmedSelected: ImGuiCol_ = 39;
+pub const ImGuiCol__ImGuiCol_TabDimmedSelectedOverline: ImGuiCol_ = 40;
+pub const ImGuiCol__ImGuiCol_PlotLines: ImGuiCol_ = 41;
+pub const ImGuiCol__ImGuiCol_PlotLinesHovered: ImGuiCol_ = 42;
+pub const ImGuiCol__ImGuiCol_PlotHistogram: ImGuiCol_ = 43;
+pub const ImGuiCol__ImGuiCol_PlotHistogramHovered: ImGuiCol_ = 44;
+pub const ImGuiCol__ImGuiCol_TableHeaderBg: ImGuiCol_ = 45;
+pub const ImGuiCol__ImGuiCol_TableBorderStrong: ImGuiCol_ = 46;
+pub const ImGuiCol__ImGuiCol_TableBorderLight: ImGuiCol_ = 47;
+pub const ImGuiCol__ImGuiCol_TableRowBg: ImGuiCol_ = 48;
+pub const ImGuiCol__ImGuiCol_TableRowBgAlt: ImGuiCol_ = 49;
+pub const ImGuiCol__ImGuiCol_TextLink: ImGuiCol_ = 50;
+pub const ImGuiCol__ImGuiCol_TextSelectedBg: ImGuiCol_ = 51;
+pub const ImGuiCol__ImGuiCol_TreeLines: ImGuiCol_ = 52;
+pub const ImGuiCol__ImGuiCol_DragDropTarget: ImGuiCol_ = 53;
+pub const ImGuiCol__ImGuiCol_DragDropTargetBg: ImGuiCol_ = 54;
+pub const ImGuiCol__ImGuiCol_UnsavedMarker: ImGuiCol_ = 55;
+pub const ImGuiCol__ImGuiCol_NavCursor: ImGuiCol_ = 56;
+pub const ImGuiCol__ImGuiCol_NavWindowingHighlight: ImGuiCol_ = 57;
+pub const ImGuiCol__ImGuiCol_NavWindowingDimBg: ImGuiCol_ = 58;
+pub const ImGuiCol__ImGuiCol_ModalWindowDimBg: ImGuiCol_ = 59;
+pub const ImGuiCol__ImGuiCol_COUNT: ImGuiCol_ = 60;
+pub const ImGuiCol__ImGuiCol_TabActive: ImGuiCol_ = 36;
+pub const ImGuiCol__ImGuiCol_TabUnfocused: ImGuiCol_ = 38;
+pub const ImGuiCol__ImGuiCol_TabUnfocusedActive: ImGuiCol_ = 39;
+pub const ImGuiCol__ImGuiCol_NavHighlight: ImGuiCol_ = 56;
+pub type ImGuiCol_ = ::core::ffi::c_uint;
+pub const ImGuiStyleVar__ImGuiStyleVar_Alpha: ImGuiStyleVar_ = 0;
+pub const ImGuiStyleVar__ImGuiStyleVar_DisabledAlpha: ImGuiStyleVar_ = 1;
+pub const ImGuiStyleVar__ImGuiStyleVar_WindowPadding: ImGuiStyleVar_ = 2;
+pub const ImGuiStyleVar__ImGuiStyleVar_WindowRounding: ImGuiStyleVar_ = 3;
+pub const ImGuiStyleVar__ImGuiStyleVar_WindowBorderSize: ImGuiStyleVar_ = 4;
+pub const ImGuiStyleVar__ImGuiStyleVar_WindowMinSize: ImGuiStyleVar_ = 5;
+pub const ImGuiStyleVar__ImGuiStyleVar_WindowTitleAlign: ImGuiStyleVar_ = 6;
+pub const ImGuiStyleVar__ImGuiStyleVar_ChildRounding: ImGuiStyleVar_ = 7;
+pub const ImGuiStyleVar__ImGuiStyleVar_ChildBorderSize: ImGuiStyleVar_ = 8;
+pub const ImGuiStyleVar__ImGuiStyleVar_PopupRounding: ImGuiStyleVar_ = 9;
+pub const ImGuiStyleVar__ImGuiStyleVar_PopupBorderSize: ImGuiStyleVar_ = 10;
+pub const ImGuiStyleVar__ImGuiStyleVar_FramePadding: ImGuiStyleVar_ = 11;
+pub const ImGuiStyleVar__ImGuiStyleVar_FrameRounding: ImGuiStyleVar_ = 12;
+pub const ImGuiStyleVar__ImGuiStyleVar_FrameBorderSize: ImGuiStyleVar_ = 13;
+pub const ImGuiStyleVar__ImGuiStyleVar_ItemSpacing: ImGuiStyleVar_ = 14;
+pub const ImGuiStyleVar__ImGuiStyleVar_ItemInnerSpacing: ImGuiStyleVar_ = 15;
+pub const ImGuiStyleVar__ImGuiStyleVar_IndentSpacing: ImGuiStyleVar_ = 16;
+pub const ImGuiStyleVar__ImGuiStyleVar_CellPadding: ImGuiStyleVar_ = 17;
+pub const ImGuiStyleVar__ImGuiStyleVar_ScrollbarSize: ImGuiStyleVar_ = 18;
+pub const ImGuiStyleVar__ImGuiStyleVar_ScrollbarRounding: ImGuiStyleVar_ = 19;
+pub const ImGuiStyleVar__ImGuiStyleVar_ScrollbarPadding: ImGuiStyleVar_ = 20;
+pub const ImGuiStyleVar__ImGuiStyleVar_GrabMinSize: ImGuiStyleVar_ = 21;
+pub const ImGuiStyleVar__ImGuiStyleVar_GrabRounding: ImGuiStyleVar_ = 22;
+pub const ImGuiStyleVar__ImGuiStyleVar_ImageBorderSize: ImGuiStyleVar_ = 23;
+pub const ImGuiStyleVar__ImGuiStyleVar_TabRounding: ImGuiStyleVar_ = 24;
+pub const ImGuiStyleVar__ImGuiStyleVar_TabBorderSize: ImGuiStyleVar_ = 25;
+pub const ImGuiStyleVar__ImGuiStyleVar_TabMinWidthBase: ImGuiStyleVar_ = 26;
+pub const ImGuiStyleVar__ImGuiStyleVar_TabMinWidthShrink: ImGuiStyleVar_ = 27;
+pub const ImGuiStyleVar__ImGuiStyleVar_TabBarBorderSize: ImGuiStyleVar_ = 28;
+pub const ImGuiStyleVar__ImGuiStyleVar_TabBarOverlineSize: ImGuiStyleVar_ = 29;
+pub const ImGuiStyleVar__ImGuiStyleVar_TableAngledHeadersAngle: ImGuiStyleVar_ = 30;
+pub const ImGuiStyleVar__ImGuiStyleVar_TableAngledHeadersTextAlign: ImGuiStyleVar_ = 31;
+pub const ImGuiStyleVar__ImGuiStyleVar_TreeLinesSize: ImGuiStyleVar_ = 32;
+pub const ImGuiStyleVar__ImGuiStyleVar_TreeLinesRounding: ImGuiStyleVar_ = 33;
+pub const ImGuiStyleVar__ImGuiStyleVar_ButtonTextAlign: ImGuiStyleVar_ = 34;
+pub const ImGuiStyleVar__ImGuiStyleVar_SelectableTextAlign: ImGuiStyleVar_ = 35;
+pub const ImGuiStyleVar__ImGuiStyleVar_SeparatorTextBorderSize: ImGuiStyleVar_ = 36;
+pub const ImGuiStyleVar__ImGuiStyleVar_SeparatorTextAlign: ImGuiStyleVar_ = 37;
+pub const ImGuiStyleVar__ImGuiStyleVar_SeparatorTextPadding: ImGuiStyleVar_ = 38;
+pub const ImGuiStyleVar__ImGuiStyleVar_COUNT: ImGuiStyleVar_ = 39;
+pub type ImGuiStyleVar_ = ::core::ffi::c_uint;
+pub const ImGuiButtonFlags__ImGuiButtonFlags_None: ImGuiButtonFlags_ = 0;
+pub const ImGuiButtonFlags__ImGuiButtonFlags_MouseButtonLeft: ImGuiButtonFlags_ = 1;
+pub const ImGuiButtonFlags__ImGuiButtonFlags_MouseButtonRight: ImGuiButtonFlags_ = 2;
+pub const ImGuiButtonFlags__ImGuiButtonFlags_MouseButtonMiddle: ImGuiButtonFlags_ = 4;
+pub const ImGuiButtonFlags__ImGuiButtonFlags_MouseButtonMask_: ImGuiButtonFlags_ = 7;
+pub const ImGuiButtonFlags__ImGuiButtonFlags_EnableNav: ImGuiButtonFlags_ = 8;
+pub type ImGuiButtonFlags_ = ::core::ffi::c_uint;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_None: ImGuiColorEditFlags_ = 0;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoAlpha: ImGuiColorEditFlags_ = 2;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoPicker: ImGuiColorEditFlags_ = 4;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoOptions: ImGuiColorEditFlags_ = 8;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoSmallPreview: ImGuiColorEditFlags_ = 16;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoInputs: ImGuiColorEditFlags_ = 32;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoTooltip: ImGuiColorEditFlags_ = 64;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoLabel: ImGuiColorEditFlags_ = 128;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoSidePreview: ImGuiColorEditFlags_ = 256;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoDragDrop: ImGuiColorEditFlags_ = 512;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_NoBorder: ImGuiColorEditFlags_ = 1024;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_AlphaOpaque: ImGuiColorEditFlags_ = 2048;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_AlphaNoBg: ImGuiColorEditFlags_ = 4096;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_AlphaPreviewHalf: ImGuiColorEditFlags_ = 8192;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_AlphaBar: ImGuiColorEditFlags_ = 65536;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_HDR: ImGuiColorEditFlags_ = 524288;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_DisplayRGB: ImGuiColorEditFlags_ = 1048576;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_DisplayHSV: ImGuiColorEditFlags_ = 2097152;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_DisplayHex: ImGuiColorEditFlags_ = 4194304;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_Uint8: ImGuiColorEditFlags_ = 8388608;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_Float: ImGuiColorEditFlags_ = 16777216;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_PickerHueBar: ImGuiColorEditFlags_ = 33554432;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_PickerHueWheel: ImGuiColorEditFlags_ = 67108864;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_InputRGB: ImGuiColorEditFlags_ = 134217728;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_InputHSV: ImGuiColorEditFlags_ = 268435456;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_DefaultOptions_: ImGuiColorEditFlags_ =
+    177209344;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_AlphaMask_: ImGuiColorEditFlags_ = 14338;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_DisplayMask_: ImGuiColorEditFlags_ = 7340032;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_DataTypeMask_: ImGuiColorEditFlags_ = 25165824;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_PickerMask_: ImGuiColorEditFlags_ = 100663296;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_InputMask_: ImGuiColorEditFlags_ = 402653184;
+pub const ImGuiColorEditFlags__ImGuiColorEditFlags_AlphaPreview: ImGuiColorEditFlags_ = 0;
+pub type ImGuiColorEditFlags_ = ::core::ffi::c_uint;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_None: ImGuiSliderFlags_ = 0;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_Logarithmic: ImGuiSliderFlags_ = 32;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_NoRoundToFormat: ImGuiSliderFlags_ = 64;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_NoInput: ImGuiSliderFlags_ = 128;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_WrapAround: ImGuiSliderFlags_ = 256;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_ClampOnInput: ImGuiSliderFlags_ = 512;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_ClampZeroRange: ImGuiSliderFlags_ = 1024;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_NoSpeedTweaks: ImGuiSliderFlags_ = 2048;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_AlwaysClamp: ImGuiSliderFlags_ = 1536;
+pub const ImGuiSliderFlags__ImGuiSliderFlags_InvalidMask_: ImGuiSliderFlags_ = 1879048207;
+pub type ImGuiSliderFlags_ = ::core::ffi::c_uint;
+pub const ImGuiMouseButton__ImGuiMouseButton_Left: ImGuiMouseButton_ = 0;
+pub const ImGuiMouseButton__ImGuiMouseButton_Right: ImGuiMouseButton_ = 1;
+pub const ImGuiMouseButton__ImGuiMouseButton_Middle: ImGuiMouseButton_ = 2;
+pub const ImGuiMouseButton__ImGuiMouseButton_COUNT: ImGuiMouseButton_ = 5;
+pub type ImGuiMouseButton_ = ::core::ffi::c_uint;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_None: ImGuiMouseCursor_ = -1;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_Arrow: ImGuiMouseCursor_ = 0;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_TextInput: ImGuiMouseCursor_ = 1;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_ResizeAll: ImGuiMouseCursor_ = 2;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_ResizeNS: ImGuiMouseCursor_ = 3;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_ResizeEW: ImGuiMouseCursor_ = 4;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_ResizeNESW: ImGuiMouseCursor_ = 5;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_ResizeNWSE: ImGuiMouseCursor_ = 6;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_Hand: ImGuiMouseCursor_ = 7;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_Wait: ImGuiMouseCursor_ = 8;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_Progress: ImGuiMouseCursor_ = 9;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_NotAllowed: ImGuiMouseCursor_ = 10;
+pub const ImGuiMouseCursor__ImGuiMouseCursor_COUNT: ImGuiMouseCursor_ = 11;
+pub type ImGuiMouseCursor_ = ::core::ffi::c_int;
+pub const ImGuiMouseSource_Mouse: _bindgen_ty_4 = 0;
+pub const ImGuiMouseSource_TouchScreen: _bindgen_ty_4 = 1;
+pub const ImGuiMouseSource_Pen: _bindgen_ty_4 = 2;
+pub const ImGuiMouseSource_COUNT: _bindgen_ty_4 = 3;
+pub type _bindgen_ty_4 = ::core::ffi::c_uint;
+pub const ImGuiCond__ImGuiCond_None: ImGuiCond_ = 0;
+pub const ImGuiCond__ImGuiCond_Always: ImGuiCond_ = 1;
+pub const ImGuiCond__ImGuiCond_Once: ImGuiCond_ = 2;
+pub const ImGuiCond__ImGuiCond_FirstUseEver: ImGuiCond_ = 4;
+pub const ImGuiCond__ImGuiCond_Appearing: ImGuiCond_ = 8;
+pub type ImGuiCond_ = ::core::ffi::c_uint;
+pub const ImGuiTableFlags__ImGuiTableFlags_None: ImGuiTableFlags_ = 0;
+pub const ImGuiTableFlags__ImGuiTableFlags_Resizable: ImGuiTableFlags_ = 1;
+pub const ImGuiTableFlags__ImGuiTableFlags_Reorderable: ImGuiTableFlags_ = 2;
+pub const ImGuiTableFlags__ImGuiTableFlags_Hideable: ImGuiTableFlags_ = 4;
+pub const ImGuiTableFlags__ImGuiTableFlags_Sortable: ImGuiTableFlags_ = 8;
+pub const ImGuiTableFlags__ImGuiTableFlags_NoSavedSettings: ImGuiTableFlags_ = 16;
+pub const ImGuiTableFlags__ImGuiTableFlags_ContextMenuInBody: ImGuiTableFlags_ = 32;
+pub const ImGuiTableFlags__ImGuiTableFlags_RowBg: ImGuiTableFlags_ = 64;
+pub const ImGuiTableFlags__ImGuiTableFlags_BordersInnerH: ImGuiTableFlags_ = 128;
+pub const ImGuiTableFlags__ImGuiTableFlags_BordersOuterH: ImGuiTableFlags_ = 256;
+pub const ImGuiTableFlags__ImGuiTableFlags_BordersInnerV: ImGuiTableFlags_ = 512;
+pub const ImGuiTableFlags__ImGuiTableFlags_BordersOuterV: ImGuiTableFlags_ = 1024;
+pub const ImGuiTableFlags__ImGuiTableFlags_BordersH: ImGuiTableFlags_ = 384;
+pub const ImGuiTableFlags__ImGuiTableFlags_BordersV: ImGuiTableFlags_ = 1536;
+pub const ImGuiTableFlags__ImGuiTableFlags_BordersInner: ImGuiTableFlags_ = 640;
+pub const ImGuiTableFlags__ImGuiTableFlags_BordersOuter: ImGuiTableFlags_ = 1280;
+pub const ImGuiTableFlags__ImGuiTableFlags_Borders: ImGuiTableFlags_ = 1920;
+pub const ImGuiTableFlags__ImGuiTableFlags_NoBordersInBody: ImGuiTableFlags_ = 2048;
+pub const ImGuiTableFlags__ImGuiTableFlags_NoBordersInBodyUntilResize: ImGuiTableFlags_ = 4096;
+pub const ImGuiTableFlags__ImGuiTableFlags_SizingFixedFit: ImGuiTableFlags_ = 8192;
+pub const ImGuiTableFlags__ImGuiTableFlags_SizingFixedSame: ImGuiTableFlags_ = 16384;
+pub const ImGuiTableFlags__ImGuiTableFlags_SizingStretchProp: ImGuiTableFlags_ = 24576;
+pub const ImGuiTableFlags__ImGuiTableFlags_SizingStretchSame: ImGuiTableFlags_ = 32768;
+pub const ImGuiTableFlags__ImGuiTableFlags_NoHostExtendX: ImGuiTableFlags_ = 65536;
+pub const ImGuiTableFlags__ImGuiTableFlags_NoHostExtendY: ImGuiTableFlags_ = 131072;
+pub const ImGuiTableFlags__ImGuiTableFlags_NoKeepColumnsVisible: ImGuiTableFlags_ = 262144;
+pub const ImGuiTableFlags__ImGuiTableFlags_PreciseWidths: ImGuiTableFlags_ = 524288;
+pub const ImGuiTableFlags__ImGuiTableFlags_NoClip: ImGuiTableFlags_ = 1048576;
+pub const ImGuiTableFlags__ImGuiTableFlags_PadOuterX: ImGuiTableFlags_ = 2097152;
+pub const ImGuiTableFlags__ImGuiTableFlags_NoPadOuterX: ImGuiTableFlags_ = 4194304;
+pub const ImGuiTableFlags__ImGuiTableFlags_NoPadInnerX: ImGuiTableFlags_ = 8388608;
+pub const ImGuiTableFlags__ImGuiTableFlags_ScrollX: ImGuiTableFlags_ = 16777216;
+pub const ImGuiTableFlags__ImGuiTableFlags_ScrollY: ImGuiTableFlags_ = 33554432;
+pub const ImGuiTableFlags__ImGuiTableFlags_SortMulti: ImGuiTableFlags_ = 67108864;
+pub const ImGuiTableFlags__ImGuiTableFlags_SortTristate: ImGuiTableFlags_ = 134217728;
+pub const ImGuiTableFlags__ImGuiTableFlags_HighlightHoveredColumn: ImGuiTableFlags_ = 268435456;
+pub const ImGuiTableFlags__ImGuiTableFlags_SizingMask_: ImGuiTableFlags_ = 57344;
+pub type ImGuiTableFlags_ = ::core::ffi::c_uint;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_None: ImGuiTableColumnFlags_ = 0;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_Disabled: ImGuiTableColumnFlags_ = 1;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_DefaultHide: ImGuiTableColumnFlags_ = 2;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_DefaultSort: ImGuiTableColumnFlags_ = 4;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_WidthStretch: ImGuiTableColumnFlags_ = 8;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_WidthFixed: ImGuiTableColumnFlags_ = 16;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoResize: ImGuiTableColumnFlags_ = 32;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoReorder: ImGuiTableColumnFlags_ = 64;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoHide: ImGuiTableColumnFlags_ = 128;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoClip: ImGuiTableColumnFlags_ = 256;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoSort: ImGuiTableColumnFlags_ = 512;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoSortAscending: ImGuiTableColumnFlags_ =
+    1024;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoSortDescending: ImGuiTableColumnFlags_ =
+    2048;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoHeaderLabel: ImGuiTableColumnFlags_ = 4096;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoHeaderWidth: ImGuiTableColumnFlags_ = 8192;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_PreferSortAscending: ImGuiTableColumnFlags_ =
+    16384;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_PreferSortDescending:
+    ImGuiTableColumnFlags_ = 32768;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_IndentEnable: ImGuiTableColumnFlags_ = 65536;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_IndentDisable: ImGuiTableColumnFlags_ =
+    131072;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_AngledHeader: ImGuiTableColumnFlags_ =
+    262144;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_IsEnabled: ImGuiTableColumnFlags_ = 16777216;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_IsVisible: ImGuiTableColumnFlags_ = 33554432;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_IsSorted: ImGuiTableColumnFlags_ = 67108864;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_IsHovered: ImGuiTableColumnFlags_ =
+    134217728;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_WidthMask_: ImGuiTableColumnFlags_ = 24;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_IndentMask_: ImGuiTableColumnFlags_ = 196608;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_StatusMask_: ImGuiTableColumnFlags_ =
+    251658240;
+pub const ImGuiTableColumnFlags__ImGuiTableColumnFlags_NoDirectResize_: ImGuiTableColumnFlags_ =
+    1073741824;
+pub type ImGuiTableColumnFlags_ = ::core::ffi::c_uint;
+pub const ImGuiTableRowFlags__ImGuiTableRowFlags_None: ImGuiTableRowFlags_ = 0;
+pub const ImGuiTableRowFlags__ImGuiTableRowFlags_Headers: ImGuiTableRowFlags_ = 1;
+pub type ImGuiTableRowFlags_ = ::core::ffi::c_uint;
+pub const ImGuiTableBgTarget__ImGuiTableBgTarget_None: ImGuiTableBgTarget_ = 0;
+pub const ImGuiTableBgTarget__ImGuiTableBgTarget_RowBg0: ImGuiTableBgTarget_ = 1;
+pub const ImGuiTableBgTarget__ImGuiTableBgTarget_RowBg1: ImGuiTableBgTarget_ = 2;
+pub const ImGuiTableBgTarget__ImGuiTableBgTarget_CellBg: ImGuiTableBgTarget_ = 3;
+pub type ImGuiTableBgTarget_ = ::core::ffi::c_uint;
+pub const ImGuiListClipperFlags__ImGuiListClipperFlags_None: ImGuiListClipperFlags_ = 0;
+pub const ImGuiListClipperFlags__ImGuiListClipperFlags_NoSetTableRowCounters:
+    ImGuiListClipperFlags_ = 1;
+pub type ImGuiListClipperFlags_ = ::core::ffi::c_uint;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_None: ImGuiMultiSelectFlags_ = 0;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_SingleSelect: ImGuiMultiSelectFlags_ = 1;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_NoSelectAll: ImGuiMultiSelectFlags_ = 2;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_NoRangeSelect: ImGuiMultiSelectFlags_ = 4;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_NoAutoSelect: ImGuiMultiSelectFlags_ = 8;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_NoAutoClear: ImGuiMultiSelectFlags_ = 16;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_NoAutoClearOnReselect:
+    ImGuiMultiSelectFlags_ = 32;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_BoxSelect1d: ImGuiMultiSelectFlags_ = 64;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_BoxSelect2d: ImGuiMultiSelectFlags_ = 128;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_BoxSelectNoScroll: ImGuiMultiSelectFlags_ =
+    256;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_ClearOnEscape: ImGuiMultiSelectFlags_ = 512;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_ClearOnClickVoid: ImGuiMultiSelectFlags_ =
+    1024;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_ScopeWindow: ImGuiMultiSelectFlags_ = 2048;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_ScopeRect: ImGuiMultiSelectFlags_ = 4096;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_SelectOnClick: ImGuiMultiSelectFlags_ = 8192;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_SelectOnClickRelease:
+    ImGuiMultiSelectFlags_ = 16384;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_NavWrapX: ImGuiMultiSelectFlags_ = 65536;
+pub const ImGuiMultiSelectFlags__ImGuiMultiSelectFlags_NoSelectOnRightClick:
+    ImGuiMultiSelectFlags_ = 131072;
+pub type ImGuiMultiSelectFlags_ = ::core::ffi::c_uint;
+pub const ImGuiSelectionRequestType_ImGuiSelectionRequestType_None: ImGuiSelectionRequestType = 0;
+pub const ImGuiSelectionRequestType_ImGuiSelectionRequestType_SetAll: ImGuiSelectionRequestType = 1;
+pub const ImGuiSelectionRequestType_ImGuiSelectionRequestType_SetRange: ImGuiSelectionRequestType =
+    2;
+pub type ImGuiSelectionRequestType = ::core::ffi::c_uint;
+pub const ImDrawFlags__ImDrawFlags_None: ImDrawFlags_ = 0;
+pub const ImDrawFlags__ImDrawFlags_Closed: ImDrawFlags_ = 1;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersTopLeft: ImDrawFlags_ = 16;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersTopRight: ImDrawFlags_ = 32;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersBottomLeft: ImDrawFlags_ = 64;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersBottomRight: ImDrawFlags_ = 128;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersNone: ImDrawFlags_ = 256;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersTop: ImDrawFlags_ = 48;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersBottom: ImDrawFlags_ = 192;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersLeft: ImDrawFlags_ = 80;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersRight: ImDrawFlags_ = 160;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersAll: ImDrawFlags_ = 240;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersDefault_: ImDrawFlags_ = 240;
+pub const ImDrawFlags__ImDrawFlags_RoundCornersMask_: ImDrawFlags_ = 496;
+pub type ImDrawFlags_ = ::core::ffi::c_uint;
+pub const ImDrawListFlags__ImDrawListFlags_None: ImDrawListFlags_ = 0;
+pub const ImDrawListFlags__ImDrawListFlags_AntiAliasedLines: ImDrawListFlags_ = 1;
+pub const ImDrawListFlags__ImDrawListFlags_AntiAliasedLinesUseTex: ImDrawListFlags_ = 2;
+pub const ImDrawListFlags__ImDrawListFlags_AntiAliasedFill: ImDrawListFlags_ = 4;
+pub const ImDrawListFlags__ImDrawListFlags_AllowVtxOffset: ImDrawListFlags_ = 8;
+pub type ImDrawListFlags_ = ::core::ffi::c_uint;
+pub const ImTextureFormat_ImTextureFormat_RGBA32: ImTextureFormat = 0;
+pub const ImTextureFormat_ImTextureFormat_Alpha8: ImTextureFormat = 1;
+pub type ImTextureFormat = ::core::ffi::c_uint;
+pub const ImTextureStatus_ImTextureStatus_OK: ImTextureStatus = 0;
+pub const ImTextureStatus_ImTextureStatus_Destroyed: ImTextureStatus = 1;
+pub const ImTextureStatus_ImTextureStatus_WantCreate: ImTextureStatus = 2;
+pub const ImTextureStatus_ImTextureStatus_WantUpdates: ImTextureStatus = 3;
+pub const ImTextureStatus_ImTextureStatus_WantDestroy: ImTextureStatus = 4;
+pub type ImTextureStatus = ::core::ffi::c_uint;
+pub const ImFontAtlasFlags__ImFontAtlasFlags_None: ImFontAtlasFlags_ = 0;
+pub const ImFontAtlasFlags__ImFontAtlasFlags_NoPowerOfTwoHeight: ImFontAtlasFlags_ = 1;
+pub const ImFontAtlasFlags__ImFontAtlasFlags_NoMouseCursors: ImFontAtlasFlags_ = 2;
+pub const ImFontAtlasFlags__ImFontAtlasFlags_NoBakedLines: ImFontAtlasFlags_ = 4;
+pub type ImFontAtlasFlags_ = ::core::ffi::c_uint;
+pub const ImFontFlags__ImFontFlags_None: ImFontFlags_ = 0;
+pub const ImFontFlags__ImFontFlags_NoLoadError: ImFontFlags_ = 2;
+pub const ImFontFlags__ImFontFlags_NoLoadGlyphs: ImFontFlags_ = 4;
+pub const ImFontFlags__ImFontFlags_LockBakedSizes: ImFontFlags_ = 8;
+pub type ImFontFlags_ = ::core::ffi::c_uint;
+pub const ImGuiViewportFlags__ImGuiViewportFlags_None: ImGuiViewportFlags_ = 0;
+pub const ImGuiViewportFlags__ImGuiViewportFlags_IsPlatformWindow: ImGuiViewportFlags_ = 1;
+pub const ImGuiViewportFlags__ImGuiViewportFlags_IsPlatformMonitor: ImGuiViewportFlags_ = 2;
+pub const ImGuiViewportFlags__ImGuiViewportFlags_OwnedByApp: ImGuiViewportFlags_ = 4;
+pub type ImGuiViewportFlags_ = ::core::ffi::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVec2_t {
+    pub x: f32,
+    pub y: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVec4_t {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImTextureRef_t {
+    pub _TexData: *mut ImTextureData,
+    pub _TexID: ImTextureID,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiTableSortSpecs_t {
+    pub Specs: *const ImGuiTableColumnSortSpecs,
+    pub SpecsCount: ::core::ffi::c_int,
+    pub SpecsDirty: bool,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiTableColumnSortSpecs_t {
+    pub ColumnUserID: ImGuiID,
+    pub ColumnIndex: ImS16,
+    pub SortOrder: ImS16,
+    pub SortDirection: ImGuiSortDirection,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImGuiTextRange_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImGuiTextFilter_ImGuiTextRange,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_char_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ::core::ffi::c_char,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImGuiStoragePair_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImGuiStoragePair,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImGuiSelectionRequest_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImGuiSelectionRequest,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImDrawChannel_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImDrawChannel,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImDrawCmd_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImDrawCmd,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImDrawIdx_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImDrawIdx,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImDrawVert_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImDrawVert,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImVec2_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImVec2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImVec4_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImVec4,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImTextureRef_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImTextureRef,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImU8_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImU8,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImDrawListPtr_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut *mut ImDrawList,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImTextureRect_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImTextureRect,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImU32_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImU32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImWchar_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImWchar,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImFontPtr_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut *mut ImFont,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImFontConfig_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImFontConfig,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImDrawListSharedDataPtr_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut *mut ImDrawListSharedData,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_float_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImU16_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImU16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImFontGlyph_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut ImFontGlyph,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImFontConfigPtr_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut *mut ImFontConfig,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImVector_ImTextureDataPtr_t {
+    pub Size: ::core::ffi::c_int,
+    pub Capacity: ::core::ffi::c_int,
+    pub Data: *mut *mut ImTextureData,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiStyle_t {
+    pub FontSizeBase: f32,
+    pub FontScaleMain: f32,
+    pub FontScaleDpi: f32,
+    pub Alpha: f32,
+    pub DisabledAlpha: f32,
+    pub WindowPadding: ImVec2,
+    pub WindowRounding: f32,
+    pub WindowBorderSize: f32,
+    pub WindowBorderHoverPadding: f32,
+    pub WindowMinSize: ImVec2,
+    pub WindowTitleAlign: ImVec2,
+    pub WindowMenuButtonPosition: ImGuiDir,
+    pub ChildRounding: f32,
+    pub ChildBorderSize: f32,
+    pub PopupRounding: f32,
+    pub PopupBorderSize: f32,
+    pub FramePadding: ImVec2,
+    pub FrameRounding: f32,
+    pub FrameBorderSize: f32,
+    pub ItemSpacing: ImVec2,
+    pub ItemInnerSpacing: ImVec2,
+    pub CellPadding: ImVec2,
+    pub TouchExtraPadding: ImVec2,
+    pub IndentSpacing: f32,
+    pub ColumnsMinSpacing: f32,
+    pub ScrollbarSize: f32,
+    pub ScrollbarRounding: f32,
+    pub ScrollbarPadding: f32,
+    pub GrabMinSize: f32,
+    pub GrabRounding: f32,
+    pub LogSliderDeadzone: f32,
+    pub ImageBorderSize: f32,
+    pub TabRounding: f32,
+    pub TabBorderSize: f32,
+    pub TabMinWidthBase: f32,
+    pub TabMinWidthShrink: f32,
+    pub TabCloseButtonMinWidthSelected: f32,
+    pub TabCloseButtonMinWidthUnselected: f32,
+    pub TabBarBorderSize: f32,
+    pub TabBarOverlineSize: f32,
+    pub TableAngledHeadersAngle: f32,
+    pub TableAngledHeadersTextAlign: ImVec2,
+    pub TreeLinesFlags: ImGuiTreeNodeFlags,
+    pub TreeLinesSize: f32,
+    pub TreeLinesRounding: f32,
+    pub DragDropTargetRounding: f32,
+    pub DragDropTargetBorderSize: f32,
+    pub DragDropTargetPadding: f32,
+    pub ColorButtonPosition: ImGuiDir,
+    pub ButtonTextAlign: ImVec2,
+    pub SelectableTextAlign: ImVec2,
+    pub SeparatorTextBorderSize: f32,
+    pub SeparatorTextAlign: ImVec2,
+    pub SeparatorTextPadding: ImVec2,
+    pub DisplayWindowPadding: ImVec2,
+    pub DisplaySafeAreaPadding: ImVec2,
+    pub MouseCursorScale: f32,
+    pub AntiAliasedLines: bool,
+    pub AntiAliasedLinesUseTex: bool,
+    pub AntiAliasedFill: bool,
+    pub CurveTessellationTol: f32,
+    pub CircleTessellationMaxError: f32,
+    pub Colors: [ImVec4; 60usize],
+    pub HoverStationaryDelay: f32,
+    pub HoverDelayShort: f32,
+    pub HoverDelayNormal: f32,
+    pub HoverFlagsForTooltipMouse: ImGuiHoveredFlags,
+    pub HoverFlagsForTooltipNav: ImGuiHoveredFlags,
+    pub _MainScale: f32,
+    pub _NextFrameFontSizeBase: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiKeyData_t {
+    pub Down: bool,
+    pub DownDuration: f32,
+    pub DownDurationPrev: f32,
+    pub AnalogValue: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiIO_t {
+    pub ConfigFlags: ImGuiConfigFlags,
+    pub BackendFlags: ImGuiBackendFlags,
+    pub DisplaySize: ImVec2,
+    pub DisplayFramebufferScale: ImVec2,
+    pub DeltaTime: f32,
+    pub IniSavingRate: f32,
+    pub IniFilename: *const ::core::ffi::c_char,
+    pub LogFilename: *const ::core::ffi::c_char,
+    pub UserData: *mut ::core::ffi::c_void,
+    pub Fonts: *mut ImFontAtlas,
+    pub FontDefault: *mut ImFont,
+    pub FontAllowUserScaling: bool,
+    pub ConfigNavSwapGamepadButtons: bool,
+    pub ConfigNavMoveSetMousePos: bool,
+    pub ConfigNavCaptureKeyboard: bool,
+    pub ConfigNavEscapeClearFocusItem: bool,
+    pub ConfigNavEscapeClearFocusWindow: bool,
+    pub ConfigNavCursorVisibleAuto: bool,
+    pub ConfigNavCursorVisibleAlways: bool,
+    pub MouseDrawCursor: bool,
+    pub ConfigMacOSXBehaviors: bool,
+    pub ConfigInputTrickleEventQueue: bool,
+    pub ConfigInputTextCursorBlink: bool,
+    pub ConfigInputTextEnterKeepActive: bool,
+    pub ConfigDragClickToInputText: bool,
+    pub ConfigWindowsResizeFromEdges: bool,
+    pub ConfigWindowsMoveFromTitleBarOnly: bool,
+    pub ConfigWindowsCopyContentsWithCtrlC: bool,
+    pub ConfigScrollbarScrollByPage: bool,
+    pub ConfigMemoryCompactTimer: f32,
+    pub MouseDoubleClickTime: f32,
+    pub MouseDoubleClickMaxDist: f32,
+    pub MouseDragThreshold: f32,
+    pub KeyRepeatDelay: f32,
+    pub KeyRepeatRate: f32,
+    pub ConfigErrorRecovery: bool,
+    pub ConfigErrorRecoveryEnableAssert: bool,
+    pub ConfigErrorRecoveryEnableDebugLog: bool,
+    pub ConfigErrorRecoveryEnableTooltip: bool,
+    pub ConfigDebugIsDebuggerPresent: bool,
+    pub ConfigDebugHighlightIdConflicts: bool,
+    pub ConfigDebugHighlightIdConflictsShowItemPicker: bool,
+    pub ConfigDebugBeginReturnValueOnce: bool,
+    pub ConfigDebugBeginReturnValueLoop: bool,
+    pub ConfigDebugIgnoreFocusLoss: bool,
+    pub ConfigDebugIniSettings: bool,
+    pub BackendPlatformName: *const ::core::ffi::c_char,
+    pub BackendRendererName: *const ::core::ffi::c_char,
+    pub BackendPlatformUserData: *mut ::core::ffi::c_void,
+    pub BackendRendererUserData: *mut ::core::ffi::c_void,
+    pub BackendLanguageUserData: *mut ::core::ffi::c_void,
+    pub WantCaptureMouse: bool,
+    pub WantCaptureKeyboard: bool,
+    pub WantTextInput: bool,
+    pub WantSetMousePos: bool,
+    pub WantSaveIniSettings: bool,
+    pub NavActive: bool,
+    pub NavVisible: bool,
+    pub Framerate: f32,
+    pub MetricsRenderVertices: ::core::ffi::c_int,
+    pub MetricsRenderIndices: ::core::ffi::c_int,
+    pub MetricsRenderWindows: ::core::ffi::c_int,
+    pub MetricsActiveWindows: ::core::ffi::c_int,
+    pub MouseDelta: ImVec2,
+    pub Ctx: *mut ImGuiContext,
+    pub MousePos: ImVec2,
+    pub MouseDown: [bool; 5usize],
+    pub MouseWheel: f32,
+    pub MouseWheelH: f32,
+    pub MouseSource: ImGuiMouseSource,
+    pub KeyCtrl: bool,
+    pub KeyShift: bool,
+    pub KeyAlt: bool,
+    pub KeySuper: bool,
+    pub KeyMods: ImGuiKeyChord,
+    pub KeysData: [ImGuiKeyData; 155usize],
+    pub WantCaptureMouseUnlessPopupClose: bool,
+    pub MousePosPrev: ImVec2,
+    pub MouseClickedPos: [ImVec2; 5usize],
+    pub MouseClickedTime: [f64; 5usize],
+    pub MouseClicked: [bool; 5usize],
+    pub MouseDoubleClicked: [bool; 5usize],
+    pub MouseClickedCount: [ImU16; 5usize],
+    pub MouseClickedLastCount: [ImU16; 5usize],
+    pub MouseReleased: [bool; 5usize],
+    pub MouseReleasedTime: [f64; 5usize],
+    pub MouseDownOwned: [bool; 5usize],
+    pub MouseDownOwnedUnlessPopupClose: [bool; 5usize],
+    pub MouseWheelRequestAxisSwap: bool,
+    pub MouseCtrlLeftAsRightClick: bool,
+    pub MouseDownDuration: [f32; 5usize],
+    pub MouseDownDurationPrev: [f32; 5usize],
+    pub MouseDragMaxDistanceSqr: [f32; 5usize],
+    pub PenPressure: f32,
+    pub AppFocusLost: bool,
+    pub AppAcceptingEvents: bool,
+    pub InputQueueSurrogate: ImWchar16,
+    pub InputQueueCharacters: ImVector_ImWchar,
+    pub FontGlobalScale: f32,
+    pub GetClipboardTextFn: ::core::option::Option<
+        unsafe extern "C" fn(user_data: *mut ::core::ffi::c_void) -> *const ::core::ffi::c_char,
+    >,
+    pub SetClipboardTextFn: ::core::option::Option<
+        unsafe extern "C" fn(user_data: *mut ::core::ffi::c_void, text: *const ::core::ffi::c_char),
+    >,
+    pub ClipboardUserData: *mut ::core::ffi::c_void,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiInputTextCallbackData_t {
+    pub Ctx: *mut ImGuiContext,
+    pub EventFlag: ImGuiInputTextFlags,
+    pub Flags: ImGuiInputTextFlags,
+    pub UserData: *mut ::core::ffi::c_void,
+    pub EventChar: ImWchar,
+    pub EventKey: ImGuiKey,
+    pub Buf: *mut ::core::ffi::c_char,
+    pub BufTextLen: ::core::ffi::c_int,
+    pub BufSize: ::core::ffi::c_int,
+    pub BufDirty: bool,
+    pub CursorPos: ::core::ffi::c_int,
+    pub SelectionStart: ::core::ffi::c_int,
+    pub SelectionEnd: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiSizeCallbackData_t {
+    pub UserData: *mut ::core::ffi::c_void,
+    pub Pos: ImVec2,
+    pub CurrentSize: ImVec2,
+    pub DesiredSize: ImVec2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiPayload_t {
+    pub Data: *mut ::core::ffi::c_void,
+    pub DataSize: ::core::ffi::c_int,
+    pub SourceId: ImGuiID,
+    pub SourceParentId: ImGuiID,
+    pub DataFrameCount: ::core::ffi::c_int,
+    pub DataType: [::core::ffi::c_char; 33usize],
+    pub Preview: bool,
+    pub Delivery: bool,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiTextFilter_ImGuiTextRange_t {
+    pub b: *const ::core::ffi::c_char,
+    pub e: *const ::core::ffi::c_char,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiTextFilter_t {
+    pub InputBuf: [::core::ffi::c_char; 256usize],
+    pub Filters: ImVector_ImGuiTextRange,
+    pub CountGrep: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiTextBuffer_t {
+    pub Buf: ImVector_char,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImGuiStoragePair_t {
+    pub key: ImGuiID,
+    pub __bindgen_anon_1: ImGuiStoragePair_t__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union ImGuiStoragePair_t__bindgen_ty_1 {
+    pub val_i: ::core::ffi::c_int,
+    pub val_f: f32,
+    pub val_p: *mut ::core::ffi::c_void,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiStorage_t {
+    pub Data: ImVector_ImGuiStoragePair,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiListClipper_t {
+    pub Ctx: *mut ImGuiContext,
+    pub DisplayStart: ::core::ffi::c_int,
+    pub DisplayEnd: ::core::ffi::c_int,
+    pub ItemsCount: ::core::ffi::c_int,
+    pub ItemsHeight: f32,
+    pub StartPosY: f64,
+    pub StartSeekOffsetY: f64,
+    pub TempData: *mut ::core::ffi::c_void,
+    pub Flags: ImGuiListClipperFlags,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImColor_t {
+    pub Value: ImVec4,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiMultiSelectIO_t {
+    pub Requests: ImVector_ImGuiSelectionRequest,
+    pub RangeSrcItem: ImGuiSelectionUserData,
+    pub NavIdItem: ImGuiSelectionUserData,
+    pub NavIdSelected: bool,
+    pub RangeSrcReset: bool,
+    pub ItemsCount: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiSelectionRequest_t {
+    pub Type: ImGuiSelectionRequestType,
+    pub Selected: bool,
+    pub RangeDirection: ImS8,
+    pub RangeFirstItem: ImGuiSelectionUserData,
+    pub RangeLastItem: ImGuiSelectionUserData,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiSelectionBasicStorage_t {
+    pub Size: ::core::ffi::c_int,
+    pub PreserveOrder: bool,
+    pub UserData: *mut ::core::ffi::c_void,
+    pub AdapterIndexToStorageId: ::core::option::Option<
+        unsafe extern "C" fn(
+            self_: *mut ImGuiSelectionBasicStorage,
+            idx: ::core::ffi::c_int,
+        ) -> ImGuiID,
+    >,
+    pub _SelectionOrder: ::core::ffi::c_int,
+    pub _Storage: ImGuiStorage,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiSelectionExternalStorage_t {
+    pub UserData: *mut ::core::ffi::c_void,
+    pub AdapterSetItemSelected: ::core::option::Option<
+        unsafe extern "C" fn(
+            self_: *mut ImGuiSelectionExternalStorage,
+            idx: ::core::ffi::c_int,
+            selected: bool,
+        ),
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImDrawCmd_t {
+    pub ClipRect: ImVec4,
+    pub TexRef: ImTextureRef,
+    pub VtxOffset: ::core::ffi::c_uint,
+    pub IdxOffset: ::core::ffi::c_uint,
+    pub ElemCount: ::core::ffi::c_uint,
+    pub UserCallback: ImDrawCallback,
+    pub UserCallbackData: *mut ::core::ffi::c_void,
+    pub UserCallbackDataSize: ::core::ffi::c_int,
+    pub UserCallbackDataOffset: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImDrawVert_t {
+    pub pos: ImVec2,
+    pub uv: ImVec2,
+    pub col: ImU32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImDrawCmdHeader_t {
+    pub ClipRect: ImVec4,
+    pub TexRef: ImTextureRef,
+    pub VtxOffset: ::core::ffi::c_uint,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImDrawChannel_t {
+    pub _CmdBuffer: ImVector_ImDrawCmd,
+    pub _IdxBuffer: ImVector_ImDrawIdx,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImDrawListSplitter_t {
+    pub _Current: ::core::ffi::c_int,
+    pub _Count: ::core::ffi::c_int,
+    pub _Channels: ImVector_ImDrawChannel,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImDrawList_t {
+    pub CmdBuffer: ImVector_ImDrawCmd,
+    pub IdxBuffer: ImVector_ImDrawIdx,
+    pub VtxBuffer: ImVector_ImDrawVert,
+    pub Flags: ImDrawListFlags,
+    pub _VtxCurrentIdx: ::core::ffi::c_uint,
+    pub _Data: *mut ImDrawListSharedData,
+    pub _VtxWritePtr: *mut ImDrawVert,
+    pub _IdxWritePtr: *mut ImDrawIdx,
+    pub _Path: ImVector_ImVec2,
+    pub _CmdHeader: ImDrawCmdHeader,
+    pub _Splitter: ImDrawListSplitter,
+    pub _ClipRectStack: ImVector_ImVec4,
+    pub _TextureStack: ImVector_ImTextureRef,
+    pub _CallbacksDataBuf: ImVector_ImU8,
+    pub _FringeScale: f32,
+    pub _OwnerName: *const ::core::ffi::c_char,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImDrawData_t {
+    pub Valid: bool,
+    pub CmdListsCount: ::core::ffi::c_int,
+    pub TotalIdxCount: ::core::ffi::c_int,
+    pub TotalVtxCount: ::core::ffi::c_int,
+    pub CmdLists: ImVector_ImDrawListPtr,
+    pub DisplayPos: ImVec2,
+    pub DisplaySize: ImVec2,
+    pub FramebufferScale: ImVec2,
+    pub OwnerViewport: *mut ImGuiViewport,
+    pub Textures: *mut ImVector_ImTextureDataPtr,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImTextureRect_t {
+    pub x: ::core::ffi::c_ushort,
+    pub y: ::core::ffi::c_ushort,
+    pub w: ::core::ffi::c_ushort,
+    pub h: ::core::ffi::c_ushort,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImTextureData_t {
+    pub UniqueID: ::core::ffi::c_int,
+    pub Status: ImTextureStatus,
+    pub BackendUserData: *mut ::core::ffi::c_void,
+    pub TexID: ImTextureID,
+    pub Format: ImTextureFormat,
+    pub Width: ::core::ffi::c_int,
+    pub Height: ::core::ffi::c_int,
+    pub BytesPerPixel: ::core::ffi::c_int,
+    pub Pixels: *mut ::core::ffi::c_uchar,
+    pub UsedRect: ImTextureRect,
+    pub UpdateRect: ImTextureRect,
+    pub Updates: ImVector_ImTextureRect,
+    pub UnusedFrames: ::core::ffi::c_int,
+    pub RefCount: ::core::ffi::c_ushort,
+    pub UseColors: bool,
+    pub WantDestroyNextFrame: bool,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImFontConfig_t {
+    pub Name: [::core::ffi::c_char; 40usize],
+    pub FontData: *mut ::core::ffi::c_void,
+    pub FontDataSize: ::core::ffi::c_int,
+    pub FontDataOwnedByAtlas: bool,
+    pub MergeMode: bool,
+    pub PixelSnapH: bool,
+    pub PixelSnapV: bool,
+    pub OversampleH: ImS8,
+    pub OversampleV: ImS8,
+    pub EllipsisChar: ImWchar,
+    pub SizePixels: f32,
+    pub GlyphRanges: *const ImWchar,
+    pub GlyphExcludeRanges: *const ImWchar,
+    pub GlyphOffset: ImVec2,
+    pub GlyphMinAdvanceX: f32,
+    pub GlyphMaxAdvanceX: f32,
+    pub GlyphExtraAdvanceX: f32,
+    pub FontNo: ImU32,
+    pub FontLoaderFlags: ::core::ffi::c_uint,
+    pub RasterizerMultiply: f32,
+    pub RasterizerDensity: f32,
+    pub Flags: ImFontFlags,
+    pub DstFont: *mut ImFont,
+    pub FontLoader: *const ImFontLoader,
+    pub FontLoaderData: *mut ::core::ffi::c_void,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImFontGlyph_t {
+    pub _bitfield_align_1: [u32; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+    pub AdvanceX: f32,
+    pub X0: f32,
+    pub Y0: f32,
+    pub X1: f32,
+    pub Y1: f32,
+    pub U0: f32,
+    pub V0: f32,
+    pub U1: f32,
+    pub V1: f32,
+    pub PackId: ::core::ffi::c_int,
+}
+impl ImFontGlyph_t {
+    #[inline]
+    pub fn Colored(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_Colored(&mut self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn Colored_raw(this: *const Self) -> ::core::ffi::c_uint {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_Colored_raw(this: *mut Self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn Visible(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_Visible(&mut self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn Visible_raw(this: *const Self) -> ::core::ffi::c_uint {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                1usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_Visible_raw(this: *mut Self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                1usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn SourceIdx(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 4u8) as u32) }
+    }
+    #[inline]
+    pub fn set_SourceIdx(&mut self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(2usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn SourceIdx_raw(this: *const Self) -> ::core::ffi::c_uint {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                2usize,
+                4u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_SourceIdx_raw(this: *mut Self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                2usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn Codepoint(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(6usize, 26u8) as u32) }
+    }
+    #[inline]
+    pub fn set_Codepoint(&mut self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(6usize, 26u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn Codepoint_raw(this: *const Self) -> ::core::ffi::c_uint {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                6usize,
+                26u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_Codepoint_raw(this: *mut Self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                6usize,
+                26u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        Colored: ::core::ffi::c_uint,
+        Visible: ::core::ffi::c_uint,
+        SourceIdx: ::core::ffi::c_uint,
+        Codepoint: ::core::ffi::c_uint,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let Colored: u32 = unsafe { ::core::mem::transmute(Colored) };
+            Colored as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let Visible: u32 = unsafe { ::core::mem::transmute(Visible) };
+            Visible as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 4u8, {
+            let SourceIdx: u32 = unsafe { ::core::mem::transmute(SourceIdx) };
+            SourceIdx as u64
+        });
+        __bindgen_bitfield_unit.set(6usize, 26u8, {
+            let Codepoint: u32 = unsafe { ::core::mem::transmute(Codepoint) };
+            Codepoint as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImFontGlyphRangesBuilder_t {
+    pub UsedChars: ImVector_ImU32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImFontAtlasRect_t {
+    pub x: ::core::ffi::c_ushort,
+    pub y: ::core::ffi::c_ushort,
+    pub w: ::core::ffi::c_ushort,
+    pub h: ::core::ffi::c_ushort,
+    pub uv0: ImVec2,
+    pub uv1: ImVec2,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImFontAtlas_t {
+    pub Flags: ImFontAtlasFlags,
+    pub TexDesiredFormat: ImTextureFormat,
+    pub TexGlyphPadding: ::core::ffi::c_int,
+    pub TexMinWidth: ::core::ffi::c_int,
+    pub TexMinHeight: ::core::ffi::c_int,
+    pub TexMaxWidth: ::core::ffi::c_int,
+    pub TexMaxHeight: ::core::ffi::c_int,
+    pub UserData: *mut ::core::ffi::c_void,
+    pub __bindgen_anon_1: ImFontAtlas_t__bindgen_ty_1,
+    pub TexData: *mut ImTextureData,
+    pub TexList: ImVector_ImTextureDataPtr,
+    pub Locked: bool,
+    pub RendererHasTextures: bool,
+    pub TexIsBuilt: bool,
+    pub TexPixelsUseColors: bool,
+    pub TexUvScale: ImVec2,
+    pub TexUvWhitePixel: ImVec2,
+    pub Fonts: ImVector_ImFontPtr,
+    pub Sources: ImVector_ImFontConfig,
+    pub TexUvLines: [ImVec4; 33usize],
+    pub TexNextUniqueID: ::core::ffi::c_int,
+    pub FontNextUniqueID: ::core::ffi::c_int,
+    pub DrawListSharedDatas: ImVector_ImDrawListSharedDataPtr,
+    pub Builder: *mut ImFontAtlasBuilder,
+    pub FontLoader: *const ImFontLoader,
+    pub FontLoaderName: *const ::core::ffi::c_char,
+    pub FontLoaderData: *mut ::core::ffi::c_void,
+    pub FontLoaderFlags: ::core::ffi::c_uint,
+    pub RefCount: ::core::ffi::c_int,
+    pub OwnerContext: *mut ImGuiContext,
+    pub TempRect: ImFontAtlasRect,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union ImFontAtlas_t__bindgen_ty_1 {
+    pub TexRef: ImTextureRef,
+    pub TexID: ImTextureRef,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImFontBaked_t {
+    pub IndexAdvanceX: ImVector_float,
+    pub FallbackAdvanceX: f32,
+    pub Size: f32,
+    pub RasterizerDensity: f32,
+    pub IndexLookup: ImVector_ImU16,
+    pub Glyphs: ImVector_ImFontGlyph,
+    pub FallbackGlyphIndex: ::core::ffi::c_int,
+    pub Ascent: f32,
+    pub Descent: f32,
+    pub _bitfield_align_1: [u32; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+    pub LastUsedFrame: ::core::ffi::c_int,
+    pub BakedId: ImGuiID,
+    pub OwnerFont: *mut ImFont,
+    pub FontLoaderDatas: *mut ::core::ffi::c_void,
+}
+impl ImFontBaked_t {
+    #[inline]
+    pub fn MetricsTotalSurface(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 26u8) as u32) }
+    }
+    #[inline]
+    pub fn set_MetricsTotalSurface(&mut self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 26u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn MetricsTotalSurface_raw(this: *const Self) -> ::core::ffi::c_uint {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                26u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_MetricsTotalSurface_raw(this: *mut Self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                26u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn WantDestroy(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(26usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_WantDestroy(&mut self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(26usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn WantDestroy_raw(this: *const Self) -> ::core::ffi::c_uint {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                26usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_WantDestroy_raw(this: *mut Self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                26usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn LoadNoFallback(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(27usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_LoadNoFallback(&mut self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(27usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn LoadNoFallback_raw(this: *const Self) -> ::core::ffi::c_uint {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                27usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_LoadNoFallback_raw(this: *mut Self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                27usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn LoadNoRenderOnLayout(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(28usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_LoadNoRenderOnLayout(&mut self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(28usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn LoadNoRenderOnLayout_raw(this: *const Self) -> ::core::ffi::c_uint {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                28usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_LoadNoRenderOnLayout_raw(this: *mut Self, val: ::core::ffi::c_uint) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                28usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        MetricsTotalSurface: ::core::ffi::c_uint,
+        WantDestroy: ::core::ffi::c_uint,
+        LoadNoFallback: ::core::ffi::c_uint,
+        LoadNoRenderOnLayout: ::core::ffi::c_uint,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 26u8, {
+            let MetricsTotalSurface: u32 = unsafe { ::core::mem::transmute(MetricsTotalSurface) };
+            MetricsTotalSurface as u64
+        });
+        __bindgen_bitfield_unit.set(26usize, 1u8, {
+            let WantDestroy: u32 = unsafe { ::core::mem::transmute(WantDestroy) };
+            WantDestroy as u64
+        });
+        __bindgen_bitfield_unit.set(27usize, 1u8, {
+            let LoadNoFallback: u32 = unsafe { ::core::mem::transmute(LoadNoFallback) };
+            LoadNoFallback as u64
+        });
+        __bindgen_bitfield_unit.set(28usize, 1u8, {
+            let LoadNoRenderOnLayout: u32 = unsafe { ::core::mem::transmute(LoadNoRenderOnLayout) };
+            LoadNoRenderOnLayout as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImFont_t {
+    pub LastBaked: *mut ImFontBaked,
+    pub OwnerAtlas: *mut ImFontAtlas,
+    pub Flags: ImFontFlags,
+    pub CurrentRasterizerDensity: f32,
+    pub FontId: ImGuiID,
+    pub LegacySize: f32,
+    pub Sources: ImVector_ImFontConfigPtr,
+    pub EllipsisChar: ImWchar,
+    pub FallbackChar: ImWchar,
+    pub Used8kPagesMap: [ImU8; 1usize],
+    pub EllipsisAutoBake: bool,
+    pub RemapPairs: ImGuiStorage,
+    pub Scale: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiViewport_t {
+    pub ID: ImGuiID,
+    pub Flags: ImGuiViewportFlags,
+    pub Pos: ImVec2,
+    pub Size: ImVec2,
+    pub FramebufferScale: ImVec2,
+    pub WorkPos: ImVec2,
+    pub WorkSize: ImVec2,
+    pub PlatformHandle: *mut ::core::ffi::c_void,
+    pub PlatformHandleRaw: *mut ::core::ffi::c_void,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiPlatformIO_t {
+    pub Platform_GetClipboardTextFn: ::core::option::Option<
+        unsafe extern "C" fn(ctx: *mut ImGuiContext) -> *const ::core::ffi::c_char,
+    >,
+    pub Platform_SetClipboardTextFn: ::core::option::Option<
+        unsafe extern "C" fn(ctx: *mut ImGuiContext, text: *const ::core::ffi::c_char),
+    >,
+    pub Platform_ClipboardUserData: *mut ::core::ffi::c_void,
+    pub Platform_OpenInShellFn: ::core::option::Option<
+        unsafe extern "C" fn(ctx: *mut ImGuiContext, path: *const ::core::ffi::c_char) -> bool,
+    >,
+    pub Platform_OpenInShellUserData: *mut ::core::ffi::c_void,
+    pub Platform_SetImeDataFn: ::core::option::Option<
+        unsafe extern "C" fn(
+            ctx: *mut ImGuiContext,
+            viewport: *mut ImGuiViewport,
+            data: *mut ImGuiPlatformImeData,
+        ),
+    >,
+    pub Platform_ImeUserData: *mut ::core::ffi::c_void,
+    pub Platform_LocaleDecimalPoint: ImWchar,
+    pub Renderer_TextureMaxWidth: ::core::ffi::c_int,
+    pub Renderer_TextureMaxHeight: ::core::ffi::c_int,
+    pub Renderer_RenderState: *mut ::core::ffi::c_void,
+    pub Textures: ImVector_ImTextureDataPtr,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiPlatformImeData_t {
+    pub WantVisible: bool,
+    pub WantTextInput: bool,
+    pub InputPos: ImVec2,
+    pub InputLineHeight: f32,
+    pub ViewportId: ImGuiID,
+}
+unsafe extern "C" {
+    pub fn ImTextureRef_GetTexID(self_: *const ImTextureRef) -> ImTextureID;
+}
+unsafe extern "C" {
+    pub fn igCreateContext(shared_font_atlas: *mut ImFontAtlas) -> *mut ImGuiContext;
+}
+unsafe extern "C" {
+    pub fn igDestroyContext(ctx: *mut ImGuiContext);
+}
+unsafe extern "C" {
+    pub fn igGetCurrentContext() -> *mut ImGuiContext;
+}
+unsafe extern "C" {
+    pub fn igSetCurrentContext(ctx: *mut ImGuiContext);
+}
+unsafe extern "C" {
+    pub fn igGetIO() -> *mut ImGuiIO;
+}
+unsafe extern "C" {
+    pub fn igGetPlatformIO() -> *mut ImGuiPlatformIO;
+}
+unsafe extern "C" {
+    pub fn igGetStyle() -> *mut ImGuiStyle;
+}
+unsafe extern "C" {
+    pub fn igNewFrame();
+}
+unsafe extern "C" {
+    pub fn igEndFrame();
+}
+unsafe extern "C" {
+    pub fn igRender();
+}
+unsafe extern "C" {
+    pub fn igGetDrawData() -> *mut ImDrawData;
+}
+unsafe extern "C" {
+    pub fn igShowDemoWindow(p_open: *mut bool);
+}
+unsafe extern "C" {
+    pub fn igShowMetricsWindow(p_open: *mut bool);
+}
+unsafe extern "C" {
+    pub fn igShowDebugLogWindow(p_open: *mut bool);
+}
+unsafe extern "C" {
+    pub fn igShowIDStackToolWindow();
+}
+unsafe extern "C" {
+    pub fn igShowIDStackToolWindowEx(p_open: *mut bool);
+}
+unsafe extern "C" {
+    pub fn igShowAboutWindow(p_open: *mut bool);
+}
+unsafe extern "C" {
+    pub fn igShowStyleEditor(ref_: *mut ImGuiStyle);
+}
+unsafe extern "C" {
+    pub fn igShowStyleSelector(label: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    pub fn igShowFontSelector(label: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igShowUserGuide();
+}
+unsafe extern "C" {
+    pub fn igGetVersion() -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn igStyleColorsDark(dst: *mut ImGuiStyle);
+}
+unsafe extern "C" {
+    pub fn igStyleColorsLight(dst: *mut ImGuiStyle);
+}
+unsafe extern "C" {
+    pub fn igStyleColorsClassic(dst: *mut ImGuiStyle);
+}
+unsafe extern "C" {
+    pub fn igBegin(
+        name: *const ::core::ffi::c_char,
+        p_open: *mut bool,
+        flags: ImGuiWindowFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEnd();
+}
+unsafe extern "C" {
+    pub fn igBeginChild(
+        str_id: *const ::core::ffi::c_char,
+        size: ImVec2,
+        child_flags: ImGuiChildFlags,
+        window_flags: ImGuiWindowFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginChildID(
+        id: ImGuiID,
+        size: ImVec2,
+        child_flags: ImGuiChildFlags,
+        window_flags: ImGuiWindowFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndChild();
+}
+unsafe extern "C" {
+    pub fn igIsWindowAppearing() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsWindowCollapsed() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsWindowFocused(flags: ImGuiFocusedFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsWindowHovered(flags: ImGuiHoveredFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igGetWindowDrawList() -> *mut ImDrawList;
+}
+unsafe extern "C" {
+    pub fn igGetWindowPos() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetWindowSize() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetWindowWidth() -> f32;
+}
+unsafe extern "C" {
+    pub fn igGetWindowHeight() -> f32;
+}
+unsafe extern "C" {
+    pub fn igSetNextWindowPos(pos: ImVec2, cond: ImGuiCond);
+}
+unsafe extern "C" {
+    pub fn igSetNextWindowPosEx(pos: ImVec2, cond: ImGuiCond, pivot: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igSetNextWindowSize(size: ImVec2, cond: ImGuiCond);
+}
+unsafe extern "C" {
+    pub fn igSetNextWindowSizeConstraints(
+        size_min: ImVec2,
+        size_max: ImVec2,
+        custom_callback: ImGuiSizeCallback,
+        custom_callback_data: *mut ::core::ffi::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn igSetNextWindowContentSize(size: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igSetNextWindowCollapsed(collapsed: bool, cond: ImGuiCond);
+}
+unsafe extern "C" {
+    pub fn igSetNextWindowFocus();
+}
+unsafe extern "C" {
+    pub fn igSetNextWindowScroll(scroll: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igSetNextWindowBgAlpha(alpha: f32);
+}
+unsafe extern "C" {
+    pub fn igSetWindowPos(pos: ImVec2, cond: ImGuiCond);
+}
+unsafe extern "C" {
+    pub fn igSetWindowSize(size: ImVec2, cond: ImGuiCond);
+}
+unsafe extern "C" {
+    pub fn igSetWindowCollapsed(collapsed: bool, cond: ImGuiCond);
+}
+unsafe extern "C" {
+    pub fn igSetWindowFocus();
+}
+unsafe extern "C" {
+    pub fn igSetWindowPosStr(name: *const ::core::ffi::c_char, pos: ImVec2, cond: ImGuiCond);
+}
+unsafe extern "C" {
+    pub fn igSetWindowSizeStr(name: *const ::core::ffi::c_char, size: ImVec2, cond: ImGuiCond);
+}
+unsafe extern "C" {
+    pub fn igSetWindowCollapsedStr(
+        name: *const ::core::ffi::c_char,
+        collapsed: bool,
+        cond: ImGuiCond,
+    );
+}
+unsafe extern "C" {
+    pub fn igSetWindowFocusStr(name: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igGetScrollX() -> f32;
+}
+unsafe extern "C" {
+    pub fn igGetScrollY() -> f32;
+}
+unsafe extern "C" {
+    pub fn igSetScrollX(scroll_x: f32);
+}
+unsafe extern "C" {
+    pub fn igSetScrollY(scroll_y: f32);
+}
+unsafe extern "C" {
+    pub fn igGetScrollMaxX() -> f32;
+}
+unsafe extern "C" {
+    pub fn igGetScrollMaxY() -> f32;
+}
+unsafe extern "C" {
+    pub fn igSetScrollHereX(center_x_ratio: f32);
+}
+unsafe extern "C" {
+    pub fn igSetScrollHereY(center_y_ratio: f32);
+}
+unsafe extern "C" {
+    pub fn igSetScrollFromPosX(local_x: f32, center_x_ratio: f32);
+}
+unsafe extern "C" {
+    pub fn igSetScrollFromPosY(local_y: f32, center_y_ratio: f32);
+}
+unsafe extern "C" {
+    pub fn igPushFontFloat(font: *mut ImFont, font_size_base_unscaled: f32);
+}
+unsafe extern "C" {
+    pub fn igPopFont();
+}
+unsafe extern "C" {
+    pub fn igGetFont() -> *mut ImFont;
+}
+unsafe extern "C" {
+    pub fn igGetFontSize() -> f32;
+}
+unsafe extern "C" {
+    pub fn igGetFontBaked() -> *mut ImFontBaked;
+}
+unsafe extern "C" {
+    pub fn igPushStyleColor(idx: ImGuiCol, col: ImU32);
+}
+unsafe extern "C" {
+    pub fn igPushStyleColorImVec4(idx: ImGuiCol, col: ImVec4);
+}
+unsafe extern "C" {
+    pub fn igPopStyleColor();
+}
+unsafe extern "C" {
+    pub fn igPopStyleColorEx(count: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn igPushStyleVar(idx: ImGuiStyleVar, val: f32);
+}
+unsafe extern "C" {
+    pub fn igPushStyleVarImVec2(idx: ImGuiStyleVar, val: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igPushStyleVarX(idx: ImGuiStyleVar, val_x: f32);
+}
+unsafe extern "C" {
+    pub fn igPushStyleVarY(idx: ImGuiStyleVar, val_y: f32);
+}
+unsafe extern "C" {
+    pub fn igPopStyleVar();
+}
+unsafe extern "C" {
+    pub fn igPopStyleVarEx(count: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn igPushItemFlag(option: ImGuiItemFlags, enabled: bool);
+}
+unsafe extern "C" {
+    pub fn igPopItemFlag();
+}
+unsafe extern "C" {
+    pub fn igPushItemWidth(item_width: f32);
+}
+unsafe extern "C" {
+    pub fn igPopItemWidth();
+}
+unsafe extern "C" {
+    pub fn igSetNextItemWidth(item_width: f32);
+}
+unsafe extern "C" {
+    pub fn igCalcItemWidth() -> f32;
+}
+unsafe extern "C" {
+    pub fn igPushTextWrapPos(wrap_local_pos_x: f32);
+}
+unsafe extern "C" {
+    pub fn igPopTextWrapPos();
+}
+unsafe extern "C" {
+    pub fn igGetFontTexUvWhitePixel() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetColorU32(idx: ImGuiCol) -> ImU32;
+}
+unsafe extern "C" {
+    pub fn igGetColorU32Ex(idx: ImGuiCol, alpha_mul: f32) -> ImU32;
+}
+unsafe extern "C" {
+    pub fn igGetColorU32ImVec4(col: ImVec4) -> ImU32;
+}
+unsafe extern "C" {
+    pub fn igGetColorU32ImU32(col: ImU32) -> ImU32;
+}
+unsafe extern "C" {
+    pub fn igGetColorU32ImU32Ex(col: ImU32, alpha_mul: f32) -> ImU32;
+}
+unsafe extern "C" {
+    pub fn igGetStyleColorVec4(idx: ImGuiCol) -> *const ImVec4;
+}
+unsafe extern "C" {
+    pub fn igGetCursorScreenPos() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igSetCursorScreenPos(pos: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igGetContentRegionAvail() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetCursorPos() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetCursorPosX() -> f32;
+}
+unsafe extern "C" {
+    pub fn igGetCursorPosY() -> f32;
+}
+unsafe extern "C" {
+    pub fn igSetCursorPos(local_pos: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igSetCursorPosX(local_x: f32);
+}
+unsafe extern "C" {
+    pub fn igSetCursorPosY(local_y: f32);
+}
+unsafe extern "C" {
+    pub fn igGetCursorStartPos() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igSeparator();
+}
+unsafe extern "C" {
+    pub fn igSameLine();
+}
+unsafe extern "C" {
+    pub fn igSameLineEx(offset_from_start_x: f32, spacing: f32);
+}
+unsafe extern "C" {
+    pub fn igNewLine();
+}
+unsafe extern "C" {
+    pub fn igSpacing();
+}
+unsafe extern "C" {
+    pub fn igDummy(size: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igIndent();
+}
+unsafe extern "C" {
+    pub fn igIndentEx(indent_w: f32);
+}
+unsafe extern "C" {
+    pub fn igUnindent();
+}
+unsafe extern "C" {
+    pub fn igUnindentEx(indent_w: f32);
+}
+unsafe extern "C" {
+    pub fn igBeginGroup();
+}
+unsafe extern "C" {
+    pub fn igEndGroup();
+}
+unsafe extern "C" {
+    pub fn igAlignTextToFramePadding();
+}
+unsafe extern "C" {
+    pub fn igGetTextLineHeight() -> f32;
+}
+unsafe extern "C" {
+    pub fn igGetTextLineHeightWithSpacing() -> f32;
+}
+unsafe extern "C" {
+    pub fn igGetFrameHeight() -> f32;
+}
+unsafe extern "C" {
+    pub fn igGetFrameHeightWithSpacing() -> f32;
+}
+unsafe extern "C" {
+    pub fn igPushID(str_id: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igPushIDStr(
+        str_id_begin: *const ::core::ffi::c_char,
+        str_id_end: *const ::core::ffi::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn igPushIDPtr(ptr_id: *const ::core::ffi::c_void);
+}
+unsafe extern "C" {
+    pub fn igPushIDInt(int_id: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn igPopID();
+}
+unsafe extern "C" {
+    pub fn igGetID(str_id: *const ::core::ffi::c_char) -> ImGuiID;
+}
+unsafe extern "C" {
+    pub fn igGetIDStr(
+        str_id_begin: *const ::core::ffi::c_char,
+        str_id_end: *const ::core::ffi::c_char,
+    ) -> ImGuiID;
+}
+unsafe extern "C" {
+    pub fn igGetIDPtr(ptr_id: *const ::core::ffi::c_void) -> ImGuiID;
+}
+unsafe extern "C" {
+    pub fn igGetIDInt(int_id: ::core::ffi::c_int) -> ImGuiID;
+}
+unsafe extern "C" {
+    pub fn igTextUnformatted(text: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igTextUnformattedEx(
+        text: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+    );
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igText(fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igTextV(fmt: *const ::core::ffi::c_char, args: *mut __va_list_tag);
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igTextColored(col: ImVec4, fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igTextColoredV(col: ImVec4, fmt: *const ::core::ffi::c_char, args: *mut __va_list_tag);
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igTextDisabled(fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igTextDisabledV(fmt: *const ::core::ffi::c_char, args: *mut __va_list_tag);
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igTextWrapped(fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igTextWrappedV(fmt: *const ::core::ffi::c_char, args: *mut __va_list_tag);
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igLabelText(label: *const ::core::ffi::c_char, fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igLabelTextV(
+        label: *const ::core::ffi::c_char,
+        fmt: *const ::core::ffi::c_char,
+        args: *mut __va_list_tag,
+    );
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igBulletText(fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igBulletTextV(fmt: *const ::core::ffi::c_char, args: *mut __va_list_tag);
+}
+unsafe extern "C" {
+    pub fn igSeparatorText(label: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igButton(label: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    pub fn igButtonEx(label: *const ::core::ffi::c_char, size: ImVec2) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSmallButton(label: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInvisibleButton(
+        str_id: *const ::core::ffi::c_char,
+        size: ImVec2,
+        flags: ImGuiButtonFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igArrowButton(str_id: *const ::core::ffi::c_char, dir: ImGuiDir) -> bool;
+}
+unsafe extern "C" {
+    pub fn igCheckbox(label: *const ::core::ffi::c_char, v: *mut bool) -> bool;
+}
+unsafe extern "C" {
+    pub fn igCheckboxFlagsIntPtr(
+        label: *const ::core::ffi::c_char,
+        flags: *mut ::core::ffi::c_int,
+        flags_value: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igCheckboxFlagsUintPtr(
+        label: *const ::core::ffi::c_char,
+        flags: *mut ::core::ffi::c_uint,
+        flags_value: ::core::ffi::c_uint,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igRadioButton(label: *const ::core::ffi::c_char, active: bool) -> bool;
+}
+unsafe extern "C" {
+    pub fn igRadioButtonIntPtr(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_button: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igProgressBar(fraction: f32, size_arg: ImVec2, overlay: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igBullet();
+}
+unsafe extern "C" {
+    pub fn igTextLink(label: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    pub fn igTextLinkOpenURL(label: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    pub fn igTextLinkOpenURLEx(
+        label: *const ::core::ffi::c_char,
+        url: *const ::core::ffi::c_char,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igImage(tex_ref: ImTextureRef, image_size: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igImageEx(tex_ref: ImTextureRef, image_size: ImVec2, uv0: ImVec2, uv1: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igImageWithBg(tex_ref: ImTextureRef, image_size: ImVec2);
+}
+unsafe extern "C" {
+    pub fn igImageWithBgEx(
+        tex_ref: ImTextureRef,
+        image_size: ImVec2,
+        uv0: ImVec2,
+        uv1: ImVec2,
+        bg_col: ImVec4,
+        tint_col: ImVec4,
+    );
+}
+unsafe extern "C" {
+    pub fn igImageButton(
+        str_id: *const ::core::ffi::c_char,
+        tex_ref: ImTextureRef,
+        image_size: ImVec2,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igImageButtonEx(
+        str_id: *const ::core::ffi::c_char,
+        tex_ref: ImTextureRef,
+        image_size: ImVec2,
+        uv0: ImVec2,
+        uv1: ImVec2,
+        bg_col: ImVec4,
+        tint_col: ImVec4,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginCombo(
+        label: *const ::core::ffi::c_char,
+        preview_value: *const ::core::ffi::c_char,
+        flags: ImGuiComboFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndCombo();
+}
+unsafe extern "C" {
+    pub fn igComboChar(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        items: *const *const ::core::ffi::c_char,
+        items_count: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igComboCharEx(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        items: *const *const ::core::ffi::c_char,
+        items_count: ::core::ffi::c_int,
+        popup_max_height_in_items: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igCombo(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        items_separated_by_zeros: *const ::core::ffi::c_char,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igComboEx(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        items_separated_by_zeros: *const ::core::ffi::c_char,
+        popup_max_height_in_items: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igComboCallback(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        getter: ::core::option::Option<
+            unsafe extern "C" fn(
+                user_data: *mut ::core::ffi::c_void,
+                idx: ::core::ffi::c_int,
+            ) -> *const ::core::ffi::c_char,
+        >,
+        user_data: *mut ::core::ffi::c_void,
+        items_count: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igComboCallbackEx(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        getter: ::core::option::Option<
+            unsafe extern "C" fn(
+                user_data: *mut ::core::ffi::c_void,
+                idx: ::core::ffi::c_int,
+            ) -> *const ::core::ffi::c_char,
+        >,
+        user_data: *mut ::core::ffi::c_void,
+        items_count: ::core::ffi::c_int,
+        popup_max_height_in_items: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloat(label: *const ::core::ffi::c_char, v: *mut f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloatEx(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_speed: f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloat2(label: *const ::core::ffi::c_char, v: *mut f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloat2Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_speed: f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloat3(label: *const ::core::ffi::c_char, v: *mut f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloat3Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_speed: f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloat4(label: *const ::core::ffi::c_char, v: *mut f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloat4Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_speed: f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloatRange2(
+        label: *const ::core::ffi::c_char,
+        v_current_min: *mut f32,
+        v_current_max: *mut f32,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragFloatRange2Ex(
+        label: *const ::core::ffi::c_char,
+        v_current_min: *mut f32,
+        v_current_max: *mut f32,
+        v_speed: f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        format_max: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragInt(label: *const ::core::ffi::c_char, v: *mut ::core::ffi::c_int) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragIntEx(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_speed: f32,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragInt2(label: *const ::core::ffi::c_char, v: *mut ::core::ffi::c_int) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragInt2Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_speed: f32,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragInt3(label: *const ::core::ffi::c_char, v: *mut ::core::ffi::c_int) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragInt3Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_speed: f32,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragInt4(label: *const ::core::ffi::c_char, v: *mut ::core::ffi::c_int) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragInt4Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_speed: f32,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragIntRange2(
+        label: *const ::core::ffi::c_char,
+        v_current_min: *mut ::core::ffi::c_int,
+        v_current_max: *mut ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragIntRange2Ex(
+        label: *const ::core::ffi::c_char,
+        v_current_min: *mut ::core::ffi::c_int,
+        v_current_max: *mut ::core::ffi::c_int,
+        v_speed: f32,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        format_max: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragScalar(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragScalarEx(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        v_speed: f32,
+        p_min: *const ::core::ffi::c_void,
+        p_max: *const ::core::ffi::c_void,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragScalarN(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        components: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igDragScalarNEx(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        components: ::core::ffi::c_int,
+        v_speed: f32,
+        p_min: *const ::core::ffi::c_void,
+        p_max: *const ::core::ffi::c_void,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderFloat(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderFloatEx(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderFloat2(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderFloat2Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderFloat3(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderFloat3Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderFloat4(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderFloat4Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderAngle(label: *const ::core::ffi::c_char, v_rad: *mut f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderAngleEx(
+        label: *const ::core::ffi::c_char,
+        v_rad: *mut f32,
+        v_degrees_min: f32,
+        v_degrees_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderInt(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderIntEx(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderInt2(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderInt2Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderInt3(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderInt3Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderInt4(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderInt4Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderScalar(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        p_min: *const ::core::ffi::c_void,
+        p_max: *const ::core::ffi::c_void,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderScalarEx(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        p_min: *const ::core::ffi::c_void,
+        p_max: *const ::core::ffi::c_void,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderScalarN(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        components: ::core::ffi::c_int,
+        p_min: *const ::core::ffi::c_void,
+        p_max: *const ::core::ffi::c_void,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSliderScalarNEx(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        components: ::core::ffi::c_int,
+        p_min: *const ::core::ffi::c_void,
+        p_max: *const ::core::ffi::c_void,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igVSliderFloat(
+        label: *const ::core::ffi::c_char,
+        size: ImVec2,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igVSliderFloatEx(
+        label: *const ::core::ffi::c_char,
+        size: ImVec2,
+        v: *mut f32,
+        v_min: f32,
+        v_max: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igVSliderInt(
+        label: *const ::core::ffi::c_char,
+        size: ImVec2,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igVSliderIntEx(
+        label: *const ::core::ffi::c_char,
+        size: ImVec2,
+        v: *mut ::core::ffi::c_int,
+        v_min: ::core::ffi::c_int,
+        v_max: ::core::ffi::c_int,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igVSliderScalar(
+        label: *const ::core::ffi::c_char,
+        size: ImVec2,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        p_min: *const ::core::ffi::c_void,
+        p_max: *const ::core::ffi::c_void,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igVSliderScalarEx(
+        label: *const ::core::ffi::c_char,
+        size: ImVec2,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        p_min: *const ::core::ffi::c_void,
+        p_max: *const ::core::ffi::c_void,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiSliderFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputText(
+        label: *const ::core::ffi::c_char,
+        buf: *mut ::core::ffi::c_char,
+        buf_size: usize,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputTextEx(
+        label: *const ::core::ffi::c_char,
+        buf: *mut ::core::ffi::c_char,
+        buf_size: usize,
+        flags: ImGuiInputTextFlags,
+        callback: ImGuiInputTextCallback,
+        user_data: *mut ::core::ffi::c_void,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputTextMultiline(
+        label: *const ::core::ffi::c_char,
+        buf: *mut ::core::ffi::c_char,
+        buf_size: usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputTextMultilineEx(
+        label: *const ::core::ffi::c_char,
+        buf: *mut ::core::ffi::c_char,
+        buf_size: usize,
+        size: ImVec2,
+        flags: ImGuiInputTextFlags,
+        callback: ImGuiInputTextCallback,
+        user_data: *mut ::core::ffi::c_void,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputTextWithHint(
+        label: *const ::core::ffi::c_char,
+        hint: *const ::core::ffi::c_char,
+        buf: *mut ::core::ffi::c_char,
+        buf_size: usize,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputTextWithHintEx(
+        label: *const ::core::ffi::c_char,
+        hint: *const ::core::ffi::c_char,
+        buf: *mut ::core::ffi::c_char,
+        buf_size: usize,
+        flags: ImGuiInputTextFlags,
+        callback: ImGuiInputTextCallback,
+        user_data: *mut ::core::ffi::c_void,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputFloat(label: *const ::core::ffi::c_char, v: *mut f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputFloatEx(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        step: f32,
+        step_fast: f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputFloat2(label: *const ::core::ffi::c_char, v: *mut f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputFloat2Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputFloat3(label: *const ::core::ffi::c_char, v: *mut f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputFloat3Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputFloat4(label: *const ::core::ffi::c_char, v: *mut f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputFloat4Ex(
+        label: *const ::core::ffi::c_char,
+        v: *mut f32,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputInt(label: *const ::core::ffi::c_char, v: *mut ::core::ffi::c_int) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputIntEx(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        step: ::core::ffi::c_int,
+        step_fast: ::core::ffi::c_int,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputInt2(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputInt3(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputInt4(
+        label: *const ::core::ffi::c_char,
+        v: *mut ::core::ffi::c_int,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputDouble(label: *const ::core::ffi::c_char, v: *mut f64) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputDoubleEx(
+        label: *const ::core::ffi::c_char,
+        v: *mut f64,
+        step: f64,
+        step_fast: f64,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputScalar(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputScalarEx(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        p_step: *const ::core::ffi::c_void,
+        p_step_fast: *const ::core::ffi::c_void,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputScalarN(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        components: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igInputScalarNEx(
+        label: *const ::core::ffi::c_char,
+        data_type: ImGuiDataType,
+        p_data: *mut ::core::ffi::c_void,
+        components: ::core::ffi::c_int,
+        p_step: *const ::core::ffi::c_void,
+        p_step_fast: *const ::core::ffi::c_void,
+        format: *const ::core::ffi::c_char,
+        flags: ImGuiInputTextFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igColorEdit3(
+        label: *const ::core::ffi::c_char,
+        col: *mut f32,
+        flags: ImGuiColorEditFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igColorEdit4(
+        label: *const ::core::ffi::c_char,
+        col: *mut f32,
+        flags: ImGuiColorEditFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igColorPicker3(
+        label: *const ::core::ffi::c_char,
+        col: *mut f32,
+        flags: ImGuiColorEditFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igColorPicker4(
+        label: *const ::core::ffi::c_char,
+        col: *mut f32,
+        flags: ImGuiColorEditFlags,
+        ref_col: *const f32,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igColorButton(
+        desc_id: *const ::core::ffi::c_char,
+        col: ImVec4,
+        flags: ImGuiColorEditFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igColorButtonEx(
+        desc_id: *const ::core::ffi::c_char,
+        col: ImVec4,
+        flags: ImGuiColorEditFlags,
+        size: ImVec2,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSetColorEditOptions(flags: ImGuiColorEditFlags);
+}
+unsafe extern "C" {
+    pub fn igTreeNode(label: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igTreeNodeStr(
+        str_id: *const ::core::ffi::c_char,
+        fmt: *const ::core::ffi::c_char,
+        ...
+    ) -> bool;
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igTreeNodePtr(
+        ptr_id: *const ::core::ffi::c_void,
+        fmt: *const ::core::ffi::c_char,
+        ...
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igTreeNodeV(
+        str_id: *const ::core::ffi::c_char,
+        fmt: *const ::core::ffi::c_char,
+        args: *mut __va_list_tag,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igTreeNodeVPtr(
+        ptr_id: *const ::core::ffi::c_void,
+        fmt: *const ::core::ffi::c_char,
+        args: *mut __va_list_tag,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igTreeNodeEx(label: *const ::core::ffi::c_char, flags: ImGuiTreeNodeFlags) -> bool;
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igTreeNodeExStr(
+        str_id: *const ::core::ffi::c_char,
+        flags: ImGuiTreeNodeFlags,
+        fmt: *const ::core::ffi::c_char,
+        ...
+    ) -> bool;
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igTreeNodeExPtr(
+        ptr_id: *const ::core::ffi::c_void,
+        flags: ImGuiTreeNodeFlags,
+        fmt: *const ::core::ffi::c_char,
+        ...
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igTreeNodeExV(
+        str_id: *const ::core::ffi::c_char,
+        flags: ImGuiTreeNodeFlags,
+        fmt: *const ::core::ffi::c_char,
+        args: *mut __va_list_tag,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igTreeNodeExVPtr(
+        ptr_id: *const ::core::ffi::c_void,
+        flags: ImGuiTreeNodeFlags,
+        fmt: *const ::core::ffi::c_char,
+        args: *mut __va_list_tag,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igTreePush(str_id: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igTreePushPtr(ptr_id: *const ::core::ffi::c_void);
+}
+unsafe extern "C" {
+    pub fn igTreePop();
+}
+unsafe extern "C" {
+    pub fn igGetTreeNodeToLabelSpacing() -> f32;
+}
+unsafe extern "C" {
+    pub fn igCollapsingHeader(label: *const ::core::ffi::c_char, flags: ImGuiTreeNodeFlags)
+        -> bool;
+}
+unsafe extern "C" {
+    pub fn igCollapsingHeaderBoolPtr(
+        label: *const ::core::ffi::c_char,
+        p_visible: *mut bool,
+        flags: ImGuiTreeNodeFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSetNextItemOpen(is_open: bool, cond: ImGuiCond);
+}
+unsafe extern "C" {
+    pub fn igSetNextItemStorageID(storage_id: ImGuiID);
+}
+unsafe extern "C" {
+    pub fn igSelectable(label: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSelectableEx(
+        label: *const ::core::ffi::c_char,
+        selected: bool,
+        flags: ImGuiSelectableFlags,
+        size: ImVec2,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSelectableBoolPtr(
+        label: *const ::core::ffi::c_char,
+        p_selected: *mut bool,
+        flags: ImGuiSelectableFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSelectableBoolPtrEx(
+        label: *const ::core::ffi::c_char,
+        p_selected: *mut bool,
+        flags: ImGuiSelectableFlags,
+        size: ImVec2,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginMultiSelect(flags: ImGuiMultiSelectFlags) -> *mut ImGuiMultiSelectIO;
+}
+unsafe extern "C" {
+    pub fn igBeginMultiSelectEx(
+        flags: ImGuiMultiSelectFlags,
+        selection_size: ::core::ffi::c_int,
+        items_count: ::core::ffi::c_int,
+    ) -> *mut ImGuiMultiSelectIO;
+}
+unsafe extern "C" {
+    pub fn igEndMultiSelect() -> *mut ImGuiMultiSelectIO;
+}
+unsafe extern "C" {
+    pub fn igSetNextItemSelectionUserData(selection_user_data: ImGuiSelectionUserData);
+}
+unsafe extern "C" {
+    pub fn igIsItemToggledSelection() -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginListBox(label: *const ::core::ffi::c_char, size: ImVec2) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndListBox();
+}
+unsafe extern "C" {
+    pub fn igListBox(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        items: *const *const ::core::ffi::c_char,
+        items_count: ::core::ffi::c_int,
+        height_in_items: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igListBoxCallback(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        getter: ::core::option::Option<
+            unsafe extern "C" fn(
+                user_data: *mut ::core::ffi::c_void,
+                idx: ::core::ffi::c_int,
+            ) -> *const ::core::ffi::c_char,
+        >,
+        user_data: *mut ::core::ffi::c_void,
+        items_count: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igListBoxCallbackEx(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        getter: ::core::option::Option<
+            unsafe extern "C" fn(
+                user_data: *mut ::core::ffi::c_void,
+                idx: ::core::ffi::c_int,
+            ) -> *const ::core::ffi::c_char,
+        >,
+        user_data: *mut ::core::ffi::c_void,
+        items_count: ::core::ffi::c_int,
+        height_in_items: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igPlotLines(
+        label: *const ::core::ffi::c_char,
+        values: *const f32,
+        values_count: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn igPlotLinesEx(
+        label: *const ::core::ffi::c_char,
+        values: *const f32,
+        values_count: ::core::ffi::c_int,
+        values_offset: ::core::ffi::c_int,
+        overlay_text: *const ::core::ffi::c_char,
+        scale_min: f32,
+        scale_max: f32,
+        graph_size: ImVec2,
+        stride: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn igPlotLinesCallback(
+        label: *const ::core::ffi::c_char,
+        values_getter: ::core::option::Option<
+            unsafe extern "C" fn(data: *mut ::core::ffi::c_void, idx: ::core::ffi::c_int) -> f32,
+        >,
+        data: *mut ::core::ffi::c_void,
+        values_count: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn igPlotLinesCallbackEx(
+        label: *const ::core::ffi::c_char,
+        values_getter: ::core::option::Option<
+            unsafe extern "C" fn(data: *mut ::core::ffi::c_void, idx: ::core::ffi::c_int) -> f32,
+        >,
+        data: *mut ::core::ffi::c_void,
+        values_count: ::core::ffi::c_int,
+        values_offset: ::core::ffi::c_int,
+        overlay_text: *const ::core::ffi::c_char,
+        scale_min: f32,
+        scale_max: f32,
+        graph_size: ImVec2,
+    );
+}
+unsafe extern "C" {
+    pub fn igPlotHistogram(
+        label: *const ::core::ffi::c_char,
+        values: *const f32,
+        values_count: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn igPlotHistogramEx(
+        label: *const ::core::ffi::c_char,
+        values: *const f32,
+        values_count: ::core::ffi::c_int,
+        values_offset: ::core::ffi::c_int,
+        overlay_text: *const ::core::ffi::c_char,
+        scale_min: f32,
+        scale_max: f32,
+        graph_size: ImVec2,
+        stride: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn igPlotHistogramCallback(
+        label: *const ::core::ffi::c_char,
+        values_getter: ::core::option::Option<
+            unsafe extern "C" fn(data: *mut ::core::ffi::c_void, idx: ::core::ffi::c_int) -> f32,
+        >,
+        data: *mut ::core::ffi::c_void,
+        values_count: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn igPlotHistogramCallbackEx(
+        label: *const ::core::ffi::c_char,
+        values_getter: ::core::option::Option<
+            unsafe extern "C" fn(data: *mut ::core::ffi::c_void, idx: ::core::ffi::c_int) -> f32,
+        >,
+        data: *mut ::core::ffi::c_void,
+        values_count: ::core::ffi::c_int,
+        values_offset: ::core::ffi::c_int,
+        overlay_text: *const ::core::ffi::c_char,
+        scale_min: f32,
+        scale_max: f32,
+        graph_size: ImVec2,
+    );
+}
+unsafe extern "C" {
+    pub fn igBeginMenuBar() -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndMenuBar();
+}
+unsafe extern "C" {
+    pub fn igBeginMainMenuBar() -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndMainMenuBar();
+}
+unsafe extern "C" {
+    pub fn igBeginMenu(label: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginMenuEx(label: *const ::core::ffi::c_char, enabled: bool) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndMenu();
+}
+unsafe extern "C" {
+    pub fn igMenuItem(label: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    pub fn igMenuItemEx(
+        label: *const ::core::ffi::c_char,
+        shortcut: *const ::core::ffi::c_char,
+        selected: bool,
+        enabled: bool,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igMenuItemBoolPtr(
+        label: *const ::core::ffi::c_char,
+        shortcut: *const ::core::ffi::c_char,
+        p_selected: *mut bool,
+        enabled: bool,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginTooltip() -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndTooltip();
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igSetTooltip(fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igSetTooltipV(fmt: *const ::core::ffi::c_char, args: *mut __va_list_tag);
+}
+unsafe extern "C" {
+    pub fn igBeginItemTooltip() -> bool;
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igSetItemTooltip(fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igSetItemTooltipV(fmt: *const ::core::ffi::c_char, args: *mut __va_list_tag);
+}
+unsafe extern "C" {
+    pub fn igBeginPopup(str_id: *const ::core::ffi::c_char, flags: ImGuiWindowFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginPopupModal(
+        name: *const ::core::ffi::c_char,
+        p_open: *mut bool,
+        flags: ImGuiWindowFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndPopup();
+}
+unsafe extern "C" {
+    pub fn igOpenPopup(str_id: *const ::core::ffi::c_char, popup_flags: ImGuiPopupFlags);
+}
+unsafe extern "C" {
+    pub fn igOpenPopupID(id: ImGuiID, popup_flags: ImGuiPopupFlags);
+}
+unsafe extern "C" {
+    pub fn igOpenPopupOnItemClick(str_id: *const ::core::ffi::c_char, popup_flags: ImGuiPopupFlags);
+}
+unsafe extern "C" {
+    pub fn igCloseCurrentPopup();
+}
+unsafe extern "C" {
+    pub fn igBeginPopupContextItem() -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginPopupContextItemEx(
+        str_id: *const ::core::ffi::c_char,
+        popup_flags: ImGuiPopupFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginPopupContextWindow() -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginPopupContextWindowEx(
+        str_id: *const ::core::ffi::c_char,
+        popup_flags: ImGuiPopupFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginPopupContextVoid() -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginPopupContextVoidEx(
+        str_id: *const ::core::ffi::c_char,
+        popup_flags: ImGuiPopupFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsPopupOpen(str_id: *const ::core::ffi::c_char, flags: ImGuiPopupFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginTable(
+        str_id: *const ::core::ffi::c_char,
+        columns: ::core::ffi::c_int,
+        flags: ImGuiTableFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginTableEx(
+        str_id: *const ::core::ffi::c_char,
+        columns: ::core::ffi::c_int,
+        flags: ImGuiTableFlags,
+        outer_size: ImVec2,
+        inner_width: f32,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndTable();
+}
+unsafe extern "C" {
+    pub fn igTableNextRow();
+}
+unsafe extern "C" {
+    pub fn igTableNextRowEx(row_flags: ImGuiTableRowFlags, min_row_height: f32);
+}
+unsafe extern "C" {
+    pub fn igTableNextColumn() -> bool;
+}
+unsafe extern "C" {
+    pub fn igTableSetColumnIndex(column_n: ::core::ffi::c_int) -> bool;
+}
+unsafe extern "C" {
+    pub fn igTableSetupColumn(label: *const ::core::ffi::c_char, flags: ImGuiTableColumnFlags);
+}
+unsafe extern "C" {
+    pub fn igTableSetupColumnEx(
+        label: *const ::core::ffi::c_char,
+        flags: ImGuiTableColumnFlags,
+        init_width_or_weight: f32,
+        user_id: ImGuiID,
+    );
+}
+unsafe extern "C" {
+    pub fn igTableSetupScrollFreeze(cols: ::core::ffi::c_int, rows: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn igTableHeader(label: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igTableHeadersRow();
+}
+unsafe extern "C" {
+    pub fn igTableAngledHeadersRow();
+}
+unsafe extern "C" {
+    pub fn igTableGetSortSpecs() -> *mut ImGuiTableSortSpecs;
+}
+unsafe extern "C" {
+    pub fn igTableGetColumnCount() -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn igTableGetColumnIndex() -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn igTableGetRowIndex() -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn igTableGetColumnName(column_n: ::core::ffi::c_int) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn igTableGetColumnFlags(column_n: ::core::ffi::c_int) -> ImGuiTableColumnFlags;
+}
+unsafe extern "C" {
+    pub fn igTableSetColumnEnabled(column_n: ::core::ffi::c_int, v: bool);
+}
+unsafe extern "C" {
+    pub fn igTableGetHoveredColumn() -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn igTableSetBgColor(
+        target: ImGuiTableBgTarget,
+        color: ImU32,
+        column_n: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn igColumns();
+}
+unsafe extern "C" {
+    pub fn igColumnsEx(count: ::core::ffi::c_int, id: *const ::core::ffi::c_char, borders: bool);
+}
+unsafe extern "C" {
+    pub fn igNextColumn();
+}
+unsafe extern "C" {
+    pub fn igGetColumnIndex() -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn igGetColumnWidth(column_index: ::core::ffi::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn igSetColumnWidth(column_index: ::core::ffi::c_int, width: f32);
+}
+unsafe extern "C" {
+    pub fn igGetColumnOffset(column_index: ::core::ffi::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn igSetColumnOffset(column_index: ::core::ffi::c_int, offset_x: f32);
+}
+unsafe extern "C" {
+    pub fn igGetColumnsCount() -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn igBeginTabBar(str_id: *const ::core::ffi::c_char, flags: ImGuiTabBarFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndTabBar();
+}
+unsafe extern "C" {
+    pub fn igBeginTabItem(
+        label: *const ::core::ffi::c_char,
+        p_open: *mut bool,
+        flags: ImGuiTabItemFlags,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndTabItem();
+}
+unsafe extern "C" {
+    pub fn igTabItemButton(label: *const ::core::ffi::c_char, flags: ImGuiTabItemFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSetTabItemClosed(tab_or_docked_window_label: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igLogToTTY(auto_open_depth: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn igLogToFile(auto_open_depth: ::core::ffi::c_int, filename: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igLogToClipboard(auto_open_depth: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn igLogFinish();
+}
+unsafe extern "C" {
+    pub fn igLogButtons();
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igLogText(fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igLogTextV(fmt: *const ::core::ffi::c_char, args: *mut __va_list_tag);
+}
+unsafe extern "C" {
+    pub fn igBeginDragDropSource(flags: ImGuiDragDropFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSetDragDropPayload(
+        type_: *const ::core::ffi::c_char,
+        data: *const ::core::ffi::c_void,
+        sz: usize,
+        cond: ImGuiCond,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndDragDropSource();
+}
+unsafe extern "C" {
+    pub fn igBeginDragDropTarget() -> bool;
+}
+unsafe extern "C" {
+    pub fn igAcceptDragDropPayload(
+        type_: *const ::core::ffi::c_char,
+        flags: ImGuiDragDropFlags,
+    ) -> *const ImGuiPayload;
+}
+unsafe extern "C" {
+    pub fn igEndDragDropTarget();
+}
+unsafe extern "C" {
+    pub fn igGetDragDropPayload() -> *const ImGuiPayload;
+}
+unsafe extern "C" {
+    pub fn igBeginDisabled(disabled: bool);
+}
+unsafe extern "C" {
+    pub fn igEndDisabled();
+}
+unsafe extern "C" {
+    pub fn igPushClipRect(
+        clip_rect_min: ImVec2,
+        clip_rect_max: ImVec2,
+        intersect_with_current_clip_rect: bool,
+    );
+}
+unsafe extern "C" {
+    pub fn igPopClipRect();
+}
+unsafe extern "C" {
+    pub fn igSetItemDefaultFocus();
+}
+unsafe extern "C" {
+    pub fn igSetKeyboardFocusHere();
+}
+unsafe extern "C" {
+    pub fn igSetKeyboardFocusHereEx(offset: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn igSetNavCursorVisible(visible: bool);
+}
+unsafe extern "C" {
+    pub fn igSetNextItemAllowOverlap();
+}
+unsafe extern "C" {
+    pub fn igIsItemHovered(flags: ImGuiHoveredFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemActive() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemFocused() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemClicked() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemClickedEx(mouse_button: ImGuiMouseButton) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemVisible() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemEdited() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemActivated() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemDeactivated() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemDeactivatedAfterEdit() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsItemToggledOpen() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsAnyItemHovered() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsAnyItemActive() -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsAnyItemFocused() -> bool;
+}
+unsafe extern "C" {
+    pub fn igGetItemID() -> ImGuiID;
+}
+unsafe extern "C" {
+    pub fn igGetItemRectMin() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetItemRectMax() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetItemRectSize() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetMainViewport() -> *mut ImGuiViewport;
+}
+unsafe extern "C" {
+    pub fn igGetBackgroundDrawList() -> *mut ImDrawList;
+}
+unsafe extern "C" {
+    pub fn igGetForegroundDrawList() -> *mut ImDrawList;
+}
+unsafe extern "C" {
+    pub fn igIsRectVisibleBySize(size: ImVec2) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsRectVisible(rect_min: ImVec2, rect_max: ImVec2) -> bool;
+}
+unsafe extern "C" {
+    pub fn igGetTime() -> f64;
+}
+unsafe extern "C" {
+    pub fn igGetFrameCount() -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn igGetDrawListSharedData() -> *mut ImDrawListSharedData;
+}
+unsafe extern "C" {
+    pub fn igGetStyleColorName(idx: ImGuiCol) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn igSetStateStorage(storage: *mut ImGuiStorage);
+}
+unsafe extern "C" {
+    pub fn igGetStateStorage() -> *mut ImGuiStorage;
+}
+unsafe extern "C" {
+    pub fn igCalcTextSize(text: *const ::core::ffi::c_char) -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igCalcTextSizeEx(
+        text: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+        hide_text_after_double_hash: bool,
+        wrap_width: f32,
+    ) -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igColorConvertU32ToFloat4(in_: ImU32) -> ImVec4;
+}
+unsafe extern "C" {
+    pub fn igColorConvertFloat4ToU32(in_: ImVec4) -> ImU32;
+}
+unsafe extern "C" {
+    pub fn igColorConvertRGBtoHSV(
+        r: f32,
+        g: f32,
+        b: f32,
+        out_h: *mut f32,
+        out_s: *mut f32,
+        out_v: *mut f32,
+    );
+}
+unsafe extern "C" {
+    pub fn igColorConvertHSVtoRGB(
+        h: f32,
+        s: f32,
+        v: f32,
+        out_r: *mut f32,
+        out_g: *mut f32,
+        out_b: *mut f32,
+    );
+}
+unsafe extern "C" {
+    pub fn igIsKeyDown(key: ImGuiKey) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsKeyPressed(key: ImGuiKey) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsKeyPressedEx(key: ImGuiKey, repeat: bool) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsKeyReleased(key: ImGuiKey) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsKeyChordPressed(key_chord: ImGuiKeyChord) -> bool;
+}
+unsafe extern "C" {
+    pub fn igGetKeyPressedAmount(key: ImGuiKey, repeat_delay: f32, rate: f32)
+        -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn igGetKeyName(key: ImGuiKey) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn igSetNextFrameWantCaptureKeyboard(want_capture_keyboard: bool);
+}
+unsafe extern "C" {
+    pub fn igShortcut(key_chord: ImGuiKeyChord, flags: ImGuiInputFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igSetNextItemShortcut(key_chord: ImGuiKeyChord, flags: ImGuiInputFlags);
+}
+unsafe extern "C" {
+    pub fn igSetItemKeyOwner(key: ImGuiKey);
+}
+unsafe extern "C" {
+    pub fn igIsMouseDown(button: ImGuiMouseButton) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsMouseClicked(button: ImGuiMouseButton) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsMouseClickedEx(button: ImGuiMouseButton, repeat: bool) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsMouseReleased(button: ImGuiMouseButton) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsMouseDoubleClicked(button: ImGuiMouseButton) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsMouseReleasedWithDelay(button: ImGuiMouseButton, delay: f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igGetMouseClickedCount(button: ImGuiMouseButton) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn igIsMouseHoveringRect(r_min: ImVec2, r_max: ImVec2) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsMouseHoveringRectEx(r_min: ImVec2, r_max: ImVec2, clip: bool) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsMousePosValid(mouse_pos: *const ImVec2) -> bool;
+}
+unsafe extern "C" {
+    pub fn igIsAnyMouseDown() -> bool;
+}
+unsafe extern "C" {
+    pub fn igGetMousePos() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetMousePosOnOpeningCurrentPopup() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igIsMouseDragging(button: ImGuiMouseButton, lock_threshold: f32) -> bool;
+}
+unsafe extern "C" {
+    pub fn igGetMouseDragDelta(button: ImGuiMouseButton, lock_threshold: f32) -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igResetMouseDragDelta();
+}
+unsafe extern "C" {
+    pub fn igResetMouseDragDeltaEx(button: ImGuiMouseButton);
+}
+unsafe extern "C" {
+    pub fn igGetMouseCursor() -> ImGuiMouseCursor;
+}
+unsafe extern "C" {
+    pub fn igSetMouseCursor(cursor_type: ImGuiMouseCursor);
+}
+unsafe extern "C" {
+    pub fn igSetNextFrameWantCaptureMouse(want_capture_mouse: bool);
+}
+unsafe extern "C" {
+    pub fn igGetClipboardText() -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn igSetClipboardText(text: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igLoadIniSettingsFromDisk(ini_filename: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igLoadIniSettingsFromMemory(ini_data: *const ::core::ffi::c_char, ini_size: usize);
+}
+unsafe extern "C" {
+    pub fn igSaveIniSettingsToDisk(ini_filename: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igSaveIniSettingsToMemory(out_ini_size: *mut usize) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn igDebugTextEncoding(text: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn igDebugFlashStyleColor(idx: ImGuiCol);
+}
+unsafe extern "C" {
+    pub fn igDebugStartItemPicker();
+}
+unsafe extern "C" {
+    pub fn igDebugCheckVersionAndDataLayout(
+        version_str: *const ::core::ffi::c_char,
+        sz_io: usize,
+        sz_style: usize,
+        sz_vec2: usize,
+        sz_vec4: usize,
+        sz_drawvert: usize,
+        sz_drawidx: usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn igDebugLog(fmt: *const ::core::ffi::c_char, ...);
+}
+unsafe extern "C" {
+    pub fn igDebugLogV(fmt: *const ::core::ffi::c_char, args: *mut __va_list_tag);
+}
+unsafe extern "C" {
+    pub fn igSetAllocatorFunctions(
+        alloc_func: ImGuiMemAllocFunc,
+        free_func: ImGuiMemFreeFunc,
+        user_data: *mut ::core::ffi::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn igGetAllocatorFunctions(
+        p_alloc_func: *mut ImGuiMemAllocFunc,
+        p_free_func: *mut ImGuiMemFreeFunc,
+        p_user_data: *mut *mut ::core::ffi::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn igMemAlloc(size: usize) -> *mut ::core::ffi::c_void;
+}
+unsafe extern "C" {
+    pub fn igMemFree(ptr: *mut ::core::ffi::c_void);
+}
+unsafe extern "C" {
+    pub fn ImVector_Construct(vector: *mut ::core::ffi::c_void);
+}
+unsafe extern "C" {
+    pub fn ImVector_Destruct(vector: *mut ::core::ffi::c_void);
+}
+unsafe extern "C" {
+    pub fn ImGuiStyle_ScaleAllSizes(self_: *mut ImGuiStyle, scale_factor: f32);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddKeyEvent(self_: *mut ImGuiIO, key: ImGuiKey, down: bool);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddKeyAnalogEvent(self_: *mut ImGuiIO, key: ImGuiKey, down: bool, v: f32);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddMousePosEvent(self_: *mut ImGuiIO, x: f32, y: f32);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddMouseButtonEvent(self_: *mut ImGuiIO, button: ::core::ffi::c_int, down: bool);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddMouseWheelEvent(self_: *mut ImGuiIO, wheel_x: f32, wheel_y: f32);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddMouseSourceEvent(self_: *mut ImGuiIO, source: ImGuiMouseSource);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddFocusEvent(self_: *mut ImGuiIO, focused: bool);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddInputCharacter(self_: *mut ImGuiIO, c: ::core::ffi::c_uint);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddInputCharacterUTF16(self_: *mut ImGuiIO, c: ImWchar16);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_AddInputCharactersUTF8(self_: *mut ImGuiIO, str: *const ::core::ffi::c_char);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_SetKeyEventNativeData(
+        self_: *mut ImGuiIO,
+        key: ImGuiKey,
+        native_keycode: ::core::ffi::c_int,
+        native_scancode: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_SetKeyEventNativeDataEx(
+        self_: *mut ImGuiIO,
+        key: ImGuiKey,
+        native_keycode: ::core::ffi::c_int,
+        native_scancode: ::core::ffi::c_int,
+        native_legacy_index: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_SetAppAcceptingEvents(self_: *mut ImGuiIO, accepting_events: bool);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_ClearEventsQueue(self_: *mut ImGuiIO);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_ClearInputKeys(self_: *mut ImGuiIO);
+}
+unsafe extern "C" {
+    pub fn ImGuiIO_ClearInputMouse(self_: *mut ImGuiIO);
+}
+unsafe extern "C" {
+    pub fn ImGuiInputTextCallbackData_DeleteChars(
+        self_: *mut ImGuiInputTextCallbackData,
+        pos: ::core::ffi::c_int,
+        bytes_count: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiInputTextCallbackData_InsertChars(
+        self_: *mut ImGuiInputTextCallbackData,
+        pos: ::core::ffi::c_int,
+        text: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiInputTextCallbackData_SelectAll(self_: *mut ImGuiInputTextCallbackData);
+}
+unsafe extern "C" {
+    pub fn ImGuiInputTextCallbackData_ClearSelection(self_: *mut ImGuiInputTextCallbackData);
+}
+unsafe extern "C" {
+    pub fn ImGuiInputTextCallbackData_HasSelection(
+        self_: *const ImGuiInputTextCallbackData,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiPayload_Clear(self_: *mut ImGuiPayload);
+}
+unsafe extern "C" {
+    pub fn ImGuiPayload_IsDataType(
+        self_: *const ImGuiPayload,
+        type_: *const ::core::ffi::c_char,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiPayload_IsPreview(self_: *const ImGuiPayload) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiPayload_IsDelivery(self_: *const ImGuiPayload) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextFilter_ImGuiTextRange_empty(
+        self_: *const ImGuiTextFilter_ImGuiTextRange,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextFilter_ImGuiTextRange_split(
+        self_: *const ImGuiTextFilter_ImGuiTextRange,
+        separator: ::core::ffi::c_char,
+        out: *mut ImVector_ImGuiTextRange,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiTextFilter_Draw(
+        self_: *mut ImGuiTextFilter,
+        label: *const ::core::ffi::c_char,
+        width: f32,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextFilter_PassFilter(
+        self_: *const ImGuiTextFilter,
+        text: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextFilter_Build(self_: *mut ImGuiTextFilter);
+}
+unsafe extern "C" {
+    pub fn ImGuiTextFilter_Clear(self_: *mut ImGuiTextFilter);
+}
+unsafe extern "C" {
+    pub fn ImGuiTextFilter_IsActive(self_: *const ImGuiTextFilter) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_begin(self_: *const ImGuiTextBuffer) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_end(self_: *const ImGuiTextBuffer) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_size(self_: *const ImGuiTextBuffer) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_empty(self_: *const ImGuiTextBuffer) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_clear(self_: *mut ImGuiTextBuffer);
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_resize(self_: *mut ImGuiTextBuffer, size: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_reserve(self_: *mut ImGuiTextBuffer, capacity: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_c_str(self_: *const ImGuiTextBuffer) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_append(
+        self_: *mut ImGuiTextBuffer,
+        str: *const ::core::ffi::c_char,
+        str_end: *const ::core::ffi::c_char,
+    );
+}
+unsafe extern "C" {
+    /// # Warning
+    ///
+    /// C variadic function taking a printf format string. Passing text that isn't a literal as the format is a
+    /// format-string bug: any `%` in it reads arguments that were never passed. Use `c"%s"` as the format with the
+    /// text as argument, or a non-variadic alternative (`igTextUnformatted`, the text functions of `safe::Ui`...).
+    pub fn ImGuiTextBuffer_appendf(
+        self_: *mut ImGuiTextBuffer,
+        fmt: *const ::core::ffi::c_char,
+        ...
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiTextBuffer_appendfv(
+        self_: *mut ImGuiTextBuffer,
+        fmt: *const ::core::ffi::c_char,
+        args: *mut __va_list_tag,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_Clear(self_: *mut ImGuiStorage);
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_GetInt(
+        self_: *const ImGuiStorage,
+        key: ImGuiID,
+        default_val: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_SetInt(self_: *mut ImGuiStorage, key: ImGuiID, val: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_GetBool(
+        self_: *const ImGuiStorage,
+        key: ImGuiID,
+        default_val: bool,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_SetBool(self_: *mut ImGuiStorage, key: ImGuiID, val: bool);
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_GetFloat(self_: *const ImGuiStorage, key: ImGuiID, default_val: f32)
+        -> f32;
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_SetFloat(self_: *mut ImGuiStorage, key: ImGuiID, val: f32);
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_GetVoidPtr(
+        self_: *const ImGuiStorage,
+        key: ImGuiID,
+    ) -> *mut ::core::ffi::c_void;
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_SetVoidPtr(
+        self_: *mut ImGuiStorage,
+        key: ImGuiID,
+        val: *mut ::core::ffi::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_GetIntRef(
+        self_: *mut ImGuiStorage,
+        key: ImGuiID,
+        default_val: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_GetBoolRef(
+        self_: *mut ImGuiStorage,
+        key: ImGuiID,
+        default_val: bool,
+    ) -> *mut bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_GetFloatRef(
+        self_: *mut ImGuiStorage,
+        key: ImGuiID,
+        default_val: f32,
+    ) -> *mut f32;
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_GetVoidPtrRef(
+        self_: *mut ImGuiStorage,
+        key: ImGuiID,
+        default_val: *mut ::core::ffi::c_void,
+    ) -> *mut *mut ::core::ffi::c_void;
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_BuildSortByKey(self_: *mut ImGuiStorage);
+}
+unsafe extern "C" {
+    pub fn ImGuiStorage_SetAllInt(self_: *mut ImGuiStorage, val: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn ImGuiListClipper_Begin(
+        self_: *mut ImGuiListClipper,
+        items_count: ::core::ffi::c_int,
+        items_height: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiListClipper_End(self_: *mut ImGuiListClipper);
+}
+unsafe extern "C" {
+    pub fn ImGuiListClipper_Step(self_: *mut ImGuiListClipper) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiListClipper_IncludeItemByIndex(
+        self_: *mut ImGuiListClipper,
+        item_index: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiListClipper_IncludeItemsByIndex(
+        self_: *mut ImGuiListClipper,
+        item_begin: ::core::ffi::c_int,
+        item_end: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiListClipper_SeekCursorForItem(
+        self_: *mut ImGuiListClipper,
+        item_index: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImColor_SetHSV(self_: *mut ImColor, h: f32, s: f32, v: f32, a: f32);
+}
+unsafe extern "C" {
+    pub fn ImColor_HSV(h: f32, s: f32, v: f32, a: f32) -> ImColor;
+}
+unsafe extern "C" {
+    pub fn ImGuiSelectionBasicStorage_ApplyRequests(
+        self_: *mut ImGuiSelectionBasicStorage,
+        ms_io: *mut ImGuiMultiSelectIO,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiSelectionBasicStorage_Contains(
+        self_: *const ImGuiSelectionBasicStorage,
+        id: ImGuiID,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiSelectionBasicStorage_Clear(self_: *mut ImGuiSelectionBasicStorage);
+}
+unsafe extern "C" {
+    pub fn ImGuiSelectionBasicStorage_Swap(
+        self_: *mut ImGuiSelectionBasicStorage,
+        r: *mut ImGuiSelectionBasicStorage,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiSelectionBasicStorage_SetItemSelected(
+        self_: *mut ImGuiSelectionBasicStorage,
+        id: ImGuiID,
+        selected: bool,
+    );
+}
+unsafe extern "C" {
+    pub fn ImGuiSelectionBasicStorage_GetNextSelectedItem(
+        self_: *mut ImGuiSelectionBasicStorage,
+        opaque_it: *mut *mut ::core::ffi::c_void,
+        out_id: *mut ImGuiID,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiSelectionBasicStorage_GetStorageIdFromIndex(
+        self_: *mut ImGuiSelectionBasicStorage,
+        idx: ::core::ffi::c_int,
+    ) -> ImGuiID;
+}
+unsafe extern "C" {
+    pub fn ImGuiSelectionExternalStorage_ApplyRequests(
+        self_: *mut ImGuiSelectionExternalStorage,
+        ms_io: *mut ImGuiMultiSelectIO,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawCmd_GetTexID(self_: *const ImDrawCmd) -> ImTextureID;
+}
+unsafe extern "C" {
+    pub fn ImDrawListSplitter_Clear(self_: *mut ImDrawListSplitter);
+}
+unsafe extern "C" {
+    pub fn ImDrawListSplitter_ClearFreeMemory(self_: *mut ImDrawListSplitter);
+}
+unsafe extern "C" {
+    pub fn ImDrawListSplitter_Split(
+        self_: *mut ImDrawListSplitter,
+        draw_list: *mut ImDrawList,
+        count: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawListSplitter_Merge(self_: *mut ImDrawListSplitter, draw_list: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawListSplitter_SetCurrentChannel(
+        self_: *mut ImDrawListSplitter,
+        draw_list: *mut ImDrawList,
+        channel_idx: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PushClipRect(
+        self_: *mut ImDrawList,
+        clip_rect_min: ImVec2,
+        clip_rect_max: ImVec2,
+        intersect_with_current_clip_rect: bool,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PushClipRectFullScreen(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PopClipRect(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PushTexture(self_: *mut ImDrawList, tex_ref: ImTextureRef);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PopTexture(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_GetClipRectMin(self_: *const ImDrawList) -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn ImDrawList_GetClipRectMax(self_: *const ImDrawList) -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddLine(self_: *mut ImDrawList, p1: ImVec2, p2: ImVec2, col: ImU32);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddLineEx(
+        self_: *mut ImDrawList,
+        p1: ImVec2,
+        p2: ImVec2,
+        col: ImU32,
+        thickness: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddRect(self_: *mut ImDrawList, p_min: ImVec2, p_max: ImVec2, col: ImU32);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddRectEx(
+        self_: *mut ImDrawList,
+        p_min: ImVec2,
+        p_max: ImVec2,
+        col: ImU32,
+        rounding: f32,
+        flags: ImDrawFlags,
+        thickness: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddRectFilled(
+        self_: *mut ImDrawList,
+        p_min: ImVec2,
+        p_max: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddRectFilledEx(
+        self_: *mut ImDrawList,
+        p_min: ImVec2,
+        p_max: ImVec2,
+        col: ImU32,
+        rounding: f32,
+        flags: ImDrawFlags,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddRectFilledMultiColor(
+        self_: *mut ImDrawList,
+        p_min: ImVec2,
+        p_max: ImVec2,
+        col_upr_left: ImU32,
+        col_upr_right: ImU32,
+        col_bot_right: ImU32,
+        col_bot_left: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddQuad(
+        self_: *mut ImDrawList,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        p4: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddQuadEx(
+        self_: *mut ImDrawList,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        p4: ImVec2,
+        col: ImU32,
+        thickness: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddQuadFilled(
+        self_: *mut ImDrawList,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        p4: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddTriangle(
+        self_: *mut ImDrawList,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddTriangleEx(
+        self_: *mut ImDrawList,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        col: ImU32,
+        thickness: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddTriangleFilled(
+        self_: *mut ImDrawList,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddCircle(self_: *mut ImDrawList, center: ImVec2, radius: f32, col: ImU32);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddCircleEx(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: f32,
+        col: ImU32,
+        num_segments: ::core::ffi::c_int,
+        thickness: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddCircleFilled(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: f32,
+        col: ImU32,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddNgon(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: f32,
+        col: ImU32,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddNgonEx(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: f32,
+        col: ImU32,
+        num_segments: ::core::ffi::c_int,
+        thickness: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddNgonFilled(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: f32,
+        col: ImU32,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddEllipse(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddEllipseEx(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: ImVec2,
+        col: ImU32,
+        rot: f32,
+        num_segments: ::core::ffi::c_int,
+        thickness: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddEllipseFilled(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddEllipseFilledEx(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: ImVec2,
+        col: ImU32,
+        rot: f32,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddText(
+        self_: *mut ImDrawList,
+        pos: ImVec2,
+        col: ImU32,
+        text_begin: *const ::core::ffi::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddTextEx(
+        self_: *mut ImDrawList,
+        pos: ImVec2,
+        col: ImU32,
+        text_begin: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddTextImFontPtr(
+        self_: *mut ImDrawList,
+        font: *mut ImFont,
+        font_size: f32,
+        pos: ImVec2,
+        col: ImU32,
+        text_begin: *const ::core::ffi::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddTextImFontPtrEx(
+        self_: *mut ImDrawList,
+        font: *mut ImFont,
+        font_size: f32,
+        pos: ImVec2,
+        col: ImU32,
+        text_begin: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+        wrap_width: f32,
+        cpu_fine_clip_rect: *const ImVec4,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddBezierCubic(
+        self_: *mut ImDrawList,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        p4: ImVec2,
+        col: ImU32,
+        thickness: f32,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddBezierQuadratic(
+        self_: *mut ImDrawList,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        col: ImU32,
+        thickness: f32,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddPolyline(
+        self_: *mut ImDrawList,
+        points: *const ImVec2,
+        num_points: ::core::ffi::c_int,
+        col: ImU32,
+        flags: ImDrawFlags,
+        thickness: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddConvexPolyFilled(
+        self_: *mut ImDrawList,
+        points: *const ImVec2,
+        num_points: ::core::ffi::c_int,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddConcavePolyFilled(
+        self_: *mut ImDrawList,
+        points: *const ImVec2,
+        num_points: ::core::ffi::c_int,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddImage(
+        self_: *mut ImDrawList,
+        tex_ref: ImTextureRef,
+        p_min: ImVec2,
+        p_max: ImVec2,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddImageEx(
+        self_: *mut ImDrawList,
+        tex_ref: ImTextureRef,
+        p_min: ImVec2,
+        p_max: ImVec2,
+        uv_min: ImVec2,
+        uv_max: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddImageQuad(
+        self_: *mut ImDrawList,
+        tex_ref: ImTextureRef,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        p4: ImVec2,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddImageQuadEx(
+        self_: *mut ImDrawList,
+        tex_ref: ImTextureRef,
+        p1: ImVec2,
+        p2: ImVec2,
+        p3: ImVec2,
+        p4: ImVec2,
+        uv1: ImVec2,
+        uv2: ImVec2,
+        uv3: ImVec2,
+        uv4: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddImageRounded(
+        self_: *mut ImDrawList,
+        tex_ref: ImTextureRef,
+        p_min: ImVec2,
+        p_max: ImVec2,
+        uv_min: ImVec2,
+        uv_max: ImVec2,
+        col: ImU32,
+        rounding: f32,
+        flags: ImDrawFlags,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathClear(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathLineTo(self_: *mut ImDrawList, pos: ImVec2);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathLineToMergeDuplicate(self_: *mut ImDrawList, pos: ImVec2);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathFillConvex(self_: *mut ImDrawList, col: ImU32);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathFillConcave(self_: *mut ImDrawList, col: ImU32);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathStroke(
+        self_: *mut ImDrawList,
+        col: ImU32,
+        flags: ImDrawFlags,
+        thickness: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathArcTo(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: f32,
+        a_min: f32,
+        a_max: f32,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathArcToFast(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: f32,
+        a_min_of_12: ::core::ffi::c_int,
+        a_max_of_12: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathEllipticalArcTo(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: ImVec2,
+        rot: f32,
+        a_min: f32,
+        a_max: f32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathEllipticalArcToEx(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: ImVec2,
+        rot: f32,
+        a_min: f32,
+        a_max: f32,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathBezierCubicCurveTo(
+        self_: *mut ImDrawList,
+        p2: ImVec2,
+        p3: ImVec2,
+        p4: ImVec2,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathBezierQuadraticCurveTo(
+        self_: *mut ImDrawList,
+        p2: ImVec2,
+        p3: ImVec2,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PathRect(
+        self_: *mut ImDrawList,
+        rect_min: ImVec2,
+        rect_max: ImVec2,
+        rounding: f32,
+        flags: ImDrawFlags,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddCallback(
+        self_: *mut ImDrawList,
+        callback: ImDrawCallback,
+        userdata: *mut ::core::ffi::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddCallbackEx(
+        self_: *mut ImDrawList,
+        callback: ImDrawCallback,
+        userdata: *mut ::core::ffi::c_void,
+        userdata_size: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_AddDrawCmd(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_CloneOutput(self_: *const ImDrawList) -> *mut ImDrawList;
+}
+unsafe extern "C" {
+    pub fn ImDrawList_ChannelsSplit(self_: *mut ImDrawList, count: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_ChannelsMerge(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_ChannelsSetCurrent(self_: *mut ImDrawList, n: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PrimReserve(
+        self_: *mut ImDrawList,
+        idx_count: ::core::ffi::c_int,
+        vtx_count: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PrimUnreserve(
+        self_: *mut ImDrawList,
+        idx_count: ::core::ffi::c_int,
+        vtx_count: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PrimRect(self_: *mut ImDrawList, a: ImVec2, b: ImVec2, col: ImU32);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PrimRectUV(
+        self_: *mut ImDrawList,
+        a: ImVec2,
+        b: ImVec2,
+        uv_a: ImVec2,
+        uv_b: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PrimQuadUV(
+        self_: *mut ImDrawList,
+        a: ImVec2,
+        b: ImVec2,
+        c: ImVec2,
+        d: ImVec2,
+        uv_a: ImVec2,
+        uv_b: ImVec2,
+        uv_c: ImVec2,
+        uv_d: ImVec2,
+        col: ImU32,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PrimWriteVtx(self_: *mut ImDrawList, pos: ImVec2, uv: ImVec2, col: ImU32);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PrimWriteIdx(self_: *mut ImDrawList, idx: ImDrawIdx);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PrimVtx(self_: *mut ImDrawList, pos: ImVec2, uv: ImVec2, col: ImU32);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PushTextureID(self_: *mut ImDrawList, tex_ref: ImTextureRef);
+}
+unsafe extern "C" {
+    pub fn ImDrawList_PopTextureID(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList__SetDrawListSharedData(
+        self_: *mut ImDrawList,
+        data: *mut ImDrawListSharedData,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList__ResetForNewFrame(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList__ClearFreeMemory(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList__PopUnusedDrawCmd(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList__TryMergeDrawCmds(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList__OnChangedClipRect(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList__OnChangedTexture(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList__OnChangedVtxOffset(self_: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawList__SetTexture(self_: *mut ImDrawList, tex_ref: ImTextureRef);
+}
+unsafe extern "C" {
+    pub fn ImDrawList__CalcCircleAutoSegmentCount(
+        self_: *const ImDrawList,
+        radius: f32,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn ImDrawList__PathArcToFastEx(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: f32,
+        a_min_sample: ::core::ffi::c_int,
+        a_max_sample: ::core::ffi::c_int,
+        a_step: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawList__PathArcToN(
+        self_: *mut ImDrawList,
+        center: ImVec2,
+        radius: f32,
+        a_min: f32,
+        a_max: f32,
+        num_segments: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImDrawData_Clear(self_: *mut ImDrawData);
+}
+unsafe extern "C" {
+    pub fn ImDrawData_AddDrawList(self_: *mut ImDrawData, draw_list: *mut ImDrawList);
+}
+unsafe extern "C" {
+    pub fn ImDrawData_DeIndexAllBuffers(self_: *mut ImDrawData);
+}
+unsafe extern "C" {
+    pub fn ImDrawData_ScaleClipRects(self_: *mut ImDrawData, fb_scale: ImVec2);
+}
+unsafe extern "C" {
+    pub fn ImTextureData_Create(
+        self_: *mut ImTextureData,
+        format: ImTextureFormat,
+        w: ::core::ffi::c_int,
+        h: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImTextureData_DestroyPixels(self_: *mut ImTextureData);
+}
+unsafe extern "C" {
+    pub fn ImTextureData_GetPixels(self_: *mut ImTextureData) -> *mut ::core::ffi::c_void;
+}
+unsafe extern "C" {
+    pub fn ImTextureData_GetPixelsAt(
+        self_: *mut ImTextureData,
+        x: ::core::ffi::c_int,
+        y: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_void;
+}
+unsafe extern "C" {
+    pub fn ImTextureData_GetSizeInBytes(self_: *const ImTextureData) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn ImTextureData_GetPitch(self_: *const ImTextureData) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn ImTextureData_GetTexRef(self_: *mut ImTextureData) -> ImTextureRef;
+}
+unsafe extern "C" {
+    pub fn ImTextureData_GetTexID(self_: *const ImTextureData) -> ImTextureID;
+}
+unsafe extern "C" {
+    pub fn ImTextureData_SetTexID(self_: *mut ImTextureData, tex_id: ImTextureID);
+}
+unsafe extern "C" {
+    pub fn ImTextureData_SetStatus(self_: *mut ImTextureData, status: ImTextureStatus);
+}
+unsafe extern "C" {
+    pub fn ImFontGlyphRangesBuilder_Clear(self_: *mut ImFontGlyphRangesBuilder);
+}
+unsafe extern "C" {
+    pub fn ImFontGlyphRangesBuilder_GetBit(
+        self_: *const ImFontGlyphRangesBuilder,
+        n: usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImFontGlyphRangesBuilder_SetBit(self_: *mut ImFontGlyphRangesBuilder, n: usize);
+}
+unsafe extern "C" {
+    pub fn ImFontGlyphRangesBuilder_AddChar(self_: *mut ImFontGlyphRangesBuilder, c: ImWchar);
+}
+unsafe extern "C" {
+    pub fn ImFontGlyphRangesBuilder_AddText(
+        self_: *mut ImFontGlyphRangesBuilder,
+        text: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn ImFontGlyphRangesBuilder_AddRanges(
+        self_: *mut ImFontGlyphRangesBuilder,
+        ranges: *const ImWchar,
+    );
+}
+unsafe extern "C" {
+    pub fn ImFontGlyphRangesBuilder_BuildRanges(
+        self_: *mut ImFontGlyphRangesBuilder,
+        out_ranges: *mut ImVector_ImWchar,
+    );
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddFont(
+        self_: *mut ImFontAtlas,
+        font_cfg: *const ImFontConfig,
+    ) -> *mut ImFont;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddFontDefault(
+        self_: *mut ImFontAtlas,
+        font_cfg: *const ImFontConfig,
+    ) -> *mut ImFont;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddFontFromFileTTF(
+        self_: *mut ImFontAtlas,
+        filename: *const ::core::ffi::c_char,
+        size_pixels: f32,
+        font_cfg: *const ImFontConfig,
+        glyph_ranges: *const ImWchar,
+    ) -> *mut ImFont;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddFontFromMemoryTTF(
+        self_: *mut ImFontAtlas,
+        font_data: *mut ::core::ffi::c_void,
+        font_data_size: ::core::ffi::c_int,
+        size_pixels: f32,
+        font_cfg: *const ImFontConfig,
+        glyph_ranges: *const ImWchar,
+    ) -> *mut ImFont;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddFontFromMemoryCompressedTTF(
+        self_: *mut ImFontAtlas,
+        compressed_font_data: *const ::core::ffi::c_void,
+        compressed_font_data_size: ::core::ffi::c_int,
+        size_pixels: f32,
+        font_cfg: *const ImFontConfig,
+        glyph_ranges: *const ImWchar,
+    ) -> *mut ImFont;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddFontFromMemoryCompressedBase85TTF(
+        self_: *mut ImFontAtlas,
+        compressed_font_data_base85: *const ::core::ffi::c_char,
+        size_pixels: f32,
+        font_cfg: *const ImFontConfig,
+        glyph_ranges: *const ImWchar,
+    ) -> *mut ImFont;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_RemoveFont(self_: *mut ImFontAtlas, font: *mut ImFont);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_Clear(self_: *mut ImFontAtlas);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_CompactCache(self_: *mut ImFontAtlas);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_SetFontLoader(self_: *mut ImFontAtlas, font_loader: *const ImFontLoader);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_ClearInputData(self_: *mut ImFontAtlas);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_ClearFonts(self_: *mut ImFontAtlas);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_ClearTexData(self_: *mut ImFontAtlas);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_Build(self_: *mut ImFontAtlas) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetTexDataAsAlpha8(
+        self_: *mut ImFontAtlas,
+        out_pixels: *mut *mut ::core::ffi::c_uchar,
+        out_width: *mut ::core::ffi::c_int,
+        out_height: *mut ::core::ffi::c_int,
+        out_bytes_per_pixel: *mut ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetTexDataAsRGBA32(
+        self_: *mut ImFontAtlas,
+        out_pixels: *mut *mut ::core::ffi::c_uchar,
+        out_width: *mut ::core::ffi::c_int,
+        out_height: *mut ::core::ffi::c_int,
+        out_bytes_per_pixel: *mut ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_SetTexID(self_: *mut ImFontAtlas, id: ImTextureID);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_SetTexIDImTextureRef(self_: *mut ImFontAtlas, id: ImTextureRef);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_IsBuilt(self_: *const ImFontAtlas) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetGlyphRangesDefault(self_: *mut ImFontAtlas) -> *const ImWchar;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetGlyphRangesGreek(self_: *mut ImFontAtlas) -> *const ImWchar;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetGlyphRangesKorean(self_: *mut ImFontAtlas) -> *const ImWchar;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetGlyphRangesJapanese(self_: *mut ImFontAtlas) -> *const ImWchar;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetGlyphRangesChineseFull(self_: *mut ImFontAtlas) -> *const ImWchar;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetGlyphRangesChineseSimplifiedCommon(
+        self_: *mut ImFontAtlas,
+    ) -> *const ImWchar;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetGlyphRangesCyrillic(self_: *mut ImFontAtlas) -> *const ImWchar;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetGlyphRangesThai(self_: *mut ImFontAtlas) -> *const ImWchar;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetGlyphRangesVietnamese(self_: *mut ImFontAtlas) -> *const ImWchar;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddCustomRect(
+        self_: *mut ImFontAtlas,
+        width: ::core::ffi::c_int,
+        height: ::core::ffi::c_int,
+        out_r: *mut ImFontAtlasRect,
+    ) -> ImFontAtlasRectId;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_RemoveCustomRect(self_: *mut ImFontAtlas, id: ImFontAtlasRectId);
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetCustomRect(
+        self_: *const ImFontAtlas,
+        id: ImFontAtlasRectId,
+        out_r: *mut ImFontAtlasRect,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddCustomRectRegular(
+        self_: *mut ImFontAtlas,
+        w: ::core::ffi::c_int,
+        h: ::core::ffi::c_int,
+    ) -> ImFontAtlasRectId;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_GetCustomRectByIndex(
+        self_: *mut ImFontAtlas,
+        id: ImFontAtlasRectId,
+    ) -> *const ImFontAtlasRect;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_CalcCustomRectUV(
+        self_: *const ImFontAtlas,
+        r: *const ImFontAtlasRect,
+        out_uv_min: *mut ImVec2,
+        out_uv_max: *mut ImVec2,
+    );
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddCustomRectFontGlyph(
+        self_: *mut ImFontAtlas,
+        font: *mut ImFont,
+        codepoint: ImWchar,
+        w: ::core::ffi::c_int,
+        h: ::core::ffi::c_int,
+        advance_x: f32,
+        offset: ImVec2,
+    ) -> ImFontAtlasRectId;
+}
+unsafe extern "C" {
+    pub fn ImFontAtlas_AddCustomRectFontGlyphForSize(
+        self_: *mut ImFontAtlas,
+        font: *mut ImFont,
+        font_size: f32,
+        codepoint: ImWchar,
+        w: ::core::ffi::c_int,
+        h: ::core::ffi::c_int,
+        advance_x: f32,
+        offset: ImVec2,
+    ) -> ImFontAtlasRectId;
+}
+unsafe extern "C" {
+    pub fn ImFontBaked_ClearOutputData(self_: *mut ImFontBaked);
+}
+unsafe extern "C" {
+    pub fn ImFontBaked_FindGlyph(self_: *mut ImFontBaked, c: ImWchar) -> *mut ImFontGlyph;
+}
+unsafe extern "C" {
+    pub fn ImFontBaked_FindGlyphNoFallback(self_: *mut ImFontBaked, c: ImWchar)
+        -> *mut ImFontGlyph;
+}
+unsafe extern "C" {
+    pub fn ImFontBaked_GetCharAdvance(self_: *mut ImFontBaked, c: ImWchar) -> f32;
+}
+unsafe extern "C" {
+    pub fn ImFontBaked_IsGlyphLoaded(self_: *mut ImFontBaked, c: ImWchar) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImFont_IsGlyphInFont(self_: *mut ImFont, c: ImWchar) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImFont_IsLoaded(self_: *const ImFont) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImFont_GetDebugName(self_: *const ImFont) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn ImFont_GetFontBaked(self_: *mut ImFont, font_size: f32) -> *mut ImFontBaked;
+}
+unsafe extern "C" {
+    pub fn ImFont_GetFontBakedEx(
+        self_: *mut ImFont,
+        font_size: f32,
+        density: f32,
+    ) -> *mut ImFontBaked;
+}
+unsafe extern "C" {
+    pub fn ImFont_CalcTextSizeA(
+        self_: *mut ImFont,
+        size: f32,
+        max_width: f32,
+        wrap_width: f32,
+        text_begin: *const ::core::ffi::c_char,
+    ) -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn ImFont_CalcTextSizeAEx(
+        self_: *mut ImFont,
+        size: f32,
+        max_width: f32,
+        wrap_width: f32,
+        text_begin: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+        out_remaining: *mut *const ::core::ffi::c_char,
+    ) -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn ImFont_CalcWordWrapPosition(
+        self_: *mut ImFont,
+        size: f32,
+        text: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+        wrap_width: f32,
+    ) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn ImFont_RenderChar(
+        self_: *mut ImFont,
+        draw_list: *mut ImDrawList,
+        size: f32,
+        pos: ImVec2,
+        col: ImU32,
+        c: ImWchar,
+    );
+}
+unsafe extern "C" {
+    pub fn ImFont_RenderCharEx(
+        self_: *mut ImFont,
+        draw_list: *mut ImDrawList,
+        size: f32,
+        pos: ImVec2,
+        col: ImU32,
+        c: ImWchar,
+        cpu_fine_clip: *const ImVec4,
+    );
+}
+unsafe extern "C" {
+    pub fn ImFont_RenderText(
+        self_: *mut ImFont,
+        draw_list: *mut ImDrawList,
+        size: f32,
+        pos: ImVec2,
+        col: ImU32,
+        clip_rect: ImVec4,
+        text_begin: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+        wrap_width: f32,
+        flags: ImDrawTextFlags,
+    );
+}
+unsafe extern "C" {
+    pub fn ImFont_CalcWordWrapPositionA(
+        self_: *mut ImFont,
+        scale: f32,
+        text: *const ::core::ffi::c_char,
+        text_end: *const ::core::ffi::c_char,
+        wrap_width: f32,
+    ) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn ImFont_ClearOutputData(self_: *mut ImFont);
+}
+unsafe extern "C" {
+    pub fn ImFont_AddRemapChar(self_: *mut ImFont, from_codepoint: ImWchar, to_codepoint: ImWchar);
+}
+unsafe extern "C" {
+    pub fn ImFont_IsGlyphRangeUnused(
+        self_: *mut ImFont,
+        c_begin: ::core::ffi::c_uint,
+        c_last: ::core::ffi::c_uint,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ImGuiViewport_GetCenter(self_: *const ImGuiViewport) -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn ImGuiViewport_GetWorkCenter(self_: *const ImGuiViewport) -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn ImGuiPlatformIO_ClearPlatformHandlers(self_: *mut ImGuiPlatformIO);
+}
+unsafe extern "C" {
+    pub fn ImGuiPlatformIO_ClearRendererHandlers(self_: *mut ImGuiPlatformIO);
+}
+unsafe extern "C" {
+    pub fn igPushFont(font: *mut ImFont);
+}
+unsafe extern "C" {
+    pub fn igSetWindowFontScale(scale: f32);
+}
+unsafe extern "C" {
+    pub fn igImageImVec4(
+        tex_ref: ImTextureRef,
+        image_size: ImVec2,
+        uv0: ImVec2,
+        uv1: ImVec2,
+        tint_col: ImVec4,
+        border_col: ImVec4,
+    );
+}
+unsafe extern "C" {
+    pub fn igPushButtonRepeat(repeat: bool);
+}
+unsafe extern "C" {
+    pub fn igPopButtonRepeat();
+}
+unsafe extern "C" {
+    pub fn igPushTabStop(tab_stop: bool);
+}
+unsafe extern "C" {
+    pub fn igPopTabStop();
+}
+unsafe extern "C" {
+    pub fn igGetContentRegionMax() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetWindowContentRegionMin() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igGetWindowContentRegionMax() -> ImVec2;
+}
+unsafe extern "C" {
+    pub fn igBeginChildFrame(id: ImGuiID, size: ImVec2) -> bool;
+}
+unsafe extern "C" {
+    pub fn igBeginChildFrameEx(id: ImGuiID, size: ImVec2, window_flags: ImGuiWindowFlags) -> bool;
+}
+unsafe extern "C" {
+    pub fn igEndChildFrame();
+}
+unsafe extern "C" {
+    pub fn igShowStackToolWindow(p_open: *mut bool);
+}
+unsafe extern "C" {
+    pub fn igComboObsolete(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        old_callback: ::core::option::Option<
+            unsafe extern "C" fn(
+                user_data: *mut ::core::ffi::c_void,
+                idx: ::core::ffi::c_int,
+                out_text: *mut *const ::core::ffi::c_char,
+            ) -> bool,
+        >,
+        user_data: *mut ::core::ffi::c_void,
+        items_count: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igComboObsoleteEx(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        old_callback: ::core::option::Option<
+            unsafe extern "C" fn(
+                user_data: *mut ::core::ffi::c_void,
+                idx: ::core::ffi::c_int,
+                out_text: *mut *const ::core::ffi::c_char,
+            ) -> bool,
+        >,
+        user_data: *mut ::core::ffi::c_void,
+        items_count: ::core::ffi::c_int,
+        popup_max_height_in_items: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igListBoxObsolete(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        old_callback: ::core::option::Option<
+            unsafe extern "C" fn(
+                user_data: *mut ::core::ffi::c_void,
+                idx: ::core::ffi::c_int,
+                out_text: *mut *const ::core::ffi::c_char,
+            ) -> bool,
+        >,
+        user_data: *mut ::core::ffi::c_void,
+        items_count: ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn igListBoxObsoleteEx(
+        label: *const ::core::ffi::c_char,
+        current_item: *mut ::core::ffi::c_int,
+        old_callback: ::core::option::Option<
+            unsafe extern "C" fn(
+                user_data: *mut ::core::ffi::c_void,
+                idx: ::core::ffi::c_int,
+                out_text: *mut *const ::core::ffi::c_char,
+            ) -> bool,
+        >,
+        user_data: *mut ::core::ffi::c_void,
+        items_count: ::core::ffi::c_int,
+        height_in_items: ::core::ffi::c_int,
+    ) -> bool;
+}
+pub type __builtin_va_list = [__va_list_tag; 1usize];
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __va_list_tag {
+    pub gp_offset: ::core::ffi::c_uint,
+    pub fp_offset: ::core::ffi::c_uint,
+    pub overflow_arg_area: *mut ::core::ffi::c_void,
+    pub reg_save_area: *mut ::core::ffi::c_void,
+}
+pub const IMGUI_HAS_TABLE: bool = true;
+pub const IMGUI_HAS_TEXTURES: bool = true;
+im_vector! {
+    ImVector_ImGuiTextRange_t => ImGuiTextFilter_ImGuiTextRange;
+    ImVector_char_t => ::core::ffi::c_char;
+    ImVector_ImGuiStoragePair_t => ImGuiStoragePair;
+    ImVector_ImGuiSelectionRequest_t => ImGuiSelectionRequest;
+    ImVector_ImDrawChannel_t => ImDrawChannel;
+    ImVector_ImDrawCmd_t => ImDrawCmd;
+    ImVector_ImDrawIdx_t => ImDrawIdx;
+    ImVector_ImDrawVert_t => ImDrawVert;
+    ImVector_ImVec2_t => ImVec2;
+    ImVector_ImVec4_t => ImVec4;
+    ImVector_ImTextureRef_t => ImTextureRef;
+    ImVector_ImU8_t => ImU8;
+    ImVector_ImDrawListPtr_t => *mut ImDrawList;
+    ImVector_ImTextureRect_t => ImTextureRect;
+    ImVector_ImU32_t => ImU32;
+    ImVector_ImWchar_t => ImWchar;
+    ImVector_ImFontPtr_t => *mut ImFont;
+    ImVector_ImFontConfig_t => ImFontConfig;
+    ImVector_ImDrawListSharedDataPtr_t => *mut ImDrawListSharedData;
+    ImVector_float_t => f32;
+    ImVector_ImU16_t => ImU16;
+    ImVector_ImFontGlyph_t => ImFontGlyph;
+    ImVector_ImFontConfigPtr_t => *mut ImFontConfig;
+    ImVector_ImTextureDataPtr_t => *mut ImTextureData;
+}
@@ -0,0 +1,33 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLRenderer3_Init(renderer: *mut SDL_Renderer) -> bool;
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLRenderer3_Shutdown();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLRenderer3_NewFrame();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLRenderer3_RenderDrawData(
+        draw_data: *mut ImDrawData,
+        renderer: *mut SDL_Renderer,
+    );
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLRenderer3_CreateDeviceObjects();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLRenderer3_DestroyDeviceObjects();
+}
+unsafe extern "C" {
+    pub fn cImGui_ImplSDLRenderer3_UpdateTexture(tex: *mut ImTextureData);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cImGui_ImplSDLRenderer3_RenderState {
+    pub Renderer: *mut SDL_Renderer,
+}
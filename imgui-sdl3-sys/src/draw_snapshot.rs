@@ -0,0 +1,488 @@
+// Draw data snapshots: a copy of the draw data in a stable format, for snapshot tests
+//
+// The snapshot keeps what a renderer sees: per draw list, the vertices, indices and commands with their clip rect,
+// texture and offsets, and markers for the callbacks. It's serialized as JSON, one vertex or command per line so
+// that a diff points at what moved, or as a compact binary.
+//
+// The textures managed by Dear ImGui (font atlas...) are identified by their index in ImDrawData::Textures, since
+// their ImTextureID depends on the renderer, the user textures by their ImTextureID. Callbacks are recorded without
+// their function and data pointers, which change from a run to the next.
+//
+// Binary format, little endian:
+// - header: the "IMDS" magic, a version byte and the size of ImDrawIdx in bytes (2, or 4 with 32-bit indices)
+// - display pos, display size and framebuffer scale (6 f32), the number of lists (u32)
+// - per list: the numbers of vertices, indices and commands (u32), the vertices (pos and uv f32, col u32), the
+//   indices (ImDrawIdx), and the commands, a tag byte followed by the fields of the elements command
+
+extern crate alloc;
+extern crate std;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::mem::size_of;
+
+use crate::imgui_sys::{self, ImDrawIdx, ImTextureID, ImVec2, ImVec4};
+use crate::reader::{self, HeaderError, InvalidData, Reader};
+use crate::safe::{DrawCmd, DrawData};
+
+const MAGIC: &[u8; 4] = b"IMDS";
+const VERSION: u8 = 1;
+
+const TAG_ELEMENTS: u8 = 0;
+const TAG_RESET_RENDER_STATE: u8 = 1;
+const TAG_CALLBACK: u8 = 2;
+
+const TEXTURE_MANAGED: u8 = 0;
+const TEXTURE_ID: u8 = 1;
+
+/// Environment variable making [`assert_snapshot`] write the snapshot files instead of comparing them.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "IMGUI_UPDATE_SNAPSHOTS";
+
+/// A copy of the draw data of a frame, comparable and serializable.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawDataSnapshot {
+    pub display_pos: [f32; 2],
+    pub display_size: [f32; 2],
+    pub framebuffer_scale: [f32; 2],
+    pub lists: Vec<DrawListSnapshot>,
+}
+
+/// A draw list of a [`DrawDataSnapshot`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawListSnapshot {
+    pub vertices: Vec<VertexSnapshot>,
+    pub indices: Vec<ImDrawIdx>,
+    pub commands: Vec<CommandSnapshot>,
+}
+
+/// A vertex of a [`DrawListSnapshot`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VertexSnapshot {
+    pub pos: [f32; 2],
+    pub uv: [f32; 2],
+    /// Packed color, `IM_COL32` order
+    pub col: u32,
+}
+
+/// A command of a [`DrawListSnapshot`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CommandSnapshot {
+    Elements {
+        count: u32,
+        /// Clipping rectangle (x1, y1, x2, y2)
+        clip_rect: [f32; 4],
+        texture: TextureSnapshot,
+        vtx_offset: u32,
+        idx_offset: u32,
+    },
+    /// `ImDrawCallback_ResetRenderState`
+    ResetRenderState,
+    /// A user callback, without its pointers
+    Callback,
+}
+
+/// Texture of a [`CommandSnapshot::Elements`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextureSnapshot {
+    /// A texture managed by Dear ImGui, by its index in `ImDrawData::Textures`
+    Managed(u32),
+    /// A user texture, by its ID
+    Id(ImTextureID),
+}
+
+/// Error of [`DrawDataSnapshot::from_bytes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The data doesn't start with the header of a snapshot.
+    NotASnapshot,
+    /// The snapshot was made by a newer version.
+    UnsupportedVersion(u8),
+    /// The snapshot was made with another `ImDrawIdx` size, in bytes.
+    UnsupportedIndexSize(u8),
+    /// The snapshot is truncated or corrupted at the given byte offset.
+    InvalidData(usize),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotASnapshot => write!(f, "not a draw data snapshot"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported draw data snapshot version {version}"),
+            Self::UnsupportedIndexSize(size) => write!(f, "unsupported draw data snapshot index size {size}"),
+            Self::InvalidData(offset) => write!(f, "invalid draw data snapshot data at offset {offset}"),
+        }
+    }
+}
+
+impl core::error::Error for SnapshotError {}
+
+impl From<HeaderError> for SnapshotError {
+    fn from(error: HeaderError) -> Self {
+        match error {
+            HeaderError::Magic => Self::NotASnapshot,
+            HeaderError::Version(version) => Self::UnsupportedVersion(version),
+        }
+    }
+}
+
+impl From<InvalidData> for SnapshotError {
+    fn from(InvalidData(offset): InvalidData) -> Self {
+        Self::InvalidData(offset)
+    }
+}
+
+impl DrawDataSnapshot {
+    /// Copies `draw_data`.
+    pub fn capture(draw_data: &DrawData) -> Self {
+        let textures = draw_data.textures();
+        let lists = draw_data
+            .lists()
+            .map(|list| DrawListSnapshot {
+                vertices: list
+                    .vertices()
+                    .iter()
+                    .map(|vertex| VertexSnapshot { pos: vec2(vertex.pos), uv: vec2(vertex.uv), col: vertex.col })
+                    .collect(),
+                indices: list.indices().to_vec(),
+                commands: list
+                    .commands()
+                    .zip(list.raw_commands())
+                    .map(|(cmd, raw)| match cmd {
+                        DrawCmd::Elements { count, params } => {
+                            let tex_data = raw.TexRef._TexData;
+                            let texture = match textures.iter().position(|&texture| texture == tex_data) {
+                                Some(index) if !tex_data.is_null() => TextureSnapshot::Managed(index as u32),
+                                _ => TextureSnapshot::Id(params.texture_id),
+                            };
+                            CommandSnapshot::Elements {
+                                count: count as u32,
+                                clip_rect: vec4(params.clip_rect),
+                                texture,
+                                vtx_offset: params.vtx_offset as u32,
+                                idx_offset: params.idx_offset as u32,
+                            }
+                        }
+                        DrawCmd::ResetRenderState => CommandSnapshot::ResetRenderState,
+                        DrawCmd::Callback(_) => CommandSnapshot::Callback,
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self {
+            display_pos: vec2(draw_data.DisplayPos),
+            display_size: vec2(draw_data.DisplaySize),
+            framebuffer_scale: vec2(draw_data.FramebufferScale),
+            lists,
+        }
+    }
+
+    /// Copies the draw data of the current context (`igGetDrawData`), `None` without a current context or before
+    /// the first `igRender`.
+    pub fn current() -> Option<Self> {
+        // igGetDrawData dereferences the current context
+        if unsafe { imgui_sys::igGetCurrentContext() }.is_null() {
+            return None;
+        }
+        let draw_data = unsafe { imgui_sys::igGetDrawData() };
+        if draw_data.is_null() || !unsafe { (*draw_data).Valid } {
+            return None;
+        }
+        // Copied right away, while the draw lists are valid
+        Some(Self::capture(&unsafe { DrawData::from_raw(draw_data) }))
+    }
+
+    /// Serializes the snapshot as pretty JSON, with a line per vertex, triangle and command.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json).unwrap();
+        json
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        out.push_str("{\n");
+        writeln!(out, "  \"display_pos\": {},", Floats(&self.display_pos))?;
+        writeln!(out, "  \"display_size\": {},", Floats(&self.display_size))?;
+        writeln!(out, "  \"framebuffer_scale\": {},", Floats(&self.framebuffer_scale))?;
+        out.push_str("  \"lists\": [");
+        for (i, list) in self.lists.iter().enumerate() {
+            out.push_str(if i == 0 { "\n" } else { ",\n" });
+            out.push_str("    {\n      \"vertices\": [");
+            for (i, vertex) in list.vertices.iter().enumerate() {
+                let separator = if i == 0 { "\n" } else { ",\n" };
+                write!(out, "{separator}        [{}, {}, {}, {}, {}]", Float(vertex.pos[0]), Float(vertex.pos[1]), Float(vertex.uv[0]), Float(vertex.uv[1]), vertex.col)?;
+            }
+            out.push_str(if list.vertices.is_empty() { "],\n" } else { "\n      ],\n" });
+            out.push_str("      \"indices\": [");
+            for (i, triangle) in list.indices.chunks(3).enumerate() {
+                out.push_str(if i == 0 { "\n        " } else { ",\n        " });
+                for (j, index) in triangle.iter().enumerate() {
+                    write!(out, "{}{index}", if j == 0 { "" } else { ", " })?;
+                }
+            }
+            out.push_str(if list.indices.is_empty() { "],\n" } else { "\n      ],\n" });
+            out.push_str("      \"commands\": [");
+            for (i, command) in list.commands.iter().enumerate() {
+                out.push_str(if i == 0 { "\n        " } else { ",\n        " });
+                match command {
+                    CommandSnapshot::Elements { count, clip_rect, texture, vtx_offset, idx_offset } => {
+                        let texture = match texture {
+                            TextureSnapshot::Managed(index) => ("managed", *index as u64),
+                            TextureSnapshot::Id(id) => ("id", *id),
+                        };
+                        write!(
+                            out,
+                            "{{\"elements\": {count}, \"clip_rect\": {}, \"texture\": {{\"{}\": {}}}, \"vtx_offset\": {vtx_offset}, \"idx_offset\": {idx_offset}}}",
+                            Floats(clip_rect),
+                            texture.0,
+                            texture.1
+                        )?;
+                    }
+                    CommandSnapshot::ResetRenderState => out.push_str("\"reset_render_state\""),
+                    CommandSnapshot::Callback => out.push_str("\"callback\""),
+                }
+            }
+            out.push_str(if list.commands.is_empty() { "]\n" } else { "\n      ]\n" });
+            out.push_str("    }");
+        }
+        out.push_str(if self.lists.is_empty() { "]\n" } else { "\n  ]\n" });
+        out.push_str("}\n");
+        Ok(())
+    }
+
+    /// Serializes the snapshot in the compact binary format read by [`from_bytes`](Self::from_bytes).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        out.push(size_of::<ImDrawIdx>() as u8);
+        for value in self.display_pos.iter().chain(&self.display_size).chain(&self.framebuffer_scale) {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        out.extend_from_slice(&(self.lists.len() as u32).to_le_bytes());
+        for list in &self.lists {
+            for len in [list.vertices.len(), list.indices.len(), list.commands.len()] {
+                out.extend_from_slice(&(len as u32).to_le_bytes());
+            }
+            for vertex in &list.vertices {
+                for value in vertex.pos.iter().chain(&vertex.uv) {
+                    out.extend_from_slice(&value.to_le_bytes());
+                }
+                out.extend_from_slice(&vertex.col.to_le_bytes());
+            }
+            for index in &list.indices {
+                out.extend_from_slice(&index.to_le_bytes());
+            }
+            for command in &list.commands {
+                match command {
+                    CommandSnapshot::Elements { count, clip_rect, texture, vtx_offset, idx_offset } => {
+                        out.push(TAG_ELEMENTS);
+                        out.extend_from_slice(&count.to_le_bytes());
+                        for value in clip_rect {
+                            out.extend_from_slice(&value.to_le_bytes());
+                        }
+                        match texture {
+                            TextureSnapshot::Managed(index) => {
+                                out.push(TEXTURE_MANAGED);
+                                out.extend_from_slice(&(*index as u64).to_le_bytes());
+                            }
+                            TextureSnapshot::Id(id) => {
+                                out.push(TEXTURE_ID);
+                                out.extend_from_slice(&id.to_le_bytes());
+                            }
+                        }
+                        out.extend_from_slice(&vtx_offset.to_le_bytes());
+                        out.extend_from_slice(&idx_offset.to_le_bytes());
+                    }
+                    CommandSnapshot::ResetRenderState => out.push(TAG_RESET_RENDER_STATE),
+                    CommandSnapshot::Callback => out.push(TAG_CALLBACK),
+                }
+            }
+        }
+        out
+    }
+
+    /// Reads a snapshot serialized by [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(data: &[u8]) -> Result<Self, SnapshotError> {
+        let mut reader = Reader::new(data, reader::check_header(data, MAGIC, VERSION)?);
+        let index_size = reader.u8()?;
+        if index_size as usize != size_of::<ImDrawIdx>() {
+            return Err(SnapshotError::UnsupportedIndexSize(index_size));
+        }
+        let display_pos = reader.vec2()?;
+        let display_size = reader.vec2()?;
+        let framebuffer_scale = reader.vec2()?;
+        let list_count = reader.count(1)?;
+        let mut lists = Vec::with_capacity(list_count);
+        for _ in 0..list_count {
+            let (vertex_count, index_count, command_count) = (reader.count(1)?, reader.count(1)?, reader.count(1)?);
+            let mut vertices = Vec::with_capacity(vertex_count);
+            for _ in 0..vertex_count {
+                vertices.push(VertexSnapshot {
+                    pos: reader.vec2()?,
+                    uv: reader.vec2()?,
+                    col: reader.u32()?,
+                });
+            }
+            let mut indices = Vec::with_capacity(index_count);
+            for _ in 0..index_count {
+                indices.push(reader.index()?);
+            }
+            let mut commands = Vec::with_capacity(command_count);
+            for _ in 0..command_count {
+                let tag_pos = reader.pos();
+                commands.push(match reader.u8()? {
+                    TAG_ELEMENTS => {
+                        let count = reader.u32()?;
+                        let clip_rect = [reader.f32()?, reader.f32()?, reader.f32()?, reader.f32()?];
+                        let texture_pos = reader.pos();
+                        let texture = match (reader.u8()?, reader.u64()?) {
+                            (TEXTURE_MANAGED, index) => TextureSnapshot::Managed(index as u32),
+                            (TEXTURE_ID, id) => TextureSnapshot::Id(id),
+                            _ => return Err(SnapshotError::InvalidData(texture_pos)),
+                        };
+                        CommandSnapshot::Elements { count, clip_rect, texture, vtx_offset: reader.u32()?, idx_offset: reader.u32()? }
+                    }
+                    TAG_RESET_RENDER_STATE => CommandSnapshot::ResetRenderState,
+                    TAG_CALLBACK => CommandSnapshot::Callback,
+                    _ => return Err(SnapshotError::InvalidData(tag_pos)),
+                });
+            }
+            lists.push(DrawListSnapshot { vertices, indices, commands });
+        }
+        reader.finish()?;
+        Ok(Self { display_pos, display_size, framebuffer_scale, lists })
+    }
+
+    /// Describes the first difference with `other`, `None` if they're equal.
+    pub fn first_difference(&self, other: &Self) -> Option<String> {
+        if self.display_pos != other.display_pos {
+            return Some(alloc::format!("display_pos: {:?} != {:?}", self.display_pos, other.display_pos));
+        }
+        if self.display_size != other.display_size {
+            return Some(alloc::format!("display_size: {:?} != {:?}", self.display_size, other.display_size));
+        }
+        if self.framebuffer_scale != other.framebuffer_scale {
+            return Some(alloc::format!("framebuffer_scale: {:?} != {:?}", self.framebuffer_scale, other.framebuffer_scale));
+        }
+        for (i, (list, other_list)) in self.lists.iter().zip(&other.lists).enumerate() {
+            if let Some(difference) = first_difference("vertex", &list.vertices, &other_list.vertices)
+                .or_else(|| first_difference("index", &list.indices, &other_list.indices))
+                .or_else(|| first_difference("command", &list.commands, &other_list.commands))
+            {
+                return Some(alloc::format!("list {i}: {difference}"));
+            }
+        }
+        if self.lists.len() != other.lists.len() {
+            return Some(alloc::format!("{} lists != {} lists", self.lists.len(), other.lists.len()));
+        }
+        None
+    }
+}
+
+fn first_difference<T: PartialEq + fmt::Debug>(name: &str, items: &[T], other_items: &[T]) -> Option<String> {
+    if let Some((i, (item, other_item))) = items.iter().zip(other_items).enumerate().find(|(_, (a, b))| a != b) {
+        return Some(alloc::format!("{name} {i}: {item:?} != {other_item:?}"));
+    }
+    if items.len() != other_items.len() {
+        return Some(alloc::format!("{} {name} items != {}", items.len(), other_items.len()));
+    }
+    None
+}
+
+/// Compares `snapshot` with the snapshot file at `path`, JSON if its extension is `.json`, binary otherwise.
+///
+/// The file is only written when the `IMGUI_UPDATE_SNAPSHOTS` environment variable is set, to create it or accept
+/// the changes. On a mismatch or a missing file, the new snapshot is written next to the file with a `.new` suffix,
+/// for diffing.
+///
+/// # Panics
+///
+/// If the snapshots differ, with the first difference, if the file doesn't exist, or if it can't be read or written.
+#[track_caller]
+pub fn assert_snapshot(path: impl AsRef<std::path::Path>, snapshot: &DrawDataSnapshot) {
+    let path = path.as_ref();
+    let json = path.extension().is_some_and(|extension| extension == "json");
+    let data = if json { snapshot.to_json().into_bytes() } else { snapshot.to_bytes() };
+
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some_and(|value| !value.is_empty() && value != "0");
+    if update {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap_or_else(|e| panic!("Can't create {}: {e}", parent.display()));
+        }
+        std::fs::write(path, &data).unwrap_or_else(|e| panic!("Can't write {}: {e}", path.display()));
+        return;
+    }
+
+    let mut new_path = std::ffi::OsString::from(path);
+    new_path.push(".new");
+    let new_path = std::path::PathBuf::from(new_path);
+    let write_new = || std::fs::write(&new_path, &data).unwrap_or_else(|e| panic!("Can't write {}: {e}", new_path.display()));
+    if !path.exists() {
+        write_new();
+        panic!(
+            "The snapshot {} doesn't exist\nThe new snapshot is in {}, set {UPDATE_SNAPSHOTS_VAR}=1 to write it",
+            path.display(),
+            new_path.display()
+        );
+    }
+
+    let expected = std::fs::read(path).unwrap_or_else(|e| panic!("Can't read {}: {e}", path.display()));
+    if expected == data {
+        return;
+    }
+    let difference = if json {
+        // Snapshot files may have been checked out with CRLF line endings
+        let expected = String::from_utf8_lossy(&expected).replace("\r\n", "\n");
+        let actual = String::from_utf8_lossy(&data);
+        if expected == actual {
+            return;
+        }
+        let mut lines = expected.lines().zip(actual.lines()).enumerate();
+        match lines.find(|(_, (a, b))| a != b) {
+            Some((i, (a, b))) => alloc::format!("line {}:\n- {}\n+ {}", i + 1, a.trim(), b.trim()),
+            None => alloc::format!("{} lines != {} lines", expected.lines().count(), actual.lines().count()),
+        }
+    } else {
+        match DrawDataSnapshot::from_bytes(&expected) {
+            Ok(expected) => expected.first_difference(snapshot).unwrap_or_default(),
+            Err(e) => alloc::format!("{e}"),
+        }
+    };
+
+    write_new();
+    panic!(
+        "The draw data differs from the snapshot {}, {difference}\nThe new snapshot is in {}, set {UPDATE_SNAPSHOTS_VAR}=1 to accept it",
+        path.display(),
+        new_path.display()
+    );
+}
+
+fn vec2(v: ImVec2) -> [f32; 2] {
+    [v.x, v.y]
+}
+
+fn vec4(v: ImVec4) -> [f32; 4] {
+    [v.x, v.y, v.z, v.w]
+}
+
+// JSON number, the shortest representation reading back to the same f32. JSON has no infinities or NaN.
+struct Float(f32);
+
+impl fmt::Display for Float {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_finite() { write!(f, "{}", self.0) } else { f.write_str("null") }
+    }
+}
+
+struct Floats<'a>(&'a [f32]);
+
+impl fmt::Display for Floats<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, &value) in self.0.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { "" } else { ", " }, Float(value))?;
+        }
+        f.write_str("]")
+    }
+}
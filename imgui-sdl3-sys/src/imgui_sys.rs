@@ -4,10 +4,47 @@
 #[allow(unused_imports)]
 use core::*;
 
+// Slice access to the ImVector_* structs, implemented by the generated bindings
+#[macro_use]
+mod vector;
+pub use vector::ImVector;
+
 // Core ImGui bindings
-#[path = "bindings/imgui.rs"]
-mod imgui_bindings;
+// Generated into OUT_DIR with the `bindgen` feature, otherwise pre-generated in src/bindings/<target>
+mod imgui_bindings {
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    #![allow(dead_code)]
+    #![allow(clippy::all)]
+    #![allow(unsafe_op_in_unsafe_fn)]
+    #![allow(unnecessary_transmutes)]
+    #[cfg(feature = "bindgen")]
+    include!(concat!(env!("OUT_DIR"), "/imgui.rs"));
+    #[cfg(not(feature = "bindgen"))]
+    include!(concat!(env!("IMGUI_SDL3_SYS_BINDINGS_DIR"), "/imgui.rs"));
+    // Only forward declared by the public API, defined by the internal bindings with the `internal` feature
+    #[cfg(feature = "internal")]
+    pub use super::internal::{ImDrawListSharedData_t, ImFontAtlasBuilder_t, ImFontLoader_t, ImGuiContext_t};
+}
 pub use imgui_bindings::*;
 
+// Internal API bindings, generated from cimgui_internal.h with the `internal` feature
+// The public types are shared with the parent module, only the internal items are defined here
+#[cfg(feature = "internal")]
+pub mod internal {
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    #![allow(dead_code)]
+    #![allow(clippy::all)]
+    #![allow(unsafe_op_in_unsafe_fn)]
+    #![allow(unnecessary_transmutes)]
+    include!(concat!(env!("OUT_DIR"), "/internal.rs"));
+}
+
+// Typed flags and enums on top of the raw enum constants
+pub mod enums;
+
 // SDL3 backend submodule (includes both renderer and GPU backends)
 pub mod sdl3;
@@ -0,0 +1,223 @@
+// Typed flags and enums
+//
+// The bindgen constants (`ImGuiConfigFlags__ImGuiConfigFlags_NavEnableKeyboard`...) are untyped and need casts.
+// The types of this module are generated from cimgui.json by the build script (or pre-generated in src/bindings):
+// - flags enums (`ImGuiWindowFlags_`...) are newtypes over the raw int with the bit operators
+// - the other enums (`ImGuiKey`, `ImGuiCol_`, `ImGuiStyleVar_`...) are Rust enums
+// Names drop the ImGui/Im prefix: `ImGuiConfigFlags_NavEnableKeyboard` is `ConfigFlags::NavEnableKeyboard`.
+
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+// The docs are the imgui comments, which aren't markdown
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::broken_intra_doc_links)]
+
+// Flags newtype, `pub` field for the raw value
+macro_rules! flags {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident: $raw:ty {
+            $(
+                $(#[$const_meta:meta])*
+                const $const:ident = $value:literal;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+        pub struct $name(pub $raw);
+
+        impl $name {
+            $(
+                $(#[$const_meta])*
+                pub const $const: Self = Self($value);
+            )*
+
+            /// Flags from a raw value, unknown bits are kept
+            #[inline]
+            pub const fn from_bits(bits: $raw) -> Self {
+                Self(bits)
+            }
+
+            /// Raw value, to pass to the C API
+            #[inline]
+            pub const fn bits(self) -> $raw {
+                self.0
+            }
+
+            /// No flag set
+            #[inline]
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// All the flags of `other` are set
+            #[inline]
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Any flag of `other` is set
+            #[inline]
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            /// `self | other`, usable in constants
+            #[inline]
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+        }
+
+        impl From<$raw> for $name {
+            #[inline]
+            fn from(bits: $raw) -> Self {
+                Self(bits)
+            }
+        }
+
+        impl From<$name> for $raw {
+            #[inline]
+            fn from(flags: $name) -> Self {
+                flags.0
+            }
+        }
+
+        impl core::ops::BitOr for $name {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl core::ops::BitOrAssign for $name {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl core::ops::BitAnd for $name {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl core::ops::BitAndAssign for $name {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+
+        impl core::ops::BitXor for $name {
+            type Output = Self;
+            #[inline]
+            fn bitxor(self, rhs: Self) -> Self {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl core::ops::BitXorAssign for $name {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: Self) {
+                self.0 ^= rhs.0;
+            }
+        }
+
+        impl core::ops::Not for $name {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self {
+                Self(!self.0)
+            }
+        }
+
+        // Raw fields can be updated in place: `(*io).ConfigFlags |= ConfigFlags::NavEnableKeyboard`
+        impl core::ops::BitOrAssign<$name> for $raw {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: $name) {
+                *self |= rhs.0;
+            }
+        }
+
+        impl core::ops::BitAndAssign<$name> for $raw {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: $name) {
+                *self &= rhs.0;
+            }
+        }
+    };
+}
+
+// Rust enum with conversions from/to the raw value
+macro_rules! enumeration {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $raw:ty {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(i32)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant = $value,
+            )*
+        }
+
+        impl $name {
+            /// Variant of a raw value, `None` if the value isn't one of the variants
+            #[inline]
+            pub const fn from_raw(raw: $raw) -> Option<Self> {
+                match raw {
+                    $($value => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// Raw value, to pass to the C API
+            #[inline]
+            pub const fn raw(self) -> $raw {
+                self as $raw
+            }
+        }
+
+        impl TryFrom<$raw> for $name {
+            type Error = $raw;
+
+            /// Fails with the raw value if it isn't one of the variants
+            #[inline]
+            fn try_from(raw: $raw) -> Result<Self, $raw> {
+                Self::from_raw(raw).ok_or(raw)
+            }
+        }
+
+        impl From<$name> for $raw {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.raw()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "bindgen")]
+include!(concat!(env!("OUT_DIR"), "/enums.rs"));
+#[cfg(not(feature = "bindgen"))]
+include!(concat!(env!("IMGUI_SDL3_SYS_BINDINGS_DIR"), "/enums.rs"));
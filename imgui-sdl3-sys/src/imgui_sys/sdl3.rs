@@ -1,4 +1,4 @@
-// SDL3 backend bindings (both renderer and GPU)
+// SDL3 backend bindings (platform, GPU and SDL_Renderer)
 
 // Re-export SDL3 types from sdl3-sys for convenience
 // Users can work with the same types across imgui and SDL3
@@ -19,14 +19,88 @@ pub use crate::sdl3_sys::everything::{
 };
 
 // Re-export ImGui types needed by the backends
-pub use super::{ImDrawData, ImTextureData};
+pub use super::{ImDrawData, ImGuiViewport, ImTextureData};
 
 // SDL3 renderer backend bindings
-#[path = "../bindings/sdl3_backend.rs"]
-mod renderer_backend;
+mod renderer_backend {
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    #![allow(dead_code)]
+    #![allow(clippy::all)]
+    #[cfg(feature = "bindgen")]
+    include!(concat!(env!("OUT_DIR"), "/sdl3_backend.rs"));
+    #[cfg(not(feature = "bindgen"))]
+    include!(concat!(env!("IMGUI_SDL3_SYS_BINDINGS_DIR"), "/sdl3_backend.rs"));
+}
 pub use renderer_backend::*;
 
 // SDL3 GPU backend bindings
-#[path = "../bindings/sdlgpu3_backend.rs"]
-mod gpu_backend;
+mod gpu_backend {
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    #![allow(dead_code)]
+    #![allow(clippy::all)]
+    #![allow(unsafe_op_in_unsafe_fn)]
+    #[cfg(feature = "bindgen")]
+    include!(concat!(env!("OUT_DIR"), "/sdlgpu3_backend.rs"));
+    #[cfg(not(feature = "bindgen"))]
+    include!(concat!(env!("IMGUI_SDL3_SYS_BINDINGS_DIR"), "/sdlgpu3_backend.rs"));
+}
 pub use gpu_backend::*;
+
+// SDL3 SDL_Renderer backend bindings
+mod sdlrenderer_backend {
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    #![allow(dead_code)]
+    #![allow(clippy::all)]
+    #[cfg(feature = "bindgen")]
+    include!(concat!(env!("OUT_DIR"), "/sdlrenderer3_backend.rs"));
+    #[cfg(not(feature = "bindgen"))]
+    include!(concat!(env!("IMGUI_SDL3_SYS_BINDINGS_DIR"), "/sdlrenderer3_backend.rs"));
+}
+pub use sdlrenderer_backend::*;
+
+// SDL_GPU draw callback helpers
+// The render state is only reachable while cImGui_ImplSDLGPU3_RenderDrawData() runs, so changing the sampler
+// has to be done from a draw callback recorded in the draw list.
+
+/// Adds a draw callback to `draw_list` that makes the SDL_GPU backend sample the following textures with `sampler`,
+/// e.g. a nearest-neighbour sampler for pixel-art previews. Restore the default one with
+/// [`sdlgpu3_add_reset_sampler_callback`].
+///
+/// # Safety
+/// `draw_list` must be a valid draw list of the current frame, and `sampler` must stay alive until the draw data
+/// has been rendered.
+pub unsafe fn sdlgpu3_add_set_sampler_callback(draw_list: *mut super::ImDrawList, sampler: *mut SDL_GPUSampler) {
+    unsafe { super::ImDrawList_AddCallback(draw_list, Some(sdlgpu3_set_sampler_callback), sampler.cast()) };
+}
+
+/// Adds a draw callback to `draw_list` that restores the default sampler (bilinear filtering) of the SDL_GPU backend.
+///
+/// # Safety
+/// `draw_list` must be a valid draw list of the current frame.
+pub unsafe fn sdlgpu3_add_reset_sampler_callback(draw_list: *mut super::ImDrawList) {
+    unsafe { super::ImDrawList_AddCallback(draw_list, Some(sdlgpu3_reset_sampler_callback), core::ptr::null_mut()) };
+}
+
+unsafe extern "C" fn sdlgpu3_set_sampler_callback(_parent_list: *const super::ImDrawList, cmd: *const super::ImDrawCmd) {
+    unsafe {
+        let render_state = cImGui_ImplSDLGPU3_GetRenderState();
+        if !render_state.is_null() {
+            (*render_state).SamplerCurrent = (*cmd).UserCallbackData.cast();
+        }
+    }
+}
+
+unsafe extern "C" fn sdlgpu3_reset_sampler_callback(_parent_list: *const super::ImDrawList, _cmd: *const super::ImDrawCmd) {
+    unsafe {
+        let render_state = cImGui_ImplSDLGPU3_GetRenderState();
+        if !render_state.is_null() {
+            (*render_state).SamplerCurrent = (*render_state).SamplerDefault;
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// Slice access to the ImVector_* structs
+//
+// bindgen generates a struct per ImVector instantiation (`ImVector_ImDrawCmd`, `ImVector_ImWchar`...) with only the
+// Size/Capacity/Data fields. The build script appends an `im_vector!` invocation listing them to the bindings, which
+// implements ImVector for each of them.
+
+use core::ffi::c_int;
+use core::slice;
+
+/// Slice access to an `ImVector_*` struct of the bindings, e.g. `unsafe { (*draw_list).CmdBuffer.as_slice() }`.
+///
+/// The structs are `Copy` with public fields, so nothing guarantees that `Data`/`Size` describe a valid buffer: the
+/// accessors building a slice from them are unsafe.
+pub trait ImVector {
+    /// Element type
+    type Item;
+
+    /// Number of elements (`Size`)
+    fn len(&self) -> usize;
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Elements of the vector, empty if it was never allocated.
+    ///
+    /// # Safety
+    ///
+    /// `Data` must be null or point to `Size` initialized elements, not modified elsewhere while the slice is
+    /// borrowed, as in the vectors owned by a live Dear ImGui context.
+    unsafe fn as_slice(&self) -> &[Self::Item];
+
+    /// Mutable elements of the vector, empty if it was never allocated.
+    ///
+    /// The size can't change, Dear ImGui's containers are resized by the C++ side.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`as_slice`](Self::as_slice), and the elements must not be accessed elsewhere while the slice is
+    /// borrowed.
+    unsafe fn as_mut_slice(&mut self) -> &mut [Self::Item];
+
+    /// # Safety
+    ///
+    /// See [`as_slice`](Self::as_slice).
+    #[inline]
+    unsafe fn iter(&self) -> slice::Iter<'_, Self::Item> {
+        unsafe { self.as_slice() }.iter()
+    }
+
+    /// # Safety
+    ///
+    /// See [`as_mut_slice`](Self::as_mut_slice).
+    #[inline]
+    unsafe fn iter_mut(&mut self) -> slice::IterMut<'_, Self::Item> {
+        unsafe { self.as_mut_slice() }.iter_mut()
+    }
+}
+
+// The data pointer is null until the first allocation
+#[inline]
+pub(super) unsafe fn slice_from_raw<'a, T>(data: *const T, size: c_int) -> &'a [T] {
+    if data.is_null() || size <= 0 { &[] } else { unsafe { slice::from_raw_parts(data, size as usize) } }
+}
+
+#[inline]
+pub(super) unsafe fn slice_from_raw_mut<'a, T>(data: *mut T, size: c_int) -> &'a mut [T] {
+    if data.is_null() || size <= 0 { &mut [] } else { unsafe { slice::from_raw_parts_mut(data, size as usize) } }
+}
+
+// Invoked at the end of the generated bindings, with the element type of each ImVector struct
+macro_rules! im_vector {
+    ($($vector:ident => $item:ty;)*) => {
+        $(
+            impl $crate::imgui_sys::ImVector for $vector {
+                type Item = $item;
+
+                #[inline]
+                fn len(&self) -> usize {
+                    self.Size.max(0) as usize
+                }
+
+                #[inline]
+                unsafe fn as_slice(&self) -> &[$item] {
+                    unsafe { $crate::imgui_sys::vector::slice_from_raw(self.Data, self.Size) }
+                }
+
+                #[inline]
+                unsafe fn as_mut_slice(&mut self) -> &mut [$item] {
+                    unsafe { $crate::imgui_sys::vector::slice_from_raw_mut(self.Data, self.Size) }
+                }
+            }
+        )*
+    };
+}
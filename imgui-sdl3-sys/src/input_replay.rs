@@ -0,0 +1,463 @@
+// Input recording and replay for the SDL3 platform backend
+//
+// The recorder captures the SDL events given to cImGui_ImplSDL3_ProcessEvent() and the frame boundaries with their
+// delta time, the player feeds them back frame by frame through cImGui_ImplSDL3_ProcessEventForMainWindow(), so a
+// session recorded in an application can be replayed in a test with another (hidden) window.
+//
+// Only the events the backend reads are recorded, with only the fields it reads: there's no timestamp or window ID,
+// and the text of the text input events is stored in the file.
+//
+// File format, little endian:
+// - header: the "IMRP" magic and a version byte
+// - records: a tag byte and the fields of the record, see the TAG_* constants
+// A frame record ends the events of a frame, the events recorded after the last frame aren't replayed.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::ffi::c_char;
+use core::fmt;
+
+use crate::imgui_sys::{self, sdl3};
+use crate::reader::{self, HeaderError, InvalidData, Reader};
+use crate::sdl3_sys::everything::*;
+
+const MAGIC: &[u8; 4] = b"IMRP";
+const VERSION: u8 = 1;
+
+// delta_time: f32
+const TAG_FRAME: u8 = 0;
+// which: u32, x: f32, y: f32
+const TAG_MOUSE_MOTION: u8 = 1;
+// which: u32, x: f32, y: f32
+const TAG_MOUSE_WHEEL: u8 = 2;
+// which: u32, button: u8, down: u8, clicks: u8, x: f32, y: f32
+const TAG_MOUSE_BUTTON: u8 = 3;
+// which: u32, scancode: i32, key: u32, mod: u16, raw: u16, down: u8, repeat: u8
+const TAG_KEY: u8 = 4;
+// length: u16, UTF-8 text, nul terminator
+const TAG_TEXT_INPUT: u8 = 5;
+// type: u32, data1: i32, data2: i32
+const TAG_WINDOW: u8 = 6;
+// type: u32, which: u32
+const TAG_GAMEPAD_DEVICE: u8 = 7;
+
+/// Records the SDL events of the SDL3 platform backend and the frame boundaries, into the replay format of
+/// [`InputPlayer`].
+///
+/// Use [`process_event`](Self::process_event) and [`new_frame`](Self::new_frame) in place of
+/// `cImGui_ImplSDL3_ProcessEvent` and `cImGui_ImplSDL3_NewFrame`, then save [`as_bytes`](Self::as_bytes) to a file.
+#[derive(Clone)]
+pub struct InputRecorder {
+    data: Vec<u8>,
+}
+
+impl InputRecorder {
+    pub fn new() -> Self {
+        let mut data = Vec::with_capacity(4096);
+        data.extend_from_slice(MAGIC);
+        data.push(VERSION);
+        Self { data }
+    }
+
+    /// Records `event` and passes it to `cImGui_ImplSDL3_ProcessEvent`, returning its result.
+    ///
+    /// # Safety
+    ///
+    /// The SDL3 platform backend must be initialized for the current context.
+    pub unsafe fn process_event(&mut self, event: &SDL_Event) -> bool {
+        self.record_event(event);
+        unsafe { sdl3::cImGui_ImplSDL3_ProcessEvent(event) }
+    }
+
+    /// Calls `cImGui_ImplSDL3_NewFrame` and records the end of the frame events with the delta time it set.
+    ///
+    /// # Safety
+    ///
+    /// The SDL3 platform backend must be initialized for the current context.
+    pub unsafe fn new_frame(&mut self) {
+        unsafe {
+            sdl3::cImGui_ImplSDL3_NewFrame();
+            self.record_frame((*imgui_sys::igGetIO()).DeltaTime);
+        }
+    }
+
+    /// Records `event` without processing it. Returns false for the event types the backend ignores, which
+    /// aren't recorded.
+    pub fn record_event(&mut self, event: &SDL_Event) -> bool {
+        let event_type = SDL_EventType(unsafe { event.r#type });
+        match event_type {
+            SDL_EVENT_MOUSE_MOTION => {
+                let motion = unsafe { &event.motion };
+                self.data.push(TAG_MOUSE_MOTION);
+                self.put_u32(motion.which.0);
+                self.put_f32(motion.x);
+                self.put_f32(motion.y);
+            }
+            SDL_EVENT_MOUSE_WHEEL => {
+                let wheel = unsafe { &event.wheel };
+                self.data.push(TAG_MOUSE_WHEEL);
+                self.put_u32(wheel.which.0);
+                self.put_f32(wheel.x);
+                self.put_f32(wheel.y);
+            }
+            SDL_EVENT_MOUSE_BUTTON_DOWN | SDL_EVENT_MOUSE_BUTTON_UP => {
+                let button = unsafe { &event.button };
+                self.data.push(TAG_MOUSE_BUTTON);
+                self.put_u32(button.which.0);
+                self.data.extend_from_slice(&[button.button, (event_type == SDL_EVENT_MOUSE_BUTTON_DOWN) as u8, button.clicks]);
+                self.put_f32(button.x);
+                self.put_f32(button.y);
+            }
+            SDL_EVENT_KEY_DOWN | SDL_EVENT_KEY_UP => {
+                let key = unsafe { &event.key };
+                self.data.push(TAG_KEY);
+                self.put_u32(key.which.0);
+                self.put_u32(key.scancode.0 as u32);
+                self.put_u32(key.key.0);
+                self.put_u16(key.r#mod.0);
+                self.put_u16(key.raw);
+                self.data.extend_from_slice(&[(event_type == SDL_EVENT_KEY_DOWN) as u8, key.repeat as u8]);
+            }
+            SDL_EVENT_TEXT_INPUT => {
+                let text = unsafe { event.text.text };
+                if text.is_null() {
+                    return false;
+                }
+                let text = unsafe { core::ffi::CStr::from_ptr(text) }.to_bytes();
+                // Longer texts are split, the backend adds the characters one after the other anyway
+                for chunk in text_chunks(text) {
+                    self.data.push(TAG_TEXT_INPUT);
+                    self.put_u16(chunk.len() as u16);
+                    self.data.extend_from_slice(chunk);
+                    self.data.push(0);
+                }
+            }
+            SDL_EVENT_GAMEPAD_ADDED | SDL_EVENT_GAMEPAD_REMOVED => {
+                self.data.push(TAG_GAMEPAD_DEVICE);
+                self.put_u32(event_type.0);
+                self.put_u32(unsafe { event.gdevice.which.0 });
+            }
+            _ if (SDL_EVENT_WINDOW_FIRST.0..=SDL_EVENT_WINDOW_LAST.0).contains(&event_type.0) => {
+                let window = unsafe { &event.window };
+                self.data.push(TAG_WINDOW);
+                self.put_u32(event_type.0);
+                self.put_u32(window.data1 as u32);
+                self.put_u32(window.data2 as u32);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Records the end of the events of a frame, which lasted `delta_time` seconds.
+    pub fn record_frame(&mut self, delta_time: f32) {
+        self.data.push(TAG_FRAME);
+        self.put_f32(delta_time);
+    }
+
+    /// The recording, to save to a file.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+
+    fn put_u16(&mut self, value: u16) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn put_u32(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn put_f32(&mut self, value: f32) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+impl Default for InputRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for InputRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputRecorder").field("len", &self.data.len()).finish_non_exhaustive()
+    }
+}
+
+// Splits a text into chunks of at most u16::MAX bytes, without cutting the UTF-8 sequences
+fn text_chunks(mut text: &[u8]) -> impl Iterator<Item = &[u8]> {
+    core::iter::from_fn(move || {
+        if text.is_empty() {
+            return None;
+        }
+        let mut len = text.len().min(u16::MAX as usize);
+        // Back to the start of a sequence, before the 0b10xxxxxx continuation bytes
+        while len > 1 && len < text.len() && text[len] & 0xC0 == 0x80 {
+            len -= 1;
+        }
+        let (chunk, rest) = text.split_at(len);
+        text = rest;
+        Some(chunk)
+    })
+}
+
+/// Error of [`InputPlayer`] reading a recording.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The data doesn't start with the header of a recording.
+    NotARecording,
+    /// The recording was made by a newer version.
+    UnsupportedVersion(u8),
+    /// The recording is truncated or corrupted at the given byte offset.
+    InvalidData(usize),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotARecording => write!(f, "not an input recording"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported input recording version {version}"),
+            Self::InvalidData(offset) => write!(f, "invalid input recording data at offset {offset}"),
+        }
+    }
+}
+
+impl core::error::Error for ReplayError {}
+
+impl From<HeaderError> for ReplayError {
+    fn from(error: HeaderError) -> Self {
+        match error {
+            HeaderError::Magic => Self::NotARecording,
+            HeaderError::Version(version) => Self::UnsupportedVersion(version),
+        }
+    }
+}
+
+impl From<InvalidData> for ReplayError {
+    fn from(InvalidData(offset): InvalidData) -> Self {
+        Self::InvalidData(offset)
+    }
+}
+
+/// Reads back a recording of [`InputRecorder`], frame by frame.
+///
+/// ```no_run
+/// # use imgui_sdl3_sys::input_replay::InputPlayer;
+/// # fn replay(recording: &[u8]) -> Result<(), imgui_sdl3_sys::input_replay::ReplayError> {
+/// let mut player = InputPlayer::new(recording)?;
+/// while let Some(frame) = player.next_frame()? {
+///     unsafe {
+///         frame.process();
+///         imgui_sdl3_sys::imgui_sys::sdl3::cImGui_ImplSDL3_NewFrame();
+///         imgui_sdl3_sys::imgui_sys::igNewFrame();
+///     }
+///     // Build the UI and render...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The first processed frame saves the fixed delta time of the backend, the player restores it at the end of the
+/// recording or when it's dropped.
+#[derive(Debug)]
+pub struct InputPlayer<'a> {
+    data: &'a [u8],
+    pos: usize,
+    // Fixed delta time before the first processed frame
+    saved_delta_time: Cell<Option<f32>>,
+}
+
+impl<'a> InputPlayer<'a> {
+    /// Checks the header of `data`, a recording saved from [`InputRecorder::as_bytes`].
+    pub fn new(data: &'a [u8]) -> Result<Self, ReplayError> {
+        let pos = reader::check_header(data, MAGIC, VERSION)?;
+        Ok(Self { data, pos, saved_delta_time: Cell::new(None) })
+    }
+
+    /// Reads the events of the next frame, `None` at the end of the recording.
+    pub fn next_frame(&mut self) -> Result<Option<ReplayFrame<'_>>, ReplayError> {
+        let mut events = Vec::new();
+        let mut reader = Reader::new(self.data, self.pos);
+        while reader.remaining() > 0 {
+            let tag = reader.u8()?;
+            let mut event = SDL_Event::default();
+            match tag {
+                TAG_FRAME => {
+                    let delta_time = reader.f32()?;
+                    self.pos = reader.pos();
+                    return Ok(Some(ReplayFrame { delta_time, events, saved_delta_time: &self.saved_delta_time }));
+                }
+                TAG_MOUSE_MOTION => {
+                    event.motion = SDL_MouseMotionEvent {
+                        r#type: SDL_EVENT_MOUSE_MOTION,
+                        which: SDL_MouseID(reader.u32()?),
+                        x: reader.f32()?,
+                        y: reader.f32()?,
+                        ..Default::default()
+                    };
+                }
+                TAG_MOUSE_WHEEL => {
+                    event.wheel = SDL_MouseWheelEvent {
+                        r#type: SDL_EVENT_MOUSE_WHEEL,
+                        which: SDL_MouseID(reader.u32()?),
+                        x: reader.f32()?,
+                        y: reader.f32()?,
+                        ..Default::default()
+                    };
+                }
+                TAG_MOUSE_BUTTON => {
+                    let which = SDL_MouseID(reader.u32()?);
+                    let button = reader.u8()?;
+                    let down = reader.bool()?;
+                    event.button = SDL_MouseButtonEvent {
+                        r#type: if down { SDL_EVENT_MOUSE_BUTTON_DOWN } else { SDL_EVENT_MOUSE_BUTTON_UP },
+                        which,
+                        button,
+                        down,
+                        clicks: reader.u8()?,
+                        x: reader.f32()?,
+                        y: reader.f32()?,
+                        ..Default::default()
+                    };
+                }
+                TAG_KEY => {
+                    let which = SDL_KeyboardID(reader.u32()?);
+                    let scancode = SDL_Scancode(reader.u32()? as i32);
+                    let key = SDL_Keycode(reader.u32()?);
+                    let r#mod = SDL_Keymod(reader.u16()?);
+                    let raw = reader.u16()?;
+                    let down = reader.bool()?;
+                    event.key = SDL_KeyboardEvent {
+                        r#type: if down { SDL_EVENT_KEY_DOWN } else { SDL_EVENT_KEY_UP },
+                        which,
+                        scancode,
+                        key,
+                        r#mod,
+                        raw,
+                        down,
+                        repeat: reader.bool()?,
+                        ..Default::default()
+                    };
+                }
+                TAG_TEXT_INPUT => {
+                    let len = reader.u16()? as usize;
+                    let start = reader.pos();
+                    let text = reader.bytes(len + 1)?;
+                    if text[len] != 0 {
+                        return Err(ReplayError::InvalidData(start + len));
+                    }
+                    // Points into the recording, borrowed by the frame
+                    event.text = SDL_TextInputEvent {
+                        r#type: SDL_EVENT_TEXT_INPUT,
+                        text: text.as_ptr() as *const c_char,
+                        ..Default::default()
+                    };
+                }
+                TAG_WINDOW => {
+                    let start = reader.pos();
+                    let event_type = reader.u32()?;
+                    if !(SDL_EVENT_WINDOW_FIRST.0..=SDL_EVENT_WINDOW_LAST.0).contains(&event_type) {
+                        return Err(ReplayError::InvalidData(start));
+                    }
+                    event.window = SDL_WindowEvent {
+                        r#type: SDL_EventType(event_type),
+                        data1: reader.i32()?,
+                        data2: reader.i32()?,
+                        ..Default::default()
+                    };
+                }
+                TAG_GAMEPAD_DEVICE => {
+                    let start = reader.pos();
+                    let event_type = SDL_EventType(reader.u32()?);
+                    if event_type != SDL_EVENT_GAMEPAD_ADDED && event_type != SDL_EVENT_GAMEPAD_REMOVED {
+                        return Err(ReplayError::InvalidData(start));
+                    }
+                    event.gdevice = SDL_GamepadDeviceEvent {
+                        r#type: event_type,
+                        which: SDL_JoystickID(reader.u32()?),
+                        ..Default::default()
+                    };
+                }
+                _ => return Err(ReplayError::InvalidData(reader.pos() - 1)),
+            }
+            events.push(event);
+        }
+        self.pos = reader.pos();
+        self.restore_delta_time();
+        Ok(None)
+    }
+
+    fn restore_delta_time(&self) {
+        if let Some(delta_time) = self.saved_delta_time.take() {
+            // Only saved by `ReplayFrame::process`, whose caller guarantees the backend can be used here
+            unsafe { sdl3::cImGui_ImplSDL3_SetDeltaTime(delta_time) };
+        }
+    }
+}
+
+impl Drop for InputPlayer<'_> {
+    fn drop(&mut self) {
+        self.restore_delta_time();
+    }
+}
+
+/// The events and delta time of a recorded frame, from [`InputPlayer::next_frame`].
+///
+/// The events have no timestamp, and their window ID is 0: they're processed for the main window by
+/// [`process`](Self::process). The text of the text input events points into the recording.
+pub struct ReplayFrame<'a> {
+    delta_time: f32,
+    // The text input events point into the recording, which outlives the player
+    events: Vec<SDL_Event>,
+    saved_delta_time: &'a Cell<Option<f32>>,
+}
+
+impl ReplayFrame<'_> {
+    /// Time the frame lasted when it was recorded, in seconds.
+    pub fn delta_time(&self) -> f32 {
+        self.delta_time
+    }
+
+    /// Events given to the backend before the frame, in order.
+    pub fn events(&self) -> &[SDL_Event] {
+        &self.events
+    }
+
+    /// Feeds the frame to the SDL3 platform backend: processes the events with
+    /// `cImGui_ImplSDL3_ProcessEventForMainWindow` and sets the recorded delta time with
+    /// `cImGui_ImplSDL3_SetDeltaTime`. Call `cImGui_ImplSDL3_NewFrame` next.
+    ///
+    /// The player restores the previous fixed delta time at the end of the recording, or when it's dropped.
+    ///
+    /// # Safety
+    ///
+    /// The SDL3 platform backend must be initialized for the current context, and the player must reach the end of
+    /// the recording or be dropped on this thread.
+    pub unsafe fn process(&self) {
+        unsafe {
+            for event in &self.events {
+                sdl3::cImGui_ImplSDL3_ProcessEventForMainWindow(event);
+            }
+            if self.saved_delta_time.get().is_none() {
+                self.saved_delta_time.set(Some(sdl3::cImGui_ImplSDL3_GetDeltaTime()));
+            }
+            sdl3::cImGui_ImplSDL3_SetDeltaTime(self.delta_time);
+        }
+    }
+}
+
+impl fmt::Debug for ReplayFrame<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReplayFrame")
+            .field("delta_time", &self.delta_time)
+            .field("events", &self.events.len())
+            .finish()
+    }
+}
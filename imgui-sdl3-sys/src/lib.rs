@@ -4,6 +4,45 @@
 // ImGui bindings module - contains core ImGui and all backends
 pub mod imgui_sys;
 
+// Safe wrappers on top of the raw bindings
+#[cfg(feature = "safe")]
+#[cfg_attr(all(feature = "nightly", doc), doc(cfg(feature = "safe")))]
+pub mod safe;
+
+// Software renderer backend, rasterizing the draw data into an image on the CPU
+#[cfg(feature = "software-renderer")]
+#[cfg_attr(all(feature = "nightly", doc), doc(cfg(feature = "software-renderer")))]
+pub mod software_renderer;
+
+// Recording and replay of the SDL3 platform backend events
+#[cfg(feature = "input-replay")]
+#[cfg_attr(all(feature = "nightly", doc), doc(cfg(feature = "input-replay")))]
+pub mod input_replay;
+
+// Draw data snapshots for snapshot tests
+#[cfg(feature = "draw-snapshot")]
+#[cfg_attr(all(feature = "nightly", doc), doc(cfg(feature = "draw-snapshot")))]
+pub mod draw_snapshot;
+
+// Remote UI over TCP: the draw data streamed to a viewer, its inputs sent back
+#[cfg(feature = "remote")]
+#[cfg_attr(all(feature = "nightly", doc), doc(cfg(feature = "remote")))]
+pub mod remote;
+
+// Dear ImGui allocations through the Rust global allocator, with statistics per context
+#[cfg(feature = "rust-allocator")]
+#[cfg_attr(all(feature = "nightly", doc), doc(cfg(feature = "rust-allocator")))]
+pub mod rust_allocator;
+
+// Dear ImGui assertions (IM_ASSERT) routed to a Rust handler, panicking by default
+#[cfg(feature = "assert-hook")]
+#[cfg_attr(all(feature = "nightly", doc), doc(cfg(feature = "assert-hook")))]
+pub mod assert_hook;
+
+// Reader shared by the binary formats of the features above
+#[cfg(any(feature = "input-replay", feature = "draw-snapshot", feature = "remote"))]
+mod reader;
+
 // Re-export sdl3-sys so users don't need to add it as a separate dependency
 // This ensures version compatibility between imgui-sdl3-sys and user code
 pub use sdl3_sys;
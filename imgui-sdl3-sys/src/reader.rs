@@ -0,0 +1,114 @@
+// Reader of the little endian binary formats: input recordings, draw data snapshots and remote UI messages
+
+// Each format uses a part of it
+#![allow(dead_code)]
+
+use core::mem::size_of;
+
+use crate::imgui_sys::ImDrawIdx;
+
+// Offset of the truncated or corrupted data, converted to the error type of each format
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct InvalidData(pub(crate) usize);
+
+// Error of `check_header`
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum HeaderError {
+    Magic,
+    Version(u8),
+}
+
+// Checks the magic and the version byte at the start of `data`, returns the offset after them
+pub(crate) fn check_header(data: &[u8], magic: &[u8; 4], version: u8) -> Result<usize, HeaderError> {
+    if data.len() < magic.len() + 1 || &data[..magic.len()] != magic {
+        return Err(HeaderError::Magic);
+    }
+    match data[magic.len()] {
+        data_version if data_version == version => Ok(magic.len() + 1),
+        data_version => Err(HeaderError::Version(data_version)),
+    }
+}
+
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8], pos: usize) -> Self {
+        Self { data, pos }
+    }
+
+    pub(crate) fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Result<&'a [u8], InvalidData> {
+        let bytes = self.data.get(self.pos..self.pos.saturating_add(len)).ok_or(InvalidData(self.pos))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, InvalidData> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub(crate) fn bool(&mut self) -> Result<bool, InvalidData> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(InvalidData(self.pos - 1)),
+        }
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, InvalidData> {
+        Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, InvalidData> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn i32(&mut self) -> Result<i32, InvalidData> {
+        Ok(i32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, InvalidData> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn f32(&mut self) -> Result<f32, InvalidData> {
+        Ok(f32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn vec2(&mut self) -> Result<[f32; 2], InvalidData> {
+        Ok([self.f32()?, self.f32()?])
+    }
+
+    // An ImDrawIdx, 16 or 32 bits depending on the Dear ImGui build
+    pub(crate) fn index(&mut self) -> Result<ImDrawIdx, InvalidData> {
+        Ok(ImDrawIdx::from_le_bytes(self.bytes(size_of::<ImDrawIdx>())?.try_into().unwrap()))
+    }
+
+    // A count of items of at least `item_len` bytes, checked against the remaining bytes before allocating
+    pub(crate) fn count(&mut self, item_len: usize) -> Result<usize, InvalidData> {
+        let pos = self.pos;
+        let count = self.u32()? as usize;
+        if count.saturating_mul(item_len) > self.remaining() {
+            return Err(InvalidData(pos));
+        }
+        Ok(count)
+    }
+
+    // Checks that all the data was read
+    pub(crate) fn finish(self) -> Result<(), InvalidData> {
+        if self.pos != self.data.len() {
+            return Err(InvalidData(self.pos));
+        }
+        Ok(())
+    }
+}
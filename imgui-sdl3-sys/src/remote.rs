@@ -0,0 +1,96 @@
+// Remote UI: streams the draw data of a context to a viewer over TCP, the inputs of the viewer flow back
+//
+// Like netImgui, the application runs its UI without a window: `RemoteServer` is its platform and renderer backend.
+// Each frame, it sends the texture requests (create/update/destroy of the ImTextureData) and the draw data to the
+// connected viewer, and gives the inputs received from it to Dear ImGui. The viewer runs its own context with real
+// backends (the examples have one on SDL_GPU): it recreates the textures locally, draws the remote draw lists in
+// its background draw list, and sends its inputs with the `RemoteInput` of each frame.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::imgui_sys::{ImGuiIO, ImVector};
+use crate::imgui_sys::enums::Key;
+
+mod protocol;
+mod server;
+mod viewer;
+
+pub use server::RemoteServer;
+pub use viewer::RemoteViewer;
+
+/// Input state of the viewer for a frame, sent to the server with [`RemoteViewer::send_input`].
+///
+/// The server turns the differences with the previous state into input events, except for the wheel and the text
+/// which are the ones of this frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoteInput {
+    /// Display size of the viewer, in points, used as the display size of the server
+    pub display_size: [f32; 2],
+    /// Framebuffer pixels per point of the viewer
+    pub framebuffer_scale: [f32; 2],
+    /// Mouse position in points, `None` when the mouse isn't over the viewer
+    pub mouse_pos: Option<[f32; 2]>,
+    /// Left, right, middle and extra mouse buttons
+    pub mouse_down: [bool; 5],
+    /// Mouse wheel of the frame (horizontal, vertical), 1 being a notch
+    pub mouse_wheel: [f32; 2],
+    /// Named keys held down, without the mouse keys. The modifiers are `Key::ReservedForModCtrl`... like in `io.KeysData`, or `Key::Mod_Ctrl`...
+    pub keys_down: Vec<Key>,
+    /// Text typed during the frame
+    pub text: String,
+    /// Whether the viewer window has the focus
+    pub focused: bool,
+}
+
+impl RemoteInput {
+    /// Captures the input state of the viewer context from its IO, after `igNewFrame`.
+    ///
+    /// # Safety
+    ///
+    /// `io` must be the IO of a live context, its vectors are read through their pointers.
+    pub unsafe fn from_io(io: &ImGuiIO) -> Self {
+        let mouse_pos = io.MousePos;
+        // `igIsMousePosValid`
+        let mouse_valid = mouse_pos.x >= -256000.0 && mouse_pos.y >= -256000.0;
+        let keys_down = io
+            .KeysData
+            .iter()
+            .enumerate()
+            .filter(|(_, data)| data.Down)
+            .filter_map(|(i, _)| Key::from_raw(Key::NamedKey_BEGIN + i as i32))
+            // The mouse keys are aliases of the mouse state, which is sent on its own
+            .filter(|key| !(Key::MouseLeft.raw()..=Key::MouseWheelY.raw()).contains(&key.raw()))
+            .collect();
+        // With the default 16-bit ImWchar, Dear ImGui replaces the characters outside of the BMP, there are no surrogates
+        let text = unsafe { io.InputQueueCharacters.iter() }.filter_map(|&c| char::from_u32(c as u32)).collect();
+        Self {
+            display_size: [io.DisplaySize.x, io.DisplaySize.y],
+            framebuffer_scale: [io.DisplayFramebufferScale.x, io.DisplayFramebufferScale.y],
+            mouse_pos: mouse_valid.then_some([mouse_pos.x, mouse_pos.y]),
+            mouse_down: io.MouseDown,
+            mouse_wheel: [io.MouseWheelH, io.MouseWheel],
+            keys_down,
+            text,
+            focused: !io.AppFocusLost,
+        }
+    }
+}
+
+// No input: the state the server starts with, and goes back to when the viewer disconnects
+impl Default for RemoteInput {
+    fn default() -> Self {
+        Self {
+            display_size: [0.0; 2],
+            framebuffer_scale: [1.0; 2],
+            mouse_pos: None,
+            mouse_down: [false; 5],
+            mouse_wheel: [0.0; 2],
+            keys_down: Vec::new(),
+            text: String::new(),
+            focused: true,
+        }
+    }
+}
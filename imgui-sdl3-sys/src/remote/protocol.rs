@@ -0,0 +1,468 @@
+// Wire protocol of the remote UI, and the TCP connection exchanging its messages
+//
+// Messages are framed by their u32 length (tag included), followed by a tag byte and the payload, little endian.
+// Both ends start with a HELLO message, with the magic, the protocol version and the size of ImDrawIdx (u8), which
+// must be the same on both ends.
+//
+// Server to viewer:
+// - TEXTURE_CREATE: texture ID u64, format u32 (ImTextureFormat), width u32, height u32, all the pixels
+// - TEXTURE_UPDATE: texture ID u64, rect x/y/w/h u16, the pixels of the rect, rows top to bottom
+// - TEXTURE_DESTROY: texture ID u64
+// - FRAME: display pos and display size (2 f32 each), list count u32, then per list the vertex,
+//   index and command counts (u32 each), the vertices (pos, uv: 2 f32 each, col u32), the indices (ImDrawIdx), and the
+//   commands: element count u32, clip rect 4 f32, texture kind u8 (0: ImTextureData by ID, 1: user ImTextureID),
+//   texture u64, vertex offset u32, index offset u32
+// Viewer to server:
+// - INPUT: display size and framebuffer scale (2 f32 each), mouse pos flag u8 + 2 f32, mouse buttons bits u8,
+//   mouse wheel 2 f32, focused u8, key count u16 + keys i32 (ImGuiKey), text length u32 + UTF-8 text
+
+extern crate alloc;
+extern crate std;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem::size_of;
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::TcpStream;
+
+use super::RemoteInput;
+use crate::imgui_sys::enums::{Key, TextureFormat};
+use crate::imgui_sys::{ImDrawIdx, ImDrawVert, ImTextureID, ImVec2};
+use crate::reader::{InvalidData, Reader};
+use crate::safe::{DrawCmd, DrawData};
+
+const MAGIC: &[u8; 4] = b"IMRM";
+const VERSION: u8 = 1;
+
+const HELLO: u8 = 0;
+const TEXTURE_CREATE: u8 = 1;
+const TEXTURE_UPDATE: u8 = 2;
+const TEXTURE_DESTROY: u8 = 3;
+const FRAME: u8 = 4;
+const INPUT: u8 = 5;
+
+// Texture kinds of the FRAME commands
+const TEXTURE_MANAGED: u8 = 0;
+const TEXTURE_USER: u8 = 1;
+
+// Bigger than a 4096x4096 RGBA32 texture, smaller than what a corrupted length would allocate
+const MAX_MESSAGE_LEN: usize = 1 << 28;
+
+/// Texture of a remote draw command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum RemoteTexture {
+    /// An `ImTextureData` of the server, created on the viewer by a TEXTURE_CREATE message
+    Managed(u64),
+    /// A user texture ID, passed as is
+    User(ImTextureID),
+}
+
+pub(super) struct FrameCommand {
+    pub count: usize,
+    pub clip_rect: [f32; 4],
+    pub texture: RemoteTexture,
+    pub vtx_offset: usize,
+    pub idx_offset: usize,
+}
+
+pub(super) struct FrameList {
+    pub vertices: Vec<ImDrawVert>,
+    pub indices: Vec<ImDrawIdx>,
+    pub commands: Vec<FrameCommand>,
+}
+
+/// Draw data received by the viewer, the commands are checked to stay in their buffers.
+pub(super) struct Frame {
+    pub display_pos: [f32; 2],
+    pub display_size: [f32; 2],
+    pub lists: Vec<FrameList>,
+}
+
+pub(super) enum Message {
+    TextureCreate { id: u64, format: TextureFormat, width: u32, height: u32, pixels: Vec<u8> },
+    TextureUpdate { id: u64, rect: [u16; 4], pixels: Vec<u8> },
+    TextureDestroy { id: u64 },
+    Frame(Frame),
+    Input(RemoteInput),
+}
+
+pub(super) fn bytes_per_pixel(format: TextureFormat) -> usize {
+    match format {
+        TextureFormat::RGBA32 => 4,
+        TextureFormat::Alpha8 => 1,
+    }
+}
+
+pub(super) fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+impl From<InvalidData> for io::Error {
+    fn from(InvalidData(offset): InvalidData) -> Self {
+        io::Error::new(ErrorKind::InvalidData, format!("invalid remote UI message data at offset {offset}"))
+    }
+}
+
+// A message being written, its length is set by `finish`
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn new(tag: u8) -> Self {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.push(tag);
+        Self { bytes }
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn i32(&mut self, value: i32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn index(&mut self, value: ImDrawIdx) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn vec2(&mut self, value: ImVec2) {
+        self.f32(value.x);
+        self.f32(value.y);
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let len = (self.bytes.len() - 4) as u32;
+        self.bytes[..4].copy_from_slice(&len.to_le_bytes());
+        self.bytes
+    }
+}
+
+fn hello() -> Vec<u8> {
+    let mut writer = Writer::new(HELLO);
+    writer.bytes(MAGIC);
+    writer.u8(VERSION);
+    writer.u8(size_of::<ImDrawIdx>() as u8);
+    writer.finish()
+}
+
+pub(super) fn texture_create(id: u64, format: TextureFormat, width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    let mut writer = Writer::new(TEXTURE_CREATE);
+    writer.u64(id);
+    writer.u32(format.raw());
+    writer.u32(width);
+    writer.u32(height);
+    writer.bytes(pixels);
+    writer.finish()
+}
+
+/// `rows` gives the pixels of each row of the rect, top to bottom.
+pub(super) fn texture_update<'a>(id: u64, rect: [u16; 4], rows: impl Iterator<Item = &'a [u8]>) -> Vec<u8> {
+    let mut writer = Writer::new(TEXTURE_UPDATE);
+    writer.u64(id);
+    for value in rect {
+        writer.u16(value);
+    }
+    for row in rows {
+        writer.bytes(row);
+    }
+    writer.finish()
+}
+
+pub(super) fn texture_destroy(id: u64) -> Vec<u8> {
+    let mut writer = Writer::new(TEXTURE_DESTROY);
+    writer.u64(id);
+    writer.finish()
+}
+
+/// The draw data of the server, after its textures have an ID. Callbacks and render state resets are dropped,
+/// they only make sense to the renderer of the server.
+pub(super) fn frame(draw_data: &DrawData) -> Vec<u8> {
+    let mut writer = Writer::new(FRAME);
+    writer.vec2(draw_data.DisplayPos);
+    writer.vec2(draw_data.DisplaySize);
+    writer.u32(draw_data.lists().len() as u32);
+    for list in draw_data.lists() {
+        let commands: Vec<_> = list
+            .commands()
+            .zip(list.raw_commands())
+            .filter_map(|(cmd, raw)| match cmd {
+                DrawCmd::Elements { count, params } => Some((count, params, raw)),
+                DrawCmd::ResetRenderState | DrawCmd::Callback(_) => None,
+            })
+            .collect();
+        writer.u32(list.vertices().len() as u32);
+        writer.u32(list.indices().len() as u32);
+        writer.u32(commands.len() as u32);
+        for vertex in list.vertices() {
+            writer.vec2(vertex.pos);
+            writer.vec2(vertex.uv);
+            writer.u32(vertex.col);
+        }
+        for &index in list.indices() {
+            writer.index(index);
+        }
+        for (count, params, raw) in commands {
+            writer.u32(count as u32);
+            let clip = params.clip_rect;
+            for value in [clip.x, clip.y, clip.z, clip.w] {
+                writer.f32(value);
+            }
+            // The managed textures are identified by the TexID the server gave them
+            let kind = if raw.TexRef._TexData.is_null() { TEXTURE_USER } else { TEXTURE_MANAGED };
+            writer.u8(kind);
+            writer.u64(params.texture_id);
+            writer.u32(params.vtx_offset as u32);
+            writer.u32(params.idx_offset as u32);
+        }
+    }
+    writer.finish()
+}
+
+pub(super) fn input(input: &RemoteInput) -> Vec<u8> {
+    let mut writer = Writer::new(INPUT);
+    for value in input.display_size.into_iter().chain(input.framebuffer_scale) {
+        writer.f32(value);
+    }
+    writer.u8(input.mouse_pos.is_some() as u8);
+    for value in input.mouse_pos.unwrap_or_default() {
+        writer.f32(value);
+    }
+    let buttons = input.mouse_down.iter().enumerate().fold(0, |bits, (i, &down)| bits | ((down as u8) << i));
+    writer.u8(buttons);
+    for value in input.mouse_wheel {
+        writer.f32(value);
+    }
+    writer.u8(input.focused as u8);
+    writer.u16(input.keys_down.len() as u16);
+    for key in &input.keys_down {
+        writer.i32(key.raw());
+    }
+    writer.u32(input.text.len() as u32);
+    writer.bytes(input.text.as_bytes());
+    writer.finish()
+}
+
+fn parse(tag: u8, payload: &[u8]) -> io::Result<Message> {
+    let mut reader = Reader::new(payload, 0);
+    let message = match tag {
+        TEXTURE_CREATE => {
+            let id = reader.u64()?;
+            let format = TextureFormat::from_raw(reader.u32()?).ok_or_else(|| invalid_data("unknown texture format"))?;
+            let (width, height) = (reader.u32()?, reader.u32()?);
+            let len = (width as usize).saturating_mul(height as usize).saturating_mul(bytes_per_pixel(format));
+            let pixels = reader.bytes(len)?.to_vec();
+            Message::TextureCreate { id, format, width, height, pixels }
+        }
+        TEXTURE_UPDATE => {
+            let id = reader.u64()?;
+            let rect = [reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?];
+            // The pixel size depends on the texture, checked by the viewer
+            let pixels = reader.bytes(reader.remaining())?.to_vec();
+            Message::TextureUpdate { id, rect, pixels }
+        }
+        TEXTURE_DESTROY => Message::TextureDestroy { id: reader.u64()? },
+        FRAME => Message::Frame(parse_frame(&mut reader)?),
+        INPUT => Message::Input(parse_input(&mut reader)?),
+        _ => return Err(invalid_data("unknown remote UI message")),
+    };
+    reader.finish()?;
+    Ok(message)
+}
+
+fn parse_frame(reader: &mut Reader) -> io::Result<Frame> {
+    let display_pos = reader.vec2()?;
+    let display_size = reader.vec2()?;
+    let list_count = reader.count(12)?;
+    let mut lists = Vec::with_capacity(list_count);
+    for _ in 0..list_count {
+        let vertex_count = reader.u32()? as usize;
+        let index_count = reader.u32()? as usize;
+        let command_count = reader.u32()? as usize;
+        let len = vertex_count.saturating_mul(20).saturating_add(index_count.saturating_mul(size_of::<ImDrawIdx>()));
+        if len.saturating_add(command_count.saturating_mul(37)) > reader.remaining() {
+            return Err(invalid_data("truncated remote UI message"));
+        }
+        let mut vertices = Vec::with_capacity(vertex_count);
+        for _ in 0..vertex_count {
+            let [x, y] = reader.vec2()?;
+            let [u, v] = reader.vec2()?;
+            let col = reader.u32()?;
+            vertices.push(ImDrawVert { pos: ImVec2 { x, y }, uv: ImVec2 { x: u, y: v }, col });
+        }
+        let mut indices = Vec::with_capacity(index_count);
+        for _ in 0..index_count {
+            indices.push(reader.index()?);
+        }
+        let mut commands = Vec::with_capacity(command_count);
+        for _ in 0..command_count {
+            let count = reader.u32()? as usize;
+            let clip_rect = [reader.f32()?, reader.f32()?, reader.f32()?, reader.f32()?];
+            let texture = match (reader.u8()?, reader.u64()?) {
+                (TEXTURE_MANAGED, id) => RemoteTexture::Managed(id),
+                (TEXTURE_USER, id) => RemoteTexture::User(id),
+                _ => return Err(invalid_data("unknown texture kind")),
+            };
+            let vtx_offset = reader.u32()? as usize;
+            let idx_offset = reader.u32()? as usize;
+            // The viewer copies the commands without checking them again
+            let command_indices = indices
+                .get(idx_offset..idx_offset.saturating_add(count))
+                .ok_or_else(|| invalid_data("draw command outside of the index buffer"))?;
+            if let Some(&max) = command_indices.iter().max() {
+                if vtx_offset.saturating_add(max as usize) >= vertices.len() {
+                    return Err(invalid_data("draw command outside of the vertex buffer"));
+                }
+            }
+            commands.push(FrameCommand { count, clip_rect, texture, vtx_offset, idx_offset });
+        }
+        lists.push(FrameList { vertices, indices, commands });
+    }
+    Ok(Frame { display_pos, display_size, lists })
+}
+
+fn parse_input(reader: &mut Reader) -> io::Result<RemoteInput> {
+    let display_size = reader.vec2()?;
+    let framebuffer_scale = reader.vec2()?;
+    let has_mouse_pos = reader.u8()? != 0;
+    let mouse_pos = reader.vec2()?;
+    let buttons = reader.u8()?;
+    let mouse_wheel = reader.vec2()?;
+    let focused = reader.u8()? != 0;
+    let key_count = reader.u16()?;
+    let mut keys_down = Vec::with_capacity(key_count as usize);
+    for _ in 0..key_count {
+        let key = Key::from_raw(reader.i32()?).filter(|&key| is_input_key(key));
+        keys_down.push(key.ok_or_else(|| invalid_data("invalid key"))?);
+    }
+    let text_len = reader.count(1)?;
+    let text = String::from_utf8(reader.bytes(text_len)?.to_vec()).map_err(|_| invalid_data("text isn't UTF-8"))?;
+    Ok(RemoteInput {
+        display_size,
+        framebuffer_scale,
+        mouse_pos: has_mouse_pos.then_some(mouse_pos),
+        mouse_down: core::array::from_fn(|i| buttons & (1 << i) != 0),
+        mouse_wheel,
+        keys_down,
+        text,
+        focused,
+    })
+}
+
+// The keys Dear ImGui accepts in key events: named keys but the mouse aliases, and modifiers
+fn is_input_key(key: Key) -> bool {
+    let raw = key.raw();
+    let named = (Key::NamedKey_BEGIN..Key::NamedKey_END).contains(&raw);
+    let mouse = (Key::MouseLeft.raw()..=Key::MouseWheelY.raw()).contains(&raw);
+    let modifier = matches!(key, Key::Mod_Ctrl | Key::Mod_Shift | Key::Mod_Alt | Key::Mod_Super);
+    (named && !mouse) || modifier
+}
+
+/// A TCP connection exchanging the messages, reads don't block while writes do.
+pub(super) struct Connection {
+    stream: TcpStream,
+    received: Vec<u8>,
+    hello_received: bool,
+}
+
+impl Connection {
+    /// Sends the HELLO message, the one of the other end is checked by `receive`.
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        // The messages are written whole, no need to wait for more
+        stream.set_nodelay(true)?;
+        stream.set_nonblocking(false)?;
+        let mut connection = Self { stream, received: Vec::new(), hello_received: false };
+        connection.send(&hello())?;
+        Ok(connection)
+    }
+
+    pub fn send(&mut self, message: &[u8]) -> io::Result<()> {
+        self.stream.write_all(message)
+    }
+
+    /// Returns the complete messages received so far, without waiting for more.
+    ///
+    /// Fails when the other end closed the connection, or sent an invalid message.
+    pub fn receive(&mut self) -> io::Result<Vec<Message>> {
+        self.stream.set_nonblocking(true)?;
+        let result = self.read_available();
+        self.stream.set_nonblocking(false)?;
+        result?;
+
+        let mut messages = Vec::new();
+        let mut start = 0;
+        while let Some(len) = message_len(&self.received[start..])? {
+            let Some(message) = self.received.get(start + 4..start + 4 + len) else {
+                break;
+            };
+            let (tag, payload) = (message[0], &message[1..]);
+            if !self.hello_received {
+                if tag != HELLO || payload.get(..4) != Some(MAGIC) {
+                    return Err(invalid_data("not a remote UI connection"));
+                }
+                match payload[4..] {
+                    [VERSION, index_size] if index_size as usize == size_of::<ImDrawIdx>() => {}
+                    [VERSION, _] => return Err(invalid_data("remote UI ImDrawIdx size mismatch")),
+                    _ => return Err(invalid_data("unsupported remote UI protocol version")),
+                }
+                self.hello_received = true;
+            } else {
+                messages.push(parse(tag, payload)?);
+            }
+            start += 4 + len;
+        }
+        self.received.drain(..start);
+        Ok(messages)
+    }
+
+    // Reads until a whole message is pending: a peer sending faster than it's read, or an invalid length, doesn't
+    // make the buffer grow past a message
+    fn read_available(&mut self) -> io::Result<()> {
+        let mut buffer = [0; 64 * 1024];
+        while message_len(&self.received)?.is_none_or(|len| self.received.len() < 4 + len) {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => return Err(io::Error::new(ErrorKind::UnexpectedEof, "remote UI connection closed")),
+                Ok(len) => self.received.extend_from_slice(&buffer[..len]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+// Length of the message starting `received`, `None` until its length is received
+fn message_len(received: &[u8]) -> io::Result<Option<usize>> {
+    let Some(header) = received.get(..4) else {
+        return Ok(None);
+    };
+    let len = u32::from_le_bytes(header.try_into().unwrap()) as usize;
+    if len == 0 || len > MAX_MESSAGE_LEN {
+        return Err(invalid_data("invalid remote UI message length"));
+    }
+    Ok(Some(len))
+}
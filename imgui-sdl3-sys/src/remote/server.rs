@@ -0,0 +1,284 @@
+// Remote UI server: the platform and renderer backend of the application, without a window or a GPU
+
+extern crate alloc;
+extern crate std;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::{ptr, slice};
+use std::io;
+use std::net::{SocketAddr, TcpListener, ToSocketAddrs};
+use std::time::Instant;
+
+use super::RemoteInput;
+use super::protocol::{self, Connection, Message};
+use crate::imgui_sys::enums::{BackendFlags, Key, TextureFormat, TextureStatus};
+use crate::imgui_sys::{self, ImGuiIO, ImTextureData, ImVec2};
+use crate::safe::{Context, DrawData, Ui};
+
+/// Serves the UI of a context to a [`RemoteViewer`](super::RemoteViewer) over TCP.
+///
+/// It replaces both backends of the context: [`new_frame`](Self::new_frame) gives it the inputs of the viewer, and
+/// [`render`](Self::render) handles the texture requests and sends the draw data. Without a viewer, the frames run
+/// with the display size given to [`bind`](Self::bind) and no input. A single viewer is served at a time, the next
+/// one is accepted when it disconnects.
+///
+/// Dropping the server shuts both backends down: it clears their names and flags from the context and destroys the
+/// textures, which the next renderer creates again.
+pub struct RemoteServer {
+    context: u64,
+    listener: TcpListener,
+    connection: Option<Connection>,
+    // Whether the viewer has all the textures, they are sent on the first frame after it connects
+    synced: bool,
+    // The viewer disconnected, its inputs have to be released on the next frame
+    viewer_lost: bool,
+    input: RemoteInput,
+    display_size: [f32; 2],
+    framebuffer_scale: [f32; 2],
+    last_frame: Option<Instant>,
+    // 0 is `ImTextureID_Invalid`
+    next_texture_id: u64,
+    // Dropped on the thread of its context
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl RemoteServer {
+    /// Listens for a viewer on `addr`, and initializes the backends of `context` with a display of
+    /// `width` x `height` until a viewer gives its own.
+    ///
+    /// Use port 0 to let the OS pick one, see [`local_addr`](Self::local_addr).
+    pub fn bind(context: &Context, addr: impl ToSocketAddrs, width: f32, height: f32) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let _current = context.make_current();
+        unsafe {
+            let io = imgui_sys::igGetIO();
+            (*io).BackendPlatformName = c"imgui_sdl3_sys_remote".as_ptr();
+            (*io).BackendRendererName = c"imgui_sdl3_sys_remote".as_ptr();
+            (*io).BackendFlags |= BackendFlags::RendererHasVtxOffset | BackendFlags::RendererHasTextures;
+        }
+        Ok(Self {
+            context: context.id(),
+            listener,
+            connection: None,
+            synced: false,
+            viewer_lost: false,
+            input: RemoteInput::default(),
+            display_size: [width, height],
+            framebuffer_scale: [1.0, 1.0],
+            last_frame: None,
+            next_texture_id: 1,
+            _not_send: PhantomData,
+        })
+    }
+
+    /// Address the server listens on.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Returns true if a viewer is connected.
+    pub fn is_connected(&self) -> bool {
+        self.connection.is_some()
+    }
+
+    /// Accepts a viewer, gives the inputs it sent since the last frame to Dear ImGui, and starts a new frame,
+    /// see [`Context::new_frame`]. Doesn't wait for the viewer.
+    ///
+    /// The delta time is the time since the previous frame.
+    ///
+    /// # Panics
+    ///
+    /// If `context` isn't the one given to [`bind`](Self::bind).
+    pub fn new_frame<'ctx>(&mut self, context: &'ctx mut Context) -> Ui<'ctx> {
+        assert_eq!(context.id(), self.context, "the context isn't the one of the remote server");
+        if self.connection.is_none() {
+            if let Ok((stream, _)) = self.listener.accept() {
+                self.connection = Connection::new(stream).ok();
+            }
+        }
+        let mut inputs = Vec::new();
+        if let Some(connection) = &mut self.connection {
+            match connection.receive() {
+                Ok(messages) => inputs.extend(messages.into_iter().filter_map(|message| match message {
+                    Message::Input(input) => Some(input),
+                    _ => None,
+                })),
+                Err(_) => self.disconnect(),
+            }
+        }
+        if self.viewer_lost {
+            self.viewer_lost = false;
+            inputs.push(RemoteInput { display_size: self.display_size, ..RemoteInput::default() });
+        }
+
+        let now = Instant::now();
+        let delta_time = match self.last_frame {
+            // Dear ImGui asserts a positive delta time
+            Some(last_frame) => now.duration_since(last_frame).as_secs_f32().max(1.0e-6),
+            None => 1.0 / 60.0,
+        };
+        self.last_frame = Some(now);
+
+        {
+            let _current = context.make_current();
+            let io = unsafe { imgui_sys::igGetIO() };
+            for input in inputs {
+                unsafe { apply_input(io, &self.input, &input) };
+                if input.display_size[0] > 0.0 && input.display_size[1] > 0.0 {
+                    self.display_size = input.display_size;
+                    self.framebuffer_scale = input.framebuffer_scale;
+                }
+                self.input = input;
+            }
+            unsafe {
+                (*io).DisplaySize = ImVec2 { x: self.display_size[0], y: self.display_size[1] };
+                (*io).DisplayFramebufferScale = ImVec2 { x: self.framebuffer_scale[0], y: self.framebuffer_scale[1] };
+                (*io).DeltaTime = delta_time;
+            }
+        }
+        context.new_frame()
+    }
+
+    /// Handles the texture requests of the draw data, and sends them with the draw data to the viewer.
+    ///
+    /// The textures get their ID whether a viewer is connected or not, a viewer connecting later gets all of them.
+    pub fn render(&mut self, draw_data: &DrawData) {
+        let textures = draw_data.textures();
+        for &tex in textures {
+            if unsafe { (*tex).Status } != TextureStatus::OK.raw() {
+                unsafe { self.update_texture(tex) };
+            }
+        }
+
+        if self.connection.is_some() && !self.synced {
+            self.synced = true;
+            for &tex in textures {
+                let data = unsafe { &*tex };
+                if data.TexID != 0 {
+                    let Some(format) = TextureFormat::from_raw(data.Format) else { continue };
+                    self.send(&protocol::texture_create(
+                        data.TexID,
+                        format,
+                        data.Width as u32,
+                        data.Height as u32,
+                        unsafe { pixels(data) },
+                    ));
+                }
+            }
+        }
+        if self.synced {
+            self.send(&protocol::frame(draw_data));
+        }
+    }
+
+    // Create/update/destroy request of a texture, like `ImGui_ImplSDLGPU3_UpdateTexture`
+    unsafe fn update_texture(&mut self, tex: *mut ImTextureData) {
+        let data = unsafe { &*tex };
+        let status = data.Status;
+        let format = TextureFormat::from_raw(data.Format).unwrap_or(TextureFormat::RGBA32);
+        if status == TextureStatus::WantCreate.raw() {
+            let id = self.next_texture_id;
+            self.next_texture_id += 1;
+            if self.synced {
+                let (width, height) = (data.Width as u32, data.Height as u32);
+                self.send(&protocol::texture_create(id, format, width, height, unsafe { pixels(data) }));
+            }
+            unsafe {
+                imgui_sys::ImTextureData_SetTexID(tex, id);
+                imgui_sys::ImTextureData_SetStatus(tex, TextureStatus::OK.raw());
+            }
+        } else if status == TextureStatus::WantUpdates.raw() {
+            if self.synced {
+                let rect = data.UpdateRect;
+                let row_len = rect.w as usize * protocol::bytes_per_pixel(format);
+                let pixels = unsafe { pixels(data) };
+                let pitch = data.Width as usize * protocol::bytes_per_pixel(format);
+                let rows = (rect.y as usize..rect.y as usize + rect.h as usize).map(|row| {
+                    let start = row * pitch + rect.x as usize * protocol::bytes_per_pixel(format);
+                    &pixels[start..start + row_len]
+                });
+                let message = protocol::texture_update(data.TexID, [rect.x, rect.y, rect.w, rect.h], rows);
+                self.send(&message);
+            }
+            unsafe { imgui_sys::ImTextureData_SetStatus(tex, TextureStatus::OK.raw()) };
+        } else if status == TextureStatus::WantDestroy.raw() && data.UnusedFrames > 0 {
+            if self.synced {
+                self.send(&protocol::texture_destroy(data.TexID));
+            }
+            unsafe {
+                imgui_sys::ImTextureData_SetTexID(tex, 0);
+                imgui_sys::ImTextureData_SetStatus(tex, TextureStatus::Destroyed.raw());
+            }
+        }
+    }
+
+    fn send(&mut self, message: &[u8]) {
+        if let Some(connection) = &mut self.connection {
+            if connection.send(message).is_err() {
+                self.disconnect();
+            }
+        }
+    }
+
+    fn disconnect(&mut self) {
+        self.connection = None;
+        self.synced = false;
+        self.viewer_lost = true;
+    }
+}
+
+impl Drop for RemoteServer {
+    fn drop(&mut self) {
+        Context::shutdown_renderer(self.context);
+        Context::with_live(self.context, || unsafe { (*imgui_sys::igGetIO()).BackendPlatformName = ptr::null() });
+    }
+}
+
+// All the pixels of a texture
+unsafe fn pixels(data: &ImTextureData) -> &[u8] {
+    let len = data.Width as usize * data.Height as usize * data.BytesPerPixel as usize;
+    if data.Pixels.is_null() { &[] } else { unsafe { slice::from_raw_parts(data.Pixels, len) } }
+}
+
+// Input events for the changes of the viewer state
+unsafe fn apply_input(io: *mut ImGuiIO, previous: &RemoteInput, input: &RemoteInput) {
+    unsafe {
+        if input.focused != previous.focused {
+            imgui_sys::ImGuiIO_AddFocusEvent(io, input.focused);
+        }
+        if input.mouse_pos != previous.mouse_pos {
+            let [x, y] = input.mouse_pos.unwrap_or([-f32::MAX; 2]);
+            imgui_sys::ImGuiIO_AddMousePosEvent(io, x, y);
+        }
+        for (button, (&down, &was_down)) in input.mouse_down.iter().zip(&previous.mouse_down).enumerate() {
+            if down != was_down {
+                imgui_sys::ImGuiIO_AddMouseButtonEvent(io, button as i32, down);
+            }
+        }
+        if input.mouse_wheel != [0.0; 2] {
+            imgui_sys::ImGuiIO_AddMouseWheelEvent(io, input.mouse_wheel[0], input.mouse_wheel[1]);
+        }
+        for &key in previous.keys_down.iter().filter(|key| !input.keys_down.contains(key)) {
+            imgui_sys::ImGuiIO_AddKeyEvent(io, event_key(key).raw(), false);
+        }
+        for &key in input.keys_down.iter().filter(|key| !previous.keys_down.contains(key)) {
+            imgui_sys::ImGuiIO_AddKeyEvent(io, event_key(key).raw(), true);
+        }
+        for c in input.text.chars() {
+            imgui_sys::ImGuiIO_AddInputCharacter(io, c as u32);
+        }
+    }
+}
+
+// The modifiers are stored in `io.KeysData` at the reserved keys, but their events are the `ImGuiMod_*` ones
+fn event_key(key: Key) -> Key {
+    match key {
+        Key::ReservedForModCtrl => Key::Mod_Ctrl,
+        Key::ReservedForModShift => Key::Mod_Shift,
+        Key::ReservedForModAlt => Key::Mod_Alt,
+        Key::ReservedForModSuper => Key::Mod_Super,
+        key => key,
+    }
+}
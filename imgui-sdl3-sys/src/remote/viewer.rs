@@ -0,0 +1,284 @@
+// Remote UI viewer: draws the UI of a server with the backends of a local context
+
+extern crate alloc;
+extern crate std;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::{mem, ptr};
+use std::collections::HashMap;
+use std::io;
+use std::net::{TcpStream, ToSocketAddrs};
+
+use super::RemoteInput;
+use super::protocol::{self, Connection, Frame, Message, RemoteTexture, invalid_data};
+use crate::imgui_sys::enums::{TextureFormat, TextureStatus};
+use crate::imgui_sys::{
+    self, ImDrawIdx, ImDrawList, ImDrawVert, ImTextureData, ImTextureRef, ImTextureRect, ImVec2,
+    ImVector_ImTextureDataPtr,
+};
+use crate::safe::DrawData;
+
+/// Connects to a [`RemoteServer`](super::RemoteServer) and draws its UI in a local context.
+///
+/// The textures of the server are recreated as `ImTextureData` owned by the viewer, and handed to the renderer
+/// backend of the local context with [`prepare_draw_data`](Self::prepare_draw_data), like the font atlas. Each frame:
+/// ```ignore
+/// // After igNewFrame
+/// viewer.send_input(&RemoteInput::from_io(&*igGetIO()))?;
+/// viewer.poll()?;
+/// viewer.draw(igGetBackgroundDrawList());
+/// igRender();
+/// let mut draw_data = DrawData::from_raw(igGetDrawData());
+/// viewer.prepare_draw_data(&mut draw_data);
+/// // Render the draw data with the renderer backend
+/// ```
+///
+/// The user textures of the server (drawn with an `ImTextureID`) are drawn with the same ID, they have to be
+/// created by the viewer.
+pub struct RemoteViewer {
+    connection: Connection,
+    // Boxed, the draw lists and the renderer backend keep pointers to them
+    textures: HashMap<u64, *mut ImTextureData>,
+    // Destroyed by the server, until the renderer backend destroys them too
+    destroyed: Vec<*mut ImTextureData>,
+    frame: Option<Frame>,
+    // Storage of the textures given to the renderer backend in `ImDrawData::Textures`
+    texture_list: Vec<*mut ImTextureData>,
+    texture_vector: Box<ImVector_ImTextureDataPtr>,
+}
+
+impl RemoteViewer {
+    /// Connects to the server listening on `addr`.
+    pub fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let connection = Connection::new(TcpStream::connect(addr)?)?;
+        Ok(Self {
+            connection,
+            textures: HashMap::new(),
+            destroyed: Vec::new(),
+            frame: None,
+            texture_list: Vec::new(),
+            texture_vector: Box::new(ImVector_ImTextureDataPtr { Size: 0, Capacity: 0, Data: ptr::null_mut() }),
+        })
+    }
+
+    /// Sends the input state of this frame to the server, see [`RemoteInput::from_io`].
+    pub fn send_input(&mut self, input: &RemoteInput) -> io::Result<()> {
+        self.connection.send(&protocol::input(input))
+    }
+
+    /// Handles the textures and frames received so far, without waiting for more. Returns true if a new frame was
+    /// received.
+    ///
+    /// Fails when the server disconnected or sent invalid data, the textures are kept until the viewer is dropped.
+    pub fn poll(&mut self) -> io::Result<bool> {
+        let mut new_frame = false;
+        // The connection reads a message at a time
+        loop {
+            let messages = self.connection.receive()?;
+            if messages.is_empty() {
+                break;
+            }
+            for message in messages {
+                match message {
+                    Message::TextureCreate { id, format, width, height, pixels } => {
+                        self.create_texture(id, format, width, height, &pixels)?
+                    }
+                    Message::TextureUpdate { id, rect, pixels } => self.update_texture(id, rect, &pixels)?,
+                    Message::TextureDestroy { id } => {
+                        let tex = self.textures.remove(&id).ok_or_else(|| invalid_data("unknown remote texture"))?;
+                        let status = unsafe { (*tex).Status };
+                        if status == TextureStatus::WantCreate.raw() || status == TextureStatus::Destroyed.raw() {
+                            // The renderer backend doesn't have it
+                            unsafe { free_texture(tex) };
+                        } else {
+                            unsafe {
+                                (*tex).WantDestroyNextFrame = true;
+                                (*tex).UnusedFrames = 0;
+                                imgui_sys::ImTextureData_SetStatus(tex, TextureStatus::WantDestroy.raw());
+                            }
+                            self.destroyed.push(tex);
+                        }
+                    }
+                    Message::Frame(frame) => {
+                        self.frame = Some(frame);
+                        new_frame = true;
+                    }
+                    Message::Input(_) => return Err(invalid_data("input sent to the viewer")),
+                }
+            }
+        }
+        Ok(new_frame)
+    }
+
+    /// Display size of the last frame received, `None` before the first one.
+    pub fn display_size(&self) -> Option<[f32; 2]> {
+        self.frame.as_ref().map(|frame| frame.display_size)
+    }
+
+    fn create_texture(&mut self, id: u64, format: TextureFormat, width: u32, height: u32, pixels: &[u8]) -> io::Result<()> {
+        // The texture rects are u16
+        if self.textures.contains_key(&id) || width == 0 || height == 0 || width > 0xFFFF || height > 0xFFFF {
+            return Err(invalid_data("invalid remote texture"));
+        }
+        unsafe {
+            // Like the ImTextureData constructor
+            let tex = Box::into_raw(Box::new(mem::zeroed::<ImTextureData>()));
+            (*tex).Status = TextureStatus::Destroyed.raw();
+            imgui_sys::ImTextureData_Create(tex, format.raw(), width as i32, height as i32);
+            ptr::copy_nonoverlapping(pixels.as_ptr(), (*tex).Pixels, pixels.len());
+            self.textures.insert(id, tex);
+        }
+        Ok(())
+    }
+
+    fn update_texture(&mut self, id: u64, rect: [u16; 4], pixels: &[u8]) -> io::Result<()> {
+        let &tex = self.textures.get(&id).ok_or_else(|| invalid_data("unknown remote texture"))?;
+        let data = unsafe { &mut *tex };
+        let [x, y, w, h] = rect.map(usize::from);
+        let bytes_per_pixel = data.BytesPerPixel as usize;
+        let in_bounds = x + w <= data.Width as usize && y + h <= data.Height as usize;
+        if !in_bounds || pixels.len() != w * h * bytes_per_pixel {
+            return Err(invalid_data("invalid remote texture update"));
+        }
+        if w == 0 || h == 0 {
+            return Ok(());
+        }
+        let pitch = data.Width as usize * bytes_per_pixel;
+        for (row, src) in pixels.chunks_exact(w * bytes_per_pixel).enumerate() {
+            let offset = (y + row) * pitch + x * bytes_per_pixel;
+            unsafe { ptr::copy_nonoverlapping(src.as_ptr(), data.Pixels.add(offset), src.len()) };
+        }
+
+        // A texture waiting for creation is uploaded whole
+        let rect = ImTextureRect { x: rect[0], y: rect[1], w: rect[2], h: rect[3] };
+        if data.Status == TextureStatus::OK.raw() {
+            data.UpdateRect = rect;
+            unsafe { imgui_sys::ImTextureData_SetStatus(tex, TextureStatus::WantUpdates.raw()) };
+        } else if data.Status == TextureStatus::WantUpdates.raw() {
+            let current = data.UpdateRect;
+            let min_x = current.x.min(rect.x);
+            let min_y = current.y.min(rect.y);
+            let max_x = (current.x + current.w).max(rect.x + rect.w);
+            let max_y = (current.y + current.h).max(rect.y + rect.h);
+            data.UpdateRect = ImTextureRect { x: min_x, y: min_y, w: max_x - min_x, h: max_y - min_y };
+        }
+        Ok(())
+    }
+
+    /// Appends the draw lists of the last frame received to `draw_list`, e.g. the background draw list of the
+    /// local context, with the remote clip rects and textures. Draws nothing before the first frame.
+    ///
+    /// The remote display is drawn at the origin: the server uses the display size of the viewer.
+    ///
+    /// # Safety
+    /// `draw_list` must be a valid draw list of the current context, between `igNewFrame` and `igRender`.
+    pub unsafe fn draw(&self, draw_list: *mut ImDrawList) {
+        let Some(frame) = &self.frame else { return };
+        let offset = ImVec2 { x: frame.display_pos[0], y: frame.display_pos[1] };
+        for list in &frame.lists {
+            for cmd in &list.commands {
+                let tex_ref = match cmd.texture {
+                    RemoteTexture::Managed(id) => match self.textures.get(&id) {
+                        Some(&tex) => ImTextureRef { _TexData: tex, _TexID: 0 },
+                        None => continue,
+                    },
+                    RemoteTexture::User(id) => ImTextureRef { _TexData: ptr::null_mut(), _TexID: id },
+                };
+                // The protocol checked the ranges
+                let indices = &list.indices[cmd.idx_offset..cmd.idx_offset + cmd.count];
+                let (Some(&min), Some(&max)) = (indices.iter().min(), indices.iter().max()) else { continue };
+                let vertices = &list.vertices[cmd.vtx_offset + min as usize..=cmd.vtx_offset + max as usize];
+
+                let [min_x, min_y, max_x, max_y] = cmd.clip_rect;
+                unsafe {
+                    imgui_sys::ImDrawList_PushClipRect(
+                        draw_list,
+                        ImVec2 { x: min_x - offset.x, y: min_y - offset.y },
+                        ImVec2 { x: max_x - offset.x, y: max_y - offset.y },
+                        true,
+                    );
+                    imgui_sys::ImDrawList_PushTexture(draw_list, tex_ref);
+                    imgui_sys::ImDrawList_PrimReserve(draw_list, indices.len() as i32, vertices.len() as i32);
+                    // Read after reserving, which may start a new vertex offset
+                    let list = &mut *draw_list;
+                    let base = list._VtxCurrentIdx;
+                    for (i, vertex) in vertices.iter().enumerate() {
+                        let pos = ImVec2 { x: vertex.pos.x - offset.x, y: vertex.pos.y - offset.y };
+                        list._VtxWritePtr.add(i).write(ImDrawVert { pos, ..*vertex });
+                    }
+                    for (i, &index) in indices.iter().enumerate() {
+                        *list._IdxWritePtr.add(i) = (base + u32::from(index - min)) as ImDrawIdx;
+                    }
+                    list._VtxWritePtr = list._VtxWritePtr.add(vertices.len());
+                    list._IdxWritePtr = list._IdxWritePtr.add(indices.len());
+                    list._VtxCurrentIdx += vertices.len() as u32;
+                    imgui_sys::ImDrawList_PopTexture(draw_list);
+                    imgui_sys::ImDrawList_PopClipRect(draw_list);
+                }
+            }
+        }
+    }
+
+    /// Adds the remote textures to the texture requests of the draw data of the local context, after `igRender`
+    /// and before the renderer backend renders it.
+    ///
+    /// `draw_data.Textures` points to a list owned by the viewer afterwards, so the viewer stays borrowed as long as
+    /// the draw data.
+    pub fn prepare_draw_data<'a>(&'a mut self, draw_data: &mut DrawData<'a>) {
+        // The renderer backend destroyed them during the previous render
+        self.destroyed.retain(|&tex| unsafe {
+            if (*tex).Status == TextureStatus::Destroyed.raw() {
+                free_texture(tex);
+                false
+            } else {
+                (*tex).UnusedFrames += 1;
+                true
+            }
+        });
+
+        self.texture_list.clear();
+        self.texture_list.extend_from_slice(draw_data.textures());
+        self.texture_list.extend(self.textures.values().chain(&self.destroyed));
+        *self.texture_vector = ImVector_ImTextureDataPtr {
+            Size: self.texture_list.len() as i32,
+            Capacity: self.texture_list.len() as i32,
+            Data: self.texture_list.as_mut_ptr(),
+        };
+        draw_data.raw_mut().Textures = &mut *self.texture_vector;
+    }
+
+    /// Destroys the renderer backend side of the remote textures with `update_texture`, e.g. with
+    /// `cImGui_ImplSDLGPU3_UpdateTexture` before shutting the renderer backend down, and frees them.
+    pub fn destroy_textures(&mut self, mut update_texture: impl FnMut(*mut ImTextureData)) {
+        for tex in self.textures.drain().map(|(_, tex)| tex).chain(self.destroyed.drain(..)) {
+            unsafe {
+                let status = (*tex).Status;
+                if status != TextureStatus::WantCreate.raw() && status != TextureStatus::Destroyed.raw() {
+                    (*tex).WantDestroyNextFrame = true;
+                    (*tex).UnusedFrames = 1;
+                    imgui_sys::ImTextureData_SetStatus(tex, TextureStatus::WantDestroy.raw());
+                    update_texture(tex);
+                }
+                free_texture(tex);
+            }
+        }
+        self.texture_list.clear();
+    }
+}
+
+// Frees the CPU side only, see `destroy_textures` for the renderer backend side
+impl Drop for RemoteViewer {
+    fn drop(&mut self) {
+        for tex in self.textures.drain().map(|(_, tex)| tex).chain(self.destroyed.drain(..)) {
+            unsafe { free_texture(tex) };
+        }
+    }
+}
+
+unsafe fn free_texture(tex: *mut ImTextureData) {
+    unsafe {
+        imgui_sys::ImTextureData_DestroyPixels(tex);
+        drop(Box::from_raw(tex));
+    }
+}
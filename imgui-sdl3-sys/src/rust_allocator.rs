@@ -0,0 +1,210 @@
+// Dear ImGui allocations routed through the Rust global allocator, with per-context statistics
+//
+// igSetAllocatorFunctions() replaces malloc/free for the whole library. The free function only gets the pointer,
+// so each block starts with a header holding its size, and the statistics slot of the context that was current
+// when it was allocated:
+// - header: size (usize), slot index (u32, NO_SLOT without a current context), slot generation (u32)
+// The header is MIN_ALIGN bytes so the blocks keep the alignment of malloc.
+//
+// The slots are a fixed table of atomics, so the trampolines don't allocate or lock. A slot is claimed by the first
+// allocation of a context, and released by forget_context(): the generation tells the frees of the blocks of a
+// released slot apart, they only update the totals.
+
+extern crate alloc;
+
+use core::alloc::Layout;
+use core::ffi::c_void;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, AtomicU32, AtomicUsize, Ordering};
+
+use crate::imgui_sys::{self, ImGuiContext};
+
+// `alignof(max_align_t)` on the 64-bit targets, what malloc guarantees
+const MIN_ALIGN: usize = 16;
+const HEADER_SIZE: usize = MIN_ALIGN;
+const NO_SLOT: u32 = u32::MAX;
+
+/// Number of contexts with their own statistics at the same time, the allocations of the others are only counted
+/// in [`total_stats`].
+pub const MAX_CONTEXTS: usize = 32;
+
+#[repr(C)]
+struct Header {
+    size: usize,
+    slot: u32,
+    generation: u32,
+}
+
+const _: () = assert!(size_of::<Header>() <= HEADER_SIZE);
+
+/// Allocation statistics, of a context or of the whole library.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// Bytes currently allocated, without the block headers
+    pub live_bytes: usize,
+    /// Highest `live_bytes` so far
+    pub peak_bytes: usize,
+    /// Number of allocations so far
+    pub alloc_count: usize,
+}
+
+struct Counters {
+    live_bytes: AtomicUsize,
+    peak_bytes: AtomicUsize,
+    alloc_count: AtomicUsize,
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Self { live_bytes: AtomicUsize::new(0), peak_bytes: AtomicUsize::new(0), alloc_count: AtomicUsize::new(0) }
+    }
+
+    fn add(&self, size: usize) {
+        let live = self.live_bytes.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_bytes.fetch_max(live, Ordering::Relaxed);
+        self.alloc_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn remove(&self, size: usize) {
+        self.live_bytes.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    fn reset(&self) {
+        self.live_bytes.store(0, Ordering::Relaxed);
+        self.peak_bytes.store(0, Ordering::Relaxed);
+        self.alloc_count.store(0, Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> AllocationStats {
+        AllocationStats {
+            live_bytes: self.live_bytes.load(Ordering::Relaxed),
+            peak_bytes: self.peak_bytes.load(Ordering::Relaxed),
+            alloc_count: self.alloc_count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+struct Slot {
+    context: AtomicPtr<ImGuiContext>,
+    generation: AtomicU32,
+    counters: Counters,
+}
+
+static TOTAL: Counters = Counters::new();
+static SLOTS: [Slot; MAX_CONTEXTS] = [const {
+    Slot { context: AtomicPtr::new(ptr::null_mut()), generation: AtomicU32::new(0), counters: Counters::new() }
+}; MAX_CONTEXTS];
+
+/// Makes Dear ImGui allocate with the Rust global allocator (`alloc::alloc`), instead of malloc/free.
+///
+/// The allocations show up in a tracking `#[global_allocator]` and in heap profiles, and are counted in
+/// [`total_stats`] and per context in [`context_stats`].
+///
+/// # Safety
+/// Like `igSetAllocatorFunctions`, it must be called before creating the first context: the memory allocated
+/// with malloc would be freed by the Rust allocator. It must not be called while a context exists.
+pub unsafe fn install() {
+    unsafe { imgui_sys::igSetAllocatorFunctions(Some(alloc_func), Some(free_func), ptr::null_mut()) };
+}
+
+/// Returns true if the allocator functions of Dear ImGui are the ones of [`install`].
+pub fn is_installed() -> bool {
+    let mut alloc: imgui_sys::ImGuiMemAllocFunc = None;
+    let mut free: imgui_sys::ImGuiMemFreeFunc = None;
+    let mut user_data = ptr::null_mut();
+    unsafe { imgui_sys::igGetAllocatorFunctions(&mut alloc, &mut free, &mut user_data) };
+    alloc.is_some_and(|alloc| ptr::fn_addr_eq(alloc, alloc_func as AllocFunc))
+}
+
+/// Statistics of all the allocations made since [`install`].
+pub fn total_stats() -> AllocationStats {
+    TOTAL.stats()
+}
+
+/// Statistics of the allocations made while `context` was the current context, e.g. to show every frame.
+///
+/// `None` if it didn't allocate anything yet, or if more than [`MAX_CONTEXTS`] contexts did. The allocations made
+/// without a current context, like the `ImGuiContext` itself in `igCreateContext`, are only in [`total_stats`].
+pub fn context_stats(context: *mut ImGuiContext) -> Option<AllocationStats> {
+    find_slot(context).map(|slot| SLOTS[slot].counters.stats())
+}
+
+/// Releases the statistics of a context, after `igDestroyContext`. A new context created at the same address then
+/// starts from zero.
+///
+/// The safe `Context` does it when dropped.
+pub fn forget_context(context: *mut ImGuiContext) {
+    if let Some(slot) = find_slot(context) {
+        let slot = &SLOTS[slot];
+        slot.generation.fetch_add(1, Ordering::Relaxed);
+        slot.counters.reset();
+        slot.context.store(ptr::null_mut(), Ordering::Release);
+    }
+}
+
+type AllocFunc = unsafe extern "C" fn(usize, *mut c_void) -> *mut c_void;
+
+fn find_slot(context: *mut ImGuiContext) -> Option<usize> {
+    if context.is_null() {
+        return None;
+    }
+    SLOTS.iter().position(|slot| slot.context.load(Ordering::Acquire) == context)
+}
+
+// The slot of the current context, claimed by its first allocation
+fn current_slot() -> Option<usize> {
+    let context = unsafe { imgui_sys::igGetCurrentContext() };
+    if context.is_null() {
+        return None;
+    }
+    find_slot(context).or_else(|| {
+        SLOTS.iter().position(|slot| {
+            slot.context.compare_exchange(ptr::null_mut(), context, Ordering::AcqRel, Ordering::Acquire).is_ok()
+        })
+    })
+}
+
+fn layout(size: usize) -> Option<Layout> {
+    Layout::from_size_align(size.checked_add(HEADER_SIZE)?, MIN_ALIGN).ok()
+}
+
+unsafe extern "C" fn alloc_func(size: usize, _user_data: *mut c_void) -> *mut c_void {
+    // Fails like malloc for the sizes no allocator can give
+    let Some(layout) = layout(size) else { return ptr::null_mut() };
+    let block = unsafe { alloc::alloc::alloc(layout) };
+    if block.is_null() {
+        alloc::alloc::handle_alloc_error(layout);
+    }
+
+    let slot = current_slot();
+    let header = match slot {
+        Some(slot) => {
+            SLOTS[slot].counters.add(size);
+            Header { size, slot: slot as u32, generation: SLOTS[slot].generation.load(Ordering::Relaxed) }
+        }
+        None => Header { size, slot: NO_SLOT, generation: 0 },
+    };
+    TOTAL.add(size);
+    unsafe {
+        block.cast::<Header>().write(header);
+        block.add(HEADER_SIZE).cast()
+    }
+}
+
+unsafe extern "C" fn free_func(ptr: *mut c_void, _user_data: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    unsafe {
+        let block = ptr.cast::<u8>().sub(HEADER_SIZE);
+        let header = block.cast::<Header>().read();
+        if let Some(slot) = SLOTS.get(header.slot as usize) {
+            if slot.generation.load(Ordering::Relaxed) == header.generation {
+                slot.counters.remove(header.size);
+            }
+        }
+        TOTAL.remove(header.size);
+        // The layout was valid when allocating
+        alloc::alloc::dealloc(block, layout(header.size).unwrap_unchecked());
+    }
+}
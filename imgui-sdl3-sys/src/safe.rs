@@ -0,0 +1,21 @@
+// Safe wrappers on top of the raw bindings
+// Only covers the common parts of the API, the raw bindings in imgui_sys remain available for everything else
+// The plain Ui functions (widgets, queries...) are generated from cimgui.json by imgui-safe-gen into safe/generated.rs,
+// the functions needing tokens or text handling are written by hand
+
+mod context;
+mod draw_data;
+mod generated;
+mod headless;
+mod label;
+mod text;
+mod tokens;
+mod ui;
+mod widgets;
+
+pub use context::{Context, CurrentContextGuard};
+pub use draw_data::{DrawCallback, DrawCmd, DrawCmdParams, DrawCmds, DrawData, DrawList, DrawLists};
+pub use headless::HeadlessPlatform;
+pub use label::{Label, Text};
+pub use tokens::*;
+pub use ui::Ui;
@@ -0,0 +1,182 @@
+// Dear ImGui context ownership
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use super::label::ScratchBuffer;
+use crate::imgui_sys;
+
+/// An owned Dear ImGui context, created with `igCreateContext` and destroyed with `igDestroyContext` on drop.
+///
+/// Dear ImGui keeps the current context in a global, so a context can't be shared with or sent to another thread.
+pub struct Context {
+    raw: *mut imgui_sys::ImGuiContext,
+    // Unique in the process, unlike the address which the next context can reuse
+    id: u64,
+    scratch: ScratchBuffer,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl Context {
+    /// Creates a context with its own font atlas.
+    ///
+    /// Like `igCreateContext`, it becomes the current context if there isn't one yet.
+    pub fn new() -> Self {
+        unsafe { Self::from_raw(imgui_sys::igCreateContext(ptr::null_mut())) }
+    }
+
+    /// Creates a context using the font atlas of another context, see [`Context::font_atlas`].
+    ///
+    /// Like `igCreateContext`, it becomes the current context if there isn't one yet.
+    ///
+    /// # Safety
+    /// `shared_font_atlas` must be a valid font atlas, and the context that created it must outlive this one,
+    /// as it is in charge of updating the atlas every frame.
+    pub unsafe fn with_shared_font_atlas(shared_font_atlas: *mut imgui_sys::ImFontAtlas) -> Self {
+        unsafe { Self::from_raw(imgui_sys::igCreateContext(shared_font_atlas)) }
+    }
+
+    unsafe fn from_raw(raw: *mut imgui_sys::ImGuiContext) -> Self {
+        assert!(!raw.is_null(), "igCreateContext failed");
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        LIVE_CONTEXTS.with(|live| live.push((raw, id)));
+        Self { raw, id, scratch: ScratchBuffer::new(), _not_send: PhantomData }
+    }
+
+    // Identifies the context of a backend, see `Context::with_live`
+    pub(crate) fn id(&self) -> u64 {
+        self.id
+    }
+
+    // Runs `f` with the context `id` current if it's still alive, then restores the current context.
+    // For the drops of the backends, which can outlive their context. The backends aren't Send, so the context
+    // can't be destroyed by another thread meanwhile.
+    pub(crate) fn with_live<R>(id: u64, f: impl FnOnce() -> R) -> Option<R> {
+        let raw = LIVE_CONTEXTS.with(|live| live.iter().find(|&&(_, live_id)| live_id == id).map(|&(raw, _)| raw))?;
+        unsafe {
+            let current = imgui_sys::igGetCurrentContext();
+            imgui_sys::igSetCurrentContext(raw);
+            let result = f();
+            imgui_sys::igSetCurrentContext(current);
+            Some(result)
+        }
+    }
+
+    // Like the Shutdown function of the renderer backends: destroys the textures of the context which aren't shared
+    // with another one, so the next renderer creates them again, and clears the renderer name and flags.
+    // No-op if the context was destroyed.
+    #[cfg(any(feature = "software-renderer", feature = "remote"))]
+    pub(crate) fn shutdown_renderer(id: u64) {
+        use crate::imgui_sys::ImVector;
+        use crate::imgui_sys::enums::{BackendFlags, TextureStatus};
+
+        Self::with_live(id, || unsafe {
+            for &tex in (*imgui_sys::igGetPlatformIO()).Textures.as_slice() {
+                if (*tex).RefCount == 1 {
+                    imgui_sys::ImTextureData_SetTexID(tex, 0);
+                    imgui_sys::ImTextureData_SetStatus(tex, TextureStatus::Destroyed.raw());
+                }
+            }
+            let io = imgui_sys::igGetIO();
+            (*io).BackendRendererName = ptr::null();
+            (*io).BackendFlags &= !(BackendFlags::RendererHasVtxOffset | BackendFlags::RendererHasTextures);
+        });
+    }
+
+    pub(super) fn scratch(&self) -> &ScratchBuffer {
+        &self.scratch
+    }
+
+    /// Returns the raw context pointer, e.g. to call `igSetCurrentContext`.
+    pub fn as_raw(&self) -> *mut imgui_sys::ImGuiContext {
+        self.raw
+    }
+
+    /// Returns true if this is the current context.
+    pub fn is_current(&self) -> bool {
+        unsafe { imgui_sys::igGetCurrentContext() == self.raw }
+    }
+
+    /// Makes this the current context until the returned guard is dropped, which restores the previous one.
+    pub fn make_current(&self) -> CurrentContextGuard<'_> {
+        unsafe {
+            let previous = imgui_sys::igGetCurrentContext();
+            let previous_owned = LIVE_CONTEXTS.with(|live| live.iter().any(|&(raw, _)| raw == previous));
+            imgui_sys::igSetCurrentContext(self.raw);
+            CurrentContextGuard { previous, previous_owned, _context: PhantomData }
+        }
+    }
+
+    /// Returns the font atlas of this context (`io.Fonts`), to create other contexts sharing it with
+    /// [`Context::with_shared_font_atlas`].
+    pub fn font_atlas(&self) -> *mut imgui_sys::ImFontAtlas {
+        let _current = self.make_current();
+        unsafe { (*imgui_sys::igGetIO()).Fonts }
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        // igDestroyContext restores the current context, or clears it if it was this one
+        unsafe { imgui_sys::igDestroyContext(self.raw) };
+        LIVE_CONTEXTS.with(|live| live.retain(|&(_, id)| id != self.id));
+        // A context created at the same address gets its own statistics
+        #[cfg(feature = "rust-allocator")]
+        crate::rust_allocator::forget_context(self.raw);
+    }
+}
+
+/// Guard returned by [`Context::make_current`], restores the previously current context when dropped.
+///
+/// If the previous context was a [`Context`] dropped since, there is no current context afterwards.
+pub struct CurrentContextGuard<'ctx> {
+    previous: *mut imgui_sys::ImGuiContext,
+    // Owned by a Context when the guard was created, only restored if it's still alive.
+    // A context created with the raw API can only be destroyed with it, the caller keeps it alive.
+    previous_owned: bool,
+    _context: PhantomData<&'ctx Context>,
+}
+
+impl Drop for CurrentContextGuard<'_> {
+    fn drop(&mut self) {
+        let alive = !self.previous_owned || LIVE_CONTEXTS.with(|live| live.iter().any(|&(raw, _)| raw == self.previous));
+        let previous = if alive { self.previous } else { ptr::null_mut() };
+        unsafe { imgui_sys::igSetCurrentContext(previous) };
+    }
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+// The contexts owned by a Context with their ID, the guards don't restore the ones that were destroyed.
+// Context isn't Send, but contexts can be created on several threads, hence the lock.
+static LIVE_CONTEXTS: LiveContexts = LiveContexts { locked: AtomicBool::new(false), raw: UnsafeCell::new(Vec::new()) };
+
+struct LiveContexts {
+    locked: AtomicBool,
+    raw: UnsafeCell<Vec<(*mut imgui_sys::ImGuiContext, u64)>>,
+}
+
+// The Vec is only accessed with the lock held
+unsafe impl Sync for LiveContexts {}
+
+impl LiveContexts {
+    fn with<R>(&self, f: impl FnOnce(&mut Vec<(*mut imgui_sys::ImGuiContext, u64)>) -> R) -> R {
+        while self.locked.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+            core::hint::spin_loop();
+        }
+        let result = f(unsafe { &mut *self.raw.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
@@ -0,0 +1,232 @@
+// Borrowed views of the draw data, for custom renderers and exporters
+
+use core::ffi::c_void;
+use core::iter::FusedIterator;
+use core::ops::Deref;
+use core::slice;
+
+use crate::imgui_sys::{ImDrawCmd, ImDrawData, ImDrawIdx, ImDrawList, ImDrawVert, ImTextureData, ImTextureID, ImVec4, ImVector};
+
+// `ImDrawCallback_ResetRenderState`, a sentinel callback value defined by a macro bindgen doesn't see
+const RESET_RENDER_STATE: usize = -8isize as usize;
+
+/// Draw data of a rendered frame, returned by [`Ui::render`](super::Ui::render).
+///
+/// It dereferences to the raw `ImDrawData`, whose draw lists and textures stay valid while it's borrowed, so it can
+/// hand out views of them.
+pub struct DrawData<'a> {
+    raw: &'a mut ImDrawData,
+}
+
+impl<'a> DrawData<'a> {
+    /// Wraps raw draw data, e.g. from `igGetDrawData` after `igRender`.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must point to valid draw data, whose draw lists and textures stay valid and aren't modified elsewhere
+    /// for `'a`.
+    pub unsafe fn from_raw(raw: *mut ImDrawData) -> Self {
+        Self { raw: unsafe { &mut *raw } }
+    }
+
+    /// Returns the raw pointer, to give the draw data to a renderer backend.
+    pub fn as_mut_ptr(&mut self) -> *mut ImDrawData {
+        self.raw
+    }
+
+    // Mutable raw draw data, for the crate modules keeping its pointers valid
+    #[cfg(feature = "remote")]
+    pub(crate) fn raw_mut(&mut self) -> &mut ImDrawData {
+        self.raw
+    }
+
+    /// Draw lists to render, back to front.
+    pub fn lists(&self) -> DrawLists<'_> {
+        DrawLists { iter: unsafe { self.raw.CmdLists.iter() } }
+    }
+
+    /// Textures to create, update or destroy before rendering (`ImDrawData::Textures`).
+    pub fn textures(&self) -> &[*mut ImTextureData] {
+        if self.raw.Textures.is_null() { &[] } else { unsafe { (*self.raw.Textures).as_slice() } }
+    }
+}
+
+impl Deref for DrawData<'_> {
+    type Target = ImDrawData;
+
+    fn deref(&self) -> &ImDrawData {
+        self.raw
+    }
+}
+
+/// Iterator over the draw lists of [`DrawData::lists`].
+#[derive(Clone)]
+pub struct DrawLists<'a> {
+    iter: slice::Iter<'a, *mut ImDrawList>,
+}
+
+impl<'a> Iterator for DrawLists<'a> {
+    type Item = DrawList<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|&raw| DrawList { raw: unsafe { &*raw } })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for DrawLists<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|&raw| DrawList { raw: unsafe { &*raw } })
+    }
+}
+
+impl ExactSizeIterator for DrawLists<'_> {}
+impl FusedIterator for DrawLists<'_> {}
+
+/// A draw list of the draw data: vertex and index buffers, and the commands drawing them.
+#[derive(Clone, Copy)]
+pub struct DrawList<'a> {
+    // From a `DrawData`, its buffers are valid for 'a
+    raw: &'a ImDrawList,
+}
+
+impl<'a> DrawList<'a> {
+    /// Returns the raw draw list.
+    pub fn raw(&self) -> &'a ImDrawList {
+        self.raw
+    }
+
+    /// Vertex buffer, indexed by the commands from their [`DrawCmdParams::vtx_offset`].
+    pub fn vertices(&self) -> &'a [ImDrawVert] {
+        unsafe { self.raw.VtxBuffer.as_slice() }
+    }
+
+    /// Index buffer, each command draws triangles from [`DrawCmdParams::idx_offset`].
+    pub fn indices(&self) -> &'a [ImDrawIdx] {
+        unsafe { self.raw.IdxBuffer.as_slice() }
+    }
+
+    /// Commands to execute in order.
+    pub fn commands(&self) -> DrawCmds<'a> {
+        DrawCmds { list: self.raw, iter: self.raw_commands().iter() }
+    }
+
+    /// Raw command buffer, in the order of [`DrawList::commands`].
+    pub fn raw_commands(&self) -> &'a [ImDrawCmd] {
+        unsafe { self.raw.CmdBuffer.as_slice() }
+    }
+}
+
+/// Iterator over the commands of [`DrawList::commands`].
+#[derive(Clone)]
+pub struct DrawCmds<'a> {
+    list: &'a ImDrawList,
+    iter: slice::Iter<'a, ImDrawCmd>,
+}
+
+impl<'a> Iterator for DrawCmds<'a> {
+    type Item = DrawCmd<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|cmd| DrawCmd::new(self.list, cmd))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for DrawCmds<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|cmd| DrawCmd::new(self.list, cmd))
+    }
+}
+
+impl ExactSizeIterator for DrawCmds<'_> {}
+impl FusedIterator for DrawCmds<'_> {}
+
+/// A command of a draw list.
+#[derive(Clone, Copy, Debug)]
+pub enum DrawCmd<'a> {
+    /// Draws `count` indices as triangles.
+    Elements {
+        count: usize,
+        params: DrawCmdParams,
+    },
+    /// `ImDrawCallback_ResetRenderState`: the renderer has to restore its default render state.
+    ResetRenderState,
+    /// A user callback added with `ImDrawList_AddCallback`, for the renderer to call.
+    Callback(DrawCallback<'a>),
+}
+
+impl<'a> DrawCmd<'a> {
+    fn new(list: &'a ImDrawList, cmd: &'a ImDrawCmd) -> Self {
+        match cmd.UserCallback {
+            Some(callback) if callback as usize == RESET_RENDER_STATE => DrawCmd::ResetRenderState,
+            Some(callback) => DrawCmd::Callback(DrawCallback { callback, list, cmd }),
+            None => DrawCmd::Elements {
+                count: cmd.ElemCount as usize,
+                params: DrawCmdParams {
+                    clip_rect: cmd.ClipRect,
+                    texture_id: texture_id(cmd),
+                    vtx_offset: cmd.VtxOffset as usize,
+                    idx_offset: cmd.IdxOffset as usize,
+                },
+            },
+        }
+    }
+}
+
+// Like `ImDrawCmd_GetTexID`, without asserting when the renderer hasn't created the texture yet
+fn texture_id(cmd: &ImDrawCmd) -> ImTextureID {
+    let tex_data = cmd.TexRef._TexData;
+    if tex_data.is_null() { cmd.TexRef._TexID } else { unsafe { (*tex_data).TexID } }
+}
+
+/// Parameters of [`DrawCmd::Elements`].
+#[derive(Clone, Copy, Debug)]
+pub struct DrawCmdParams {
+    /// Clipping rectangle (x1, y1, x2, y2), in the coordinates of the draw data `DisplayPos`
+    pub clip_rect: ImVec4,
+    /// Texture to sample, resolved from the texture ref.
+    ///
+    /// For the textures managed by Dear ImGui (font atlas...), it's 0 (`ImTextureID_Invalid`) until the renderer
+    /// backend created the texture.
+    pub texture_id: ImTextureID,
+    /// Offset added to the indices
+    pub vtx_offset: usize,
+    /// First index of the command in the index buffer
+    pub idx_offset: usize,
+}
+
+/// User callback of [`DrawCmd::Callback`].
+#[derive(Clone, Copy, Debug)]
+pub struct DrawCallback<'a> {
+    callback: unsafe extern "C" fn(parent_list: *const ImDrawList, cmd: *const ImDrawCmd),
+    list: &'a ImDrawList,
+    cmd: &'a ImDrawCmd,
+}
+
+impl<'a> DrawCallback<'a> {
+    /// Returns the raw command, with the callback and its data.
+    pub fn raw_cmd(&self) -> &'a ImDrawCmd {
+        self.cmd
+    }
+
+    /// User data given to `ImDrawList_AddCallback`, or its copy in the draw list when it had a size.
+    pub fn user_data(&self) -> *mut c_void {
+        self.cmd.UserCallbackData
+    }
+
+    /// Calls the callback with its draw list and command.
+    ///
+    /// # Safety
+    ///
+    /// The callback may expect to be called by a specific renderer, with its render state set.
+    pub unsafe fn call(&self) {
+        unsafe { (self.callback)(self.list, self.cmd) };
+    }
+}
@@ -0,0 +1,1662 @@
+// Generated by imgui-safe-gen from cimgui.json, do not edit
+// Regenerate with `cargo run -p imgui-safe-gen` after updating dcimgui
+//
+// The Dear ImGui functions whose arguments all have a safe mapping, see imgui-safe-gen for the mapping.
+// Arguments taking an `Option` use the Dear ImGui default value when `None`.
+
+#![allow(clippy::too_many_arguments)]
+
+use core::ptr;
+
+use super::label::WriteText;
+use super::{Label, Ui};
+use crate::imgui_sys;
+use crate::imgui_sys::enums;
+
+impl Ui<'_> {
+    /// `igShowDemoWindow`: create Demo window. demonstrate most ImGui features. call this to learn about the library! try to make it always available in your application!
+    pub fn show_demo_window(&self, p_open: Option<&mut bool>) {
+        self.check_current();
+        unsafe { imgui_sys::igShowDemoWindow(p_open.map_or(ptr::null_mut(), ptr::from_mut)) }
+    }
+
+    /// `igShowMetricsWindow`: create Metrics/Debugger window. display Dear ImGui internals: windows, draw commands, various internal state, etc.
+    pub fn show_metrics_window(&self, p_open: Option<&mut bool>) {
+        self.check_current();
+        unsafe { imgui_sys::igShowMetricsWindow(p_open.map_or(ptr::null_mut(), ptr::from_mut)) }
+    }
+
+    /// `igShowDebugLogWindow`: create Debug Log window. display a simplified log of important dear imgui events.
+    pub fn show_debug_log_window(&self, p_open: Option<&mut bool>) {
+        self.check_current();
+        unsafe { imgui_sys::igShowDebugLogWindow(p_open.map_or(ptr::null_mut(), ptr::from_mut)) }
+    }
+
+    /// `igShowIDStackToolWindow`: create Stack Tool window. hover items with mouse to query information about the source of their unique ID.
+    pub fn show_id_stack_tool_window(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igShowIDStackToolWindow() }
+    }
+
+    /// `igShowIDStackToolWindowEx`: create Stack Tool window. hover items with mouse to query information about the source of their unique ID.
+    pub fn show_id_stack_tool_window_ex(&self, p_open: Option<&mut bool>) {
+        self.check_current();
+        unsafe { imgui_sys::igShowIDStackToolWindowEx(p_open.map_or(ptr::null_mut(), ptr::from_mut)) }
+    }
+
+    /// `igShowAboutWindow`: create About window. display Dear ImGui version, credits and build/system information.
+    pub fn show_about_window(&self, p_open: Option<&mut bool>) {
+        self.check_current();
+        unsafe { imgui_sys::igShowAboutWindow(p_open.map_or(ptr::null_mut(), ptr::from_mut)) }
+    }
+
+    /// `igShowStyleSelector`: add style selector block (not a window), essentially a combo listing the default styles.
+    pub fn show_style_selector(&self, label: impl Label) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igShowStyleSelector(scratch.c_str(label)) }
+    }
+
+    /// `igShowFontSelector`: add font selector block (not a window), essentially a combo listing the loaded fonts.
+    pub fn show_font_selector(&self, label: impl Label) {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igShowFontSelector(scratch.c_str(label)) }
+    }
+
+    /// `igShowUserGuide`: add basic help/info block (not a window): how to manipulate ImGui as an end-user (mouse/keyboard controls).
+    pub fn show_user_guide(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igShowUserGuide() }
+    }
+
+    /// `igIsWindowAppearing`
+    pub fn is_window_appearing(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsWindowAppearing() }
+    }
+
+    /// `igIsWindowCollapsed`
+    pub fn is_window_collapsed(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsWindowCollapsed() }
+    }
+
+    /// `igIsWindowFocused`: is current window focused? or its root/child, depending on flags. see flags for options.
+    pub fn is_window_focused(&self, flags: Option<enums::FocusedFlags>) -> bool {
+        self.check_current();
+        let flags = flags.unwrap_or(enums::FocusedFlags::None);
+        unsafe { imgui_sys::igIsWindowFocused(flags.bits()) }
+    }
+
+    /// `igIsWindowHovered`: is current window hovered and hoverable (e.g. not blocked by a popup/modal)? See ImGuiHoveredFlags_ for options. IMPORTANT: If you are trying to check whether your mouse should be dispatched to Dear ImGui or to your underlying app, you should not use this function! Use the 'io.WantCaptureMouse' boolean for that! Refer to FAQ entry "How can I tell whether to dispatch mouse/keyboard to Dear ImGui or my application?" for details.
+    pub fn is_window_hovered(&self, flags: Option<enums::HoveredFlags>) -> bool {
+        self.check_current();
+        let flags = flags.unwrap_or(enums::HoveredFlags::None);
+        unsafe { imgui_sys::igIsWindowHovered(flags.bits()) }
+    }
+
+    /// `igGetWindowPos`: get current window position in screen space (IT IS UNLIKELY YOU EVER NEED TO USE THIS. Consider always using GetCursorScreenPos() and GetContentRegionAvail() instead)
+    pub fn get_window_pos(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetWindowPos() }
+    }
+
+    /// `igGetWindowSize`: get current window size (IT IS UNLIKELY YOU EVER NEED TO USE THIS. Consider always using GetCursorScreenPos() and GetContentRegionAvail() instead)
+    pub fn get_window_size(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetWindowSize() }
+    }
+
+    /// `igGetWindowWidth`: get current window width (IT IS UNLIKELY YOU EVER NEED TO USE THIS). Shortcut for GetWindowSize().x.
+    pub fn get_window_width(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetWindowWidth() }
+    }
+
+    /// `igGetWindowHeight`: get current window height (IT IS UNLIKELY YOU EVER NEED TO USE THIS). Shortcut for GetWindowSize().y.
+    pub fn get_window_height(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetWindowHeight() }
+    }
+
+    /// `igSetNextWindowPos`: set next window position. call before Begin(). use pivot=(0.5f,0.5f) to center on given point, etc.
+    pub fn set_next_window_pos(&self, pos: imgui_sys::ImVec2) {
+        self.check_current();
+        let cond = enums::Cond::None;
+        unsafe { imgui_sys::igSetNextWindowPos(pos, cond.raw()) }
+    }
+
+    /// `igSetNextWindowPosEx`: set next window position. call before Begin(). use pivot=(0.5f,0.5f) to center on given point, etc.
+    pub fn set_next_window_pos_ex(&self, pos: imgui_sys::ImVec2, cond: Option<enums::Cond>, pivot: Option<imgui_sys::ImVec2>) {
+        self.check_current();
+        let cond = cond.unwrap_or(enums::Cond::None);
+        let pivot = pivot.unwrap_or(imgui_sys::ImVec2 { x: 0.0, y: 0.0 });
+        unsafe { imgui_sys::igSetNextWindowPosEx(pos, cond.raw(), pivot) }
+    }
+
+    /// `igSetNextWindowSize`: set next window size. set axis to 0.0f to force an auto-fit on this axis. call before Begin()
+    pub fn set_next_window_size(&self, size: imgui_sys::ImVec2, cond: Option<enums::Cond>) {
+        self.check_current();
+        let cond = cond.unwrap_or(enums::Cond::None);
+        unsafe { imgui_sys::igSetNextWindowSize(size, cond.raw()) }
+    }
+
+    /// `igSetNextWindowContentSize`: set next window content size (~ scrollable client area, which enforce the range of scrollbars). Not including window decorations (title bar, menu bar, etc.) nor WindowPadding. set an axis to 0.0f to leave it automatic. call before Begin()
+    pub fn set_next_window_content_size(&self, size: imgui_sys::ImVec2) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNextWindowContentSize(size) }
+    }
+
+    /// `igSetNextWindowCollapsed`: set next window collapsed state. call before Begin()
+    pub fn set_next_window_collapsed(&self, collapsed: bool, cond: Option<enums::Cond>) {
+        self.check_current();
+        let cond = cond.unwrap_or(enums::Cond::None);
+        unsafe { imgui_sys::igSetNextWindowCollapsed(collapsed, cond.raw()) }
+    }
+
+    /// `igSetNextWindowFocus`: set next window to be focused / top-most. call before Begin()
+    pub fn set_next_window_focus(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNextWindowFocus() }
+    }
+
+    /// `igSetNextWindowScroll`: set next window scrolling value (use < 0.0f to not affect a given axis).
+    pub fn set_next_window_scroll(&self, scroll: imgui_sys::ImVec2) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNextWindowScroll(scroll) }
+    }
+
+    /// `igSetNextWindowBgAlpha`: set next window background color alpha. helper to easily override the Alpha component of ImGuiCol_WindowBg/ChildBg/PopupBg. you may also use ImGuiWindowFlags_NoBackground.
+    pub fn set_next_window_bg_alpha(&self, alpha: f32) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNextWindowBgAlpha(alpha) }
+    }
+
+    /// `igSetWindowPos`: (not recommended) set current window position - call within Begin()/End(). prefer using SetNextWindowPos(), as this may incur tearing and side-effects.
+    pub fn set_window_pos(&self, pos: imgui_sys::ImVec2, cond: Option<enums::Cond>) {
+        self.check_current();
+        let cond = cond.unwrap_or(enums::Cond::None);
+        unsafe { imgui_sys::igSetWindowPos(pos, cond.raw()) }
+    }
+
+    /// `igSetWindowSize`: (not recommended) set current window size - call within Begin()/End(). set to ImVec2(0, 0) to force an auto-fit. prefer using SetNextWindowSize(), as this may incur tearing and minor side-effects.
+    pub fn set_window_size(&self, size: imgui_sys::ImVec2, cond: Option<enums::Cond>) {
+        self.check_current();
+        let cond = cond.unwrap_or(enums::Cond::None);
+        unsafe { imgui_sys::igSetWindowSize(size, cond.raw()) }
+    }
+
+    /// `igSetWindowCollapsed`: (not recommended) set current window collapsed state. prefer using SetNextWindowCollapsed().
+    pub fn set_window_collapsed(&self, collapsed: bool, cond: Option<enums::Cond>) {
+        self.check_current();
+        let cond = cond.unwrap_or(enums::Cond::None);
+        unsafe { imgui_sys::igSetWindowCollapsed(collapsed, cond.raw()) }
+    }
+
+    /// `igSetWindowFocus`: (not recommended) set current window to be focused / top-most. prefer using SetNextWindowFocus().
+    pub fn set_window_focus(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igSetWindowFocus() }
+    }
+
+    /// `igSetWindowPosStr`: (not recommended) set current window position - call within Begin()/End(). prefer using SetNextWindowPos(), as this may incur tearing and side-effects.
+    pub fn set_window_pos_str(&self, name: impl Label, pos: imgui_sys::ImVec2, cond: Option<enums::Cond>) {
+        let scratch = self.scratch();
+        let name = name.write_c_str(scratch);
+        let cond = cond.unwrap_or(enums::Cond::None);
+        unsafe { imgui_sys::igSetWindowPosStr(scratch.c_str(name), pos, cond.raw()) }
+    }
+
+    /// `igSetWindowSizeStr`: (not recommended) set current window size - call within Begin()/End(). set to ImVec2(0, 0) to force an auto-fit. prefer using SetNextWindowSize(), as this may incur tearing and minor side-effects.
+    pub fn set_window_size_str(&self, name: impl Label, size: imgui_sys::ImVec2, cond: Option<enums::Cond>) {
+        let scratch = self.scratch();
+        let name = name.write_c_str(scratch);
+        let cond = cond.unwrap_or(enums::Cond::None);
+        unsafe { imgui_sys::igSetWindowSizeStr(scratch.c_str(name), size, cond.raw()) }
+    }
+
+    /// `igSetWindowCollapsedStr`: (not recommended) set current window collapsed state. prefer using SetNextWindowCollapsed().
+    pub fn set_window_collapsed_str(&self, name: impl Label, collapsed: bool, cond: Option<enums::Cond>) {
+        let scratch = self.scratch();
+        let name = name.write_c_str(scratch);
+        let cond = cond.unwrap_or(enums::Cond::None);
+        unsafe { imgui_sys::igSetWindowCollapsedStr(scratch.c_str(name), collapsed, cond.raw()) }
+    }
+
+    /// `igSetWindowFocusStr`: (not recommended) set current window to be focused / top-most. prefer using SetNextWindowFocus().
+    pub fn set_window_focus_str(&self, name: impl Label) {
+        let scratch = self.scratch();
+        let name = name.write_c_str(scratch);
+        unsafe { imgui_sys::igSetWindowFocusStr(scratch.c_str(name)) }
+    }
+
+    /// `igGetScrollX`: get scrolling amount [0 .. GetScrollMaxX()]
+    pub fn get_scroll_x(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetScrollX() }
+    }
+
+    /// `igGetScrollY`: get scrolling amount [0 .. GetScrollMaxY()]
+    pub fn get_scroll_y(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetScrollY() }
+    }
+
+    /// `igSetScrollX`: set scrolling amount [0 .. GetScrollMaxX()]
+    pub fn set_scroll_x(&self, scroll_x: f32) {
+        self.check_current();
+        unsafe { imgui_sys::igSetScrollX(scroll_x) }
+    }
+
+    /// `igSetScrollY`: set scrolling amount [0 .. GetScrollMaxY()]
+    pub fn set_scroll_y(&self, scroll_y: f32) {
+        self.check_current();
+        unsafe { imgui_sys::igSetScrollY(scroll_y) }
+    }
+
+    /// `igGetScrollMaxX`: get maximum scrolling amount ~~ ContentSize.x - WindowSize.x - DecorationsSize.x
+    pub fn get_scroll_max_x(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetScrollMaxX() }
+    }
+
+    /// `igGetScrollMaxY`: get maximum scrolling amount ~~ ContentSize.y - WindowSize.y - DecorationsSize.y
+    pub fn get_scroll_max_y(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetScrollMaxY() }
+    }
+
+    /// `igSetScrollHereX`: adjust scrolling amount to make current cursor position visible. center_x_ratio=0.0: left, 0.5: center, 1.0: right. When using to make a "default/current item" visible, consider using SetItemDefaultFocus() instead.
+    pub fn set_scroll_here_x(&self, center_x_ratio: Option<f32>) {
+        self.check_current();
+        let center_x_ratio = center_x_ratio.unwrap_or(0.5);
+        unsafe { imgui_sys::igSetScrollHereX(center_x_ratio) }
+    }
+
+    /// `igSetScrollHereY`: adjust scrolling amount to make current cursor position visible. center_y_ratio=0.0: top, 0.5: center, 1.0: bottom. When using to make a "default/current item" visible, consider using SetItemDefaultFocus() instead.
+    pub fn set_scroll_here_y(&self, center_y_ratio: Option<f32>) {
+        self.check_current();
+        let center_y_ratio = center_y_ratio.unwrap_or(0.5);
+        unsafe { imgui_sys::igSetScrollHereY(center_y_ratio) }
+    }
+
+    /// `igSetScrollFromPosX`: adjust scrolling amount to make given position visible. Generally GetCursorStartPos() + offset to compute a valid position.
+    pub fn set_scroll_from_pos_x(&self, local_x: f32, center_x_ratio: Option<f32>) {
+        self.check_current();
+        let center_x_ratio = center_x_ratio.unwrap_or(0.5);
+        unsafe { imgui_sys::igSetScrollFromPosX(local_x, center_x_ratio) }
+    }
+
+    /// `igSetScrollFromPosY`: adjust scrolling amount to make given position visible. Generally GetCursorStartPos() + offset to compute a valid position.
+    pub fn set_scroll_from_pos_y(&self, local_y: f32, center_y_ratio: Option<f32>) {
+        self.check_current();
+        let center_y_ratio = center_y_ratio.unwrap_or(0.5);
+        unsafe { imgui_sys::igSetScrollFromPosY(local_y, center_y_ratio) }
+    }
+
+    /// `igGetFontSize`: get current scaled font size (= height in pixels). AFTER global scale factors applied. *IMPORTANT* DO NOT PASS THIS VALUE TO PushFont()! Use ImGui::GetStyle().FontSizeBase to get value before global scale factors.
+    pub fn get_font_size(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetFontSize() }
+    }
+
+    /// `igSetNextItemWidth`: set width of the _next_ common large "item+label" widget. >0.0f: width in pixels, <0.0f align xx pixels to the right of window (so -FLT_MIN always align width to the right side)
+    pub fn set_next_item_width(&self, item_width: f32) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNextItemWidth(item_width) }
+    }
+
+    /// `igCalcItemWidth`: width of item given pushed settings and current cursor position. NOT necessarily the width of last item unlike most 'Item' functions.
+    pub fn calc_item_width(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igCalcItemWidth() }
+    }
+
+    /// `igGetFontTexUvWhitePixel`: get UV coordinate for a white pixel, useful to draw custom shapes via the ImDrawList API
+    pub fn get_font_tex_uv_white_pixel(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetFontTexUvWhitePixel() }
+    }
+
+    /// `igGetColorU32`: retrieve given style color with style alpha applied and optional extra alpha multiplier, packed as a 32-bit value suitable for ImDrawList
+    pub fn get_color_u32(&self, idx: enums::Col) -> imgui_sys::ImU32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetColorU32(idx.raw()) }
+    }
+
+    /// `igGetColorU32Ex`: retrieve given style color with style alpha applied and optional extra alpha multiplier, packed as a 32-bit value suitable for ImDrawList
+    pub fn get_color_u32_ex(&self, idx: enums::Col, alpha_mul: Option<f32>) -> imgui_sys::ImU32 {
+        self.check_current();
+        let alpha_mul = alpha_mul.unwrap_or(1.0);
+        unsafe { imgui_sys::igGetColorU32Ex(idx.raw(), alpha_mul) }
+    }
+
+    /// `igGetColorU32ImVec4`: retrieve given style color with style alpha applied and optional extra alpha multiplier, packed as a 32-bit value suitable for ImDrawList
+    pub fn get_color_u32_im_vec4(&self, col: imgui_sys::ImVec4) -> imgui_sys::ImU32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetColorU32ImVec4(col) }
+    }
+
+    /// `igGetColorU32ImU32`: retrieve given style color with style alpha applied and optional extra alpha multiplier, packed as a 32-bit value suitable for ImDrawList
+    pub fn get_color_u32_im_u32(&self, col: imgui_sys::ImU32) -> imgui_sys::ImU32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetColorU32ImU32(col) }
+    }
+
+    /// `igGetColorU32ImU32Ex`: retrieve given style color with style alpha applied and optional extra alpha multiplier, packed as a 32-bit value suitable for ImDrawList
+    pub fn get_color_u32_im_u32_ex(&self, col: imgui_sys::ImU32, alpha_mul: Option<f32>) -> imgui_sys::ImU32 {
+        self.check_current();
+        let alpha_mul = alpha_mul.unwrap_or(1.0);
+        unsafe { imgui_sys::igGetColorU32ImU32Ex(col, alpha_mul) }
+    }
+
+    /// `igGetCursorScreenPos`: cursor position, absolute coordinates. THIS IS YOUR BEST FRIEND (prefer using this rather than GetCursorPos(), also more useful to work with ImDrawList API).
+    pub fn get_cursor_screen_pos(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetCursorScreenPos() }
+    }
+
+    /// `igSetCursorScreenPos`: cursor position, absolute coordinates. THIS IS YOUR BEST FRIEND.
+    pub fn set_cursor_screen_pos(&self, pos: imgui_sys::ImVec2) {
+        self.check_current();
+        unsafe { imgui_sys::igSetCursorScreenPos(pos) }
+    }
+
+    /// `igGetContentRegionAvail`: available space from current position. THIS IS YOUR BEST FRIEND.
+    pub fn get_content_region_avail(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetContentRegionAvail() }
+    }
+
+    /// `igGetCursorPos`: [window-local] cursor position in window-local coordinates. This is not your best friend.
+    pub fn get_cursor_pos(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetCursorPos() }
+    }
+
+    /// `igGetCursorPosX`: [window-local] "
+    pub fn get_cursor_pos_x(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetCursorPosX() }
+    }
+
+    /// `igGetCursorPosY`: [window-local] "
+    pub fn get_cursor_pos_y(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetCursorPosY() }
+    }
+
+    /// `igSetCursorPos`: [window-local] "
+    pub fn set_cursor_pos(&self, local_pos: imgui_sys::ImVec2) {
+        self.check_current();
+        unsafe { imgui_sys::igSetCursorPos(local_pos) }
+    }
+
+    /// `igSetCursorPosX`: [window-local] "
+    pub fn set_cursor_pos_x(&self, local_x: f32) {
+        self.check_current();
+        unsafe { imgui_sys::igSetCursorPosX(local_x) }
+    }
+
+    /// `igSetCursorPosY`: [window-local] "
+    pub fn set_cursor_pos_y(&self, local_y: f32) {
+        self.check_current();
+        unsafe { imgui_sys::igSetCursorPosY(local_y) }
+    }
+
+    /// `igGetCursorStartPos`: [window-local] initial cursor position, in window-local coordinates. Call GetCursorScreenPos() after Begin() to get the absolute coordinates version.
+    pub fn get_cursor_start_pos(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetCursorStartPos() }
+    }
+
+    /// `igSeparator`: separator, generally horizontal. inside a menu bar or in horizontal layout mode, this becomes a vertical separator.
+    pub fn separator(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igSeparator() }
+    }
+
+    /// `igSameLine`: call between widgets or groups to layout them horizontally. X position given in window coordinates.
+    pub fn same_line(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igSameLine() }
+    }
+
+    /// `igSameLineEx`: call between widgets or groups to layout them horizontally. X position given in window coordinates.
+    pub fn same_line_ex(&self, offset_from_start_x: Option<f32>, spacing: Option<f32>) {
+        self.check_current();
+        let offset_from_start_x = offset_from_start_x.unwrap_or(0.0);
+        let spacing = spacing.unwrap_or(-1.0);
+        unsafe { imgui_sys::igSameLineEx(offset_from_start_x, spacing) }
+    }
+
+    /// `igNewLine`: undo a SameLine() or force a new line when in a horizontal-layout context.
+    pub fn new_line(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igNewLine() }
+    }
+
+    /// `igSpacing`: add vertical spacing.
+    pub fn spacing(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igSpacing() }
+    }
+
+    /// `igDummy`: add a dummy item of given size. unlike InvisibleButton(), Dummy() won't take the mouse click or be navigable into.
+    pub fn dummy(&self, size: imgui_sys::ImVec2) {
+        self.check_current();
+        unsafe { imgui_sys::igDummy(size) }
+    }
+
+    /// `igIndent`: move content position toward the right, by indent_w, or style.IndentSpacing if indent_w <= 0
+    pub fn indent(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igIndent() }
+    }
+
+    /// `igIndentEx`: move content position toward the right, by indent_w, or style.IndentSpacing if indent_w <= 0
+    pub fn indent_ex(&self, indent_w: Option<f32>) {
+        self.check_current();
+        let indent_w = indent_w.unwrap_or(0.0);
+        unsafe { imgui_sys::igIndentEx(indent_w) }
+    }
+
+    /// `igUnindent`: move content position back to the left, by indent_w, or style.IndentSpacing if indent_w <= 0
+    pub fn unindent(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igUnindent() }
+    }
+
+    /// `igUnindentEx`: move content position back to the left, by indent_w, or style.IndentSpacing if indent_w <= 0
+    pub fn unindent_ex(&self, indent_w: Option<f32>) {
+        self.check_current();
+        let indent_w = indent_w.unwrap_or(0.0);
+        unsafe { imgui_sys::igUnindentEx(indent_w) }
+    }
+
+    /// `igAlignTextToFramePadding`: vertically align upcoming text baseline to FramePadding.y so that it will align properly to regularly framed items (call if you have text on a line before a framed item)
+    pub fn align_text_to_frame_padding(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igAlignTextToFramePadding() }
+    }
+
+    /// `igGetTextLineHeight`: ~ FontSize
+    pub fn get_text_line_height(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetTextLineHeight() }
+    }
+
+    /// `igGetTextLineHeightWithSpacing`: ~ FontSize + style.ItemSpacing.y (distance in pixels between 2 consecutive lines of text)
+    pub fn get_text_line_height_with_spacing(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetTextLineHeightWithSpacing() }
+    }
+
+    /// `igGetFrameHeight`: ~ FontSize + style.FramePadding.y * 2
+    pub fn get_frame_height(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetFrameHeight() }
+    }
+
+    /// `igGetFrameHeightWithSpacing`: ~ FontSize + style.FramePadding.y * 2 + style.ItemSpacing.y (distance in pixels between 2 consecutive lines of framed widgets)
+    pub fn get_frame_height_with_spacing(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetFrameHeightWithSpacing() }
+    }
+
+    /// `igGetID`: calculate unique ID (hash of whole ID stack + given parameter). e.g. if you want to query into ImGuiStorage yourself
+    pub fn get_id(&self, str_id: impl Label) -> imgui_sys::ImGuiID {
+        let scratch = self.scratch();
+        let str_id = str_id.write_c_str(scratch);
+        unsafe { imgui_sys::igGetID(scratch.c_str(str_id)) }
+    }
+
+    /// `igGetIDStr`: calculate unique ID (hash of whole ID stack + given parameter). e.g. if you want to query into ImGuiStorage yourself
+    pub fn get_id_str(&self, str_id: impl Label) -> imgui_sys::ImGuiID {
+        let scratch = self.scratch();
+        let str_id = str_id.write_range(scratch);
+        let (str_id_begin, str_id_end) = scratch.range(str_id);
+        unsafe { imgui_sys::igGetIDStr(str_id_begin, str_id_end) }
+    }
+
+    /// `igGetIDInt`: calculate unique ID (hash of whole ID stack + given parameter). e.g. if you want to query into ImGuiStorage yourself
+    pub fn get_id_int(&self, int_id: i32) -> imgui_sys::ImGuiID {
+        self.check_current();
+        unsafe { imgui_sys::igGetIDInt(int_id) }
+    }
+
+    /// `igSeparatorText`: currently: formatted text with a horizontal line
+    pub fn separator_text(&self, label: impl Label) {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSeparatorText(scratch.c_str(label)) }
+    }
+
+    /// `igButton`: button
+    pub fn button(&self, label: impl Label) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igButton(scratch.c_str(label)) }
+    }
+
+    /// `igButtonEx`: button
+    pub fn button_ex(&self, label: impl Label, size: Option<imgui_sys::ImVec2>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let size = size.unwrap_or(imgui_sys::ImVec2 { x: 0.0, y: 0.0 });
+        unsafe { imgui_sys::igButtonEx(scratch.c_str(label), size) }
+    }
+
+    /// `igSmallButton`: button with (FramePadding.y == 0) to easily embed within text
+    pub fn small_button(&self, label: impl Label) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSmallButton(scratch.c_str(label)) }
+    }
+
+    /// `igInvisibleButton`: flexible button behavior without the visuals, frequently useful to build custom behaviors using the public api (along with IsItemActive, IsItemHovered, etc.)
+    pub fn invisible_button(&self, str_id: impl Label, size: imgui_sys::ImVec2, flags: Option<enums::ButtonFlags>) -> bool {
+        let scratch = self.scratch();
+        let str_id = str_id.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::ButtonFlags::None);
+        unsafe { imgui_sys::igInvisibleButton(scratch.c_str(str_id), size, flags.bits()) }
+    }
+
+    /// `igArrowButton`: square button with an arrow shape
+    pub fn arrow_button(&self, str_id: impl Label, dir: enums::Dir) -> bool {
+        let scratch = self.scratch();
+        let str_id = str_id.write_c_str(scratch);
+        unsafe { imgui_sys::igArrowButton(scratch.c_str(str_id), dir.raw()) }
+    }
+
+    /// `igCheckbox`
+    pub fn checkbox(&self, label: impl Label, v: &mut bool) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igCheckbox(scratch.c_str(label), v) }
+    }
+
+    /// `igCheckboxFlagsIntPtr`
+    pub fn checkbox_flags_int_ptr(&self, label: impl Label, flags: &mut i32, flags_value: i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igCheckboxFlagsIntPtr(scratch.c_str(label), flags, flags_value) }
+    }
+
+    /// `igCheckboxFlagsUintPtr`
+    pub fn checkbox_flags_uint_ptr(&self, label: impl Label, flags: &mut u32, flags_value: u32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igCheckboxFlagsUintPtr(scratch.c_str(label), flags, flags_value) }
+    }
+
+    /// `igRadioButton`: use with e.g. if (RadioButton("one", my_value==1)) { my_value = 1; }
+    pub fn radio_button(&self, label: impl Label, active: bool) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igRadioButton(scratch.c_str(label), active) }
+    }
+
+    /// `igRadioButtonIntPtr`: use with e.g. if (RadioButton("one", my_value==1)) { my_value = 1; }
+    pub fn radio_button_int_ptr(&self, label: impl Label, v: &mut i32, v_button: i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igRadioButtonIntPtr(scratch.c_str(label), v, v_button) }
+    }
+
+    /// `igProgressBar`
+    pub fn progress_bar(&self, fraction: f32, size_arg: Option<imgui_sys::ImVec2>, overlay: Option<&str>) {
+        let scratch = self.scratch();
+        let size_arg = size_arg.unwrap_or(imgui_sys::ImVec2 { x: -f32::MIN_POSITIVE, y: 0.0 });
+        let overlay = overlay.map(|overlay| overlay.write_c_str(scratch));
+        unsafe { imgui_sys::igProgressBar(fraction, size_arg, overlay.map_or(ptr::null(), |overlay| scratch.c_str(overlay))) }
+    }
+
+    /// `igBullet`: draw a small circle + keep the cursor on the same line. advance cursor x position by GetTreeNodeToLabelSpacing(), same distance that TreeNode() uses
+    pub fn bullet(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igBullet() }
+    }
+
+    /// `igDragFloat`: If v_min >= v_max we have no bound
+    pub fn drag_float(&self, label: impl Label, v: &mut f32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragFloat(scratch.c_str(label), v) }
+    }
+
+    /// `igDragFloat2`
+    pub fn drag_float2(&self, label: impl Label, v: &mut [f32; 2]) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragFloat2(scratch.c_str(label), v.as_mut_ptr()) }
+    }
+
+    /// `igDragFloat3`
+    pub fn drag_float3(&self, label: impl Label, v: &mut [f32; 3]) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragFloat3(scratch.c_str(label), v.as_mut_ptr()) }
+    }
+
+    /// `igDragFloat4`
+    pub fn drag_float4(&self, label: impl Label, v: &mut [f32; 4]) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragFloat4(scratch.c_str(label), v.as_mut_ptr()) }
+    }
+
+    /// `igDragFloatRange2`
+    pub fn drag_float_range2(&self, label: impl Label, v_current_min: &mut f32, v_current_max: &mut f32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragFloatRange2(scratch.c_str(label), v_current_min, v_current_max) }
+    }
+
+    /// `igDragInt`: If v_min >= v_max we have no bound
+    pub fn drag_int(&self, label: impl Label, v: &mut i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragInt(scratch.c_str(label), v) }
+    }
+
+    /// `igDragInt2`
+    pub fn drag_int2(&self, label: impl Label, v: &mut [i32; 2]) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragInt2(scratch.c_str(label), v.as_mut_ptr()) }
+    }
+
+    /// `igDragInt3`
+    pub fn drag_int3(&self, label: impl Label, v: &mut [i32; 3]) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragInt3(scratch.c_str(label), v.as_mut_ptr()) }
+    }
+
+    /// `igDragInt4`
+    pub fn drag_int4(&self, label: impl Label, v: &mut [i32; 4]) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragInt4(scratch.c_str(label), v.as_mut_ptr()) }
+    }
+
+    /// `igDragIntRange2`
+    pub fn drag_int_range2(&self, label: impl Label, v_current_min: &mut i32, v_current_max: &mut i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igDragIntRange2(scratch.c_str(label), v_current_min, v_current_max) }
+    }
+
+    /// `igSliderFloat`: adjust format to decorate the value with a prefix or a suffix for in-slider labels or unit display.
+    pub fn slider_float(&self, label: impl Label, v: &mut f32, v_min: f32, v_max: f32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSliderFloat(scratch.c_str(label), v, v_min, v_max) }
+    }
+
+    /// `igSliderFloat2`
+    pub fn slider_float2(&self, label: impl Label, v: &mut [f32; 2], v_min: f32, v_max: f32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSliderFloat2(scratch.c_str(label), v.as_mut_ptr(), v_min, v_max) }
+    }
+
+    /// `igSliderFloat3`
+    pub fn slider_float3(&self, label: impl Label, v: &mut [f32; 3], v_min: f32, v_max: f32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSliderFloat3(scratch.c_str(label), v.as_mut_ptr(), v_min, v_max) }
+    }
+
+    /// `igSliderFloat4`
+    pub fn slider_float4(&self, label: impl Label, v: &mut [f32; 4], v_min: f32, v_max: f32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSliderFloat4(scratch.c_str(label), v.as_mut_ptr(), v_min, v_max) }
+    }
+
+    /// `igSliderAngle`
+    pub fn slider_angle(&self, label: impl Label, v_rad: &mut f32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSliderAngle(scratch.c_str(label), v_rad) }
+    }
+
+    /// `igSliderInt`
+    pub fn slider_int(&self, label: impl Label, v: &mut i32, v_min: i32, v_max: i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSliderInt(scratch.c_str(label), v, v_min, v_max) }
+    }
+
+    /// `igSliderInt2`
+    pub fn slider_int2(&self, label: impl Label, v: &mut [i32; 2], v_min: i32, v_max: i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSliderInt2(scratch.c_str(label), v.as_mut_ptr(), v_min, v_max) }
+    }
+
+    /// `igSliderInt3`
+    pub fn slider_int3(&self, label: impl Label, v: &mut [i32; 3], v_min: i32, v_max: i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSliderInt3(scratch.c_str(label), v.as_mut_ptr(), v_min, v_max) }
+    }
+
+    /// `igSliderInt4`
+    pub fn slider_int4(&self, label: impl Label, v: &mut [i32; 4], v_min: i32, v_max: i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSliderInt4(scratch.c_str(label), v.as_mut_ptr(), v_min, v_max) }
+    }
+
+    /// `igVSliderFloat`
+    pub fn v_slider_float(&self, label: impl Label, size: imgui_sys::ImVec2, v: &mut f32, v_min: f32, v_max: f32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igVSliderFloat(scratch.c_str(label), size, v, v_min, v_max) }
+    }
+
+    /// `igVSliderInt`
+    pub fn v_slider_int(&self, label: impl Label, size: imgui_sys::ImVec2, v: &mut i32, v_min: i32, v_max: i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igVSliderInt(scratch.c_str(label), size, v, v_min, v_max) }
+    }
+
+    /// `igInputFloat`
+    pub fn input_float(&self, label: impl Label, v: &mut f32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igInputFloat(scratch.c_str(label), v) }
+    }
+
+    /// `igInputFloat2`
+    pub fn input_float2(&self, label: impl Label, v: &mut [f32; 2]) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igInputFloat2(scratch.c_str(label), v.as_mut_ptr()) }
+    }
+
+    /// `igInputFloat3`
+    pub fn input_float3(&self, label: impl Label, v: &mut [f32; 3]) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igInputFloat3(scratch.c_str(label), v.as_mut_ptr()) }
+    }
+
+    /// `igInputFloat4`
+    pub fn input_float4(&self, label: impl Label, v: &mut [f32; 4]) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igInputFloat4(scratch.c_str(label), v.as_mut_ptr()) }
+    }
+
+    /// `igInputInt`
+    pub fn input_int(&self, label: impl Label, v: &mut i32) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igInputInt(scratch.c_str(label), v) }
+    }
+
+    /// `igInputIntEx`
+    pub fn input_int_ex(&self, label: impl Label, v: &mut i32, step: Option<i32>, step_fast: Option<i32>, flags: Option<enums::InputTextFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let step = step.unwrap_or(1);
+        let step_fast = step_fast.unwrap_or(100);
+        let flags = flags.unwrap_or(enums::InputTextFlags::None);
+        unsafe { imgui_sys::igInputIntEx(scratch.c_str(label), v, step, step_fast, flags.bits()) }
+    }
+
+    /// `igInputInt2`
+    pub fn input_int2(&self, label: impl Label, v: &mut [i32; 2], flags: Option<enums::InputTextFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::InputTextFlags::None);
+        unsafe { imgui_sys::igInputInt2(scratch.c_str(label), v.as_mut_ptr(), flags.bits()) }
+    }
+
+    /// `igInputInt3`
+    pub fn input_int3(&self, label: impl Label, v: &mut [i32; 3], flags: Option<enums::InputTextFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::InputTextFlags::None);
+        unsafe { imgui_sys::igInputInt3(scratch.c_str(label), v.as_mut_ptr(), flags.bits()) }
+    }
+
+    /// `igInputInt4`
+    pub fn input_int4(&self, label: impl Label, v: &mut [i32; 4], flags: Option<enums::InputTextFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::InputTextFlags::None);
+        unsafe { imgui_sys::igInputInt4(scratch.c_str(label), v.as_mut_ptr(), flags.bits()) }
+    }
+
+    /// `igInputDouble`
+    pub fn input_double(&self, label: impl Label, v: &mut f64) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igInputDouble(scratch.c_str(label), v) }
+    }
+
+    /// `igColorEdit3`
+    pub fn color_edit3(&self, label: impl Label, col: &mut [f32; 3], flags: Option<enums::ColorEditFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::ColorEditFlags::None);
+        unsafe { imgui_sys::igColorEdit3(scratch.c_str(label), col.as_mut_ptr(), flags.bits()) }
+    }
+
+    /// `igColorEdit4`
+    pub fn color_edit4(&self, label: impl Label, col: &mut [f32; 4], flags: Option<enums::ColorEditFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::ColorEditFlags::None);
+        unsafe { imgui_sys::igColorEdit4(scratch.c_str(label), col.as_mut_ptr(), flags.bits()) }
+    }
+
+    /// `igColorPicker3`
+    pub fn color_picker3(&self, label: impl Label, col: &mut [f32; 3], flags: Option<enums::ColorEditFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::ColorEditFlags::None);
+        unsafe { imgui_sys::igColorPicker3(scratch.c_str(label), col.as_mut_ptr(), flags.bits()) }
+    }
+
+    /// `igColorButton`: display a color square/button, hover for details, return true when pressed.
+    pub fn color_button(&self, desc_id: impl Label, col: imgui_sys::ImVec4) -> bool {
+        let scratch = self.scratch();
+        let desc_id = desc_id.write_c_str(scratch);
+        let flags = enums::ColorEditFlags::None;
+        unsafe { imgui_sys::igColorButton(scratch.c_str(desc_id), col, flags.bits()) }
+    }
+
+    /// `igColorButtonEx`: display a color square/button, hover for details, return true when pressed.
+    pub fn color_button_ex(&self, desc_id: impl Label, col: imgui_sys::ImVec4, flags: Option<enums::ColorEditFlags>, size: Option<imgui_sys::ImVec2>) -> bool {
+        let scratch = self.scratch();
+        let desc_id = desc_id.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::ColorEditFlags::None);
+        let size = size.unwrap_or(imgui_sys::ImVec2 { x: 0.0, y: 0.0 });
+        unsafe { imgui_sys::igColorButtonEx(scratch.c_str(desc_id), col, flags.bits(), size) }
+    }
+
+    /// `igSetColorEditOptions`: initialize current options (generally on application startup) if you want to select a default format, picker type, etc. User will be able to change many settings, unless you pass the _NoOptions flag to your calls.
+    pub fn set_color_edit_options(&self, flags: enums::ColorEditFlags) {
+        self.check_current();
+        unsafe { imgui_sys::igSetColorEditOptions(flags.bits()) }
+    }
+
+    /// `igGetTreeNodeToLabelSpacing`: horizontal distance preceding label when using TreeNode*() or Bullet() == (g.FontSize + style.FramePadding.x*2) for a regular unframed TreeNode
+    pub fn get_tree_node_to_label_spacing(&self) -> f32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetTreeNodeToLabelSpacing() }
+    }
+
+    /// `igCollapsingHeader`: if returning 'true' the header is open. doesn't indent nor push on ID stack. user doesn't have to call TreePop().
+    pub fn collapsing_header(&self, label: impl Label, flags: Option<enums::TreeNodeFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::TreeNodeFlags::None);
+        unsafe { imgui_sys::igCollapsingHeader(scratch.c_str(label), flags.bits()) }
+    }
+
+    /// `igCollapsingHeaderBoolPtr`: if returning 'true' the header is open. doesn't indent nor push on ID stack. user doesn't have to call TreePop().
+    pub fn collapsing_header_bool_ptr(&self, label: impl Label, p_visible: &mut bool, flags: Option<enums::TreeNodeFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::TreeNodeFlags::None);
+        unsafe { imgui_sys::igCollapsingHeaderBoolPtr(scratch.c_str(label), p_visible, flags.bits()) }
+    }
+
+    /// `igSetNextItemOpen`: set next TreeNode/CollapsingHeader open state.
+    pub fn set_next_item_open(&self, is_open: bool, cond: Option<enums::Cond>) {
+        self.check_current();
+        let cond = cond.unwrap_or(enums::Cond::None);
+        unsafe { imgui_sys::igSetNextItemOpen(is_open, cond.raw()) }
+    }
+
+    /// `igSetNextItemStorageID`: set id to use for open/close storage (default to same as item id).
+    pub fn set_next_item_storage_id(&self, storage_id: imgui_sys::ImGuiID) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNextItemStorageID(storage_id) }
+    }
+
+    /// `igSelectable`: "bool selected" carry the selection state (read-only). Selectable() is clicked is returns true so you can modify your selection state. size.x==0.0: use remaining width, size.x>0.0: specify width. size.y==0.0: use label height, size.y>0.0: specify height
+    pub fn selectable(&self, label: impl Label) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igSelectable(scratch.c_str(label)) }
+    }
+
+    /// `igSelectableEx`: "bool selected" carry the selection state (read-only). Selectable() is clicked is returns true so you can modify your selection state. size.x==0.0: use remaining width, size.x>0.0: specify width. size.y==0.0: use label height, size.y>0.0: specify height
+    pub fn selectable_ex(&self, label: impl Label, selected: Option<bool>, flags: Option<enums::SelectableFlags>, size: Option<imgui_sys::ImVec2>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let selected = selected.unwrap_or(false);
+        let flags = flags.unwrap_or(enums::SelectableFlags::None);
+        let size = size.unwrap_or(imgui_sys::ImVec2 { x: 0.0, y: 0.0 });
+        unsafe { imgui_sys::igSelectableEx(scratch.c_str(label), selected, flags.bits(), size) }
+    }
+
+    /// `igSelectableBoolPtr`: "bool selected" carry the selection state (read-only). Selectable() is clicked is returns true so you can modify your selection state. size.x==0.0: use remaining width, size.x>0.0: specify width. size.y==0.0: use label height, size.y>0.0: specify height
+    pub fn selectable_bool_ptr(&self, label: impl Label, p_selected: &mut bool) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = enums::SelectableFlags::None;
+        unsafe { imgui_sys::igSelectableBoolPtr(scratch.c_str(label), p_selected, flags.bits()) }
+    }
+
+    /// `igSelectableBoolPtrEx`: "bool selected" carry the selection state (read-only). Selectable() is clicked is returns true so you can modify your selection state. size.x==0.0: use remaining width, size.x>0.0: specify width. size.y==0.0: use label height, size.y>0.0: specify height
+    pub fn selectable_bool_ptr_ex(&self, label: impl Label, p_selected: &mut bool, flags: Option<enums::SelectableFlags>, size: Option<imgui_sys::ImVec2>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::SelectableFlags::None);
+        let size = size.unwrap_or(imgui_sys::ImVec2 { x: 0.0, y: 0.0 });
+        unsafe { imgui_sys::igSelectableBoolPtrEx(scratch.c_str(label), p_selected, flags.bits(), size) }
+    }
+
+    /// `igIsItemToggledSelection`: Was the last item selection state toggled? Useful if you need the per-item information _before_ reaching EndMultiSelect(). We only returns toggle _event_ in order to handle clipping correctly.
+    pub fn is_item_toggled_selection(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemToggledSelection() }
+    }
+
+    /// `igMenuItem`: return true when activated.
+    pub fn menu_item(&self, label: impl Label) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igMenuItem(scratch.c_str(label)) }
+    }
+
+    /// `igMenuItemEx`: return true when activated.
+    pub fn menu_item_ex(&self, label: impl Label, shortcut: Option<&str>, selected: Option<bool>, enabled: Option<bool>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let shortcut = shortcut.map(|shortcut| shortcut.write_c_str(scratch));
+        let selected = selected.unwrap_or(false);
+        let enabled = enabled.unwrap_or(true);
+        unsafe { imgui_sys::igMenuItemEx(scratch.c_str(label), shortcut.map_or(ptr::null(), |shortcut| scratch.c_str(shortcut)), selected, enabled) }
+    }
+
+    /// `igMenuItemBoolPtr`: return true when activated.
+    pub fn menu_item_bool_ptr(&self, label: impl Label, shortcut: impl Label, p_selected: &mut bool, enabled: Option<bool>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let shortcut = shortcut.write_c_str(scratch);
+        let enabled = enabled.unwrap_or(true);
+        unsafe { imgui_sys::igMenuItemBoolPtr(scratch.c_str(label), scratch.c_str(shortcut), p_selected, enabled) }
+    }
+
+    /// `igOpenPopup`: call to mark popup as open (don't call every frame!).
+    pub fn open_popup(&self, str_id: impl Label, popup_flags: Option<enums::PopupFlags>) {
+        let scratch = self.scratch();
+        let str_id = str_id.write_c_str(scratch);
+        let popup_flags = popup_flags.unwrap_or(enums::PopupFlags::None);
+        unsafe { imgui_sys::igOpenPopup(scratch.c_str(str_id), popup_flags.bits()) }
+    }
+
+    /// `igOpenPopupID`: call to mark popup as open (don't call every frame!).
+    pub fn open_popup_id(&self, id: imgui_sys::ImGuiID, popup_flags: Option<enums::PopupFlags>) {
+        self.check_current();
+        let popup_flags = popup_flags.unwrap_or(enums::PopupFlags::None);
+        unsafe { imgui_sys::igOpenPopupID(id, popup_flags.bits()) }
+    }
+
+    /// `igOpenPopupOnItemClick`: helper to open popup when clicked on last item. Default to ImGuiPopupFlags_MouseButtonRight == 1. (note: actually triggers on the mouse _released_ event to be consistent with popup behaviors)
+    pub fn open_popup_on_item_click(&self, str_id: Option<&str>, popup_flags: Option<enums::PopupFlags>) {
+        let scratch = self.scratch();
+        let str_id = str_id.map(|str_id| str_id.write_c_str(scratch));
+        let popup_flags = popup_flags.unwrap_or(enums::PopupFlags::MouseButtonRight);
+        unsafe { imgui_sys::igOpenPopupOnItemClick(str_id.map_or(ptr::null(), |str_id| scratch.c_str(str_id)), popup_flags.bits()) }
+    }
+
+    /// `igCloseCurrentPopup`: manually close the popup we have begin-ed into.
+    pub fn close_current_popup(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igCloseCurrentPopup() }
+    }
+
+    /// `igIsPopupOpen`: return true if the popup is open.
+    pub fn is_popup_open(&self, str_id: impl Label, flags: Option<enums::PopupFlags>) -> bool {
+        let scratch = self.scratch();
+        let str_id = str_id.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::PopupFlags::None);
+        unsafe { imgui_sys::igIsPopupOpen(scratch.c_str(str_id), flags.bits()) }
+    }
+
+    /// `igTableNextRow`: append into the first cell of a new row.
+    pub fn table_next_row(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igTableNextRow() }
+    }
+
+    /// `igTableNextRowEx`: append into the first cell of a new row.
+    pub fn table_next_row_ex(&self, row_flags: Option<enums::TableRowFlags>, min_row_height: Option<f32>) {
+        self.check_current();
+        let row_flags = row_flags.unwrap_or(enums::TableRowFlags::None);
+        let min_row_height = min_row_height.unwrap_or(0.0);
+        unsafe { imgui_sys::igTableNextRowEx(row_flags.bits(), min_row_height) }
+    }
+
+    /// `igTableNextColumn`: append into the next column (or first column of next row if currently in last column). Return true when column is visible.
+    pub fn table_next_column(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igTableNextColumn() }
+    }
+
+    /// `igTableSetColumnIndex`: append into the specified column. Return true when column is visible.
+    pub fn table_set_column_index(&self, column_n: i32) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igTableSetColumnIndex(column_n) }
+    }
+
+    /// `igTableSetupColumn`
+    pub fn table_setup_column(&self, label: impl Label) {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = enums::TableColumnFlags::None;
+        unsafe { imgui_sys::igTableSetupColumn(scratch.c_str(label), flags.bits()) }
+    }
+
+    /// `igTableSetupColumnEx`
+    pub fn table_setup_column_ex(&self, label: impl Label, flags: Option<enums::TableColumnFlags>, init_width_or_weight: Option<f32>, user_id: Option<imgui_sys::ImGuiID>) {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::TableColumnFlags::None);
+        let init_width_or_weight = init_width_or_weight.unwrap_or(0.0);
+        let user_id = user_id.unwrap_or(0);
+        unsafe { imgui_sys::igTableSetupColumnEx(scratch.c_str(label), flags.bits(), init_width_or_weight, user_id) }
+    }
+
+    /// `igTableSetupScrollFreeze`: lock columns/rows so they stay visible when scrolled.
+    pub fn table_setup_scroll_freeze(&self, cols: i32, rows: i32) {
+        self.check_current();
+        unsafe { imgui_sys::igTableSetupScrollFreeze(cols, rows) }
+    }
+
+    /// `igTableHeader`: submit one header cell manually (rarely used)
+    pub fn table_header(&self, label: impl Label) {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        unsafe { imgui_sys::igTableHeader(scratch.c_str(label)) }
+    }
+
+    /// `igTableHeadersRow`: submit a row with headers cells based on data provided to TableSetupColumn() + submit context menu
+    pub fn table_headers_row(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igTableHeadersRow() }
+    }
+
+    /// `igTableAngledHeadersRow`: submit a row with angled headers for every column with the ImGuiTableColumnFlags_AngledHeader flag. MUST BE FIRST ROW.
+    pub fn table_angled_headers_row(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igTableAngledHeadersRow() }
+    }
+
+    /// `igTableGetColumnCount`: return number of columns (value passed to BeginTable)
+    pub fn table_get_column_count(&self) -> i32 {
+        self.check_current();
+        unsafe { imgui_sys::igTableGetColumnCount() }
+    }
+
+    /// `igTableGetColumnIndex`: return current column index.
+    pub fn table_get_column_index(&self) -> i32 {
+        self.check_current();
+        unsafe { imgui_sys::igTableGetColumnIndex() }
+    }
+
+    /// `igTableGetRowIndex`: return current row index (header rows are accounted for)
+    pub fn table_get_row_index(&self) -> i32 {
+        self.check_current();
+        unsafe { imgui_sys::igTableGetRowIndex() }
+    }
+
+    /// `igTableGetColumnFlags`: return column flags so you can query their Enabled/Visible/Sorted/Hovered status flags. Pass -1 to use current column.
+    pub fn table_get_column_flags(&self, column_n: Option<i32>) -> enums::TableColumnFlags {
+        self.check_current();
+        let column_n = column_n.unwrap_or(-1);
+        enums::TableColumnFlags(unsafe { imgui_sys::igTableGetColumnFlags(column_n) })
+    }
+
+    /// `igTableSetColumnEnabled`: change user accessible enabled/disabled state of a column. Set to false to hide the column. User can use the context menu to change this themselves (right-click in headers, or right-click in columns body with ImGuiTableFlags_ContextMenuInBody)
+    pub fn table_set_column_enabled(&self, column_n: i32, v: bool) {
+        self.check_current();
+        unsafe { imgui_sys::igTableSetColumnEnabled(column_n, v) }
+    }
+
+    /// `igTableGetHoveredColumn`: return hovered column. return -1 when table is not hovered. return columns_count if the unused space at the right of visible columns is hovered. Can also use (TableGetColumnFlags() & ImGuiTableColumnFlags_IsHovered) instead.
+    pub fn table_get_hovered_column(&self) -> i32 {
+        self.check_current();
+        unsafe { imgui_sys::igTableGetHoveredColumn() }
+    }
+
+    /// `igTableSetBgColor`: change the color of a cell, row, or column. See ImGuiTableBgTarget_ flags for details.
+    pub fn table_set_bg_color(&self, target: enums::TableBgTarget, color: imgui_sys::ImU32, column_n: Option<i32>) {
+        self.check_current();
+        let column_n = column_n.unwrap_or(-1);
+        unsafe { imgui_sys::igTableSetBgColor(target.raw(), color, column_n) }
+    }
+
+    /// `igColumns`
+    pub fn columns(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igColumns() }
+    }
+
+    /// `igColumnsEx`
+    pub fn columns_ex(&self, count: Option<i32>, id: Option<&str>, borders: Option<bool>) {
+        let scratch = self.scratch();
+        let count = count.unwrap_or(1);
+        let id = id.map(|id| id.write_c_str(scratch));
+        let borders = borders.unwrap_or(true);
+        unsafe { imgui_sys::igColumnsEx(count, id.map_or(ptr::null(), |id| scratch.c_str(id)), borders) }
+    }
+
+    /// `igNextColumn`: next column, defaults to current row or next row if the current row is finished
+    pub fn next_column(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igNextColumn() }
+    }
+
+    /// `igGetColumnIndex`: get current column index
+    pub fn get_column_index(&self) -> i32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetColumnIndex() }
+    }
+
+    /// `igGetColumnWidth`: get column width (in pixels). pass -1 to use current column
+    pub fn get_column_width(&self, column_index: Option<i32>) -> f32 {
+        self.check_current();
+        let column_index = column_index.unwrap_or(-1);
+        unsafe { imgui_sys::igGetColumnWidth(column_index) }
+    }
+
+    /// `igSetColumnWidth`: set column width (in pixels). pass -1 to use current column
+    pub fn set_column_width(&self, column_index: i32, width: f32) {
+        self.check_current();
+        unsafe { imgui_sys::igSetColumnWidth(column_index, width) }
+    }
+
+    /// `igGetColumnOffset`: get position of column line (in pixels, from the left side of the contents region). pass -1 to use current column, otherwise 0..GetColumnsCount() inclusive. column 0 is typically 0.0f
+    pub fn get_column_offset(&self, column_index: Option<i32>) -> f32 {
+        self.check_current();
+        let column_index = column_index.unwrap_or(-1);
+        unsafe { imgui_sys::igGetColumnOffset(column_index) }
+    }
+
+    /// `igSetColumnOffset`: set position of column line (in pixels, from the left side of the contents region). pass -1 to use current column
+    pub fn set_column_offset(&self, column_index: i32, offset_x: f32) {
+        self.check_current();
+        unsafe { imgui_sys::igSetColumnOffset(column_index, offset_x) }
+    }
+
+    /// `igGetColumnsCount`
+    pub fn get_columns_count(&self) -> i32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetColumnsCount() }
+    }
+
+    /// `igTabItemButton`: create a Tab behaving like a button. return true when clicked. cannot be selected in the tab bar.
+    pub fn tab_item_button(&self, label: impl Label, flags: Option<enums::TabItemFlags>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let flags = flags.unwrap_or(enums::TabItemFlags::None);
+        unsafe { imgui_sys::igTabItemButton(scratch.c_str(label), flags.bits()) }
+    }
+
+    /// `igSetTabItemClosed`: notify TabBar or Docking system of a closed tab/window ahead (useful to reduce visual flicker on reorderable tab bars). For tab-bar: call after BeginTabBar() and before Tab submissions. Otherwise call with a window name.
+    pub fn set_tab_item_closed(&self, tab_or_docked_window_label: impl Label) {
+        let scratch = self.scratch();
+        let tab_or_docked_window_label = tab_or_docked_window_label.write_c_str(scratch);
+        unsafe { imgui_sys::igSetTabItemClosed(scratch.c_str(tab_or_docked_window_label)) }
+    }
+
+    /// `igLogToTTY`: start logging to tty (stdout)
+    pub fn log_to_tty(&self, auto_open_depth: Option<i32>) {
+        self.check_current();
+        let auto_open_depth = auto_open_depth.unwrap_or(-1);
+        unsafe { imgui_sys::igLogToTTY(auto_open_depth) }
+    }
+
+    /// `igLogToFile`: start logging to file
+    pub fn log_to_file(&self, auto_open_depth: Option<i32>, filename: Option<&str>) {
+        let scratch = self.scratch();
+        let auto_open_depth = auto_open_depth.unwrap_or(-1);
+        let filename = filename.map(|filename| filename.write_c_str(scratch));
+        unsafe { imgui_sys::igLogToFile(auto_open_depth, filename.map_or(ptr::null(), |filename| scratch.c_str(filename))) }
+    }
+
+    /// `igLogToClipboard`: start logging to OS clipboard
+    pub fn log_to_clipboard(&self, auto_open_depth: Option<i32>) {
+        self.check_current();
+        let auto_open_depth = auto_open_depth.unwrap_or(-1);
+        unsafe { imgui_sys::igLogToClipboard(auto_open_depth) }
+    }
+
+    /// `igLogFinish`: stop logging (close file, etc.)
+    pub fn log_finish(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igLogFinish() }
+    }
+
+    /// `igLogButtons`: helper to display buttons for logging to tty/file/clipboard
+    pub fn log_buttons(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igLogButtons() }
+    }
+
+    /// `igSetItemDefaultFocus`: make last item the default focused item of a newly appearing window.
+    pub fn set_item_default_focus(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igSetItemDefaultFocus() }
+    }
+
+    /// `igSetKeyboardFocusHere`: focus keyboard on the next widget. Use positive 'offset' to access sub components of a multiple component widget. Use -1 to access previous widget.
+    pub fn set_keyboard_focus_here(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igSetKeyboardFocusHere() }
+    }
+
+    /// `igSetKeyboardFocusHereEx`: focus keyboard on the next widget. Use positive 'offset' to access sub components of a multiple component widget. Use -1 to access previous widget.
+    pub fn set_keyboard_focus_here_ex(&self, offset: Option<i32>) {
+        self.check_current();
+        let offset = offset.unwrap_or(0);
+        unsafe { imgui_sys::igSetKeyboardFocusHereEx(offset) }
+    }
+
+    /// `igSetNavCursorVisible`: alter visibility of keyboard/gamepad cursor. by default: show when using an arrow key, hide when clicking with mouse.
+    pub fn set_nav_cursor_visible(&self, visible: bool) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNavCursorVisible(visible) }
+    }
+
+    /// `igSetNextItemAllowOverlap`: allow next item to be overlapped by a subsequent item. Useful with invisible buttons, selectable, treenode covering an area where subsequent items may need to be added. Note that both Selectable() and TreeNode() have dedicated flags doing this.
+    pub fn set_next_item_allow_overlap(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNextItemAllowOverlap() }
+    }
+
+    /// `igIsItemHovered`: is the last item hovered? (and usable, aka not blocked by a popup, etc.). See ImGuiHoveredFlags for more options.
+    pub fn is_item_hovered(&self, flags: Option<enums::HoveredFlags>) -> bool {
+        self.check_current();
+        let flags = flags.unwrap_or(enums::HoveredFlags::None);
+        unsafe { imgui_sys::igIsItemHovered(flags.bits()) }
+    }
+
+    /// `igIsItemActive`: is the last item active? (e.g. button being held, text field being edited. This will continuously return true while holding mouse button on an item. Items that don't interact will always return false)
+    pub fn is_item_active(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemActive() }
+    }
+
+    /// `igIsItemFocused`: is the last item focused for keyboard/gamepad navigation?
+    pub fn is_item_focused(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemFocused() }
+    }
+
+    /// `igIsItemClicked`: is the last item hovered and mouse clicked on? (**)  == IsMouseClicked(mouse_button) && IsItemHovered()Important. (**) this is NOT equivalent to the behavior of e.g. Button(). Read comments in function definition.
+    pub fn is_item_clicked(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemClicked() }
+    }
+
+    /// `igIsItemClickedEx`: is the last item hovered and mouse clicked on? (**)  == IsMouseClicked(mouse_button) && IsItemHovered()Important. (**) this is NOT equivalent to the behavior of e.g. Button(). Read comments in function definition.
+    pub fn is_item_clicked_ex(&self, mouse_button: Option<enums::MouseButton>) -> bool {
+        self.check_current();
+        let mouse_button = mouse_button.unwrap_or(enums::MouseButton::Left);
+        unsafe { imgui_sys::igIsItemClickedEx(mouse_button.raw()) }
+    }
+
+    /// `igIsItemVisible`: is the last item visible? (items may be out of sight because of clipping/scrolling)
+    pub fn is_item_visible(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemVisible() }
+    }
+
+    /// `igIsItemEdited`: did the last item modify its underlying value this frame? or was pressed? This is generally the same as the "bool" return value of many widgets.
+    pub fn is_item_edited(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemEdited() }
+    }
+
+    /// `igIsItemActivated`: was the last item just made active (item was previously inactive).
+    pub fn is_item_activated(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemActivated() }
+    }
+
+    /// `igIsItemDeactivated`: was the last item just made inactive (item was previously active). Useful for Undo/Redo patterns with widgets that require continuous editing.
+    pub fn is_item_deactivated(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemDeactivated() }
+    }
+
+    /// `igIsItemDeactivatedAfterEdit`: was the last item just made inactive and made a value change when it was active? (e.g. Slider/Drag moved). Useful for Undo/Redo patterns with widgets that require continuous editing. Note that you may get false positives (some widgets such as Combo()/ListBox()/Selectable() will return true even when clicking an already selected item).
+    pub fn is_item_deactivated_after_edit(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemDeactivatedAfterEdit() }
+    }
+
+    /// `igIsItemToggledOpen`: was the last item open state toggled? set by TreeNode().
+    pub fn is_item_toggled_open(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsItemToggledOpen() }
+    }
+
+    /// `igIsAnyItemHovered`: is any item hovered?
+    pub fn is_any_item_hovered(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsAnyItemHovered() }
+    }
+
+    /// `igIsAnyItemActive`: is any item active?
+    pub fn is_any_item_active(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsAnyItemActive() }
+    }
+
+    /// `igIsAnyItemFocused`: is any item focused?
+    pub fn is_any_item_focused(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsAnyItemFocused() }
+    }
+
+    /// `igGetItemID`: get ID of last item (~~ often same ImGui::GetID(label) beforehand)
+    pub fn get_item_id(&self) -> imgui_sys::ImGuiID {
+        self.check_current();
+        unsafe { imgui_sys::igGetItemID() }
+    }
+
+    /// `igGetItemRectMin`: get upper-left bounding rectangle of the last item (screen space)
+    pub fn get_item_rect_min(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetItemRectMin() }
+    }
+
+    /// `igGetItemRectMax`: get lower-right bounding rectangle of the last item (screen space)
+    pub fn get_item_rect_max(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetItemRectMax() }
+    }
+
+    /// `igGetItemRectSize`: get size of last item
+    pub fn get_item_rect_size(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetItemRectSize() }
+    }
+
+    /// `igIsRectVisibleBySize`: test if rectangle (of given size, starting from cursor position) is visible / not clipped.
+    pub fn is_rect_visible_by_size(&self, size: imgui_sys::ImVec2) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsRectVisibleBySize(size) }
+    }
+
+    /// `igIsRectVisible`: test if rectangle (of given size, starting from cursor position) is visible / not clipped.
+    pub fn is_rect_visible(&self, rect_min: imgui_sys::ImVec2, rect_max: imgui_sys::ImVec2) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsRectVisible(rect_min, rect_max) }
+    }
+
+    /// `igGetTime`: get global imgui time. incremented by io.DeltaTime every frame.
+    pub fn get_time(&self) -> f64 {
+        self.check_current();
+        unsafe { imgui_sys::igGetTime() }
+    }
+
+    /// `igGetFrameCount`: get global imgui frame count. incremented by 1 every frame.
+    pub fn get_frame_count(&self) -> i32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetFrameCount() }
+    }
+
+    /// `igColorConvertU32ToFloat4`
+    pub fn color_convert_u32_to_float4(&self, r#in: imgui_sys::ImU32) -> imgui_sys::ImVec4 {
+        self.check_current();
+        unsafe { imgui_sys::igColorConvertU32ToFloat4(r#in) }
+    }
+
+    /// `igColorConvertFloat4ToU32`
+    pub fn color_convert_float4_to_u32(&self, r#in: imgui_sys::ImVec4) -> imgui_sys::ImU32 {
+        self.check_current();
+        unsafe { imgui_sys::igColorConvertFloat4ToU32(r#in) }
+    }
+
+    /// `igColorConvertRGBtoHSV`
+    pub fn color_convert_rgb_to_hsv(&self, r: f32, g: f32, b: f32, out_h: &mut f32, out_s: &mut f32, out_v: &mut f32) {
+        self.check_current();
+        unsafe { imgui_sys::igColorConvertRGBtoHSV(r, g, b, out_h, out_s, out_v) }
+    }
+
+    /// `igColorConvertHSVtoRGB`
+    pub fn color_convert_hsv_to_rgb(&self, h: f32, s: f32, v: f32, out_r: &mut f32, out_g: &mut f32, out_b: &mut f32) {
+        self.check_current();
+        unsafe { imgui_sys::igColorConvertHSVtoRGB(h, s, v, out_r, out_g, out_b) }
+    }
+
+    /// `igIsKeyDown`: is key being held.
+    pub fn is_key_down(&self, key: enums::Key) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsKeyDown(key.raw()) }
+    }
+
+    /// `igIsKeyPressed`: was key pressed (went from !Down to Down)? if repeat=true, uses io.KeyRepeatDelay / KeyRepeatRate
+    pub fn is_key_pressed(&self, key: enums::Key) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsKeyPressed(key.raw()) }
+    }
+
+    /// `igIsKeyPressedEx`: was key pressed (went from !Down to Down)? if repeat=true, uses io.KeyRepeatDelay / KeyRepeatRate
+    pub fn is_key_pressed_ex(&self, key: enums::Key, repeat: Option<bool>) -> bool {
+        self.check_current();
+        let repeat = repeat.unwrap_or(true);
+        unsafe { imgui_sys::igIsKeyPressedEx(key.raw(), repeat) }
+    }
+
+    /// `igIsKeyReleased`: was key released (went from Down to !Down)?
+    pub fn is_key_released(&self, key: enums::Key) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsKeyReleased(key.raw()) }
+    }
+
+    /// `igIsKeyChordPressed`: was key chord (mods + key) pressed, e.g. you can pass 'ImGuiMod_Ctrl | ImGuiKey_S' as a key-chord. This doesn't do any routing or focus check, please consider using Shortcut() function instead.
+    pub fn is_key_chord_pressed(&self, key_chord: imgui_sys::ImGuiKeyChord) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsKeyChordPressed(key_chord) }
+    }
+
+    /// `igGetKeyPressedAmount`: uses provided repeat rate/delay. return a count, most often 0 or 1 but might be >1 if RepeatRate is small enough that DeltaTime > RepeatRate
+    pub fn get_key_pressed_amount(&self, key: enums::Key, repeat_delay: f32, rate: f32) -> i32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetKeyPressedAmount(key.raw(), repeat_delay, rate) }
+    }
+
+    /// `igSetNextFrameWantCaptureKeyboard`: Override io.WantCaptureKeyboard flag next frame (said flag is left for your application to handle, typically when true it instructs your app to ignore inputs). e.g. force capture keyboard when your widget is being hovered. This is equivalent to setting "io.WantCaptureKeyboard = want_capture_keyboard"; after the next NewFrame() call.
+    pub fn set_next_frame_want_capture_keyboard(&self, want_capture_keyboard: bool) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNextFrameWantCaptureKeyboard(want_capture_keyboard) }
+    }
+
+    /// `igShortcut`
+    pub fn shortcut(&self, key_chord: imgui_sys::ImGuiKeyChord, flags: Option<enums::InputFlags>) -> bool {
+        self.check_current();
+        let flags = flags.unwrap_or(enums::InputFlags::None);
+        unsafe { imgui_sys::igShortcut(key_chord, flags.bits()) }
+    }
+
+    /// `igSetNextItemShortcut`
+    pub fn set_next_item_shortcut(&self, key_chord: imgui_sys::ImGuiKeyChord, flags: Option<enums::InputFlags>) {
+        self.check_current();
+        let flags = flags.unwrap_or(enums::InputFlags::None);
+        unsafe { imgui_sys::igSetNextItemShortcut(key_chord, flags.bits()) }
+    }
+
+    /// `igSetItemKeyOwner`: Set key owner to last item ID if it is hovered or active. Equivalent to 'if (IsItemHovered() || IsItemActive()) { SetKeyOwner(key, GetItemID());'.
+    pub fn set_item_key_owner(&self, key: enums::Key) {
+        self.check_current();
+        unsafe { imgui_sys::igSetItemKeyOwner(key.raw()) }
+    }
+
+    /// `igIsMouseDown`: is mouse button held?
+    pub fn is_mouse_down(&self, button: enums::MouseButton) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsMouseDown(button.raw()) }
+    }
+
+    /// `igIsMouseClicked`: did mouse button clicked? (went from !Down to Down). Same as GetMouseClickedCount() == 1.
+    pub fn is_mouse_clicked(&self, button: enums::MouseButton) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsMouseClicked(button.raw()) }
+    }
+
+    /// `igIsMouseClickedEx`: did mouse button clicked? (went from !Down to Down). Same as GetMouseClickedCount() == 1.
+    pub fn is_mouse_clicked_ex(&self, button: enums::MouseButton, repeat: Option<bool>) -> bool {
+        self.check_current();
+        let repeat = repeat.unwrap_or(false);
+        unsafe { imgui_sys::igIsMouseClickedEx(button.raw(), repeat) }
+    }
+
+    /// `igIsMouseReleased`: did mouse button released? (went from Down to !Down)
+    pub fn is_mouse_released(&self, button: enums::MouseButton) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsMouseReleased(button.raw()) }
+    }
+
+    /// `igIsMouseDoubleClicked`: did mouse button double-clicked? Same as GetMouseClickedCount() == 2. (note that a double-click will also report IsMouseClicked() == true)
+    pub fn is_mouse_double_clicked(&self, button: enums::MouseButton) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsMouseDoubleClicked(button.raw()) }
+    }
+
+    /// `igIsMouseReleasedWithDelay`: delayed mouse release (use very sparingly!). Generally used with 'delay >= io.MouseDoubleClickTime' + combined with a 'io.MouseClickedLastCount==1' test. This is a very rarely used UI idiom, but some apps use this: e.g. MS Explorer single click on an icon to rename.
+    pub fn is_mouse_released_with_delay(&self, button: enums::MouseButton, delay: f32) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsMouseReleasedWithDelay(button.raw(), delay) }
+    }
+
+    /// `igGetMouseClickedCount`: return the number of successive mouse-clicks at the time where a click happen (otherwise 0).
+    pub fn get_mouse_clicked_count(&self, button: enums::MouseButton) -> i32 {
+        self.check_current();
+        unsafe { imgui_sys::igGetMouseClickedCount(button.raw()) }
+    }
+
+    /// `igIsMouseHoveringRect`: is mouse hovering given bounding rect (in screen space). clipped by current clipping settings, but disregarding of other consideration of focus/window ordering/popup-block.
+    pub fn is_mouse_hovering_rect(&self, r_min: imgui_sys::ImVec2, r_max: imgui_sys::ImVec2) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsMouseHoveringRect(r_min, r_max) }
+    }
+
+    /// `igIsMouseHoveringRectEx`: is mouse hovering given bounding rect (in screen space). clipped by current clipping settings, but disregarding of other consideration of focus/window ordering/popup-block.
+    pub fn is_mouse_hovering_rect_ex(&self, r_min: imgui_sys::ImVec2, r_max: imgui_sys::ImVec2, clip: Option<bool>) -> bool {
+        self.check_current();
+        let clip = clip.unwrap_or(true);
+        unsafe { imgui_sys::igIsMouseHoveringRectEx(r_min, r_max, clip) }
+    }
+
+    /// `igIsAnyMouseDown`: [WILL OBSOLETE] is any mouse button held? This was designed for backends, but prefer having backend maintain a mask of held mouse buttons, because upcoming input queue system will make this invalid.
+    pub fn is_any_mouse_down(&self) -> bool {
+        self.check_current();
+        unsafe { imgui_sys::igIsAnyMouseDown() }
+    }
+
+    /// `igGetMousePos`: shortcut to ImGui::GetIO().MousePos provided by user, to be consistent with other calls
+    pub fn get_mouse_pos(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetMousePos() }
+    }
+
+    /// `igGetMousePosOnOpeningCurrentPopup`: retrieve mouse position at the time of opening popup we have BeginPopup() into (helper to avoid user backing that value themselves)
+    pub fn get_mouse_pos_on_opening_current_popup(&self) -> imgui_sys::ImVec2 {
+        self.check_current();
+        unsafe { imgui_sys::igGetMousePosOnOpeningCurrentPopup() }
+    }
+
+    /// `igIsMouseDragging`: is mouse dragging? (uses io.MouseDraggingThreshold if lock_threshold < 0.0f)
+    pub fn is_mouse_dragging(&self, button: enums::MouseButton, lock_threshold: Option<f32>) -> bool {
+        self.check_current();
+        let lock_threshold = lock_threshold.unwrap_or(-1.0);
+        unsafe { imgui_sys::igIsMouseDragging(button.raw(), lock_threshold) }
+    }
+
+    /// `igGetMouseDragDelta`: return the delta from the initial clicking position while the mouse button is pressed or was just released. This is locked and return 0.0f until the mouse moves past a distance threshold at least once (uses io.MouseDraggingThreshold if lock_threshold < 0.0f)
+    pub fn get_mouse_drag_delta(&self, button: Option<enums::MouseButton>, lock_threshold: Option<f32>) -> imgui_sys::ImVec2 {
+        self.check_current();
+        let button = button.unwrap_or(enums::MouseButton::Left);
+        let lock_threshold = lock_threshold.unwrap_or(-1.0);
+        unsafe { imgui_sys::igGetMouseDragDelta(button.raw(), lock_threshold) }
+    }
+
+    /// `igResetMouseDragDelta`: 
+    pub fn reset_mouse_drag_delta(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igResetMouseDragDelta() }
+    }
+
+    /// `igResetMouseDragDeltaEx`: 
+    pub fn reset_mouse_drag_delta_ex(&self, button: Option<enums::MouseButton>) {
+        self.check_current();
+        let button = button.unwrap_or(enums::MouseButton::Left);
+        unsafe { imgui_sys::igResetMouseDragDeltaEx(button.raw()) }
+    }
+
+    /// `igGetMouseCursor`: get desired mouse cursor shape. Important: reset in ImGui::NewFrame(), this is updated during the frame. valid before Render(). If you use software rendering by setting io.MouseDrawCursor ImGui will render those for you
+    pub fn get_mouse_cursor(&self) -> imgui_sys::ImGuiMouseCursor {
+        self.check_current();
+        unsafe { imgui_sys::igGetMouseCursor() }
+    }
+
+    /// `igSetMouseCursor`: set desired mouse cursor shape
+    pub fn set_mouse_cursor(&self, cursor_type: enums::MouseCursor) {
+        self.check_current();
+        unsafe { imgui_sys::igSetMouseCursor(cursor_type.raw()) }
+    }
+
+    /// `igSetNextFrameWantCaptureMouse`: Override io.WantCaptureMouse flag next frame (said flag is left for your application to handle, typical when true it instructs your app to ignore inputs). This is equivalent to setting "io.WantCaptureMouse = want_capture_mouse;" after the next NewFrame() call.
+    pub fn set_next_frame_want_capture_mouse(&self, want_capture_mouse: bool) {
+        self.check_current();
+        unsafe { imgui_sys::igSetNextFrameWantCaptureMouse(want_capture_mouse) }
+    }
+
+    /// `igSetClipboardText`
+    pub fn set_clipboard_text(&self, text: impl Label) {
+        let scratch = self.scratch();
+        let text = text.write_c_str(scratch);
+        unsafe { imgui_sys::igSetClipboardText(scratch.c_str(text)) }
+    }
+
+    /// `igLoadIniSettingsFromDisk`: call after CreateContext() and before the first call to NewFrame(). NewFrame() automatically calls LoadIniSettingsFromDisk(io.IniFilename).
+    pub fn load_ini_settings_from_disk(&self, ini_filename: impl Label) {
+        let scratch = self.scratch();
+        let ini_filename = ini_filename.write_c_str(scratch);
+        unsafe { imgui_sys::igLoadIniSettingsFromDisk(scratch.c_str(ini_filename)) }
+    }
+
+    /// `igSaveIniSettingsToDisk`: this is automatically called (if io.IniFilename is not empty) a few seconds after any modification that should be reflected in the .ini file (and also by DestroyContext).
+    pub fn save_ini_settings_to_disk(&self, ini_filename: impl Label) {
+        let scratch = self.scratch();
+        let ini_filename = ini_filename.write_c_str(scratch);
+        unsafe { imgui_sys::igSaveIniSettingsToDisk(scratch.c_str(ini_filename)) }
+    }
+
+    /// `igDebugTextEncoding`
+    pub fn debug_text_encoding(&self, text: impl Label) {
+        let scratch = self.scratch();
+        let text = text.write_c_str(scratch);
+        unsafe { imgui_sys::igDebugTextEncoding(scratch.c_str(text)) }
+    }
+
+    /// `igDebugFlashStyleColor`
+    pub fn debug_flash_style_color(&self, idx: enums::Col) {
+        self.check_current();
+        unsafe { imgui_sys::igDebugFlashStyleColor(idx.raw()) }
+    }
+
+    /// `igDebugStartItemPicker`
+    pub fn debug_start_item_picker(&self) {
+        self.check_current();
+        unsafe { imgui_sys::igDebugStartItemPicker() }
+    }
+}
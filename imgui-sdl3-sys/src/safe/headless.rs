@@ -0,0 +1,155 @@
+// Headless platform backend: no window, the display and the inputs are given by the caller
+
+use core::marker::PhantomData;
+use core::ptr;
+
+use super::{Context, Ui};
+use crate::imgui_sys::enums::{BackendFlags, Key, MouseButton, MouseSource};
+use crate::imgui_sys::{self, ImGuiIO, ImVec2};
+
+/// Platform backend without a window or an OS, for tests and offline rendering.
+///
+/// It sets the display size, delta time and backend flags of each frame instead of the SDL3 backend, and the
+/// inputs are injected with the `add_*` functions, queued with the `ImGuiIO_Add*Event` functions like a platform
+/// backend does. Events added between two frames are seen by the next one, and Dear ImGui spreads the ones that
+/// would be lost in a single frame (a key pressed and released...) over the following frames.
+///
+/// The delta time is fixed, so the frames are deterministic.
+///
+/// The functions taking a context panic if it isn't the one the platform backend was initialized with. Dropping the
+/// backend clears its name and flags from the context, like the `Shutdown` function of the other backends.
+///
+/// A renderer backend is still needed for the font atlas texture, e.g. the software renderer, or the
+/// `RendererHasTextures` backend flag to run frames without rendering them.
+pub struct HeadlessPlatform {
+    context: u64,
+    display_size: ImVec2,
+    framebuffer_scale: ImVec2,
+    delta_time: f32,
+    gamepad_connected: bool,
+    // Dropped on the thread of its context
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl HeadlessPlatform {
+    /// Initializes the platform backend of `context`, with a display of `width` x `height` and 60 frames per second.
+    pub fn new(context: &Context, width: f32, height: f32) -> Self {
+        let _current = context.make_current();
+        unsafe {
+            let io = imgui_sys::igGetIO();
+            (*io).BackendPlatformName = c"imgui_sdl3_sys_headless".as_ptr();
+        }
+        Self {
+            context: context.id(),
+            display_size: ImVec2 { x: width, y: height },
+            framebuffer_scale: ImVec2 { x: 1.0, y: 1.0 },
+            delta_time: 1.0 / 60.0,
+            gamepad_connected: false,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Sets the display size, in points.
+    pub fn set_display_size(&mut self, width: f32, height: f32) {
+        self.display_size = ImVec2 { x: width, y: height };
+    }
+
+    /// Sets the framebuffer pixels per point (`io.DisplayFramebufferScale`), e.g. 2 for a high DPI display.
+    pub fn set_framebuffer_scale(&mut self, scale_x: f32, scale_y: f32) {
+        self.framebuffer_scale = ImVec2 { x: scale_x, y: scale_y };
+    }
+
+    /// Sets the time between frames, in seconds.
+    ///
+    /// # Panics
+    ///
+    /// If `delta_time` isn't positive, which Dear ImGui asserts.
+    pub fn set_delta_time(&mut self, delta_time: f32) {
+        assert!(delta_time > 0.0, "the delta time must be positive");
+        self.delta_time = delta_time;
+    }
+
+    /// Connects or disconnects a virtual gamepad, whose buttons and sticks are the `Key::Gamepad*` keys.
+    ///
+    /// It sets the `HasGamepad` backend flag, so the gamepad navigation works with `NavEnableGamepad`.
+    pub fn set_gamepad_connected(&mut self, connected: bool) {
+        self.gamepad_connected = connected;
+    }
+
+    /// Updates the display and timing of `context` and starts a new frame, see [`Context::new_frame`].
+    pub fn new_frame<'ctx>(&self, context: &'ctx mut Context) -> Ui<'ctx> {
+        self.with_io(context, |io| unsafe {
+            (*io).DisplaySize = self.display_size;
+            (*io).DisplayFramebufferScale = self.framebuffer_scale;
+            (*io).DeltaTime = self.delta_time;
+            if self.gamepad_connected {
+                (*io).BackendFlags |= BackendFlags::HasGamepad;
+            } else {
+                (*io).BackendFlags &= !BackendFlags::HasGamepad;
+            }
+        });
+        context.new_frame()
+    }
+
+    /// Moves the mouse, in points. `-f32::MAX` for both coordinates means no mouse (`ImGuiIO_AddMousePosEvent`).
+    pub fn add_mouse_pos(&self, context: &Context, x: f32, y: f32) {
+        self.with_io(context, |io| unsafe { imgui_sys::ImGuiIO_AddMousePosEvent(io, x, y) });
+    }
+
+    /// Presses or releases a mouse button (`ImGuiIO_AddMouseButtonEvent`).
+    pub fn add_mouse_button(&self, context: &Context, button: MouseButton, down: bool) {
+        self.with_io(context, |io| unsafe { imgui_sys::ImGuiIO_AddMouseButtonEvent(io, button.raw(), down) });
+    }
+
+    /// Scrolls the mouse wheel, 1 being a notch, positive to the left and up (`ImGuiIO_AddMouseWheelEvent`).
+    pub fn add_mouse_wheel(&self, context: &Context, wheel_x: f32, wheel_y: f32) {
+        self.with_io(context, |io| unsafe { imgui_sys::ImGuiIO_AddMouseWheelEvent(io, wheel_x, wheel_y) });
+    }
+
+    /// Sets the source of the following mouse events, e.g. a touch screen (`ImGuiIO_AddMouseSourceEvent`).
+    pub fn add_mouse_source(&self, context: &Context, source: MouseSource) {
+        self.with_io(context, |io| unsafe { imgui_sys::ImGuiIO_AddMouseSourceEvent(io, source.raw()) });
+    }
+
+    /// Presses or releases a key, a modifier (`Key::Mod_Ctrl`...) or a gamepad button (`ImGuiIO_AddKeyEvent`).
+    pub fn add_key(&self, context: &Context, key: Key, down: bool) {
+        self.with_io(context, |io| unsafe { imgui_sys::ImGuiIO_AddKeyEvent(io, key.raw(), down) });
+    }
+
+    /// Sets an analog key, e.g. a gamepad stick or trigger between 0 and 1 (`ImGuiIO_AddKeyAnalogEvent`).
+    pub fn add_key_analog(&self, context: &Context, key: Key, down: bool, value: f32) {
+        self.with_io(context, |io| unsafe { imgui_sys::ImGuiIO_AddKeyAnalogEvent(io, key.raw(), down, value) });
+    }
+
+    /// Types text, for the focused text input (`ImGuiIO_AddInputCharacter`).
+    pub fn add_text(&self, context: &Context, text: &str) {
+        self.with_io(context, |io| {
+            for c in text.chars() {
+                unsafe { imgui_sys::ImGuiIO_AddInputCharacter(io, c as u32) };
+            }
+        });
+    }
+
+    /// Gives or removes the focus of the application, Dear ImGui releases the keys when it loses it
+    /// (`ImGuiIO_AddFocusEvent`).
+    pub fn add_focus(&self, context: &Context, focused: bool) {
+        self.with_io(context, |io| unsafe { imgui_sys::ImGuiIO_AddFocusEvent(io, focused) });
+    }
+
+    // IO of the context of the backend, which may not be the current one
+    fn with_io<R>(&self, context: &Context, f: impl FnOnce(*mut ImGuiIO) -> R) -> R {
+        assert_eq!(context.id(), self.context, "the context isn't the one of the headless platform backend");
+        let _current = context.make_current();
+        f(unsafe { imgui_sys::igGetIO() })
+    }
+}
+
+impl Drop for HeadlessPlatform {
+    fn drop(&mut self) {
+        Context::with_live(self.context, || unsafe {
+            let io = imgui_sys::igGetIO();
+            (*io).BackendPlatformName = ptr::null();
+            (*io).BackendFlags &= !BackendFlags::HasGamepad;
+        });
+    }
+}
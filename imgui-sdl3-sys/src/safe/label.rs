@@ -0,0 +1,254 @@
+// Labels passed to Dear ImGui without allocating
+
+extern crate alloc;
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout};
+use core::cell::Cell;
+use core::ffi::{c_char, CStr};
+use core::fmt;
+use core::ptr;
+
+pub(super) use sealed::WriteText;
+
+/// Text accepted by the safe wrappers: `&str`, `&CStr` and C-string literals (`c"Label"`).
+///
+/// C strings are passed as is. Other strings are passed with the begin/end overloads when Dear ImGui has one
+/// (`igTextUnformattedEx`, `igCalcTextSizeEx`...), otherwise they are NUL-terminated into a scratch buffer reused
+/// by the context. Dear ImGui stops at the first NUL, so a `&str` containing one is truncated.
+pub trait Label {
+    /// The text, without NUL terminator.
+    fn as_bytes(&self) -> &[u8];
+
+    /// The text as a C string, if it is already NUL-terminated.
+    fn as_c_str(&self) -> Option<&CStr>;
+
+}
+
+impl Label for str {
+    fn as_bytes(&self) -> &[u8] {
+        str::as_bytes(self)
+    }
+
+    fn as_c_str(&self) -> Option<&CStr> {
+        None
+    }
+}
+
+impl Label for CStr {
+    fn as_bytes(&self) -> &[u8] {
+        self.to_bytes()
+    }
+
+    fn as_c_str(&self) -> Option<&CStr> {
+        Some(self)
+    }
+}
+
+impl<T: Label + ?Sized> Label for &T {
+    fn as_bytes(&self) -> &[u8] {
+        (**self).as_bytes()
+    }
+
+    fn as_c_str(&self) -> Option<&CStr> {
+        (**self).as_c_str()
+    }
+}
+
+/// Text accepted by the text functions of [`Ui`](super::Ui): any [`Label`], or formatted text from `format_args!`
+/// which is formatted into the scratch buffer of the context.
+///
+/// The text is never used as a format string, so it can't contain format-string bugs.
+///
+/// This trait is sealed: the text it writes is handed to Dear ImGui, only the implementations of this crate are
+/// trusted with it.
+pub trait Text: sealed::WriteText {}
+
+impl<T: sealed::WriteText + ?Sized> Text for T {}
+
+// The write methods take the crate-private scratch buffer, the trait itself can't be named outside of this crate
+#[allow(private_interfaces)]
+pub(super) mod sealed {
+    use core::fmt;
+
+    use super::{Label, ScratchBuffer, TextRef};
+
+    pub trait WriteText {
+        /// Writes the text into `scratch` if it isn't a C string already.
+        fn write_c_str(&self, scratch: &ScratchBuffer) -> TextRef;
+
+        /// Writes the text into `scratch` if it isn't a string already, it doesn't need to be NUL-terminated.
+        fn write_range(&self, scratch: &ScratchBuffer) -> TextRef;
+    }
+
+    impl<T: Label + ?Sized> WriteText for T {
+        fn write_c_str(&self, scratch: &ScratchBuffer) -> TextRef {
+            match self.as_c_str() {
+                Some(c_str) => TextRef::Borrowed(c_str.as_ptr(), c_str.to_bytes().len()),
+                None => scratch.write_bytes(self.as_bytes()),
+            }
+        }
+
+        fn write_range(&self, _scratch: &ScratchBuffer) -> TextRef {
+            let bytes = self.as_bytes();
+            TextRef::Borrowed(bytes.as_ptr().cast(), bytes.len())
+        }
+    }
+
+    impl WriteText for fmt::Arguments<'_> {
+        fn write_c_str(&self, scratch: &ScratchBuffer) -> TextRef {
+            match self.as_str() {
+                Some(text) => text.write_c_str(scratch),
+                None => scratch.write_fmt(*self),
+            }
+        }
+
+        fn write_range(&self, scratch: &ScratchBuffer) -> TextRef {
+            match self.as_str() {
+                Some(text) => text.write_range(scratch),
+                None => scratch.write_fmt(*self),
+            }
+        }
+    }
+}
+
+/// Text written into the scratch buffer, or borrowed when it already was a C string.
+#[derive(Clone, Copy)]
+pub(crate) enum TextRef {
+    Borrowed(*const c_char, usize),
+    Scratch(usize, usize),
+}
+
+/// Buffer NUL-terminating and formatting text for Dear ImGui. It only grows, so once large enough for the text of a
+/// frame, there are no more allocations.
+///
+/// It's allocated with the Rust global allocator rather than `igMemAlloc`, so it can be freed after its context
+/// was destroyed.
+///
+/// Several texts can be written for a single call, their pointers are only resolved with [`ScratchBuffer::c_str`]
+/// and [`ScratchBuffer::range`] once all are written, as the buffer may move while growing.
+///
+/// A `Display` implementation formatted into the buffer could call a [`Ui`](super::Ui) function clearing it, so
+/// clearing panics while text is formatted.
+pub(crate) struct ScratchBuffer {
+    data: Cell<*mut u8>,
+    capacity: Cell<usize>,
+    len: Cell<usize>,
+    formatting: Cell<usize>,
+}
+
+impl ScratchBuffer {
+    const MIN_CAPACITY: usize = 256;
+
+    pub(super) const fn new() -> Self {
+        Self {
+            data: Cell::new(ptr::null_mut()),
+            capacity: Cell::new(0),
+            len: Cell::new(0),
+            formatting: Cell::new(0),
+        }
+    }
+
+    /// Clears the buffer, invalidating the previously written text.
+    pub(super) fn clear(&self) -> &Self {
+        assert!(self.formatting.get() == 0, "the Ui functions can't be called while formatting their text");
+        self.len.set(0);
+        self
+    }
+
+    /// Returns the NUL-terminated text, valid until the buffer is cleared.
+    pub(super) fn c_str(&self, text: TextRef) -> *const c_char {
+        self.range(text).0
+    }
+
+    /// Returns the begin/end pointers of the text, valid until the buffer is cleared.
+    pub(super) fn range(&self, text: TextRef) -> (*const c_char, *const c_char) {
+        let (begin, len) = match text {
+            TextRef::Borrowed(begin, len) => (begin, len),
+            TextRef::Scratch(offset, len) => (unsafe { self.data.get().add(offset) }.cast_const().cast(), len),
+        };
+        (begin, unsafe { begin.add(len) })
+    }
+
+    fn write_bytes(&self, bytes: &[u8]) -> TextRef {
+        let offset = self.len.get();
+        self.push(bytes);
+        self.push(&[0]);
+        TextRef::Scratch(offset, bytes.len())
+    }
+
+    /// Writes a list of items like `"One\0Two\0\0"`. The list stops at an empty item or at a NUL in an item.
+    pub(super) fn write_zero_separated(&self, items: &[impl Label]) -> TextRef {
+        let offset = self.len.get();
+        for item in items {
+            self.push(item.as_bytes());
+            self.push(&[0]);
+        }
+        let len = self.len.get() - offset;
+        self.push(&[0]);
+        TextRef::Scratch(offset, len)
+    }
+
+    fn write_fmt(&self, args: fmt::Arguments<'_>) -> TextRef {
+        struct Writer<'a>(&'a ScratchBuffer);
+
+        impl fmt::Write for Writer<'_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.push(s.as_bytes());
+                Ok(())
+            }
+        }
+
+        // Decrements the formatting depth even if a Display implementation panics
+        struct Formatting<'a>(&'a ScratchBuffer);
+
+        impl Drop for Formatting<'_> {
+            fn drop(&mut self) {
+                self.0.formatting.set(self.0.formatting.get() - 1);
+            }
+        }
+
+        let offset = self.len.get();
+        self.formatting.set(self.formatting.get() + 1);
+        let formatting = Formatting(self);
+        // Only fails if a Display implementation does, the text is then cut where it failed
+        let _ = fmt::write(&mut Writer(self), args);
+        drop(formatting);
+        let len = self.len.get() - offset;
+        self.push(&[0]);
+        TextRef::Scratch(offset, len)
+    }
+
+    fn push(&self, bytes: &[u8]) {
+        let len = self.len.get();
+        self.reserve(len + bytes.len());
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), self.data.get().add(len), bytes.len()) };
+        self.len.set(len + bytes.len());
+    }
+
+    fn reserve(&self, capacity: usize) {
+        if capacity <= self.capacity.get() {
+            return;
+        }
+        let capacity = capacity.max(self.capacity.get() * 2).max(Self::MIN_CAPACITY);
+        let layout = Layout::array::<u8>(capacity).unwrap();
+        let old = self.data.get();
+        let data = if old.is_null() {
+            unsafe { alloc(layout) }
+        } else {
+            unsafe { realloc(old, Layout::array::<u8>(self.capacity.get()).unwrap(), capacity) }
+        };
+        if data.is_null() {
+            handle_alloc_error(layout);
+        }
+        self.data.set(data);
+        self.capacity.set(capacity);
+    }
+}
+
+impl Drop for ScratchBuffer {
+    fn drop(&mut self) {
+        if !self.data.get().is_null() {
+            unsafe { dealloc(self.data.get(), Layout::array::<u8>(self.capacity.get()).unwrap()) };
+        }
+    }
+}
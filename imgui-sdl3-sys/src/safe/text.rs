@@ -0,0 +1,62 @@
+// Text output without format strings
+// The printf-style functions of Dear ImGui get the text through "%s", or are replaced by the unformatted ones
+
+use super::{Text, Ui};
+use crate::imgui_sys;
+
+impl Ui<'_> {
+    /// `igTextUnformattedEx`: draws text, like `igText` without interpreting it as a format string.
+    ///
+    /// `text` can be a `&str`, a `&CStr` or `format_args!`.
+    pub fn text(&self, text: impl Text) {
+        let (begin, end) = self.text_range(&text);
+        unsafe { imgui_sys::igTextUnformattedEx(begin, end) };
+    }
+
+    /// `igTextColored`: draws text with the given color.
+    pub fn text_colored(&self, color: imgui_sys::ImVec4, text: impl Text) {
+        // Same as igTextColored, which pushes the text color around the unformatted text
+        self.check_current();
+        unsafe { imgui_sys::igPushStyleColorImVec4(imgui_sys::ImGuiCol__ImGuiCol_Text as i32, color) };
+        self.text(text);
+        unsafe { imgui_sys::igPopStyleColor() };
+    }
+
+    /// `igTextWrapped`: draws text wrapped at the end of the window, or at the position pushed with `igPushTextWrapPos`.
+    pub fn text_wrapped(&self, text: impl Text) {
+        let text = self.c_str(&text);
+        unsafe { imgui_sys::igTextWrapped(c"%s".as_ptr(), text) };
+    }
+
+    /// `igLabelText`: draws text and a label aligned the same way as value+label widgets.
+    pub fn label_text(&self, label: impl Text, text: impl Text) {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let text = text.write_c_str(scratch);
+        unsafe { imgui_sys::igLabelText(scratch.c_str(label), c"%s".as_ptr(), scratch.c_str(text)) };
+    }
+
+    /// `igBulletText`: draws a bullet followed by text.
+    pub fn bullet_text(&self, text: impl Text) {
+        let text = self.c_str(&text);
+        unsafe { imgui_sys::igBulletText(c"%s".as_ptr(), text) };
+    }
+
+    /// `igSetTooltip`: sets a text tooltip, overriding any previous one. Usually called after checking `igIsItemHovered`.
+    pub fn set_tooltip(&self, text: impl Text) {
+        let text = self.c_str(&text);
+        unsafe { imgui_sys::igSetTooltip(c"%s".as_ptr(), text) };
+    }
+
+    /// `igCalcTextSizeEx`: size of `text` with the current font, see [`Ui::calc_text_size_ex`].
+    pub fn calc_text_size(&self, text: impl Text) -> imgui_sys::ImVec2 {
+        self.calc_text_size_ex(text, false, -1.0)
+    }
+
+    /// `igCalcTextSizeEx`: size of `text` with the current font, optionally ignoring the text after `##`
+    /// and wrapping it at `wrap_width` when positive.
+    pub fn calc_text_size_ex(&self, text: impl Text, hide_text_after_double_hash: bool, wrap_width: f32) -> imgui_sys::ImVec2 {
+        let (begin, end) = self.text_range(&text);
+        unsafe { imgui_sys::igCalcTextSizeEx(begin, end, hide_text_after_double_hash, wrap_width) }
+    }
+}
@@ -0,0 +1,75 @@
+// Tokens returned by the Begin functions of Ui, calling the matching End/Pop function on drop
+
+use super::Ui;
+use crate::imgui_sys;
+
+macro_rules! token {
+    ($(#[$attr:meta])* $name:ident => $end:ident) => {
+        $(#[$attr])*
+        #[must_use = "the scope ends when the token is dropped"]
+        pub struct $name<'ui> {
+            ui: &'ui Ui<'ui>,
+        }
+
+        impl<'ui> $name<'ui> {
+            pub(super) fn new(ui: &'ui Ui<'_>) -> Self {
+                Self { ui }
+            }
+
+            #[doc = concat!("Ends the scope now, calling `", stringify!($end), "`.")]
+            pub fn end(self) {}
+        }
+
+        impl Drop for $name<'_> {
+            fn drop(&mut self) {
+                self.ui.end_scope(imgui_sys::$end);
+            }
+        }
+    };
+}
+
+token!(
+    /// Window pushed by [`Ui::begin`](super::Ui::begin), ended with `igEnd`.
+    WindowToken => igEnd
+);
+token!(
+    /// Child window pushed by [`Ui::begin_child`](super::Ui::begin_child), ended with `igEndChild`.
+    ChildWindowToken => igEndChild
+);
+token!(
+    /// Group started by [`Ui::begin_group`](super::Ui::begin_group), ended with `igEndGroup`.
+    GroupToken => igEndGroup
+);
+token!(
+    /// Tab bar started by [`Ui::begin_tab_bar`](super::Ui::begin_tab_bar), ended with `igEndTabBar`.
+    TabBarToken => igEndTabBar
+);
+token!(
+    /// Tab started by [`Ui::begin_tab_item`](super::Ui::begin_tab_item), ended with `igEndTabItem`.
+    TabItemToken => igEndTabItem
+);
+token!(
+    /// Menu bar started by [`Ui::begin_menu_bar`](super::Ui::begin_menu_bar), ended with `igEndMenuBar`.
+    MenuBarToken => igEndMenuBar
+);
+token!(
+    /// Main menu bar started by [`Ui::begin_main_menu_bar`](super::Ui::begin_main_menu_bar), ended with `igEndMainMenuBar`.
+    MainMenuBarToken => igEndMainMenuBar
+);
+token!(
+    /// Menu started by [`Ui::begin_menu`](super::Ui::begin_menu), ended with `igEndMenu`.
+    MenuToken => igEndMenu
+);
+token!(
+    /// Popup started by [`Ui::begin_popup`](super::Ui::begin_popup) or [`Ui::begin_popup_modal`](super::Ui::begin_popup_modal), ended with `igEndPopup`.
+    PopupToken => igEndPopup
+);
+token!(
+    /// Tooltip started by [`Ui::begin_tooltip`](super::Ui::begin_tooltip), ended with `igEndTooltip`.
+    TooltipToken => igEndTooltip
+);
+token!(
+    /// Tree node pushed by [`Ui::tree_node`](super::Ui::tree_node), popped with `igTreePop`.
+    TreeNodeToken => igTreePop
+);
+
@@ -0,0 +1,199 @@
+// Frame handle and the Begin/End functions returning tokens
+
+use core::ffi::c_char;
+use core::ptr;
+
+use super::tokens::*;
+use super::label::ScratchBuffer;
+use super::{Context, DrawData, Label, Text};
+use crate::imgui_sys;
+
+impl Context {
+    /// Makes this the current context and starts a new frame with `igNewFrame`.
+    ///
+    /// The platform and renderer backends `NewFrame` functions must be called before.
+    pub fn new_frame(&mut self) -> Ui<'_> {
+        unsafe {
+            imgui_sys::igSetCurrentContext(self.as_raw());
+            imgui_sys::igNewFrame();
+        }
+        Ui { context: self }
+    }
+}
+
+/// Handle to the frame started by [`Context::new_frame`], to submit windows and widgets.
+///
+/// The frame ends with [`Ui::render`], or with `igEndFrame` if the handle is dropped without rendering.
+///
+/// The `begin_*` functions return a token whose drop calls the matching End/Pop function. The functions only
+/// return a token when the contents are visible and there is something to end. The tokens have to be dropped in the
+/// reverse order they were created, as scopes do, but nothing enforces it: a token can be ended early or forgotten.
+/// Dear ImGui reports a wrong order or a missing End/Pop with `IM_ASSERT`, like with the C++ API.
+///
+/// The functions panic if another context was made current during the frame. The drops of the frame and of its
+/// tokens make the context of the frame current for their End/Pop call, then restore the current context.
+pub struct Ui<'ctx> {
+    context: &'ctx mut Context,
+}
+
+impl<'ctx> Ui<'ctx> {
+    /// Returns the context of this frame.
+    pub fn context(&self) -> &Context {
+        self.context
+    }
+
+    // C string of a label, only valid until the next text is converted
+    pub(super) fn c_str(&self, label: &impl Text) -> *const c_char {
+        let scratch = self.scratch();
+        scratch.c_str(label.write_c_str(scratch))
+    }
+
+    // Begin/end pointers of a text, only valid until the next text is converted
+    pub(super) fn text_range(&self, text: &impl Text) -> (*const c_char, *const c_char) {
+        let scratch = self.scratch();
+        scratch.range(text.write_range(scratch))
+    }
+
+    // Scratch buffer of the context, cleared for the next call
+    pub(super) fn scratch(&self) -> &ScratchBuffer {
+        self.check_current();
+        self.context.scratch().clear()
+    }
+
+    // The functions of the frame call Dear ImGui on the current context
+    pub(super) fn check_current(&self) {
+        assert!(self.context.is_current(), "the context of the frame isn't the current one");
+    }
+
+    // Calls an End function on the context of the frame, from a drop which can't panic while unwinding
+    pub(super) fn end_scope(&self, end: unsafe extern "C" fn()) {
+        unsafe {
+            let current = imgui_sys::igGetCurrentContext();
+            imgui_sys::igSetCurrentContext(self.context.as_raw());
+            end();
+            imgui_sys::igSetCurrentContext(current);
+        }
+    }
+
+    /// Ends the frame and renders it with `igRender`, returns the draw data to give to the renderer backend.
+    pub fn render(self) -> DrawData<'ctx> {
+        self.check_current();
+        unsafe {
+            imgui_sys::igRender();
+            // The draw data belongs to the context and stays valid until the next frame
+            DrawData::from_raw(imgui_sys::igGetDrawData())
+        }
+    }
+
+    /// `igBegin`: pushes a window, see [`Ui::begin_ex`].
+    pub fn begin(&self, name: impl Label) -> Option<WindowToken<'_>> {
+        self.begin_ex(name, None, 0)
+    }
+
+    /// `igBegin`: pushes a window, with an optional close button updating `opened` and window flags.
+    ///
+    /// Returns `None` if the window is collapsed or clipped.
+    pub fn begin_ex(&self, name: impl Label, opened: Option<&mut bool>, flags: imgui_sys::ImGuiWindowFlags) -> Option<WindowToken<'_>> {
+        let opened = opened.map_or(ptr::null_mut(), ptr::from_mut);
+        let visible = unsafe { imgui_sys::igBegin(self.c_str(&name), opened, flags) };
+        // igEnd always has to be called, the token is dropped right away if the window isn't visible
+        let token = WindowToken::new(self);
+        visible.then_some(token)
+    }
+
+    /// `igBeginChild`: pushes a child window.
+    ///
+    /// Returns `None` if the child window is clipped.
+    pub fn begin_child(
+        &self,
+        str_id: impl Label,
+        size: imgui_sys::ImVec2,
+        child_flags: imgui_sys::ImGuiChildFlags,
+        window_flags: imgui_sys::ImGuiWindowFlags,
+    ) -> Option<ChildWindowToken<'_>> {
+        let visible = unsafe { imgui_sys::igBeginChild(self.c_str(&str_id), size, child_flags, window_flags) };
+        // igEndChild always has to be called, the token is dropped right away if the child window isn't visible
+        let token = ChildWindowToken::new(self);
+        visible.then_some(token)
+    }
+
+    /// `igBeginGroup`: locks the horizontal starting position, the group is a single item once ended.
+    pub fn begin_group(&self) -> GroupToken<'_> {
+        self.check_current();
+        unsafe { imgui_sys::igBeginGroup() };
+        GroupToken::new(self)
+    }
+
+    /// `igBeginTabBar`: creates and appends into a tab bar.
+    pub fn begin_tab_bar(&self, str_id: impl Label, flags: imgui_sys::ImGuiTabBarFlags) -> Option<TabBarToken<'_>> {
+        unsafe { imgui_sys::igBeginTabBar(self.c_str(&str_id), flags) }.then(|| TabBarToken::new(self))
+    }
+
+    /// `igBeginTabItem`: creates a tab, with an optional close button updating `opened`.
+    ///
+    /// Returns a token if the tab is selected.
+    pub fn begin_tab_item(&self, label: impl Label, opened: Option<&mut bool>, flags: imgui_sys::ImGuiTabItemFlags) -> Option<TabItemToken<'_>> {
+        let opened = opened.map_or(ptr::null_mut(), ptr::from_mut);
+        unsafe { imgui_sys::igBeginTabItem(self.c_str(&label), opened, flags) }.then(|| TabItemToken::new(self))
+    }
+
+    /// `igBeginMenuBar`: appends to the menu bar of the current window, which needs the `ImGuiWindowFlags_MenuBar` flag.
+    pub fn begin_menu_bar(&self) -> Option<MenuBarToken<'_>> {
+        self.check_current();
+        unsafe { imgui_sys::igBeginMenuBar() }.then(|| MenuBarToken::new(self))
+    }
+
+    /// `igBeginMainMenuBar`: creates and appends to a full screen menu bar.
+    pub fn begin_main_menu_bar(&self) -> Option<MainMenuBarToken<'_>> {
+        self.check_current();
+        unsafe { imgui_sys::igBeginMainMenuBar() }.then(|| MainMenuBarToken::new(self))
+    }
+
+    /// `igBeginMenu`: creates a sub-menu entry, returns a token if the menu is open.
+    pub fn begin_menu(&self, label: impl Label) -> Option<MenuToken<'_>> {
+        self.begin_menu_ex(label, true)
+    }
+
+    /// `igBeginMenuEx`: creates a sub-menu entry that can be disabled, returns a token if the menu is open.
+    pub fn begin_menu_ex(&self, label: impl Label, enabled: bool) -> Option<MenuToken<'_>> {
+        unsafe { imgui_sys::igBeginMenuEx(self.c_str(&label), enabled) }.then(|| MenuToken::new(self))
+    }
+
+    /// `igBeginPopup`: returns a token if the popup opened with `igOpenPopup` is open.
+    pub fn begin_popup(&self, str_id: impl Label, flags: imgui_sys::ImGuiWindowFlags) -> Option<PopupToken<'_>> {
+        unsafe { imgui_sys::igBeginPopup(self.c_str(&str_id), flags) }.then(|| PopupToken::new(self))
+    }
+
+    /// `igBeginPopupModal`: returns a token if the modal popup is open, with an optional close button updating `opened`.
+    pub fn begin_popup_modal(&self, name: impl Label, opened: Option<&mut bool>, flags: imgui_sys::ImGuiWindowFlags) -> Option<PopupToken<'_>> {
+        let opened = opened.map_or(ptr::null_mut(), ptr::from_mut);
+        unsafe { imgui_sys::igBeginPopupModal(self.c_str(&name), opened, flags) }.then(|| PopupToken::new(self))
+    }
+
+    /// `igBeginTooltip`: begins or appends to a tooltip window.
+    pub fn begin_tooltip(&self) -> Option<TooltipToken<'_>> {
+        self.check_current();
+        unsafe { imgui_sys::igBeginTooltip() }.then(|| TooltipToken::new(self))
+    }
+
+    /// `igTreeNode`: returns a token if the tree node is open.
+    pub fn tree_node(&self, label: impl Label) -> Option<TreeNodeToken<'_>> {
+        unsafe { imgui_sys::igTreeNode(self.c_str(&label)) }.then(|| TreeNodeToken::new(self))
+    }
+
+    /// `igTreeNodeEx`: returns a token if the tree node is open.
+    ///
+    /// With `ImGuiTreeNodeFlags_NoTreePushOnOpen`, nothing is pushed so there is no token.
+    pub fn tree_node_ex(&self, label: impl Label, flags: imgui_sys::ImGuiTreeNodeFlags) -> Option<TreeNodeToken<'_>> {
+        let open = unsafe { imgui_sys::igTreeNodeEx(self.c_str(&label), flags) };
+        let pushed = flags & imgui_sys::ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_NoTreePushOnOpen as i32 == 0;
+        (open && pushed).then(|| TreeNodeToken::new(self))
+    }
+}
+
+impl Drop for Ui<'_> {
+    fn drop(&mut self) {
+        // No-op if the frame was rendered, igRender already ended it
+        self.end_scope(imgui_sys::igEndFrame);
+    }
+}
@@ -0,0 +1,24 @@
+// Widgets whose arguments have no generated mapping
+
+use super::label::WriteText;
+use super::{Label, Ui};
+use crate::imgui_sys;
+
+impl Ui<'_> {
+    /// `igCombo`: combo box selecting one of `items`, returns true when the selection changed.
+    pub fn combo(&self, label: impl Label, current_item: &mut i32, items: &[impl Label]) -> bool {
+        self.combo_ex(label, current_item, items, None)
+    }
+
+    /// `igComboEx`: like [`Ui::combo`], with the height of the popup in items, `None` for the default.
+    ///
+    /// The items are written into the scratch buffer as the `"One\0Two\0\0"` list Dear ImGui expects, so it stops
+    /// at an empty item or at a NUL in an item.
+    pub fn combo_ex(&self, label: impl Label, current_item: &mut i32, items: &[impl Label], popup_max_height_in_items: Option<i32>) -> bool {
+        let scratch = self.scratch();
+        let label = label.write_c_str(scratch);
+        let items = scratch.write_zero_separated(items);
+        let popup_max_height_in_items = popup_max_height_in_items.unwrap_or(-1);
+        unsafe { imgui_sys::igComboEx(scratch.c_str(label), current_item, scratch.c_str(items), popup_max_height_in_items) }
+    }
+}
@@ -0,0 +1,407 @@
+// Software renderer backend: rasterizes the draw data into an RGBA8 image, without a GPU
+//
+// Used for golden-image tests and headless screenshots. It follows the SDL_GPU backend (imgui_impl_sdlgpu3.cpp):
+// - same texture requests handling (create/update/destroy of the ImTextureData)
+// - same scissor rects, projected to the framebuffer and truncated to integers
+// - bilinear sampling with clamp-to-edge, vertex color times texture color
+// - SrcAlpha/OneMinusSrcAlpha blending of the color, One/OneMinusSrcAlpha of the alpha
+// Triangles are sampled at the pixel centers with the top-left fill rule, like GPUs do. The output matches what the
+// SDL_GPU backend draws up to rounding: GPUs interpolate and blend with their own precision, so a compare with a
+// GPU screenshot needs a small tolerance, while the software output itself is deterministic.
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::imgui_sys::enums::{BackendFlags, TextureFormat, TextureStatus};
+use crate::imgui_sys::{self, ImDrawVert, ImTextureData, ImTextureID, ImVec2};
+use crate::safe::{Context, DrawCmd, DrawData};
+
+/// An RGBA8 image, rows top to bottom without padding.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Creates a transparent black image.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height, pixels: vec![0; width as usize * height as usize * 4] }
+    }
+
+    /// Creates an image from its RGBA8 pixels.
+    ///
+    /// # Panics
+    ///
+    /// If `pixels` isn't `width * height * 4` bytes.
+    pub fn from_rgba8(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        assert_eq!(pixels.len(), width as usize * height as usize * 4, "the pixels don't match the image size");
+        Self { width, height, pixels }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// RGBA8 pixels
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn pixels_mut(&mut self) -> &mut [u8] {
+        &mut self.pixels
+    }
+
+    /// RGBA color of a pixel.
+    ///
+    /// # Panics
+    ///
+    /// If the pixel is outside of the image.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(x < self.width && y < self.height, "pixel ({x}, {y}) outside of the image");
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        [self.pixels[offset], self.pixels[offset + 1], self.pixels[offset + 2], self.pixels[offset + 3]]
+    }
+
+    /// Sets all the pixels to `color`, e.g. the clear color before rendering.
+    pub fn fill(&mut self, color: [u8; 4]) {
+        for pixel in self.pixels.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&color);
+        }
+    }
+}
+
+// Without the pixels, which are too many to print
+impl fmt::Debug for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Image").field("width", &self.width).field("height", &self.height).finish_non_exhaustive()
+    }
+}
+
+/// Renderer backend rasterizing the draw data on the CPU, see [`SoftwareRenderer::render`].
+///
+/// It creates the textures Dear ImGui requests (font atlas...) and the user textures added with
+/// [`SoftwareRenderer::add_texture`]. The texture IDs are only meaningful to the renderer that created them.
+///
+/// Dropping the renderer shuts it down like the `Shutdown` function of the other renderer backends: it destroys the
+/// textures of Dear ImGui, which the next renderer creates again, and clears its name and flags from the context.
+pub struct SoftwareRenderer {
+    context: u64,
+    // Indexed by texture ID - 1, as 0 is `ImTextureID_Invalid`
+    textures: Vec<Option<Image>>,
+    // Dropped on the thread of its context
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl SoftwareRenderer {
+    /// Initializes the renderer backend of `context`, like the `Init` function of the other renderer backends.
+    ///
+    /// The renderer supports `VtxOffset` and the texture requests, so the font atlas doesn't need to be built
+    /// beforehand.
+    pub fn new(context: &Context) -> Self {
+        let _current = context.make_current();
+        unsafe {
+            let io = imgui_sys::igGetIO();
+            (*io).BackendRendererName = c"imgui_sdl3_sys_software_renderer".as_ptr();
+            (*io).BackendFlags |= BackendFlags::RendererHasVtxOffset | BackendFlags::RendererHasTextures;
+        }
+        Self { context: context.id(), textures: Vec::new(), _not_send: PhantomData }
+    }
+
+    /// Adds a user texture from its RGBA8 pixels, to draw with `igImage`... and the returned ID.
+    pub fn add_texture(&mut self, image: Image) -> ImTextureID {
+        let index = match self.textures.iter().position(Option::is_none) {
+            Some(index) => {
+                self.textures[index] = Some(image);
+                index
+            }
+            None => {
+                self.textures.push(Some(image));
+                self.textures.len() - 1
+            }
+        };
+        index as ImTextureID + 1
+    }
+
+    /// Removes a texture added with [`SoftwareRenderer::add_texture`], returns its pixels.
+    pub fn remove_texture(&mut self, id: ImTextureID) -> Option<Image> {
+        let index = (id as usize).checked_sub(1)?;
+        self.textures.get_mut(index)?.take()
+    }
+
+    /// Returns a texture, a user texture or one created for Dear ImGui.
+    pub fn texture(&self, id: ImTextureID) -> Option<&Image> {
+        let index = (id as usize).checked_sub(1)?;
+        self.textures.get(index)?.as_ref()
+    }
+
+    /// Renders the draw data into a new transparent black image of the framebuffer size
+    /// (`DisplaySize * FramebufferScale`).
+    pub fn render(&mut self, draw_data: &DrawData) -> Image {
+        let width = (draw_data.DisplaySize.x * draw_data.FramebufferScale.x) as i32;
+        let height = (draw_data.DisplaySize.y * draw_data.FramebufferScale.y) as i32;
+        let mut target = Image::new(width.max(0) as u32, height.max(0) as u32);
+        self.render_into(draw_data, &mut target);
+        target
+    }
+
+    /// Renders the draw data over `target`, e.g. after filling it with a clear color.
+    ///
+    /// The target is the framebuffer: it should be `DisplaySize * FramebufferScale`, the parts outside of it are
+    /// clipped. The texture requests are handled first, and the user callbacks are called in order. The commands
+    /// drawing with a texture ID unknown to this renderer are skipped.
+    pub fn render_into(&mut self, draw_data: &DrawData, target: &mut Image) {
+        let fb_width = (draw_data.DisplaySize.x * draw_data.FramebufferScale.x) as i32;
+        let fb_height = (draw_data.DisplaySize.y * draw_data.FramebufferScale.y) as i32;
+        if fb_width <= 0 || fb_height <= 0 {
+            return;
+        }
+
+        // Catch up with the texture requests, like `ImGui_ImplSDLGPU3_PrepareDrawData`
+        for &tex in draw_data.textures() {
+            if unsafe { (*tex).Status } != TextureStatus::OK.raw() {
+                unsafe { self.update_texture(tex) };
+            }
+        }
+
+        let clip_off = draw_data.DisplayPos;
+        let clip_scale = draw_data.FramebufferScale;
+        for list in draw_data.lists() {
+            let vertices = list.vertices();
+            let indices = list.indices();
+            for cmd in list.commands() {
+                match cmd {
+                    DrawCmd::Elements { count, params } => {
+                        // Project the clip rect into framebuffer space, clamped to the viewport
+                        let clip = params.clip_rect;
+                        let min_x = ((clip.x - clip_off.x) * clip_scale.x).max(0.0);
+                        let min_y = ((clip.y - clip_off.y) * clip_scale.y).max(0.0);
+                        let max_x = ((clip.z - clip_off.x) * clip_scale.x).min(fb_width as f32);
+                        let max_y = ((clip.w - clip_off.y) * clip_scale.y).min(fb_height as f32);
+                        if max_x <= min_x || max_y <= min_y {
+                            continue;
+                        }
+                        let scissor = Scissor {
+                            min_x: min_x as i32,
+                            min_y: min_y as i32,
+                            max_x: min_x as i32 + (max_x - min_x) as i32,
+                            max_y: min_y as i32 + (max_y - min_y) as i32,
+                        };
+
+                        // A texture of another renderer, or a user texture already removed
+                        let Some(texture) = self.texture(params.texture_id) else {
+                            continue;
+                        };
+                        let indices = &indices[params.idx_offset..params.idx_offset + count];
+                        for triangle in indices.chunks_exact(3) {
+                            let vertex = |i: usize| {
+                                let vertex = &vertices[params.vtx_offset + triangle[i] as usize];
+                                project(vertex, clip_off, clip_scale)
+                            };
+                            draw_triangle(target, &scissor, texture, [vertex(0), vertex(1), vertex(2)]);
+                        }
+                    }
+                    // There is no render state to reset
+                    DrawCmd::ResetRenderState => {}
+                    DrawCmd::Callback(callback) => unsafe { callback.call() },
+                }
+            }
+        }
+    }
+
+    // Create/update/destroy request of a texture, like `ImGui_ImplSDLGPU3_UpdateTexture`
+    unsafe fn update_texture(&mut self, tex: *mut ImTextureData) {
+        let status = unsafe { (*tex).Status };
+        if status == TextureStatus::WantCreate.raw() {
+            let (width, height) = unsafe { ((*tex).Width as u32, (*tex).Height as u32) };
+            let id = self.add_texture(Image::new(width, height));
+            unsafe { imgui_sys::ImTextureData_SetTexID(tex, id) };
+        }
+        if status == TextureStatus::WantCreate.raw() || status == TextureStatus::WantUpdates.raw() {
+            let data = unsafe { &*tex };
+            // The whole texture on creation, the pixels are cleared at the same time
+            let (x, y, w, h) = if status == TextureStatus::WantCreate.raw() {
+                (0, 0, data.Width as usize, data.Height as usize)
+            } else {
+                let rect = data.UpdateRect;
+                (rect.x as usize, rect.y as usize, rect.w as usize, rect.h as usize)
+            };
+            let alpha8 = data.Format == TextureFormat::Alpha8.raw();
+            let bytes_per_pixel = data.BytesPerPixel as usize;
+            let tex_width = data.Width as usize;
+            if let Some(Some(image)) = (data.TexID as usize).checked_sub(1).and_then(|index| self.textures.get_mut(index)) {
+                for row in y..y + h {
+                    for column in x..x + w {
+                        let src = unsafe { data.Pixels.add((row * tex_width + column) * bytes_per_pixel) };
+                        let dst = (row * tex_width + column) * 4;
+                        let color = if alpha8 {
+                            [255, 255, 255, unsafe { *src }]
+                        } else {
+                            unsafe { [*src, *src.add(1), *src.add(2), *src.add(3)] }
+                        };
+                        image.pixels[dst..dst + 4].copy_from_slice(&color);
+                    }
+                }
+            }
+            unsafe { imgui_sys::ImTextureData_SetStatus(tex, TextureStatus::OK.raw()) };
+        }
+        if status == TextureStatus::WantDestroy.raw() && unsafe { (*tex).UnusedFrames } > 0 {
+            self.remove_texture(unsafe { (*tex).TexID });
+            unsafe {
+                imgui_sys::ImTextureData_SetTexID(tex, 0);
+                imgui_sys::ImTextureData_SetStatus(tex, TextureStatus::Destroyed.raw());
+            }
+        }
+    }
+}
+
+impl Drop for SoftwareRenderer {
+    fn drop(&mut self) {
+        Context::shutdown_renderer(self.context);
+    }
+}
+
+// Pixel rect [min, max) the triangles are clipped to
+struct Scissor {
+    min_x: i32,
+    min_y: i32,
+    max_x: i32,
+    max_y: i32,
+}
+
+// Vertex in framebuffer space, with its color as floats
+#[derive(Clone, Copy)]
+struct Vertex {
+    x: f32,
+    y: f32,
+    u: f32,
+    v: f32,
+    color: [f32; 4],
+}
+
+// Shifts of the R, G, B and A bytes in the packed colors, IM_COL32 puts B in the low byte with
+// IMGUI_USE_BGRA_PACKED_COLOR
+#[cfg(not(feature = "use-bgra-packed-color"))]
+const COL32_SHIFTS: [u32; 4] = [0, 8, 16, 24];
+#[cfg(feature = "use-bgra-packed-color")]
+const COL32_SHIFTS: [u32; 4] = [16, 8, 0, 24];
+
+fn project(vertex: &ImDrawVert, clip_off: ImVec2, clip_scale: ImVec2) -> Vertex {
+    let col = vertex.col;
+    Vertex {
+        x: (vertex.pos.x - clip_off.x) * clip_scale.x,
+        y: (vertex.pos.y - clip_off.y) * clip_scale.y,
+        u: vertex.uv.x,
+        v: vertex.uv.y,
+        color: COL32_SHIFTS.map(|shift| ((col >> shift) & 0xFF) as f32 / 255.0),
+    }
+}
+
+// Edge function: positive when `p` is on the inside of `a -> b` for the triangles sorted by `draw_triangle`
+fn edge(a: &Vertex, b: &Vertex, x: f32, y: f32) -> f32 {
+    (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x)
+}
+
+// Top-left fill rule: the pixel centers on a top or left edge belong to the triangle, not the ones on the other edges,
+// so the pixels on an edge shared by two triangles are drawn once
+fn is_top_left(a: &Vertex, b: &Vertex) -> bool {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    dy < 0.0 || (dy == 0.0 && dx > 0.0)
+}
+
+fn draw_triangle(target: &mut Image, scissor: &Scissor, texture: &Image, vertices: [Vertex; 3]) {
+    let [v0, mut v1, mut v2] = vertices;
+    let mut area = edge(&v0, &v1, v2.x, v2.y);
+    if area == 0.0 {
+        return;
+    }
+    // No culling, both windings are drawn
+    if area < 0.0 {
+        core::mem::swap(&mut v1, &mut v2);
+        area = -area;
+    }
+
+    let min_x = floor(v0.x.min(v1.x).min(v2.x)).max(scissor.min_x as f32) as i32;
+    let min_y = floor(v0.y.min(v1.y).min(v2.y)).max(scissor.min_y as f32) as i32;
+    let max_x = (v0.x.max(v1.x).max(v2.x) as i32 + 1).min(scissor.max_x).min(target.width as i32);
+    let max_y = (v0.y.max(v1.y).max(v2.y) as i32 + 1).min(scissor.max_y).min(target.height as i32);
+
+    let top_left = [is_top_left(&v1, &v2), is_top_left(&v2, &v0), is_top_left(&v0, &v1)];
+    for y in min_y.max(0)..max_y {
+        let center_y = y as f32 + 0.5;
+        for x in min_x.max(0)..max_x {
+            let center_x = x as f32 + 0.5;
+            let weights = [edge(&v1, &v2, center_x, center_y), edge(&v2, &v0, center_x, center_y), edge(&v0, &v1, center_x, center_y)];
+            let inside = weights.iter().zip(top_left).all(|(&w, top_left)| w > 0.0 || (w == 0.0 && top_left));
+            if !inside {
+                continue;
+            }
+
+            let [w0, w1, w2] = weights.map(|w| w / area);
+            let mut color = [0.0; 4];
+            for (c, channel) in color.iter_mut().enumerate() {
+                *channel = v0.color[c] * w0 + v1.color[c] * w1 + v2.color[c] * w2;
+            }
+            let texel = sample(texture, v0.u * w0 + v1.u * w1 + v2.u * w2, v0.v * w0 + v1.v * w1 + v2.v * w2);
+            for (channel, texel) in color.iter_mut().zip(texel) {
+                *channel *= texel;
+            }
+            blend(target, x as u32, y as u32, color);
+        }
+    }
+}
+
+// Bilinear filtering with clamp-to-edge addressing, the texel centers are at +0.5
+fn sample(texture: &Image, u: f32, v: f32) -> [f32; 4] {
+    if texture.width == 0 || texture.height == 0 {
+        return [0.0; 4];
+    }
+    let tx = u * texture.width as f32 - 0.5;
+    let ty = v * texture.height as f32 - 0.5;
+    let (x0, y0) = (floor(tx), floor(ty));
+    let (fx, fy) = (tx - x0, ty - y0);
+    let texel = |x: f32, y: f32| {
+        let x = (x as i32).clamp(0, texture.width as i32 - 1) as u32;
+        let y = (y as i32).clamp(0, texture.height as i32 - 1) as u32;
+        texture.pixel(x, y).map(|c| c as f32 / 255.0)
+    };
+    let (t00, t10, t01, t11) = (texel(x0, y0), texel(x0 + 1.0, y0), texel(x0, y0 + 1.0), texel(x0 + 1.0, y0 + 1.0));
+    let mut color = [0.0; 4];
+    for (c, channel) in color.iter_mut().enumerate() {
+        let top = t00[c] + (t10[c] - t00[c]) * fx;
+        let bottom = t01[c] + (t11[c] - t01[c]) * fx;
+        *channel = top + (bottom - top) * fy;
+    }
+    color
+}
+
+// Color: SrcAlpha, OneMinusSrcAlpha. Alpha: One, OneMinusSrcAlpha.
+fn blend(target: &mut Image, x: u32, y: u32, src: [f32; 4]) {
+    let offset = (y as usize * target.width as usize + x as usize) * 4;
+    let dst = &mut target.pixels[offset..offset + 4];
+    let src_alpha = src[3].clamp(0.0, 1.0);
+    for c in 0..4 {
+        let dst_value = dst[c] as f32 / 255.0;
+        let value = if c == 3 {
+            src_alpha + dst_value * (1.0 - src_alpha)
+        } else {
+            src[c].clamp(0.0, 1.0) * src_alpha + dst_value * (1.0 - src_alpha)
+        };
+        dst[c] = (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+    }
+}
+
+// `f32::floor` isn't in core
+fn floor(value: f32) -> f32 {
+    let truncated = value as i32 as f32;
+    if truncated > value { truncated - 1.0 } else { truncated }
+}
@@ -0,0 +1,65 @@
+//! Tests for the IM_ASSERT hook, imgui must be built with IMGUI_ASSERT_HOOK
+
+#![cfg(feature = "assert-hook")]
+
+use std::ffi::CStr;
+use std::process::Command;
+use std::sync::{Mutex, MutexGuard};
+
+use imgui_sdl3_sys::assert_hook::{self, AssertFailure, AssertHandler};
+use imgui_sdl3_sys::imgui_sys::igGetKeyName;
+
+// The handler is a global, tests setting it can't run in parallel
+static HANDLER_LOCK: Mutex<()> = Mutex::new(());
+
+fn lock_handler() -> MutexGuard<'static, ()> {
+    HANDLER_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// An assertion failing without a context, GetKeyName() returns "Unknown" after it
+fn fail_assertion() -> &'static CStr {
+    unsafe { CStr::from_ptr(igGetKeyName(1)) }
+}
+
+static FAILURES: Mutex<Vec<(String, String, u32)>> = Mutex::new(Vec::new());
+
+fn record_failure(failure: &AssertFailure<'_>) {
+    let failure = (failure.expression.to_string(), failure.file.to_string(), failure.line);
+    FAILURES.lock().unwrap().push(failure);
+}
+
+#[test]
+fn test_custom_handler() {
+    let _lock = lock_handler();
+
+    let previous = assert_hook::set_assert_handler(record_failure);
+    assert!(std::ptr::fn_addr_eq(previous, assert_hook::panic_handler as AssertHandler));
+    assert_eq!(fail_assertion(), c"Unknown", "Dear ImGui goes on when the handler returns");
+    assert_hook::set_assert_handler(previous);
+
+    let failures = std::mem::take(&mut *FAILURES.lock().unwrap());
+    assert_eq!(failures.len(), 1);
+    let (expression, file, line) = &failures[0];
+    assert!(expression.starts_with("IsNamedKeyOrMod(key) && \"Support for user key indices"), "{expression}");
+    assert!(file.ends_with("imgui.cpp") && *line > 0, "{file}:{line}");
+}
+
+#[test]
+fn test_default_handler_panics() {
+    // The panic aborts at the FFI boundary, the test runs again in a child process to check its output
+    if std::env::var_os("IMGUI_ASSERT_HOOK_TEST_CHILD").is_some() {
+        fail_assertion();
+        return;
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["test_default_handler_panics", "--exact", "--nocapture"])
+        .env("IMGUI_ASSERT_HOOK_TEST_CHILD", "1")
+        .env_remove("RUST_BACKTRACE")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(stderr.contains("Dear ImGui assertion failed: IsNamedKeyOrMod(key)"), "{stderr}");
+    assert!(stderr.contains("cImGui_AssertFailed"), "The backtrace is captured without RUST_BACKTRACE: {stderr}");
+}
@@ -0,0 +1,44 @@
+//! Fixtures shared by the integration tests
+
+// Each test binary uses a part of them
+#![allow(dead_code)]
+
+use std::sync::{Mutex, MutexGuard};
+
+#[cfg(feature = "safe")]
+use imgui_sdl3_sys::imgui_sys::enums::BackendFlags;
+#[cfg(feature = "safe")]
+use imgui_sdl3_sys::imgui_sys::igGetIO;
+#[cfg(feature = "safe")]
+use imgui_sdl3_sys::safe::{Context, HeadlessPlatform};
+
+// The current context is a global, tests using it can't run in parallel
+static CURRENT_CONTEXT_LOCK: Mutex<()> = Mutex::new(());
+
+pub fn lock_current_context() -> MutexGuard<'static, ()> {
+    CURRENT_CONTEXT_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// A context without backends, not saving the window positions to imgui.ini
+#[cfg(feature = "safe")]
+pub fn new_context() -> Context {
+    let ctx = Context::new();
+    {
+        let _current = ctx.make_current();
+        unsafe { (*igGetIO()).IniFilename = core::ptr::null() };
+    }
+    ctx
+}
+
+// A context with a headless platform backend and a `width` x `height` display
+#[cfg(feature = "safe")]
+pub fn headless_context(width: f32, height: f32) -> (Context, HeadlessPlatform) {
+    let ctx = new_context();
+    {
+        let _current = ctx.make_current();
+        // Like a renderer backend, so the font atlas doesn't need to be built for the frames that aren't rendered
+        unsafe { (*igGetIO()).BackendFlags |= BackendFlags::RendererHasTextures };
+    }
+    let platform = HeadlessPlatform::new(&ctx, width, height);
+    (ctx, platform)
+}
@@ -93,3 +93,50 @@ fn test_struct_field_access() {
     assert_eq!(vec4.x, 1.0);
     assert_eq!(vec4.w, 4.0);
 }
+
+/// Test that the SDL_Renderer backend signatures are accessible
+#[test]
+fn test_sdlrenderer3_backend_signatures() {
+    use imgui_sdl3_sys::imgui_sys::sdl3::*;
+
+    let _: unsafe extern "C" fn(*mut SDL_Renderer) -> bool = cImGui_ImplSDLRenderer3_Init;
+    let _: unsafe extern "C" fn() = cImGui_ImplSDLRenderer3_Shutdown;
+    let _: unsafe extern "C" fn() = cImGui_ImplSDLRenderer3_NewFrame;
+    let _: unsafe extern "C" fn(*mut ImDrawData, *mut SDL_Renderer) =
+        cImGui_ImplSDLRenderer3_RenderDrawData;
+    let _: unsafe extern "C" fn(*mut ImTextureData) = cImGui_ImplSDLRenderer3_UpdateTexture;
+
+    let state = cImGui_ImplSDLRenderer3_RenderState { Renderer: core::ptr::null_mut() };
+    assert!(state.Renderer.is_null());
+}
+
+/// Test that the internal API is accessible and shares the public types
+#[cfg(feature = "internal")]
+#[test]
+fn test_internal_api_accessible() {
+    use imgui_sdl3_sys::imgui_sys::internal::*;
+
+    let _: unsafe extern "C" fn() -> *mut ImGuiWindow = igGetCurrentWindow;
+    let _: unsafe extern "C" fn(ImRect, ImGuiID) -> bool = igItemAdd;
+    let _: unsafe extern "C" fn(ImRect, ImGuiID, *mut bool, *mut bool, ImGuiButtonFlags) -> bool =
+        igButtonBehavior;
+    let _: unsafe extern "C" fn(*const ImGuiSettingsHandler) = igAddSettingsHandler;
+
+    // The context returned by the public API is the one defined by the internal API
+    let _: unsafe extern "C" fn() -> *mut ImGuiContext_t = igGetCurrentContext;
+    assert!(core::mem::size_of::<ImGuiContext>() > 0);
+
+    let rect = ImRect { Min: ImVec2 { x: 0.0, y: 0.0 }, Max: ImVec2 { x: 1.0, y: 1.0 } };
+    assert_eq!(rect.Max.x, 1.0);
+}
+
+/// Test that the bindings match the imconfig.h features
+#[test]
+fn test_imconfig_features() {
+    let wchar_size = if cfg!(feature = "use-wchar32") { 4 } else { 2 };
+    assert_eq!(core::mem::size_of::<ImWchar>(), wchar_size);
+
+    let r_shift = if cfg!(feature = "use-bgra-packed-color") { 16 } else { 0 };
+    assert_eq!(IM_COL32_R_SHIFT, r_shift);
+    assert_eq!(IM_COL32_A_SHIFT, 24);
+}
@@ -0,0 +1,147 @@
+//! Tests for the draw data snapshots
+
+#![cfg(feature = "draw-snapshot")]
+
+use imgui_sdl3_sys::draw_snapshot::{
+    assert_snapshot, CommandSnapshot, DrawDataSnapshot, SnapshotError, TextureSnapshot, UPDATE_SNAPSHOTS_VAR,
+};
+use imgui_sdl3_sys::imgui_sys::enums::Cond;
+use imgui_sdl3_sys::imgui_sys::*;
+
+mod common;
+
+unsafe extern "C" fn noop_callback(_parent_list: *const ImDrawList, _cmd: *const ImDrawCmd) {}
+
+#[test]
+fn test_snapshot_serialization() {
+    let _lock = common::lock_current_context();
+    let (mut ctx, platform) = common::headless_context(320.0, 240.0);
+
+    let ui = platform.new_frame(&mut ctx);
+    unsafe {
+        let draw_list = igGetBackgroundDrawList();
+        ImDrawList_AddRectFilled(draw_list, ImVec2 { x: 10.0, y: 10.0 }, ImVec2 { x: 20.0, y: 15.0 }, 0xFF0000FF);
+        ImDrawList_AddCallback(draw_list, Some(noop_callback), core::ptr::null_mut());
+        let texture = ImTextureRef { _TexData: core::ptr::null_mut(), _TexID: 42 };
+        ImDrawList_AddImage(draw_list, texture, ImVec2 { x: 0.0, y: 0.0 }, ImVec2 { x: 8.0, y: 8.0 });
+    }
+    ui.text("Hello");
+    ui.render();
+
+    let snapshot = DrawDataSnapshot::current().expect("The frame was rendered");
+    assert_eq!(snapshot.display_size, [320.0, 240.0]);
+    let commands: Vec<_> = snapshot.lists.iter().flat_map(|list| &list.commands).collect();
+    assert!(commands.contains(&&CommandSnapshot::Callback));
+    let textures: Vec<_> = commands
+        .iter()
+        .filter_map(|command| match command {
+            CommandSnapshot::Elements { texture, .. } => Some(*texture),
+            _ => None,
+        })
+        .collect();
+    assert!(textures.contains(&TextureSnapshot::Id(42)), "The user texture is kept by ID");
+    assert!(textures.contains(&TextureSnapshot::Managed(0)), "The font atlas is the first managed texture");
+
+    // The rect is the first shape of the background list
+    let background = &snapshot.lists[0];
+    assert_eq!(background.vertices[0].pos, [10.0, 10.0]);
+    assert_eq!(background.vertices[0].col, 0xFF0000FF);
+    assert_eq!(&background.indices[..6], &[0, 1, 2, 0, 2, 3]);
+
+    let bytes = snapshot.to_bytes();
+    assert_eq!(DrawDataSnapshot::from_bytes(&bytes), Ok(snapshot.clone()));
+    // The last field is the u32 index offset of the last command
+    let truncated = DrawDataSnapshot::from_bytes(&bytes[..bytes.len() - 1]);
+    assert_eq!(truncated.unwrap_err(), SnapshotError::InvalidData(bytes.len() - 4));
+    assert_eq!(DrawDataSnapshot::from_bytes(b"IMDS\x02").unwrap_err(), SnapshotError::UnsupportedVersion(2));
+    let index_size = DrawDataSnapshot::from_bytes(b"IMDS\x01\x03").unwrap_err();
+    assert_eq!(index_size, SnapshotError::UnsupportedIndexSize(3));
+    assert_eq!(DrawDataSnapshot::from_bytes(b"{}").unwrap_err(), SnapshotError::NotASnapshot);
+
+    let json = snapshot.to_json();
+    assert!(json.contains("\n        [10, 10, "), "A line per vertex:\n{json}");
+    assert!(json.contains("\n        0, 1, 2,\n        0, 2, 3,"), "A line per triangle:\n{json}");
+    assert!(json.contains("\"texture\": {\"id\": 42}"));
+    assert!(json.contains("\"texture\": {\"managed\": 0}"));
+    assert!(json.contains("\n        \"callback\""));
+    assert_eq!(json, snapshot.to_json(), "The serialization should be stable");
+
+    let mut other = snapshot.clone();
+    other.lists[0].vertices[3].col = 0;
+    let difference = snapshot.first_difference(&other).expect("The snapshots differ");
+    assert!(difference.starts_with("list 0: vertex 3: "), "{difference}");
+    assert_eq!(snapshot.first_difference(&snapshot), None);
+}
+
+#[test]
+fn test_window_snapshot() {
+    let _lock = common::lock_current_context();
+    let (mut ctx, platform) = common::headless_context(320.0, 240.0);
+
+    // Catches the layout changes when updating Dear ImGui, run with IMGUI_UPDATE_SNAPSHOTS=1 to accept them
+    for _ in 0..2 {
+        let ui = platform.new_frame(&mut ctx);
+        unsafe {
+            igSetNextWindowPos(ImVec2 { x: 10.0, y: 10.0 }, Cond::Always.raw());
+            igSetNextWindowSize(ImVec2 { x: 200.0, y: 100.0 }, Cond::Always.raw());
+        }
+        if let Some(_window) = ui.begin("Snapshot") {
+            ui.text("Hello, world!");
+            ui.button("Button");
+        }
+        ui.render();
+    }
+
+    let snapshot = DrawDataSnapshot::current().unwrap();
+    assert_snapshot(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/window.json"), &snapshot);
+}
+
+#[test]
+fn test_no_current_context() {
+    let _lock = common::lock_current_context();
+    let previous = unsafe { igGetCurrentContext() };
+    unsafe { igSetCurrentContext(std::ptr::null_mut()) };
+    assert!(DrawDataSnapshot::current().is_none());
+    unsafe { igSetCurrentContext(previous) };
+}
+
+#[test]
+fn test_snapshot_mismatch() {
+    if std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() {
+        return;
+    }
+    let _lock = common::lock_current_context();
+    let (mut ctx, platform) = common::headless_context(320.0, 240.0);
+
+    let ui = platform.new_frame(&mut ctx);
+    unsafe {
+        ImDrawList_AddLine(igGetBackgroundDrawList(), ImVec2 { x: 0.0, y: 0.0 }, ImVec2 { x: 10.0, y: 10.0 }, 0xFFFFFFFF);
+    }
+    ui.render();
+    let snapshot = DrawDataSnapshot::current().unwrap();
+    let mut moved = snapshot.clone();
+    moved.lists[0].vertices[1].pos[0] += 1.0;
+
+    let dir = std::env::temp_dir().join(format!("imgui-draw-snapshot-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for path in [dir.join("line.json"), dir.join("line.bin")] {
+        // Only written with IMGUI_UPDATE_SNAPSHOTS, a missing snapshot fails
+        let panic = std::panic::catch_unwind(|| assert_snapshot(&path, &snapshot)).expect_err("The snapshot is missing");
+        let message = panic.downcast_ref::<String>().unwrap();
+        assert!(message.contains("doesn't exist"), "{message}");
+        assert!(!path.exists());
+
+        let data = if path.extension().unwrap() == "json" { snapshot.to_json().into_bytes() } else { snapshot.to_bytes() };
+        std::fs::write(&path, data).unwrap();
+        assert_snapshot(&path, &snapshot);
+
+        let panic = std::panic::catch_unwind(|| assert_snapshot(&path, &moved)).expect_err("The snapshots differ");
+        let message = panic.downcast_ref::<String>().unwrap();
+        let expected = if path.extension().unwrap() == "json" { "line 9:" } else { "list 0: vertex 1: " };
+        assert!(message.contains(expected), "{message}");
+        let mut new_path = path.clone().into_os_string();
+        new_path.push(".new");
+        assert!(std::path::Path::new(&new_path).exists(), "The new snapshot is written next to the old one");
+    }
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,260 @@
+//! Tests for the input recording and replay
+
+#![cfg(feature = "input-replay")]
+
+use imgui_sdl3_sys::imgui_sys::enums::BackendFlags;
+use imgui_sdl3_sys::imgui_sys::sdl3::*;
+use imgui_sdl3_sys::imgui_sys::*;
+use imgui_sdl3_sys::input_replay::{InputPlayer, InputRecorder, ReplayError};
+use imgui_sdl3_sys::sdl3_sys::everything::*;
+
+mod common;
+
+fn record_session() -> InputRecorder {
+    let mut recorder = InputRecorder::new();
+
+    let mut event = SDL_Event::default();
+    event.motion = SDL_MouseMotionEvent {
+        r#type: SDL_EVENT_MOUSE_MOTION,
+        windowID: SDL_WindowID(42),
+        which: SDL_TOUCH_MOUSEID,
+        x: 10.5,
+        y: 20.0,
+        ..Default::default()
+    };
+    assert!(recorder.record_event(&event));
+    event.button = SDL_MouseButtonEvent {
+        r#type: SDL_EVENT_MOUSE_BUTTON_DOWN,
+        windowID: SDL_WindowID(42),
+        button: SDL_BUTTON_LEFT as u8,
+        down: true,
+        clicks: 2,
+        x: 10.5,
+        y: 20.0,
+        ..Default::default()
+    };
+    assert!(recorder.record_event(&event));
+    recorder.record_frame(1.0 / 60.0);
+
+    // No events in this frame
+    recorder.record_frame(0.02);
+
+    event.key = SDL_KeyboardEvent {
+        r#type: SDL_EVENT_KEY_UP,
+        windowID: SDL_WindowID(42),
+        scancode: SDL_SCANCODE_A,
+        key: SDLK_A,
+        r#mod: SDL_KMOD_LSHIFT,
+        ..Default::default()
+    };
+    assert!(recorder.record_event(&event));
+    let text = c"héllo";
+    event.text = SDL_TextInputEvent {
+        r#type: SDL_EVENT_TEXT_INPUT,
+        windowID: SDL_WindowID(42),
+        text: text.as_ptr(),
+        ..Default::default()
+    };
+    assert!(recorder.record_event(&event));
+    event.window = SDL_WindowEvent {
+        r#type: SDL_EVENT_WINDOW_FOCUS_LOST,
+        windowID: SDL_WindowID(42),
+        ..Default::default()
+    };
+    assert!(recorder.record_event(&event));
+    // The backend doesn't read these
+    event.r#type = SDL_EVENT_QUIT.0;
+    assert!(!recorder.record_event(&event));
+    recorder.record_frame(0.5);
+
+    // Not replayed without a frame after them
+    event.r#type = SDL_EVENT_WINDOW_FOCUS_GAINED.0;
+    assert!(recorder.record_event(&event));
+
+    recorder
+}
+
+#[test]
+fn test_record_and_replay() {
+    let recorder = record_session();
+    let mut player = InputPlayer::new(recorder.as_bytes()).unwrap();
+
+    let frame = player.next_frame().unwrap().expect("first frame");
+    assert_eq!(frame.delta_time(), 1.0 / 60.0);
+    let events = frame.events();
+    assert_eq!(events.len(), 2);
+    unsafe {
+        assert_eq!(events[0].r#type, SDL_EVENT_MOUSE_MOTION.0);
+        assert_eq!(events[0].motion.windowID.0, 0, "The window ID isn't recorded");
+        assert_eq!(events[0].motion.which.0, SDL_TOUCH_MOUSEID.0);
+        assert_eq!((events[0].motion.x, events[0].motion.y), (10.5, 20.0));
+        assert_eq!(events[1].r#type, SDL_EVENT_MOUSE_BUTTON_DOWN.0);
+        assert_eq!(events[1].button.button, SDL_BUTTON_LEFT as u8);
+        assert!(events[1].button.down);
+        assert_eq!(events[1].button.clicks, 2);
+    }
+
+    let frame = player.next_frame().unwrap().expect("second frame");
+    assert_eq!(frame.delta_time(), 0.02);
+    assert!(frame.events().is_empty());
+
+    let frame = player.next_frame().unwrap().expect("third frame");
+    assert_eq!(frame.delta_time(), 0.5);
+    let events = frame.events();
+    assert_eq!(events.len(), 3);
+    unsafe {
+        assert_eq!(events[0].r#type, SDL_EVENT_KEY_UP.0);
+        assert!(!events[0].key.down);
+        let key = events[0].key;
+        assert_eq!((key.scancode.0, key.key.0, key.r#mod.0), (SDL_SCANCODE_A.0, SDLK_A.0, SDL_KMOD_LSHIFT.0));
+        assert_eq!(events[1].r#type, SDL_EVENT_TEXT_INPUT.0);
+        assert_eq!(core::ffi::CStr::from_ptr(events[1].text.text), c"héllo");
+        assert_eq!(events[2].r#type, SDL_EVENT_WINDOW_FOCUS_LOST.0);
+    }
+
+    assert!(player.next_frame().unwrap().is_none());
+    assert!(player.next_frame().unwrap().is_none());
+}
+
+#[test]
+fn test_replay_errors() {
+    assert_eq!(InputPlayer::new(b"").unwrap_err(), ReplayError::NotARecording);
+    assert_eq!(InputPlayer::new(b"PNG\0\x01").unwrap_err(), ReplayError::NotARecording);
+    assert_eq!(InputPlayer::new(b"IMRP\x63").unwrap_err(), ReplayError::UnsupportedVersion(0x63));
+
+    // Truncated in the middle of the first event, after its mouse ID
+    let recording = record_session().into_bytes();
+    let mut player = InputPlayer::new(&recording[..10]).unwrap();
+    assert_eq!(player.next_frame().unwrap_err(), ReplayError::InvalidData(10));
+
+    // Unknown record
+    let mut player = InputPlayer::new(b"IMRP\x01\xFF").unwrap();
+    assert_eq!(player.next_frame().unwrap_err(), ReplayError::InvalidData(5));
+}
+
+// A frame with a button, returns whether it was clicked and sets `center` to its center
+unsafe fn button_frame(center: &mut ImVec2) -> bool {
+    unsafe {
+        igNewFrame();
+        igSetNextWindowPos(ImVec2 { x: 0.0, y: 0.0 }, 0);
+        igBegin(c"Replay".as_ptr(), core::ptr::null_mut(), 0);
+        let clicked = igButton(c"Button".as_ptr());
+        let (min, max) = (igGetItemRectMin(), igGetItemRectMax());
+        // Whole pixels, like the mouse position after the new frame
+        *center = ImVec2 { x: ((min.x + max.x) / 2.0).floor(), y: ((min.y + max.y) / 2.0).floor() };
+        igEnd();
+        igEndFrame();
+        clicked
+    }
+}
+
+// A context with the SDL3 backend initialized for `window`
+unsafe fn backend_context(window: *mut SDL_Window) -> *mut ImGuiContext {
+    unsafe {
+        let ctx = igCreateContext(core::ptr::null_mut());
+        igSetCurrentContext(ctx);
+        let io = igGetIO();
+        (*io).IniFilename = core::ptr::null();
+        // No renderer backend, the font atlas doesn't need to be built
+        (*io).BackendFlags |= BackendFlags::RendererHasTextures;
+        assert!(cImGui_ImplSDL3_InitForOther(window));
+        ctx
+    }
+}
+
+unsafe fn destroy_backend_context(ctx: *mut ImGuiContext) {
+    unsafe {
+        cImGui_ImplSDL3_Shutdown();
+        igDestroyContext(ctx);
+    }
+}
+
+#[test]
+fn test_replay_with_backend() {
+    let _lock = common::lock_current_context();
+    unsafe {
+        // The dummy video driver doesn't need a display
+        SDL_SetHint(SDL_HINT_VIDEO_DRIVER, c"dummy".as_ptr());
+        assert!(SDL_Init(SDL_INIT_VIDEO), "SDL_Init failed");
+        let window = SDL_CreateWindow(c"Input replay".as_ptr(), 320, 240, SDL_WINDOW_HIDDEN);
+        assert!(!window.is_null(), "SDL_CreateWindow failed");
+        let window_id = SDL_GetWindowID(window);
+
+        // Move over the button, press it, release it and press A, one step per frame
+        let ctx = backend_context(window);
+        let mut recorder = InputRecorder::new();
+        let mut center = ImVec2 { x: 0.0, y: 0.0 };
+        recorder.new_frame();
+        assert!(!button_frame(&mut center));
+        let mut event = SDL_Event::default();
+        event.motion = SDL_MouseMotionEvent {
+            r#type: SDL_EVENT_MOUSE_MOTION,
+            windowID: window_id,
+            x: center.x,
+            y: center.y,
+            ..Default::default()
+        };
+        let mut events = vec![event];
+        for down in [true, false] {
+            event.button = SDL_MouseButtonEvent {
+                r#type: if down { SDL_EVENT_MOUSE_BUTTON_DOWN } else { SDL_EVENT_MOUSE_BUTTON_UP },
+                windowID: window_id,
+                button: SDL_BUTTON_LEFT as u8,
+                down,
+                clicks: 1,
+                x: center.x,
+                y: center.y,
+                ..Default::default()
+            };
+            events.push(event);
+        }
+        event.key = SDL_KeyboardEvent {
+            r#type: SDL_EVENT_KEY_DOWN,
+            windowID: window_id,
+            scancode: SDL_SCANCODE_A,
+            key: SDLK_A,
+            down: true,
+            ..Default::default()
+        };
+        events.push(event);
+        let mut clicks = Vec::new();
+        for event in &events {
+            assert!(recorder.process_event(event));
+            recorder.new_frame();
+            clicks.push(button_frame(&mut center));
+        }
+        assert_eq!(clicks, [false, false, true, false], "The recorded session should click the button");
+        destroy_backend_context(ctx);
+
+        // Replayed in a new context with its own fixed delta time, restored at the end
+        let ctx = backend_context(window);
+        cImGui_ImplSDL3_SetDeltaTime(0.75);
+        let mut player = InputPlayer::new(recorder.as_bytes()).unwrap();
+        let mut clicks = Vec::new();
+        while let Some(frame) = player.next_frame().unwrap() {
+            frame.process();
+            cImGui_ImplSDL3_NewFrame();
+            assert_eq!((*igGetIO()).DeltaTime, frame.delta_time());
+            clicks.push(button_frame(&mut center));
+        }
+        assert_eq!(clicks, [false, false, false, true, false]);
+        let io = igGetIO();
+        assert_eq!(((*io).MousePos.x, (*io).MousePos.y), (center.x, center.y));
+        assert!(igIsKeyDown(ImGuiKey_A as ImGuiKey));
+        assert!(!igIsMouseDown(0));
+        assert_eq!(cImGui_ImplSDL3_GetDeltaTime(), 0.75);
+
+        // Also restored when the player is dropped before the end
+        let mut player = InputPlayer::new(recorder.as_bytes()).unwrap();
+        let frame = player.next_frame().unwrap().expect("first frame");
+        frame.process();
+        assert_eq!(cImGui_ImplSDL3_GetDeltaTime(), frame.delta_time());
+        drop(player);
+        assert_eq!(cImGui_ImplSDL3_GetDeltaTime(), 0.75);
+
+        cImGui_ImplSDL3_SetDeltaTime(0.0);
+        destroy_backend_context(ctx);
+        SDL_DestroyWindow(window);
+        SDL_Quit();
+    }
+}
@@ -0,0 +1,260 @@
+//! Tests for the remote UI, over loopback
+
+#![cfg(all(feature = "remote", feature = "software-renderer"))]
+
+use std::io::{ErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use imgui_sdl3_sys::imgui_sys::enums::{Cond, Key, MouseButton};
+use imgui_sdl3_sys::imgui_sys::*;
+use imgui_sdl3_sys::remote::{RemoteInput, RemoteServer, RemoteViewer};
+use imgui_sdl3_sys::safe::{Context, HeadlessPlatform, Ui};
+use imgui_sdl3_sys::software_renderer::{Image, SoftwareRenderer};
+
+mod common;
+
+use common::{headless_context, lock_current_context, new_context};
+
+// A window at a fixed place, returns whether its button was clicked
+fn build_ui(ui: &Ui) -> bool {
+    unsafe {
+        igSetNextWindowPos(ImVec2 { x: 10.0, y: 10.0 }, Cond::Always.raw());
+        igSetNextWindowSize(ImVec2 { x: 200.0, y: 100.0 }, Cond::Always.raw());
+    }
+    let mut clicked = false;
+    if let Some(_window) = ui.begin("Remote") {
+        ui.text("Hello, viewer!");
+        clicked = ui.button("Button");
+    }
+    clicked
+}
+
+// Button of `build_ui`, below the title bar and the text
+const BUTTON_POS: [f32; 2] = [30.0, 65.0];
+
+struct Viewer {
+    ctx: Context,
+    platform: HeadlessPlatform,
+    renderer: SoftwareRenderer,
+    viewer: RemoteViewer,
+}
+
+impl Viewer {
+    fn connect(server: &RemoteServer) -> Self {
+        let (ctx, platform) = headless_context(320.0, 240.0);
+        let renderer = SoftwareRenderer::new(&ctx);
+        let viewer = RemoteViewer::connect(server.local_addr().unwrap()).unwrap();
+        Self { ctx, platform, renderer, viewer }
+    }
+
+    // A frame of the viewer, drawing the last remote frame, with the inputs added beforehand
+    fn frame(&mut self) -> Image {
+        let ui = self.platform.new_frame(&mut self.ctx);
+        unsafe {
+            self.viewer.send_input(&RemoteInput::from_io(&*igGetIO())).unwrap();
+            self.viewer.draw(igGetBackgroundDrawList());
+        }
+        let mut draw_data = ui.render();
+        self.viewer.prepare_draw_data(&mut draw_data);
+        self.renderer.render(&draw_data)
+    }
+
+    // Waits for the next frame of the server
+    fn wait_frame(&mut self) {
+        let start = Instant::now();
+        while !self.viewer.poll().unwrap() {
+            assert!(start.elapsed() < Duration::from_secs(5), "No frame received from the server");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+}
+
+// A server frame, after waiting for the inputs the viewer sent
+fn server_frame(ctx: &mut Context, server: &mut RemoteServer) -> bool {
+    // Loopback delivers the sent data right away, the short wait is for slow CI machines
+    std::thread::sleep(Duration::from_millis(10));
+    let ui = server.new_frame(ctx);
+    let clicked = build_ui(&ui);
+    server.render(&ui.render());
+    clicked
+}
+
+#[test]
+fn test_remote_frame() {
+    let _lock = lock_current_context();
+    let mut server_ctx = new_context();
+    let mut server = RemoteServer::bind(&server_ctx, "127.0.0.1:0", 320.0, 240.0).unwrap();
+
+    // Frames run without a viewer, it gets the textures created before it connected
+    server_frame(&mut server_ctx, &mut server);
+    assert!(!server.is_connected());
+    let mut viewer = Viewer::connect(&server);
+
+    // The same UI rendered locally
+    let (mut local_ctx, local_platform) = headless_context(320.0, 240.0);
+    let mut local_renderer = SoftwareRenderer::new(&local_ctx);
+    let mut expected = Image::new(320, 240);
+    for _ in 0..2 {
+        let ui = local_platform.new_frame(&mut local_ctx);
+        build_ui(&ui);
+        expected = local_renderer.render(&ui.render());
+    }
+
+    server_frame(&mut server_ctx, &mut server);
+    assert!(server.is_connected());
+    viewer.wait_frame();
+    assert_eq!(viewer.viewer.display_size(), Some([320.0, 240.0]));
+    let image = viewer.frame();
+    assert_ne!(image.pixel(100, 80), [0; 4], "The window is drawn");
+    assert!(image == expected, "The viewer draws the remote UI like it's drawn locally");
+
+    // The textures are destroyed with the viewer
+    viewer.viewer.destroy_textures(|_| {});
+}
+
+#[test]
+fn test_remote_input() {
+    let _lock = lock_current_context();
+    let mut server_ctx = new_context();
+    let mut server = RemoteServer::bind(&server_ctx, "127.0.0.1:0", 320.0, 240.0).unwrap();
+    let mut viewer = Viewer::connect(&server);
+    // The server uses the display size of the viewer
+    viewer.platform.set_display_size(300.0, 200.0);
+
+    // Dear ImGui trickles the text after the mouse moves, it's typed first
+    viewer.platform.add_text(&viewer.ctx, "é");
+    viewer.frame();
+    std::thread::sleep(Duration::from_millis(10));
+    let ui = server.new_frame(&mut server_ctx);
+    // The text queue is cleared at the end of the frame
+    let io = unsafe { &*igGetIO() };
+    assert_eq!(unsafe { io.InputQueueCharacters.as_slice() }, &['é' as ImWchar]);
+    assert_eq!((io.DisplaySize.x, io.DisplaySize.y), (300.0, 200.0));
+    build_ui(&ui);
+    server.render(&ui.render());
+
+    // Hover, press and release the button
+    let ctx = &viewer.ctx;
+    viewer.platform.add_mouse_pos(ctx, BUTTON_POS[0], BUTTON_POS[1]);
+    viewer.platform.add_key(ctx, Key::Mod_Ctrl, true);
+    viewer.frame();
+    assert!(!server_frame(&mut server_ctx, &mut server));
+    {
+        let _current = server_ctx.make_current();
+        let io = unsafe { &*igGetIO() };
+        assert_eq!((io.MousePos.x, io.MousePos.y), (BUTTON_POS[0], BUTTON_POS[1]));
+        assert!(io.KeyCtrl, "The modifiers are sent as ImGuiMod_* events");
+    }
+
+    let ctx = &viewer.ctx;
+    viewer.platform.add_mouse_button(ctx, MouseButton::Left, true);
+    viewer.frame();
+    assert!(!server_frame(&mut server_ctx, &mut server));
+    viewer.platform.add_mouse_button(&viewer.ctx, MouseButton::Left, false);
+    viewer.frame();
+    assert!(server_frame(&mut server_ctx, &mut server), "The button was clicked from the viewer");
+
+    // The inputs are released when the viewer disconnects
+    drop(viewer);
+    server_frame(&mut server_ctx, &mut server);
+    assert!(!server.is_connected());
+    let _current = server_ctx.make_current();
+    let io = unsafe { &*igGetIO() };
+    assert!(!io.KeyCtrl);
+    assert!(io.MousePos.x < -256000.0, "The mouse left with the viewer");
+}
+
+#[test]
+fn test_remote_protocol_errors() {
+    let _lock = lock_current_context();
+    let mut server_ctx = new_context();
+    let mut server = RemoteServer::bind(&server_ctx, "127.0.0.1:0", 320.0, 240.0).unwrap();
+
+    // Not a viewer
+    let mut stream = TcpStream::connect(server.local_addr().unwrap()).unwrap();
+    stream.write_all(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+    server_frame(&mut server_ctx, &mut server);
+    assert!(!server.is_connected());
+
+    // Not a server
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut viewer = RemoteViewer::connect(listener.local_addr().unwrap()).unwrap();
+    let (mut stream, _) = listener.accept().unwrap();
+    stream.write_all(b"\x07\0\0\0\0IMRM\x02\x02").unwrap();
+    std::thread::sleep(Duration::from_millis(10));
+    assert_eq!(viewer.poll().unwrap_err().kind(), ErrorKind::InvalidData, "Unsupported version");
+
+    // A server with another ImDrawIdx size
+    let mut viewer = RemoteViewer::connect(listener.local_addr().unwrap()).unwrap();
+    let (mut stream, _) = listener.accept().unwrap();
+    stream.write_all(b"\x07\0\0\0\0IMRM\x01\x03").unwrap();
+    std::thread::sleep(Duration::from_millis(10));
+    assert_eq!(viewer.poll().unwrap_err().kind(), ErrorKind::InvalidData, "ImDrawIdx size mismatch");
+
+    // A message length over the limit fails before its data is received
+    let mut viewer = RemoteViewer::connect(listener.local_addr().unwrap()).unwrap();
+    let (mut stream, _) = listener.accept().unwrap();
+    stream.write_all(b"\xFF\xFF\xFF\x7F").unwrap();
+    std::thread::sleep(Duration::from_millis(10));
+    assert_eq!(viewer.poll().unwrap_err().kind(), ErrorKind::InvalidData, "Invalid length");
+}
+
+#[test]
+#[should_panic(expected = "the context isn't the one of the remote server")]
+fn test_remote_server_other_context() {
+    let _lock = lock_current_context();
+    let server_ctx = new_context();
+    let mut other_ctx = new_context();
+    let mut server = RemoteServer::bind(&server_ctx, "127.0.0.1:0", 320.0, 240.0).unwrap();
+    server.new_frame(&mut other_ctx);
+}
+
+#[test]
+fn test_remote_server_drop() {
+    let _lock = lock_current_context();
+    let mut ctx = new_context();
+    let mut server = RemoteServer::bind(&ctx, "127.0.0.1:0", 320.0, 240.0).unwrap();
+    server_frame(&mut ctx, &mut server);
+
+    // Like the Shutdown of the other backends, the textures are created again by the next renderer
+    drop(server);
+    let _current = ctx.make_current();
+    unsafe {
+        let io = igGetIO();
+        assert!((*io).BackendPlatformName.is_null());
+        assert!((*io).BackendRendererName.is_null());
+        assert_eq!((*io).BackendFlags & ImGuiBackendFlags__ImGuiBackendFlags_RendererHasTextures as i32, 0);
+        for &tex in (*igGetPlatformIO()).Textures.as_slice() {
+            assert_eq!((*tex).TexID, 0);
+        }
+    }
+    drop(_current);
+
+    let platform = HeadlessPlatform::new(&ctx, 320.0, 240.0);
+    let mut renderer = SoftwareRenderer::new(&ctx);
+    for _ in 0..2 {
+        let ui = platform.new_frame(&mut ctx);
+        build_ui(&ui);
+        let image = renderer.render(&ui.render());
+        assert_eq!(image.width(), 320);
+    }
+    drop(renderer);
+
+    // Dropped after its context, the server has nothing to shut down
+    let server = RemoteServer::bind(&ctx, "127.0.0.1:0", 320.0, 240.0).unwrap();
+    drop(ctx);
+    drop(server);
+}
+
+#[test]
+#[should_panic(expected = "the context isn't the one of the remote server")]
+fn test_remote_server_destroyed_context() {
+    let _lock = lock_current_context();
+    let ctx = new_context();
+    let mut server = RemoteServer::bind(&ctx, "127.0.0.1:0", 320.0, 240.0).unwrap();
+    drop(ctx);
+    // Even if the new context gets the same address
+    let mut ctx = new_context();
+    server.new_frame(&mut ctx);
+}
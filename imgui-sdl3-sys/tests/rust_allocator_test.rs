@@ -0,0 +1,138 @@
+//! Tests for the allocations through the Rust global allocator
+
+#![cfg(feature = "rust-allocator")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::{MutexGuard, Once};
+
+use imgui_sdl3_sys::imgui_sys::enums::BackendFlags;
+use imgui_sdl3_sys::imgui_sys::*;
+use imgui_sdl3_sys::rust_allocator::{self, context_stats, total_stats};
+
+mod common;
+
+// Counts the bytes allocated by each thread, the test harness allocates on its own threads
+struct CountingAllocator;
+
+thread_local! {
+    static THREAD_ALLOCATED: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = THREAD_ALLOCATED.try_with(|allocated| allocated.set(allocated.get() + layout.size()));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn thread_allocated() -> usize {
+    THREAD_ALLOCATED.with(Cell::get)
+}
+
+// The statistics are globals like the current context, the same lock covers them
+fn lock_current_context() -> MutexGuard<'static, ()> {
+    static INSTALL: Once = Once::new();
+    // Before the first context of the test binary
+    INSTALL.call_once(|| unsafe { rust_allocator::install() });
+    common::lock_current_context()
+}
+
+#[test]
+fn test_context_stats() {
+    let _lock = lock_current_context();
+    assert!(rust_allocator::is_installed());
+    let total_before = total_stats();
+
+    unsafe {
+        let ctx = igCreateContext(core::ptr::null_mut());
+        igSetCurrentContext(ctx);
+        let created = context_stats(ctx).expect("The context allocates when initialized");
+        assert!(created.live_bytes > 0 && created.alloc_count > 0);
+        assert!(total_stats().live_bytes > total_before.live_bytes + created.live_bytes, "The context itself too");
+
+        let io = igGetIO();
+        (*io).DisplaySize = ImVec2 { x: 320.0, y: 240.0 };
+        (*io).DeltaTime = 1.0 / 60.0;
+        (*io).IniFilename = core::ptr::null();
+        (*io).BackendFlags |= BackendFlags::RendererHasTextures;
+        igNewFrame();
+        igBegin(c"Window".as_ptr(), core::ptr::null_mut(), 0);
+        igText(c"Hello".as_ptr());
+        igEnd();
+        igRender();
+        let rendered = context_stats(ctx).unwrap();
+        assert!(rendered.alloc_count > created.alloc_count);
+        assert!(rendered.peak_bytes >= rendered.live_bytes);
+
+        // Through the global allocator, with the header of the block
+        let allocated_before = thread_allocated();
+        let block = igMemAlloc(1000);
+        assert_eq!(thread_allocated() - allocated_before, 1000 + 16);
+        assert_eq!(block as usize % 16, 0, "Aligned like malloc");
+        let stats = context_stats(ctx).unwrap();
+        assert_eq!(stats.live_bytes, rendered.live_bytes + 1000);
+        assert_eq!(stats.alloc_count, rendered.alloc_count + 1);
+        igMemFree(block);
+        assert_eq!(context_stats(ctx).unwrap().live_bytes, rendered.live_bytes);
+
+        igDestroyContext(ctx);
+        assert_eq!(context_stats(ctx).unwrap().live_bytes, 0, "Everything the context allocated is freed");
+        assert_eq!(total_stats().live_bytes, total_before.live_bytes);
+        rust_allocator::forget_context(ctx);
+        assert_eq!(context_stats(ctx), None);
+    }
+}
+
+#[test]
+fn test_stats_per_context() {
+    let _lock = lock_current_context();
+
+    unsafe {
+        let a = igCreateContext(core::ptr::null_mut());
+        let b = igCreateContext(core::ptr::null_mut());
+        igSetCurrentContext(a);
+        let (a_before, b_before) = (context_stats(a).unwrap(), context_stats(b).unwrap());
+        let block = igMemAlloc(64);
+        assert_eq!(context_stats(a).unwrap().live_bytes, a_before.live_bytes + 64);
+        assert_eq!(context_stats(b), Some(b_before));
+
+        // Freed with another current context, counted by the one it was allocated with
+        igSetCurrentContext(b);
+        igMemFree(block);
+        assert_eq!(context_stats(a).unwrap().live_bytes, a_before.live_bytes);
+        assert_eq!(context_stats(b), Some(b_before));
+
+        // Without a current context, only in the totals
+        igSetCurrentContext(core::ptr::null_mut());
+        let total_before = total_stats();
+        let block = igMemAlloc(32);
+        assert_eq!(total_stats().live_bytes, total_before.live_bytes + 32);
+        assert_eq!(total_stats().alloc_count, total_before.alloc_count + 1);
+        igMemFree(block);
+
+        for ctx in [a, b] {
+            igDestroyContext(ctx);
+            rust_allocator::forget_context(ctx);
+        }
+    }
+}
+
+#[cfg(feature = "safe")]
+#[test]
+fn test_safe_context_forgets_stats() {
+    let _lock = lock_current_context();
+
+    let ctx = imgui_sdl3_sys::safe::Context::new();
+    let raw = ctx.as_raw();
+    assert!(context_stats(raw).is_some());
+    drop(ctx);
+    assert_eq!(context_stats(raw), None, "A new context at the same address starts from zero");
+}
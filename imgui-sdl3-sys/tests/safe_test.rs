@@ -0,0 +1,436 @@
+//! Tests for the safe wrappers
+
+#![cfg(feature = "safe")]
+
+use imgui_sdl3_sys::imgui_sys::*;
+use imgui_sdl3_sys::imgui_sys::enums;
+use imgui_sdl3_sys::safe::{Context, DrawCmd, Ui};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+mod common;
+use common::{headless_context, lock_current_context};
+
+#[test]
+fn test_context_drop_clears_current() {
+    let _lock = lock_current_context();
+    let ctx = Context::new();
+    let raw = ctx.as_raw();
+    assert!(!raw.is_null());
+    assert_eq!(unsafe { igGetCurrentContext() }, raw);
+
+    drop(ctx);
+    assert!(unsafe { igGetCurrentContext() }.is_null(), "Dropping the current context should clear it");
+}
+
+#[test]
+fn test_make_current_restores_previous() {
+    let _lock = lock_current_context();
+    let ctx1 = Context::new();
+    let ctx2 = Context::new();
+    assert!(ctx1.is_current(), "The first context should stay current");
+
+    {
+        let _guard = ctx2.make_current();
+        assert!(ctx2.is_current());
+        {
+            let _guard = ctx1.make_current();
+            assert!(ctx1.is_current());
+        }
+        assert!(ctx2.is_current());
+    }
+    assert!(ctx1.is_current());
+
+    // Dropping a context that isn't current keeps the current one
+    drop(ctx2);
+    assert!(ctx1.is_current());
+}
+
+#[test]
+fn test_guard_doesnt_restore_destroyed_context() {
+    let _lock = lock_current_context();
+    let ctx1 = Context::new();
+    let ctx2 = Context::new();
+    assert!(ctx1.is_current());
+
+    let guard = ctx2.make_current();
+    drop(ctx1);
+    drop(guard);
+    assert!(unsafe { igGetCurrentContext() }.is_null(), "The destroyed context isn't restored");
+    // Allocating without a current context
+    unsafe { igMemFree(igMemAlloc(16)) };
+
+    // Other contexts are restored, even when created with the raw API
+    let raw = unsafe { igCreateContext(core::ptr::null_mut()) };
+    unsafe { igSetCurrentContext(raw) };
+    drop(ctx2.make_current());
+    assert_eq!(unsafe { igGetCurrentContext() }, raw);
+    unsafe { igDestroyContext(raw) };
+}
+
+#[test]
+fn test_shared_font_atlas() {
+    let _lock = lock_current_context();
+    let ctx1 = Context::new();
+    let atlas = ctx1.font_atlas();
+    assert!(!atlas.is_null());
+
+    let ctx2 = unsafe { Context::with_shared_font_atlas(atlas) };
+    assert_eq!(ctx2.font_atlas(), atlas, "Both contexts should use the same font atlas");
+
+    // The atlas is reference counted, the owner context must be dropped last
+    drop(ctx2);
+    assert_eq!(ctx1.font_atlas(), atlas);
+}
+
+#[test]
+fn test_frame_tokens_are_balanced() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(800.0, 600.0);
+
+    // Unbalanced Begin/End would assert in the second frame
+    let mut cmd_lists_count = 0;
+    for _ in 0..2 {
+        let ui = platform.new_frame(&mut ctx);
+        if let Some(_window) = ui.begin(c"Tokens") {
+            if let Some(_child) = ui.begin_child(c"Child", ImVec2 { x: 100.0, y: 100.0 }, 0, 0) {
+                let group = ui.begin_group();
+                group.end();
+            }
+            if let Some(_tab_bar) = ui.begin_tab_bar(c"Tabs", 0) {
+                if let Some(_tab) = ui.begin_tab_item(c"Tab", None, 0) {
+                    if let Some(_node) = ui.tree_node_ex(c"Node", ImGuiTreeNodeFlags__ImGuiTreeNodeFlags_DefaultOpen as i32) {
+                        assert!(ui.begin_menu(c"Closed menu").is_none(), "A menu is closed until clicked");
+                    }
+                }
+            }
+        }
+        cmd_lists_count = ui.render().CmdListsCount;
+    }
+    // Windows are hidden on their first frame
+    assert!(cmd_lists_count > 0, "The window should have been rendered");
+}
+
+#[test]
+fn test_frame_dropped_without_render() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(800.0, 600.0);
+
+    // Dropping the frame ends it, so a new one can be started
+    drop(platform.new_frame(&mut ctx));
+    let ui = platform.new_frame(&mut ctx);
+    assert!(ui.context().is_current());
+    ui.render();
+}
+
+#[test]
+fn test_tokens_end_on_their_context() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(800.0, 600.0);
+    let other_ctx = Context::new();
+
+    let ui = platform.new_frame(&mut ctx);
+    let group = ui.begin_group();
+    {
+        // igEndGroup would assert on the other context, which has no frame
+        let _current = other_ctx.make_current();
+        group.end();
+        assert!(other_ctx.is_current());
+    }
+    assert!(ui.context().is_current());
+
+    let _current = other_ctx.make_current();
+    drop(ui);
+    assert!(other_ctx.is_current());
+}
+
+#[test]
+#[should_panic(expected = "the context of the frame isn't the current one")]
+fn test_frame_of_other_context() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(800.0, 600.0);
+    let other_ctx = Context::new();
+
+    let ui = platform.new_frame(&mut ctx);
+    let _current = other_ctx.make_current();
+    ui.button(c"Button");
+}
+
+#[test]
+fn test_labels() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(800.0, 600.0);
+
+    let ui = platform.new_frame(&mut ctx);
+    let long_label = "A label longer than the initial scratch buffer ".repeat(10);
+    for name in ["Labels", long_label.as_str(), "Labels"] {
+        if let Some(_window) = ui.begin(name) {
+            // Not NUL-terminated, only the first word is drawn
+            ui.text(&"Hello world"[..5]);
+        }
+    }
+
+    // &str, &CStr and C-string literals are the same text
+    let label = String::from("Label");
+    let size = ui.calc_text_size(c"Label");
+    assert!(size.x > 0.0);
+    assert_eq!(ui.calc_text_size("Label").x, size.x);
+    assert_eq!(ui.calc_text_size(label.as_str()).x, size.x);
+    assert_eq!(ui.calc_text_size(&"Label and more"[..5]).x, size.x);
+    assert_eq!(ui.calc_text_size_ex("Label##id", true, -1.0).x, size.x);
+
+    ui.render();
+}
+
+#[test]
+fn test_text_is_never_a_format_string() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(800.0, 600.0);
+
+    let ui = platform.new_frame(&mut ctx);
+    if let Some(_window) = ui.begin("Text") {
+        // Used as format strings, these would read arguments that were never passed
+        let user_text = "100% done %s %n";
+        ui.text(user_text);
+        ui.text(format_args!("{} and {}", user_text, 42));
+        ui.text_colored(ImVec4 { x: 1.0, y: 0.0, z: 0.0, w: 1.0 }, user_text);
+        ui.text_wrapped(user_text);
+        ui.label_text(format_args!("Label {}", 1), user_text);
+        ui.bullet_text(c"%s %d");
+        ui.set_tooltip(format_args!("{user_text}"));
+    }
+
+    // Formatted text is the same as the already formatted one
+    let size = ui.calc_text_size("Label 1");
+    assert_eq!(ui.calc_text_size(format_args!("Label {}", 1)).x, size.x);
+    assert_eq!(ui.calc_text_size(format_args!("Label 1")).x, size.x);
+
+    ui.render();
+}
+
+// Formatted into the scratch buffer, it would clear it while it is written
+struct CallsUi<'a, 'ctx>(&'a Ui<'ctx>);
+
+impl std::fmt::Display for CallsUi<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.text("Nested");
+        f.write_str("Outer")
+    }
+}
+
+#[test]
+#[should_panic(expected = "the Ui functions can't be called while formatting their text")]
+fn test_ui_called_while_formatting() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(800.0, 600.0);
+
+    let ui = platform.new_frame(&mut ctx);
+    ui.text(format_args!("Before {}", CallsUi(&ui)));
+}
+
+#[test]
+fn test_generated_functions() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(800.0, 600.0);
+
+    let mut checked = true;
+    let mut value = 0.5;
+    let mut color = [0.25, 0.5, 0.75];
+    let ui = platform.new_frame(&mut ctx);
+    if let Some(_window) = ui.begin("Generated") {
+        assert!(!ui.button("Button"), "Nothing clicked");
+        ui.same_line();
+        assert!(!ui.checkbox("Checkbox", &mut checked));
+        assert!(!ui.slider_float("Slider", &mut value, 0.0, 1.0));
+        assert!(!ui.color_edit3("Color", &mut color, Some(enums::ColorEditFlags::NoInputs)));
+        assert!(!ui.is_item_hovered(None));
+        ui.separator();
+        ui.progress_bar(value, None, Some("Half"));
+        let mut item = 1;
+        assert!(!ui.combo("Combo", &mut item, &["One", "Two", "Three"]));
+        assert!(!ui.combo_ex("Empty combo", &mut item, &[c"Label"; 0], Some(4)));
+        assert_eq!(item, 1);
+
+        // The ID of a slice, not of the rest of the string
+        let id = ui.get_id("Button");
+        assert_eq!(ui.get_id_str(&"Button##suffix"[.."Button".len()]), id);
+        assert_eq!(ui.get_id_str(c"Button"), id);
+    }
+    assert!(checked);
+    assert_eq!(value, 0.5);
+    assert_eq!(color, [0.25, 0.5, 0.75]);
+
+    let (mut h, mut s, mut v) = (0.0, 0.0, 0.0);
+    ui.color_convert_rgb_to_hsv(1.0, 0.0, 0.0, &mut h, &mut s, &mut v);
+    assert_eq!((h, s, v), (0.0, 1.0, 1.0));
+    assert_eq!(ui.get_color_u32_im_u32(0xFF00FF00), 0xFF00FF00);
+
+    ui.render();
+}
+
+static CALLBACK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "C" fn count_callback(_parent_list: *const ImDrawList, cmd: *const ImDrawCmd) {
+    assert_eq!(unsafe { (*cmd).UserCallbackData } as usize, 42);
+    CALLBACK_CALLS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[test]
+fn test_draw_data_views() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(800.0, 600.0);
+
+    for frame in 0..2 {
+        let ui = platform.new_frame(&mut ctx);
+        if let Some(_window) = ui.begin("Draw data") {
+            ui.button("Button");
+            unsafe {
+                let draw_list = igGetWindowDrawList();
+                ImDrawList_AddCallback(draw_list, Some(count_callback), 42 as *mut _);
+                // ImDrawCallback_ResetRenderState
+                ImDrawList_AddCallback(draw_list, Some(core::mem::transmute::<isize, unsafe extern "C" fn(*const ImDrawList, *const ImDrawCmd)>(-8)), core::ptr::null_mut());
+            }
+        }
+        let draw_data = ui.render();
+        if frame == 0 {
+            continue;
+        }
+
+        let mut vertices = 0;
+        let mut indices = 0;
+        let (mut elements, mut callbacks, mut resets) = (0, 0, 0);
+        assert_eq!(draw_data.lists().len(), draw_data.CmdListsCount as usize);
+        for list in draw_data.lists() {
+            vertices += list.vertices().len();
+            indices += list.indices().len();
+            for cmd in list.commands() {
+                match cmd {
+                    DrawCmd::Elements { count, params } => {
+                        assert!(params.idx_offset + count <= list.indices().len());
+                        for &idx in &list.indices()[params.idx_offset..params.idx_offset + count] {
+                            assert!(params.vtx_offset + (idx as usize) < list.vertices().len());
+                        }
+                        elements += 1;
+                    }
+                    DrawCmd::Callback(callback) => {
+                        assert_eq!(callback.user_data() as usize, 42);
+                        unsafe { callback.call() };
+                        callbacks += 1;
+                    }
+                    DrawCmd::ResetRenderState => resets += 1,
+                }
+            }
+        }
+        assert_eq!(vertices, draw_data.TotalVtxCount as usize);
+        assert_eq!(indices, draw_data.TotalIdxCount as usize);
+        assert!(elements > 0, "The window should have been drawn");
+        assert_eq!((callbacks, resets), (1, 1));
+        assert_eq!(CALLBACK_CALLS.load(Ordering::Relaxed), 1);
+    }
+}
+
+#[test]
+fn test_headless_platform_input() {
+    use imgui_sdl3_sys::safe::HeadlessPlatform;
+    use enums::{BackendFlags, Key, MouseButton};
+
+    let _lock = lock_current_context();
+    let (mut ctx, mut platform) = headless_context(320.0, 240.0);
+    platform.set_delta_time(0.5);
+
+    // Returns whether the button was clicked, its center, whether the keys are down and the text input
+    let mut text = [0u8; 32];
+    let mut frame = |ctx: &mut Context, platform: &HeadlessPlatform, keys: &[Key]| {
+        let ui = platform.new_frame(ctx);
+        let mut clicked = false;
+        let mut center = ImVec2 { x: 0.0, y: 0.0 };
+        ui.set_next_window_pos(ImVec2 { x: 10.0, y: 10.0 });
+        if let Some(_window) = ui.begin("Headless") {
+            clicked = ui.button("Button");
+            let (min, max) = (ui.get_item_rect_min(), ui.get_item_rect_max());
+            center = ImVec2 { x: (min.x + max.x) / 2.0, y: (min.y + max.y) / 2.0 };
+            unsafe { igInputText(c"Text".as_ptr(), text.as_mut_ptr().cast(), text.len(), 0) };
+        }
+        let keys_down: Vec<bool> = keys.iter().map(|&key| ui.is_key_down(key)).collect();
+        ui.render();
+        let len = text.iter().position(|&b| b == 0).unwrap();
+        (clicked, center, keys_down, String::from_utf8(text[..len].to_vec()).unwrap())
+    };
+
+    frame(&mut ctx, &platform, &[]);
+    let (_, center, _, _) = frame(&mut ctx, &platform, &[]);
+    let io = unsafe { &*igGetIO() };
+    assert_eq!((io.DisplaySize.x, io.DisplaySize.y), (320.0, 240.0));
+    assert_eq!(io.DeltaTime, 0.5);
+
+    // Click: the button is pressed, then clicked when released
+    platform.add_mouse_pos(&ctx, center.x, center.y);
+    platform.add_mouse_button(&ctx, MouseButton::Left, true);
+    assert!(!frame(&mut ctx, &platform, &[]).0);
+    platform.add_mouse_button(&ctx, MouseButton::Left, false);
+    assert!(frame(&mut ctx, &platform, &[]).0, "The button should be clicked");
+
+    // A key pressed and released before a frame is still seen down for a frame
+    platform.add_key(&ctx, Key::A, true);
+    platform.add_key(&ctx, Key::A, false);
+    assert_eq!(frame(&mut ctx, &platform, &[Key::A]).2, [true]);
+    assert_eq!(frame(&mut ctx, &platform, &[Key::A]).2, [false]);
+
+    // Text typed in the focused input
+    let text_pos = ImVec2 { x: center.x, y: center.y + 30.0 };
+    platform.add_mouse_pos(&ctx, text_pos.x, text_pos.y);
+    platform.add_mouse_button(&ctx, MouseButton::Left, true);
+    platform.add_mouse_button(&ctx, MouseButton::Left, false);
+    frame(&mut ctx, &platform, &[]);
+    frame(&mut ctx, &platform, &[]);
+    platform.add_text(&ctx, "héllo");
+    assert_eq!(frame(&mut ctx, &platform, &[]).3, "héllo");
+
+    // Gamepad buttons are keys, with the HasGamepad flag while connected
+    platform.set_gamepad_connected(true);
+    platform.add_key(&ctx, Key::GamepadFaceDown, true);
+    assert_eq!(frame(&mut ctx, &platform, &[Key::GamepadFaceDown]).2, [true]);
+    assert!(BackendFlags(unsafe { (*igGetIO()).BackendFlags }).contains(BackendFlags::HasGamepad));
+    platform.set_gamepad_connected(false);
+    frame(&mut ctx, &platform, &[]);
+    assert!(!BackendFlags(unsafe { (*igGetIO()).BackendFlags }).contains(BackendFlags::HasGamepad));
+}
+
+#[test]
+fn test_headless_platform_drop() {
+    let _lock = lock_current_context();
+    let (mut ctx, mut platform) = headless_context(320.0, 240.0);
+    platform.set_gamepad_connected(true);
+    platform.new_frame(&mut ctx).render();
+    let io = unsafe { igGetIO() };
+    assert!(!unsafe { (*io).BackendPlatformName }.is_null());
+
+    // Like the Shutdown of the other backends, so another one can be initialized
+    drop(platform);
+    assert!(unsafe { (*io).BackendPlatformName }.is_null());
+    assert!(!enums::BackendFlags(unsafe { (*io).BackendFlags }).contains(enums::BackendFlags::HasGamepad));
+
+    // Dropped after its context
+    let (ctx, platform) = headless_context(320.0, 240.0);
+    drop(ctx);
+    drop(platform);
+}
+
+#[test]
+#[should_panic(expected = "the context isn't the one of the headless platform backend")]
+fn test_headless_platform_destroyed_context() {
+    let _lock = lock_current_context();
+    let (ctx, platform) = headless_context(320.0, 240.0);
+    drop(ctx);
+    // Possibly at the same address
+    let (mut new_ctx, _new_platform) = headless_context(320.0, 240.0);
+    platform.new_frame(&mut new_ctx);
+}
+
+#[test]
+#[should_panic(expected = "the context isn't the one of the headless platform backend")]
+fn test_headless_platform_other_context() {
+    let _lock = lock_current_context();
+    let (_ctx, platform) = headless_context(320.0, 240.0);
+    let (mut other_ctx, _other_platform) = headless_context(320.0, 240.0);
+    platform.new_frame(&mut other_ctx);
+}
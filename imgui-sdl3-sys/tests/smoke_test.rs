@@ -7,6 +7,8 @@
 
 use imgui_sdl3_sys::imgui_sys::*;
 
+mod common;
+
 #[test]
 fn test_version_constants() {
     // Test that constants are accessible
@@ -101,6 +103,18 @@ fn test_frame_functions() {
     }
 }
 
+#[test]
+#[cfg(feature = "safe")]
+fn test_headless_frame_functions() {
+    let _lock = common::lock_current_context();
+    let (mut ctx, platform) = common::headless_context(800.0, 600.0);
+
+    let ui = platform.new_frame(&mut ctx);
+    let draw_data = ui.render();
+    assert!(draw_data.Valid, "Draw data should be valid after render");
+    assert_eq!((draw_data.DisplaySize.x, draw_data.DisplaySize.y), (800.0, 600.0));
+}
+
 #[test]
 fn test_window_functions_linkage() {
     unsafe {
@@ -207,6 +221,177 @@ fn test_draw_list_functions() {
     }
 }
 
+#[test]
+fn test_sdlgpu3_sampler_callbacks() {
+    use imgui_sdl3_sys::imgui_sys::sdl3::*;
+
+    let _lock = common::lock_current_context();
+
+    unsafe {
+        let ctx = igCreateContext(core::ptr::null_mut());
+        igSetCurrentContext(ctx);
+
+        let io = igGetIO();
+        (*io).DisplaySize.x = 800.0;
+        (*io).DisplaySize.y = 600.0;
+        (*io).DeltaTime = 1.0 / 60.0;
+        (*io).BackendFlags |= ImGuiBackendFlags__ImGuiBackendFlags_RendererHasTextures as i32;
+
+        // No SDL_GPU rendering in progress
+        assert!(cImGui_ImplSDLGPU3_GetRenderState().is_null());
+
+        igNewFrame();
+
+        if igBegin(c"Sampler Test".as_ptr(), core::ptr::null_mut(), 0) {
+            let draw_list = igGetWindowDrawList();
+
+            // The sampler is only used when rendering, so any pointer will do
+            let sampler = core::ptr::NonNull::<SDL_GPUSampler>::dangling().as_ptr();
+            sdlgpu3_add_set_sampler_callback(draw_list, sampler);
+            sdlgpu3_add_reset_sampler_callback(draw_list);
+
+            let cmds = core::slice::from_raw_parts((*draw_list).CmdBuffer.Data, (*draw_list).CmdBuffer.Size as usize);
+            let callbacks: Vec<_> = cmds.iter().filter(|cmd| cmd.UserCallback.is_some()).collect();
+            assert_eq!(callbacks.len(), 2, "Both sampler callbacks should be recorded");
+            assert_eq!(callbacks[0].UserCallbackData, sampler.cast());
+            assert!(callbacks[1].UserCallbackData.is_null());
+
+            igEnd();
+        }
+
+        igEndFrame();
+        igRender();
+
+        igDestroyContext(ctx);
+    }
+}
+
+#[test]
+fn test_sdl3_time_control() {
+    use imgui_sdl3_sys::imgui_sys::enums::BackendFlags;
+    use imgui_sdl3_sys::imgui_sys::sdl3::*;
+    use imgui_sdl3_sys::sdl3_sys::everything::*;
+
+    unsafe extern "C" fn clock(user_data: *mut core::ffi::c_void) -> f64 {
+        unsafe { *user_data.cast::<f64>() }
+    }
+
+    // Delta time of the next frame of the SDL3 backend
+    unsafe fn frame_delta_time() -> f32 {
+        unsafe {
+            cImGui_ImplSDL3_NewFrame();
+            igNewFrame();
+            let delta_time = (*igGetIO()).DeltaTime;
+            igEndFrame();
+            delta_time
+        }
+    }
+
+    let _lock = common::lock_current_context();
+    let time = core::cell::Cell::new(10.0f64);
+    unsafe {
+        // The dummy video driver doesn't need a display
+        SDL_SetHint(SDL_HINT_VIDEO_DRIVER, c"dummy".as_ptr());
+        assert!(SDL_Init(SDL_INIT_VIDEO), "SDL_Init failed");
+        let window = SDL_CreateWindow(c"Time control".as_ptr(), 320, 240, SDL_WINDOW_HIDDEN);
+        assert!(!window.is_null(), "SDL_CreateWindow failed");
+
+        let ctx = igCreateContext(core::ptr::null_mut());
+        igSetCurrentContext(ctx);
+        let io = igGetIO();
+        (*io).IniFilename = core::ptr::null();
+        // No renderer backend, the font atlas doesn't need to be built
+        (*io).BackendFlags |= BackendFlags::RendererHasTextures;
+        assert!(cImGui_ImplSDL3_InitForOther(window));
+
+        cImGui_ImplSDL3_SetDeltaTime(0.25);
+        assert_eq!(frame_delta_time(), 0.25);
+        assert_eq!(frame_delta_time(), 0.25);
+
+        // 1/60 on the first frame of the clock, then the difference with the previous one
+        cImGui_ImplSDL3_SetDeltaTime(0.0);
+        cImGui_ImplSDL3_SetClock(Some(clock), time.as_ptr().cast());
+        assert_eq!(frame_delta_time(), 1.0 / 60.0);
+        time.set(10.5);
+        assert_eq!(frame_delta_time(), 0.5);
+        // The fixed delta time goes first
+        cImGui_ImplSDL3_SetDeltaTime(0.125);
+        assert_eq!(frame_delta_time(), 0.125);
+
+        // Shutdown resets the time state: the next backend measures the time again
+        cImGui_ImplSDL3_Shutdown();
+        assert!(cImGui_ImplSDL3_InitForOther(window));
+        frame_delta_time();
+        let measured = frame_delta_time();
+        assert!(measured != 0.125 && measured != 0.5, "The time settings should be reset, got {measured}");
+
+        cImGui_ImplSDL3_Shutdown();
+        igDestroyContext(ctx);
+        SDL_DestroyWindow(window);
+        SDL_Quit();
+    }
+}
+
+#[test]
+fn test_sdl3_time_per_context() {
+    use imgui_sdl3_sys::imgui_sys::enums::BackendFlags;
+    use imgui_sdl3_sys::imgui_sys::sdl3::*;
+    use imgui_sdl3_sys::sdl3_sys::everything::*;
+
+    // Context with the SDL3 backend on its own window
+    unsafe fn backend_context(title: &core::ffi::CStr) -> (*mut ImGuiContext, *mut SDL_Window) {
+        unsafe {
+            let window = SDL_CreateWindow(title.as_ptr(), 320, 240, SDL_WINDOW_HIDDEN);
+            assert!(!window.is_null(), "SDL_CreateWindow failed");
+            let ctx = igCreateContext(core::ptr::null_mut());
+            igSetCurrentContext(ctx);
+            let io = igGetIO();
+            (*io).IniFilename = core::ptr::null();
+            (*io).BackendFlags |= BackendFlags::RendererHasTextures;
+            assert!(cImGui_ImplSDL3_InitForOther(window));
+            (ctx, window)
+        }
+    }
+
+    unsafe fn frame_delta_time(ctx: *mut ImGuiContext) -> f32 {
+        unsafe {
+            igSetCurrentContext(ctx);
+            cImGui_ImplSDL3_NewFrame();
+            igNewFrame();
+            let delta_time = (*igGetIO()).DeltaTime;
+            igEndFrame();
+            delta_time
+        }
+    }
+
+    let _lock = common::lock_current_context();
+    unsafe {
+        SDL_SetHint(SDL_HINT_VIDEO_DRIVER, c"dummy".as_ptr());
+        assert!(SDL_Init(SDL_INIT_VIDEO), "SDL_Init failed");
+        let (first_ctx, first_window) = backend_context(c"First");
+        cImGui_ImplSDL3_SetDeltaTime(0.25);
+        let (second_ctx, second_window) = backend_context(c"Second");
+        assert_eq!(cImGui_ImplSDL3_GetDeltaTime(), 0.0, "A new context measures the time");
+        cImGui_ImplSDL3_SetDeltaTime(0.5);
+
+        assert_eq!(frame_delta_time(first_ctx), 0.25);
+        assert_eq!(frame_delta_time(second_ctx), 0.5);
+
+        // Shutting down the backend of a context keeps the settings of the other
+        cImGui_ImplSDL3_Shutdown();
+        igDestroyContext(second_ctx);
+        assert_eq!(frame_delta_time(first_ctx), 0.25);
+        assert_eq!(cImGui_ImplSDL3_GetDeltaTime(), 0.25);
+
+        cImGui_ImplSDL3_Shutdown();
+        assert_eq!(cImGui_ImplSDL3_GetDeltaTime(), 0.0);
+        igDestroyContext(first_ctx);
+        SDL_DestroyWindow(second_window);
+        SDL_DestroyWindow(first_window);
+        SDL_Quit();
+    }
+}
+
 #[test]
 fn test_font_atlas_functions() {
     unsafe {
@@ -230,6 +415,142 @@ fn test_font_atlas_functions() {
     }
 }
 
+#[test]
+fn test_typed_flags() {
+    use imgui_sdl3_sys::imgui_sys::enums::*;
+
+    // Same values as the raw constants
+    assert_eq!(ConfigFlags::NavEnableKeyboard.bits(), ImGuiConfigFlags__ImGuiConfigFlags_NavEnableKeyboard as i32);
+    assert_eq!(WindowFlags::NoDecoration.bits(), ImGuiWindowFlags__ImGuiWindowFlags_NoDecoration as i32);
+
+    let flags = WindowFlags::NoTitleBar | WindowFlags::NoResize;
+    assert!(flags.contains(WindowFlags::NoTitleBar));
+    assert!(!flags.contains(WindowFlags::NoDecoration));
+    assert!(flags.intersects(WindowFlags::NoDecoration));
+    assert_eq!(flags & !WindowFlags::NoResize, WindowFlags::NoTitleBar);
+    assert!(WindowFlags::default().is_empty());
+
+    unsafe {
+        let ctx = igCreateContext(core::ptr::null_mut());
+        igSetCurrentContext(ctx);
+
+        // Raw fields are updated in place, and read back with the raw value
+        let io = igGetIO();
+        (*io).ConfigFlags |= ConfigFlags::NavEnableKeyboard | ConfigFlags::NavEnableGamepad;
+        (*io).ConfigFlags &= !ConfigFlags::NavEnableGamepad;
+        let config_flags = ConfigFlags((*io).ConfigFlags);
+        assert!(config_flags.contains(ConfigFlags::NavEnableKeyboard));
+        assert!(!config_flags.contains(ConfigFlags::NavEnableGamepad));
+
+        igDestroyContext(ctx);
+    }
+}
+
+#[test]
+fn test_typed_enums() {
+    use imgui_sdl3_sys::imgui_sys::enums::*;
+
+    assert_eq!(Key::Tab.raw(), ImGuiKey_Tab as i32);
+    assert_eq!(Key::from_raw(ImGuiKey_Escape as i32), Some(Key::Escape));
+    assert_eq!(Key::try_from(1), Err(1), "Keys 1 to 511 are unused");
+    assert_eq!(Key::NamedKey_BEGIN, Key::Tab.raw());
+    assert_eq!(Dir::from_raw(-1), Some(Dir::None));
+
+    // Renamed values are aliases of their variant
+    assert_eq!(Col::TabActive, Col::TabSelected);
+    assert_eq!(Col::COUNT, ImGuiCol__ImGuiCol_COUNT as i32);
+    assert_eq!(StyleVar::Alpha.raw(), ImGuiStyleVar__ImGuiStyleVar_Alpha as i32);
+
+    unsafe {
+        let ctx = igCreateContext(core::ptr::null_mut());
+        igSetCurrentContext(ctx);
+
+        let style = igGetStyle();
+        igStyleColorsDark(style);
+        let text = (*style).Colors[Col::Text as usize];
+        let color = *igGetStyleColorVec4(Col::Text.raw());
+        assert_eq!((color.x, color.y, color.z, color.w), (text.x, text.y, text.z, text.w));
+
+        igDestroyContext(ctx);
+    }
+}
+
+#[test]
+fn test_im_vector() {
+    let _lock = common::lock_current_context();
+
+    // Never allocated vectors have a null data pointer
+    let mut empty = ImVector_float { Size: 0, Capacity: 0, Data: core::ptr::null_mut() };
+    assert!(empty.is_empty());
+    assert!(unsafe { empty.as_slice() }.is_empty());
+    assert!(unsafe { empty.as_mut_slice() }.is_empty());
+    assert_eq!(unsafe { empty.iter() }.count(), 0);
+
+    unsafe {
+        let ctx = igCreateContext(core::ptr::null_mut());
+        igSetCurrentContext(ctx);
+
+        // Pairs sorted by key
+        let mut storage: ImGuiStorage = core::mem::zeroed();
+        for key in [3, 1, 2] {
+            ImGuiStorage_SetInt(&mut storage, key, key as i32 * 10);
+        }
+        let keys: Vec<_> = storage.Data.iter().map(|pair| pair.key).collect();
+        assert_eq!(keys, [1, 2, 3]);
+        assert_eq!(storage.Data.len(), 3);
+
+        for pair in storage.Data.iter_mut() {
+            pair.__bindgen_anon_1.val_i += 1;
+        }
+        assert_eq!(ImGuiStorage_GetInt(&storage, 2, 0), 21);
+        ImGuiStorage_Clear(&mut storage);
+        assert!(storage.Data.is_empty());
+
+        igDestroyContext(ctx);
+    }
+}
+
+#[test]
+#[cfg(feature = "software-renderer")]
+fn test_rendered_window_pixels() {
+    use imgui_sdl3_sys::safe::DrawData;
+    use imgui_sdl3_sys::software_renderer::SoftwareRenderer;
+
+    let _lock = common::lock_current_context();
+
+    let ctx = imgui_sdl3_sys::safe::Context::new();
+    let mut renderer = SoftwareRenderer::new(&ctx);
+    unsafe {
+        igSetCurrentContext(ctx.as_raw());
+        let io = igGetIO();
+        (*io).DisplaySize.x = 200.0;
+        (*io).DisplaySize.y = 150.0;
+        (*io).DeltaTime = 1.0 / 60.0;
+        (*io).IniFilename = core::ptr::null();
+
+        // Windows are hidden on their first frame
+        let mut image = None;
+        for _ in 0..2 {
+            igNewFrame();
+            igSetNextWindowPos(ImVec2 { x: 20.0, y: 30.0 }, 0);
+            igSetNextWindowSize(ImVec2 { x: 100.0, y: 80.0 }, 0);
+            if igBegin(c"Pixels".as_ptr(), core::ptr::null_mut(), 0) {
+                igEnd();
+            }
+            igRender();
+            image = Some(renderer.render(&DrawData::from_raw(igGetDrawData())));
+        }
+        let image = image.unwrap();
+
+        // The window background over the transparent black framebuffer
+        let bg = (*igGetStyle()).Colors[ImGuiCol__ImGuiCol_WindowBg as usize];
+        let expected = [bg.x * bg.w, bg.y * bg.w, bg.z * bg.w, bg.w].map(|c| (c * 255.0 + 0.5) as u8);
+        assert_eq!(image.pixel(70, 90), expected);
+        assert_eq!(image.pixel(10, 90), [0; 4], "Outside of the window");
+        assert_eq!(image.pixel(150, 90), [0; 4], "Outside of the window");
+    }
+}
+
 #[test]
 fn test_types_are_correct_size() {
     // Verify some key types have reasonable sizes
@@ -247,3 +568,56 @@ fn test_opaque_types_exist() {
     let _loader_size = core::mem::size_of::<*mut ImFontLoader>();
     let _shared_data_size = core::mem::size_of::<*mut ImDrawListSharedData>();
 }
+
+#[test]
+#[cfg(feature = "docking")]
+fn test_docking_has_dock() {
+    // Test that the docking branch feature macros are exposed
+    const { assert!(IMGUI_HAS_DOCK) };
+    const { assert!(IMGUI_HAS_VIEWPORT) };
+}
+
+#[test]
+#[cfg(feature = "docking")]
+fn test_docking_dockspace_round_trip() {
+    let _lock = common::lock_current_context();
+
+    unsafe {
+        let ctx = igCreateContext(core::ptr::null_mut());
+        igSetCurrentContext(ctx);
+
+        let io = igGetIO();
+        (*io).ConfigFlags |= ImGuiConfigFlags__ImGuiConfigFlags_DockingEnable as i32;
+        (*io).IniFilename = core::ptr::null();
+        (*io).DisplaySize.x = 800.0;
+        (*io).DisplaySize.y = 600.0;
+        (*io).DeltaTime = 1.0 / 60.0;
+        (*io).BackendFlags |= ImGuiBackendFlags__ImGuiBackendFlags_RendererHasTextures as i32;
+
+        // Docking settles over a couple of frames, check the state of the last one
+        let mut dockspace_id = 0;
+        let mut is_docked = false;
+        let mut dock_id = 0;
+        for _ in 0..3 {
+            igNewFrame();
+
+            dockspace_id = igDockSpaceOverViewport();
+            igSetNextWindowDockID(dockspace_id, ImGuiCond__ImGuiCond_Always as i32);
+            if igBegin(b"Docked Window\0".as_ptr() as *const i8, core::ptr::null_mut(), 0) {
+                igText(b"Docked\0".as_ptr() as *const i8);
+            }
+            is_docked = igIsWindowDocked();
+            dock_id = igGetWindowDockID();
+            igEnd();
+
+            igEndFrame();
+            igRender();
+        }
+
+        assert!(dockspace_id != 0, "DockSpace id should not be 0");
+        assert!(is_docked, "Window should be docked into the dockspace");
+        assert_eq!(dock_id, dockspace_id, "Window should be docked into the dockspace node");
+
+        igDestroyContext(ctx);
+    }
+}
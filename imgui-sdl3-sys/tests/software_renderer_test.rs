@@ -0,0 +1,232 @@
+//! Tests for the software renderer
+
+#![cfg(feature = "software-renderer")]
+
+use imgui_sdl3_sys::imgui_sys::enums::{Col, TextureStatus};
+use imgui_sdl3_sys::imgui_sys::*;
+use imgui_sdl3_sys::safe::Context;
+use imgui_sdl3_sys::software_renderer::{Image, SoftwareRenderer};
+
+mod common;
+use common::{headless_context, lock_current_context};
+
+fn vec2(x: f32, y: f32) -> ImVec2 {
+    ImVec2 { x, y }
+}
+
+fn count_pixels(image: &Image, color: [u8; 4]) -> usize {
+    image.pixels().chunks_exact(4).filter(|&pixel| pixel == color).count()
+}
+
+// IM_COL32, the shifts follow IMGUI_USE_BGRA_PACKED_COLOR
+const fn col32(r: u8, g: u8, b: u8, a: u8) -> u32 {
+    (r as u32) << IM_COL32_R_SHIFT | (g as u32) << IM_COL32_G_SHIFT | (b as u32) << IM_COL32_B_SHIFT | (a as u32) << IM_COL32_A_SHIFT
+}
+
+const RED: u32 = col32(255, 0, 0, 255);
+const RED_RGBA: [u8; 4] = [255, 0, 0, 255];
+
+#[test]
+fn test_rect_pixels() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(64.0, 48.0);
+    let mut renderer = SoftwareRenderer::new(&ctx);
+
+    let ui = platform.new_frame(&mut ctx);
+    unsafe {
+        let draw_list = igGetBackgroundDrawList();
+        // Two triangles sharing a diagonal, the pixels on it are drawn once
+        ImDrawList_AddRectFilled(draw_list, vec2(10.0, 10.0), vec2(20.0, 15.0), RED);
+        ImDrawList_AddRectFilled(draw_list, vec2(30.0, 10.0), vec2(40.0, 20.0), col32(0x40, 0x80, 0xFF, 0x80));
+    }
+    let image = renderer.render(&ui.render());
+
+    assert_eq!((image.width(), image.height()), (64, 48));
+    assert_eq!(count_pixels(&image, RED_RGBA), 10 * 5, "The rect covers exactly its pixels");
+    assert_eq!(image.pixel(10, 10), RED_RGBA);
+    assert_eq!(image.pixel(19, 14), RED_RGBA);
+    assert_eq!(image.pixel(9, 10), [0; 4]);
+    assert_eq!(image.pixel(20, 14), [0; 4]);
+    assert_eq!(image.pixel(19, 15), [0; 4]);
+
+    // Half transparent over transparent black: color is blended with the source alpha, alpha with One
+    let alpha = 0x80 as f32 / 255.0;
+    let blended = [0x40, 0x80, 0xFF].map(|c: u8| (c as f32 * alpha + 0.5) as u8);
+    let expected = [blended[0], blended[1], blended[2], 0x80];
+    assert_eq!(image.pixel(35, 15), expected);
+    assert_eq!(count_pixels(&image, expected), 100, "The diagonal shouldn't be blended twice");
+}
+
+#[test]
+fn test_unknown_texture_skipped() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(64.0, 48.0);
+    let mut renderer = SoftwareRenderer::new(&ctx);
+
+    let ui = platform.new_frame(&mut ctx);
+    unsafe {
+        let tex_ref = ImTextureRef { _TexData: core::ptr::null_mut(), _TexID: 1234 };
+        ImDrawList_AddImage(igGetBackgroundDrawList(), tex_ref, vec2(10.0, 10.0), vec2(20.0, 20.0));
+    }
+    let image = renderer.render(&ui.render());
+
+    assert_eq!(count_pixels(&image, [0; 4]), 64 * 48, "The image shouldn't be drawn untextured");
+}
+
+#[test]
+fn test_packed_color_order() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(64.0, 48.0);
+    let mut renderer = SoftwareRenderer::new(&ctx);
+
+    let ui = platform.new_frame(&mut ctx);
+    unsafe {
+        // Packed by Dear ImGui, in BGRA order with the use-bgra-packed-color feature
+        let orange = igColorConvertFloat4ToU32(ImVec4 { x: 1.0, y: 0.5, z: 0.0, w: 1.0 });
+        ImDrawList_AddRectFilled(igGetBackgroundDrawList(), vec2(10.0, 10.0), vec2(20.0, 20.0), orange);
+    }
+    let image = renderer.render(&ui.render());
+
+    assert_eq!(image.pixel(15, 15), [255, 128, 0, 255]);
+}
+
+#[test]
+fn test_clip_rect() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(64.0, 48.0);
+    let mut renderer = SoftwareRenderer::new(&ctx);
+
+    let ui = platform.new_frame(&mut ctx);
+    unsafe {
+        let draw_list = igGetBackgroundDrawList();
+        ImDrawList_PushClipRect(draw_list, vec2(15.0, 12.0), vec2(100.0, 100.0), true);
+        ImDrawList_AddRectFilled(draw_list, vec2(10.0, 10.0), vec2(20.0, 20.0), RED);
+        ImDrawList_PopClipRect(draw_list);
+    }
+    let image = renderer.render(&ui.render());
+
+    assert_eq!(count_pixels(&image, RED_RGBA), 5 * 8);
+    assert_eq!(image.pixel(15, 12), RED_RGBA);
+    assert_eq!(image.pixel(14, 12), [0; 4]);
+    assert_eq!(image.pixel(15, 11), [0; 4]);
+}
+
+#[test]
+fn test_user_texture() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(64.0, 48.0);
+    let mut renderer = SoftwareRenderer::new(&ctx);
+
+    let texels = [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255], [255, 255, 255, 255]];
+    let texture_id = renderer.add_texture(Image::from_rgba8(2, 2, texels.concat()));
+    assert!(renderer.texture(texture_id).is_some());
+
+    let ui = platform.new_frame(&mut ctx);
+    unsafe {
+        let texture = ImTextureRef { _TexData: core::ptr::null_mut(), _TexID: texture_id };
+        ImDrawList_AddImage(igGetBackgroundDrawList(), texture, vec2(0.0, 0.0), vec2(20.0, 20.0));
+    }
+    let image = renderer.render(&ui.render());
+
+    // The corners are clamped to the edge texels, the center is the average of the four texels
+    assert_eq!(image.pixel(0, 0), texels[0]);
+    assert_eq!(image.pixel(19, 0), texels[1]);
+    assert_eq!(image.pixel(0, 19), texels[2]);
+    assert_eq!(image.pixel(19, 19), texels[3]);
+    let center = image.pixel(9, 9);
+    assert!(center[0] > 64 && center[1] > 64 && center[2] > 64, "Bilinear filtering, got {center:?}");
+    assert_eq!(image.pixel(20, 0), [0; 4]);
+
+    assert_eq!(renderer.remove_texture(texture_id).map(|image| image.width()), Some(2));
+    assert!(renderer.texture(texture_id).is_none());
+}
+
+#[test]
+fn test_font_atlas_texture() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(320.0, 240.0);
+    let mut renderer = SoftwareRenderer::new(&ctx);
+
+    let mut images = Vec::new();
+    for _ in 0..4 {
+        let ui = platform.new_frame(&mut ctx);
+        if let Some(_window) = ui.begin("Software renderer") {
+            ui.text("Hello, world!");
+            ui.button("Button");
+        }
+        let draw_data = ui.render();
+        let mut image = Image::new(320, 240);
+        image.fill([0, 0, 0, 255]);
+        renderer.render_into(&draw_data, &mut image);
+        images.push(image);
+
+        // The font atlas texture was created
+        let textures = draw_data.textures();
+        assert!(!textures.is_empty());
+        for &texture in textures {
+            let texture = unsafe { &*texture };
+            assert_eq!(texture.Status, TextureStatus::OK.raw());
+            let created = renderer.texture(texture.TexID).expect("The renderer should have created the texture");
+            assert_eq!((created.width(), created.height()), (texture.Width as u32, texture.Height as u32));
+        }
+    }
+
+    // Windows are hidden on their first frame, then auto-fit their contents over a frame
+    assert_eq!(count_pixels(&images[0], [0, 0, 0, 255]), 320 * 240);
+    assert_ne!(images[1], images[0]);
+    assert_eq!(images[3], images[2], "Rendering should be deterministic");
+
+    // The window background, the button and the text are drawn
+    let colors: std::collections::HashSet<_> = images[2].pixels().chunks_exact(4).collect();
+    assert!(colors.len() > 10, "The text should be drawn with anti-aliased edges");
+    // Button over the window background over the black clear color
+    let style = unsafe { &*igGetStyle() };
+    let (window_bg, button) = (style.Colors[Col::WindowBg as usize], style.Colors[Col::Button as usize]);
+    let blend = |fg: f32, bg: f32| fg * button.w + bg * window_bg.w * (1.0 - button.w);
+    let button_color = [blend(button.x, window_bg.x), blend(button.y, window_bg.y), blend(button.z, window_bg.z)]
+        .map(|c| (c * 255.0 + 0.5) as u8);
+    assert!(
+        images[2].pixels().chunks_exact(4).any(|pixel| pixel[..3].iter().zip(button_color).all(|(&a, b)| a.abs_diff(b) <= 2)),
+        "The button background should be drawn"
+    );
+}
+
+#[test]
+fn test_renderer_drop() {
+    let _lock = lock_current_context();
+    let (mut ctx, platform) = headless_context(320.0, 240.0);
+    let mut renderer = SoftwareRenderer::new(&ctx);
+    let frame = |ctx: &mut Context, renderer: &mut SoftwareRenderer| {
+        let ui = platform.new_frame(ctx);
+        if let Some(_window) = ui.begin("Software renderer") {
+            ui.text("Hello, world!");
+        }
+        renderer.render(&ui.render())
+    };
+    // Windows auto-fit their contents over their first frames
+    for _ in 0..2 {
+        frame(&mut ctx, &mut renderer);
+    }
+    let image = frame(&mut ctx, &mut renderer);
+
+    // The textures keep their pixels, so they are created again by the next renderer
+    drop(renderer);
+    unsafe {
+        let io = igGetIO();
+        assert!((*io).BackendRendererName.is_null());
+        assert_eq!((*io).BackendFlags & ImGuiBackendFlags__ImGuiBackendFlags_RendererHasTextures as i32, 0);
+        let textures = (*igGetPlatformIO()).Textures.as_slice();
+        assert!(!textures.is_empty());
+        for &texture in textures {
+            assert_eq!((*texture).TexID, 0);
+            assert_eq!((*texture).Status, TextureStatus::WantCreate.raw());
+        }
+    }
+    let mut renderer = SoftwareRenderer::new(&ctx);
+    assert_eq!(frame(&mut ctx, &mut renderer), image);
+
+    // Dropped after its context, the renderer has nothing to shut down
+    drop(platform);
+    drop(ctx);
+    drop(renderer);
+}
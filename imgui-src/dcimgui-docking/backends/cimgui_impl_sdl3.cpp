@@ -0,0 +1,170 @@
+// C wrapper implementation for Dear ImGui SDL3 backend
+// This provides a stable C ABI by wrapping the C++ functions
+
+#include "cimgui_impl_sdl3.h"
+#include "imgui_impl_sdl3.h"
+#include "imgui.h"
+#include <SDL3/SDL.h>
+#include <stdlib.h>
+
+// Time source of cImGui_ImplSDL3_NewFrame() for each context, see cImGui_ImplSDL3_SetDeltaTime()
+struct cImGui_ImplSDL3_TimeSettings {
+    ImGuiContext* Context;
+    float FixedDeltaTime;
+    cImGui_ImplSDL3_ClockFn Clock;
+    void* ClockUserData;
+    double ClockTime;
+    bool ClockStarted;
+};
+
+// Allocated with malloc, not IM_ALLOC which may count the allocations of the current context
+static cImGui_ImplSDL3_TimeSettings* g_TimeSettings = nullptr;
+static int g_TimeSettingsCount = 0;
+
+// Settings of the current context, added with the defaults when `create` is set
+static cImGui_ImplSDL3_TimeSettings* cImGui_ImplSDL3_GetTimeSettings(bool create) {
+    ImGuiContext* ctx = ImGui::GetCurrentContext();
+    for (int i = 0; i < g_TimeSettingsCount; i++)
+        if (g_TimeSettings[i].Context == ctx)
+            return &g_TimeSettings[i];
+    if (!create)
+        return nullptr;
+    IM_ASSERT(ctx != nullptr && "No current context");
+    cImGui_ImplSDL3_TimeSettings* settings = (cImGui_ImplSDL3_TimeSettings*)realloc(g_TimeSettings, sizeof(cImGui_ImplSDL3_TimeSettings) * (g_TimeSettingsCount + 1));
+    IM_ASSERT(settings != nullptr);
+    g_TimeSettings = settings;
+    settings = &g_TimeSettings[g_TimeSettingsCount++];
+    *settings = { ctx, 0.0f, nullptr, nullptr, 0.0, false };
+    return settings;
+}
+
+extern "C" {
+
+bool cImGui_ImplSDL3_InitForOpenGL(SDL_Window* window, void* sdl_gl_context) {
+    return ImGui_ImplSDL3_InitForOpenGL(window, sdl_gl_context);
+}
+
+bool cImGui_ImplSDL3_InitForVulkan(SDL_Window* window) {
+    return ImGui_ImplSDL3_InitForVulkan(window);
+}
+
+bool cImGui_ImplSDL3_InitForD3D(SDL_Window* window) {
+    return ImGui_ImplSDL3_InitForD3D(window);
+}
+
+bool cImGui_ImplSDL3_InitForMetal(SDL_Window* window) {
+    return ImGui_ImplSDL3_InitForMetal(window);
+}
+
+bool cImGui_ImplSDL3_InitForSDLRenderer(SDL_Window* window, SDL_Renderer* renderer) {
+    return ImGui_ImplSDL3_InitForSDLRenderer(window, renderer);
+}
+
+bool cImGui_ImplSDL3_InitForSDLGPU(SDL_Window* window) {
+    return ImGui_ImplSDL3_InitForSDLGPU(window);
+}
+
+bool cImGui_ImplSDL3_InitForOther(SDL_Window* window) {
+    return ImGui_ImplSDL3_InitForOther(window);
+}
+
+void cImGui_ImplSDL3_Shutdown(void) {
+    ImGui_ImplSDL3_Shutdown();
+    // The time settings of the context don't outlive the backend
+    if (cImGui_ImplSDL3_TimeSettings* settings = cImGui_ImplSDL3_GetTimeSettings(false)) {
+        *settings = g_TimeSettings[--g_TimeSettingsCount];
+        if (g_TimeSettingsCount == 0) {
+            free(g_TimeSettings);
+            g_TimeSettings = nullptr;
+        }
+    }
+}
+
+void cImGui_ImplSDL3_NewFrame(void) {
+    ImGui_ImplSDL3_NewFrame();
+    cImGui_ImplSDL3_TimeSettings* settings = cImGui_ImplSDL3_GetTimeSettings(false);
+    if (settings == nullptr)
+        return;
+    if (settings->FixedDeltaTime > 0.0f) {
+        ImGui::GetIO().DeltaTime = settings->FixedDeltaTime;
+    } else if (settings->Clock != nullptr) {
+        // Like the backend, a clock that didn't move still gives a positive delta time, which Dear ImGui asserts
+        double current_time = settings->Clock(settings->ClockUserData);
+        if (settings->ClockStarted && current_time <= settings->ClockTime)
+            current_time = settings->ClockTime + 1e-6;
+        ImGui::GetIO().DeltaTime = settings->ClockStarted ? (float)(current_time - settings->ClockTime) : 1.0f / 60.0f;
+        settings->ClockTime = current_time;
+        settings->ClockStarted = true;
+    }
+}
+
+bool cImGui_ImplSDL3_ProcessEvent(const SDL_Event* event) {
+    return ImGui_ImplSDL3_ProcessEvent(event);
+}
+
+bool cImGui_ImplSDL3_ProcessEventForMainWindow(const SDL_Event* event) {
+    // The backend ignores the events of the windows it doesn't know, the main viewport handle is its SDL window ID
+    SDL_WindowID window_id = (SDL_WindowID)(intptr_t)ImGui::GetMainViewport()->PlatformHandle;
+    SDL_Event main_window_event = *event;
+    switch (event->type) {
+    case SDL_EVENT_MOUSE_MOTION: main_window_event.motion.windowID = window_id; break;
+    case SDL_EVENT_MOUSE_WHEEL: main_window_event.wheel.windowID = window_id; break;
+    case SDL_EVENT_MOUSE_BUTTON_DOWN:
+    case SDL_EVENT_MOUSE_BUTTON_UP: main_window_event.button.windowID = window_id; break;
+    case SDL_EVENT_TEXT_INPUT: main_window_event.text.windowID = window_id; break;
+    case SDL_EVENT_KEY_DOWN:
+    case SDL_EVENT_KEY_UP: main_window_event.key.windowID = window_id; break;
+    default:
+        if (event->type >= SDL_EVENT_WINDOW_FIRST && event->type <= SDL_EVENT_WINDOW_LAST)
+            main_window_event.window.windowID = window_id;
+        break;
+    }
+    return ImGui_ImplSDL3_ProcessEvent(&main_window_event);
+}
+
+void cImGui_ImplSDL3_SetGamepadMode(cImGui_ImplSDL3_GamepadMode mode, SDL_Gamepad** manual_gamepads_array, int manual_gamepads_count) {
+    ImGui_ImplSDL3_SetGamepadMode(
+        static_cast<ImGui_ImplSDL3_GamepadMode>(mode),
+        manual_gamepads_array,
+        manual_gamepads_count
+    );
+}
+
+ImGuiViewport* cImGui_ImplSDL3_GetViewportForWindowID(uint32_t window_id) {
+    // The backend uses the SDL window ID as the viewport platform handle
+    return ImGui::FindViewportByPlatformHandle((void*)(intptr_t)window_id);
+}
+
+SDL_Window* cImGui_ImplSDL3_GetViewportWindow(ImGuiViewport* viewport) {
+    return SDL_GetWindowFromID((SDL_WindowID)(intptr_t)viewport->PlatformHandle);
+}
+
+void cImGui_ImplSDL3_UpdateAndRenderPlatformWindows(void) {
+    if (ImGui::GetIO().ConfigFlags & ImGuiConfigFlags_ViewportsEnable) {
+        ImGui::UpdatePlatformWindows();
+        ImGui::RenderPlatformWindowsDefault();
+    }
+}
+
+void cImGui_ImplSDL3_SetDeltaTime(float delta_time) {
+    IM_ASSERT(delta_time >= 0.0f);
+    // The defaults don't need settings
+    if (cImGui_ImplSDL3_TimeSettings* settings = cImGui_ImplSDL3_GetTimeSettings(delta_time != 0.0f))
+        settings->FixedDeltaTime = delta_time;
+}
+
+float cImGui_ImplSDL3_GetDeltaTime(void) {
+    cImGui_ImplSDL3_TimeSettings* settings = cImGui_ImplSDL3_GetTimeSettings(false);
+    return settings ? settings->FixedDeltaTime : 0.0f;
+}
+
+void cImGui_ImplSDL3_SetClock(cImGui_ImplSDL3_ClockFn clock, void* user_data) {
+    cImGui_ImplSDL3_TimeSettings* settings = cImGui_ImplSDL3_GetTimeSettings(clock != nullptr);
+    if (settings == nullptr)
+        return;
+    settings->Clock = clock;
+    settings->ClockUserData = user_data;
+    settings->ClockStarted = false;
+}
+
+} // extern "C"
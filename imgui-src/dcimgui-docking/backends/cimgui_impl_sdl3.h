@@ -0,0 +1,70 @@
+// C wrapper for Dear ImGui SDL3 backend
+// This provides a stable C ABI for the ImGui SDL3 backend functions
+
+#ifndef CIMGUI_IMPL_SDL3_H
+#define CIMGUI_IMPL_SDL3_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Forward declarations
+struct SDL_Window;
+struct SDL_Renderer;
+struct SDL_Gamepad;
+typedef union SDL_Event SDL_Event;
+typedef struct ImGuiViewport ImGuiViewport;
+
+// C ABI wrapper functions for ImGui SDL3 backend
+bool cImGui_ImplSDL3_InitForOpenGL(struct SDL_Window* window, void* sdl_gl_context);
+bool cImGui_ImplSDL3_InitForVulkan(struct SDL_Window* window);
+bool cImGui_ImplSDL3_InitForD3D(struct SDL_Window* window);
+bool cImGui_ImplSDL3_InitForMetal(struct SDL_Window* window);
+bool cImGui_ImplSDL3_InitForSDLRenderer(struct SDL_Window* window, struct SDL_Renderer* renderer);
+bool cImGui_ImplSDL3_InitForSDLGPU(struct SDL_Window* window);
+bool cImGui_ImplSDL3_InitForOther(struct SDL_Window* window);
+void cImGui_ImplSDL3_Shutdown(void);
+void cImGui_ImplSDL3_NewFrame(void);
+bool cImGui_ImplSDL3_ProcessEvent(const SDL_Event* event);
+// Processes an event as if it was sent to the main window, whatever its windowID. For synthetic or replayed events,
+// whose window may not exist anymore.
+bool cImGui_ImplSDL3_ProcessEventForMainWindow(const SDL_Event* event);
+
+// Gamepad mode enum
+typedef enum {
+    cImGui_ImplSDL3_GamepadMode_AutoFirst = 0,
+    cImGui_ImplSDL3_GamepadMode_AutoAll = 1,
+    cImGui_ImplSDL3_GamepadMode_Manual = 2
+} cImGui_ImplSDL3_GamepadMode;
+
+void cImGui_ImplSDL3_SetGamepadMode(cImGui_ImplSDL3_GamepadMode mode, struct SDL_Gamepad** manual_gamepads_array, int manual_gamepads_count);
+
+// Deterministic time
+// cImGui_ImplSDL3_NewFrame() measures io.DeltaTime with SDL_GetPerformanceCounter(). For recorded sessions and
+// screenshot tests, it can use a fixed delta time or a clock given by the caller instead. The setting belongs to the
+// current context, cImGui_ImplSDL3_Shutdown() resets it.
+// Returns the current time in seconds. The delta time is the difference with the previous frame, 1/60 on the first one.
+typedef double (*cImGui_ImplSDL3_ClockFn)(void* user_data);
+// Fixed delta time of each frame, in seconds. 0 goes back to the clock.
+void cImGui_ImplSDL3_SetDeltaTime(float delta_time);
+// Fixed delta time set with cImGui_ImplSDL3_SetDeltaTime(), 0 when the clock is used.
+float cImGui_ImplSDL3_GetDeltaTime(void);
+// Clock used when there's no fixed delta time. NULL goes back to SDL_GetPerformanceCounter().
+void cImGui_ImplSDL3_SetClock(cImGui_ImplSDL3_ClockFn clock, void* user_data);
+
+// Multi-viewports (docking branch)
+// With io.ConfigFlags |= ImGuiConfigFlags_ViewportsEnable, the backend creates an SDL window for each secondary
+// viewport (platform window) through the ImGuiPlatformIO callbacks, from igUpdatePlatformWindows().
+ImGuiViewport* cImGui_ImplSDL3_GetViewportForWindowID(uint32_t window_id);
+struct SDL_Window* cImGui_ImplSDL3_GetViewportWindow(ImGuiViewport* viewport);
+// Calls igUpdatePlatformWindows() and igRenderPlatformWindowsDefault() when viewports are enabled, after igRender()
+void cImGui_ImplSDL3_UpdateAndRenderPlatformWindows(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // CIMGUI_IMPL_SDL3_H
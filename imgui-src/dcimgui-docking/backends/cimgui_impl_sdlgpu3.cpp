@@ -0,0 +1,60 @@
+// C wrapper implementation for Dear ImGui SDL_GPU backend
+// This provides a stable C ABI by wrapping the C++ functions
+
+#include "imgui_impl_sdlgpu3.h"
+#include "cimgui_impl_sdlgpu3.h"
+
+#include <stddef.h>
+
+// platform_io.Renderer_RenderState points to the C++ struct, the C one must keep the same layout
+static_assert(sizeof(cImGui_ImplSDLGPU3_RenderState) == sizeof(ImGui_ImplSDLGPU3_RenderState), "RenderState size mismatch");
+static_assert(offsetof(cImGui_ImplSDLGPU3_RenderState, Device) == offsetof(ImGui_ImplSDLGPU3_RenderState, Device), "RenderState layout mismatch");
+static_assert(offsetof(cImGui_ImplSDLGPU3_RenderState, SamplerDefault) == offsetof(ImGui_ImplSDLGPU3_RenderState, SamplerDefault), "RenderState layout mismatch");
+static_assert(offsetof(cImGui_ImplSDLGPU3_RenderState, SamplerCurrent) == offsetof(ImGui_ImplSDLGPU3_RenderState, SamplerCurrent), "RenderState layout mismatch");
+
+extern "C" {
+
+bool cImGui_ImplSDLGPU3_Init(cImGui_ImplSDLGPU3_InitInfo* info) {
+    // Struct layouts are identical, but C++ compiler needs the correct type
+    ImGui_ImplSDLGPU3_InitInfo cpp_info;
+    cpp_info.Device = info->Device;
+    cpp_info.ColorTargetFormat = info->ColorTargetFormat;
+    cpp_info.MSAASamples = info->MSAASamples;
+    cpp_info.SwapchainComposition = info->SwapchainComposition;
+    cpp_info.PresentMode = info->PresentMode;
+    return ImGui_ImplSDLGPU3_Init(&cpp_info);
+}
+
+void cImGui_ImplSDLGPU3_Shutdown(void) {
+    ImGui_ImplSDLGPU3_Shutdown();
+}
+
+void cImGui_ImplSDLGPU3_NewFrame(void) {
+    ImGui_ImplSDLGPU3_NewFrame();
+}
+
+void cImGui_ImplSDLGPU3_PrepareDrawData(ImDrawData* draw_data, SDL_GPUCommandBuffer* command_buffer) {
+    ImGui_ImplSDLGPU3_PrepareDrawData(draw_data, command_buffer);
+}
+
+void cImGui_ImplSDLGPU3_RenderDrawData(ImDrawData* draw_data, SDL_GPUCommandBuffer* command_buffer, SDL_GPURenderPass* render_pass, SDL_GPUGraphicsPipeline* pipeline) {
+    ImGui_ImplSDLGPU3_RenderDrawData(draw_data, command_buffer, render_pass, pipeline);
+}
+
+void cImGui_ImplSDLGPU3_CreateDeviceObjects(void) {
+    ImGui_ImplSDLGPU3_CreateDeviceObjects();
+}
+
+void cImGui_ImplSDLGPU3_DestroyDeviceObjects(void) {
+    ImGui_ImplSDLGPU3_DestroyDeviceObjects();
+}
+
+void cImGui_ImplSDLGPU3_UpdateTexture(ImTextureData* tex) {
+    ImGui_ImplSDLGPU3_UpdateTexture(tex);
+}
+
+cImGui_ImplSDLGPU3_RenderState* cImGui_ImplSDLGPU3_GetRenderState(void) {
+    return static_cast<cImGui_ImplSDLGPU3_RenderState*>(ImGui::GetPlatformIO().Renderer_RenderState);
+}
+
+} // extern "C"
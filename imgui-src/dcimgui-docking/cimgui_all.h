@@ -0,0 +1 @@
+#include "cimgui.h"
@@ -27,6 +27,9 @@ bool cImGui_ImplSDL3_InitForOther(struct SDL_Window* window);
 void cImGui_ImplSDL3_Shutdown(void);
 void cImGui_ImplSDL3_NewFrame(void);
 bool cImGui_ImplSDL3_ProcessEvent(const SDL_Event* event);
+// Processes an event as if it was sent to the main window, whatever its windowID. For synthetic or replayed events,
+// whose window may not exist anymore.
+bool cImGui_ImplSDL3_ProcessEventForMainWindow(const SDL_Event* event);
 
 // Gamepad mode enum
 typedef enum {
@@ -37,6 +40,19 @@ typedef enum {
 
 void cImGui_ImplSDL3_SetGamepadMode(cImGui_ImplSDL3_GamepadMode mode, struct SDL_Gamepad** manual_gamepads_array, int manual_gamepads_count);
 
+// Deterministic time
+// cImGui_ImplSDL3_NewFrame() measures io.DeltaTime with SDL_GetPerformanceCounter(). For recorded sessions and
+// screenshot tests, it can use a fixed delta time or a clock given by the caller instead. The setting belongs to the
+// current context, cImGui_ImplSDL3_Shutdown() resets it.
+// Returns the current time in seconds. The delta time is the difference with the previous frame, 1/60 on the first one.
+typedef double (*cImGui_ImplSDL3_ClockFn)(void* user_data);
+// Fixed delta time of each frame, in seconds. 0 goes back to the clock.
+void cImGui_ImplSDL3_SetDeltaTime(float delta_time);
+// Fixed delta time set with cImGui_ImplSDL3_SetDeltaTime(), 0 when the clock is used.
+float cImGui_ImplSDL3_GetDeltaTime(void);
+// Clock used when there's no fixed delta time. NULL goes back to SDL_GetPerformanceCounter().
+void cImGui_ImplSDL3_SetClock(cImGui_ImplSDL3_ClockFn clock, void* user_data);
+
 #ifdef __cplusplus
 }
 #endif
@@ -4,6 +4,14 @@
 #include "imgui_impl_sdlgpu3.h"
 #include "cimgui_impl_sdlgpu3.h"
 
+#include <stddef.h>
+
+// platform_io.Renderer_RenderState points to the C++ struct, the C one must keep the same layout
+static_assert(sizeof(cImGui_ImplSDLGPU3_RenderState) == sizeof(ImGui_ImplSDLGPU3_RenderState), "RenderState size mismatch");
+static_assert(offsetof(cImGui_ImplSDLGPU3_RenderState, Device) == offsetof(ImGui_ImplSDLGPU3_RenderState, Device), "RenderState layout mismatch");
+static_assert(offsetof(cImGui_ImplSDLGPU3_RenderState, SamplerDefault) == offsetof(ImGui_ImplSDLGPU3_RenderState, SamplerDefault), "RenderState layout mismatch");
+static_assert(offsetof(cImGui_ImplSDLGPU3_RenderState, SamplerCurrent) == offsetof(ImGui_ImplSDLGPU3_RenderState, SamplerCurrent), "RenderState layout mismatch");
+
 extern "C" {
 
 bool cImGui_ImplSDLGPU3_Init(cImGui_ImplSDLGPU3_InitInfo* info) {
@@ -45,4 +53,8 @@ void cImGui_ImplSDLGPU3_UpdateTexture(ImTextureData* tex) {
     ImGui_ImplSDLGPU3_UpdateTexture(tex);
 }
 
+cImGui_ImplSDLGPU3_RenderState* cImGui_ImplSDLGPU3_GetRenderState(void) {
+    return static_cast<cImGui_ImplSDLGPU3_RenderState*>(ImGui::GetPlatformIO().Renderer_RenderState);
+}
+
 } // extern "C"
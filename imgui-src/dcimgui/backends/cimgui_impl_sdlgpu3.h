@@ -43,6 +43,10 @@ typedef struct {
     SDL_GPUSampler* SamplerCurrent;
 } cImGui_ImplSDLGPU3_RenderState;
 
+// Render state of the cImGui_ImplSDLGPU3_RenderDrawData() call in progress (platform_io.Renderer_RenderState).
+// Only valid from draw callbacks, which may change SamplerCurrent. Returns NULL outside of rendering.
+cImGui_ImplSDLGPU3_RenderState* cImGui_ImplSDLGPU3_GetRenderState(void);
+
 #ifdef __cplusplus
 }
 #endif
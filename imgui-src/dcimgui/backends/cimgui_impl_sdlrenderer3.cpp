@@ -0,0 +1,37 @@
+// C wrapper implementation for Dear ImGui SDL_Renderer backend
+// This provides a stable C ABI by wrapping the C++ functions
+
+#include "imgui_impl_sdlrenderer3.h"
+#include "cimgui_impl_sdlrenderer3.h"
+
+extern "C" {
+
+bool cImGui_ImplSDLRenderer3_Init(SDL_Renderer* renderer) {
+    return ImGui_ImplSDLRenderer3_Init(renderer);
+}
+
+void cImGui_ImplSDLRenderer3_Shutdown(void) {
+    ImGui_ImplSDLRenderer3_Shutdown();
+}
+
+void cImGui_ImplSDLRenderer3_NewFrame(void) {
+    ImGui_ImplSDLRenderer3_NewFrame();
+}
+
+void cImGui_ImplSDLRenderer3_RenderDrawData(ImDrawData* draw_data, SDL_Renderer* renderer) {
+    ImGui_ImplSDLRenderer3_RenderDrawData(draw_data, renderer);
+}
+
+void cImGui_ImplSDLRenderer3_CreateDeviceObjects(void) {
+    ImGui_ImplSDLRenderer3_CreateDeviceObjects();
+}
+
+void cImGui_ImplSDLRenderer3_DestroyDeviceObjects(void) {
+    ImGui_ImplSDLRenderer3_DestroyDeviceObjects();
+}
+
+void cImGui_ImplSDLRenderer3_UpdateTexture(ImTextureData* tex) {
+    ImGui_ImplSDLRenderer3_UpdateTexture(tex);
+}
+
+} // extern "C"
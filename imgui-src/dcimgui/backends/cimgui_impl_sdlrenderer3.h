@@ -0,0 +1,36 @@
+// C wrapper for Dear ImGui SDL_Renderer backend
+// This provides a stable C ABI for the ImGui SDL_Renderer backend functions
+
+#ifndef CIMGUI_IMPL_SDLRENDERER3_H
+#define CIMGUI_IMPL_SDLRENDERER3_H
+
+#include <stdbool.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Forward declarations
+struct SDL_Renderer;
+typedef struct ImDrawData ImDrawData;
+typedef struct ImTextureData ImTextureData;
+
+// C ABI wrapper functions for ImGui SDL_Renderer backend
+bool cImGui_ImplSDLRenderer3_Init(struct SDL_Renderer* renderer);
+void cImGui_ImplSDLRenderer3_Shutdown(void);
+void cImGui_ImplSDLRenderer3_NewFrame(void);
+void cImGui_ImplSDLRenderer3_RenderDrawData(ImDrawData* draw_data, struct SDL_Renderer* renderer);
+void cImGui_ImplSDLRenderer3_CreateDeviceObjects(void);
+void cImGui_ImplSDLRenderer3_DestroyDeviceObjects(void);
+void cImGui_ImplSDLRenderer3_UpdateTexture(ImTextureData* tex);
+
+// Render state struct
+typedef struct {
+    struct SDL_Renderer* Renderer;
+} cImGui_ImplSDLRenderer3_RenderState;
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // CIMGUI_IMPL_SDLRENDERER3_H
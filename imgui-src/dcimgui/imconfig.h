@@ -20,6 +20,17 @@
 //#define IM_ASSERT(_EXPR)  MyAssert(_EXPR)
 //#define IM_ASSERT(_EXPR)  ((void)(_EXPR))     // Disable asserts
 
+//---- imgui-sdl3-sys: IMGUI_ASSERT_HOOK (the `assert-hook` cargo feature) makes failed assertions call a Rust handler, also with NDEBUG.
+// It overrides an IM_ASSERT defined by IMGUI_USER_CONFIG.
+#ifdef IMGUI_ASSERT_HOOK
+#ifdef __cplusplus
+extern "C"
+#endif
+void cImGui_AssertFailed(const char* expr, const char* file, int line);
+#undef IM_ASSERT
+#define IM_ASSERT(_EXPR)  ((_EXPR) ? (void)0 : cImGui_AssertFailed(#_EXPR, __FILE__, __LINE__))
+#endif
+
 //---- Define attributes of all API symbols declarations, e.g. for DLL under Windows
 // Using Dear ImGui via a shared library is not recommended, because of function call overhead and because we don't guarantee backward nor forward ABI compatibility.
 // - Windows DLL users: heaps and globals are not shared across DLL boundaries! You will need to call SetCurrentContext() + SetAllocatorFunctions()
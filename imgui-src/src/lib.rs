@@ -2,4 +2,9 @@
 #[cfg(not(windows))]
 pub const SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/dcimgui");
 #[cfg(windows)]
-pub const SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "\\dcimgui");
\ No newline at end of file
+pub const SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "\\dcimgui");
+/// Location of the docking branch source code
+#[cfg(not(windows))]
+pub const DOCKING_SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/dcimgui-docking");
+#[cfg(windows)]
+pub const DOCKING_SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "\\dcimgui-docking");